@@ -0,0 +1,444 @@
+use std::fmt;
+
+use crate::class_file::error::{Error, Result};
+
+// Access flags of 4.1, 4.5, 4.6, 4.7.6, 4.7.24 and 4.7.25.
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html
+// The same bit has different meanings in different contexts, e.g. 0x0020 is ACC_SUPER of a class and ACC_SYNCHRONIZED of a method,
+// so each context has its own type. The structures keep these types, with the bits as they are in the class file.
+
+fn error<T>(message: String) -> Result<T> {
+    Err(Error { message: format!("Invalid access flags. {}", message) })
+}
+
+pub const ACC_PUBLIC: u16 = 0x0001;
+pub const ACC_PRIVATE: u16 = 0x0002;
+pub const ACC_PROTECTED: u16 = 0x0004;
+pub const ACC_STATIC: u16 = 0x0008;
+pub const ACC_FINAL: u16 = 0x0010;
+pub const ACC_SUPER: u16 = 0x0020;
+pub const ACC_SYNCHRONIZED: u16 = 0x0020;
+pub const ACC_OPEN: u16 = 0x0020;
+pub const ACC_TRANSITIVE: u16 = 0x0020;
+pub const ACC_VOLATILE: u16 = 0x0040;
+pub const ACC_BRIDGE: u16 = 0x0040;
+pub const ACC_STATIC_PHASE: u16 = 0x0040;
+pub const ACC_TRANSIENT: u16 = 0x0080;
+pub const ACC_VARARGS: u16 = 0x0080;
+pub const ACC_NATIVE: u16 = 0x0100;
+pub const ACC_INTERFACE: u16 = 0x0200;
+pub const ACC_ABSTRACT: u16 = 0x0400;
+pub const ACC_STRICT: u16 = 0x0800;
+pub const ACC_SYNTHETIC: u16 = 0x1000;
+pub const ACC_ANNOTATION: u16 = 0x2000;
+pub const ACC_ENUM: u16 = 0x4000;
+pub const ACC_MODULE: u16 = 0x8000;
+pub const ACC_MANDATED: u16 = 0x8000;
+
+/// (flag, ACC_ name, keyword) of the flags defined in a context.
+pub type FlagTable = &'static [(u16, &'static str, &'static str)];
+
+pub trait AccessFlags: Copy + fmt::Display {
+    const FLAGS: FlagTable;
+    /// The context in error messages, e.g. "class".
+    const CONTEXT: &'static str;
+
+    /// Keeps the bits that are not defined in the context, as the reader does.
+    fn from_bits_retain(bits: u16) -> Self;
+
+    fn bits(self) -> u16;
+
+    /// Drops the bits that are not defined in the context, as they are to be ignored (4.1, 4.5, 4.6).
+    fn from_bits_truncate(bits: u16) -> Self {
+        Self::from_bits_retain(bits & defined_bits(Self::FLAGS))
+    }
+
+    fn contains(self, flag: u16) -> bool {
+        self.bits() & flag == flag
+    }
+
+    /// The flag of a keyword, e.g. ACC_SUPER for "super" of a class.
+    fn flag_of_keyword(keyword: &str) -> Option<u16> {
+        Self::FLAGS.iter().find(|(_, _, k)| *k == keyword).map(|(flag, _, _)| *flag)
+    }
+
+    /// e.g. "public final". The bits not defined in the context are written in hex.
+    fn keywords(self) -> String {
+        names(Self::FLAGS, self.bits(), |(_, _, keyword)| keyword).join(" ")
+    }
+
+    /// e.g. "ACC_PUBLIC | ACC_FINAL". The bits not defined in the context are written in hex.
+    fn acc_names(self) -> String {
+        names(Self::FLAGS, self.bits(), |(_, acc_name, _)| acc_name).join(" | ")
+    }
+}
+
+fn defined_bits(table: FlagTable) -> u16 {
+    table.iter().fold(0, |all, (flag, _, _)| all | flag)
+}
+
+fn names(table: FlagTable, bits: u16, name: fn(&(u16, &'static str, &'static str)) -> &'static str) -> Vec<String> {
+    let mut names: Vec<String> = table.iter().filter(|(flag, _, _)| bits & flag != 0).map(|entry| name(entry).to_string()).collect();
+    let undefined = bits & !defined_bits(table);
+    if undefined != 0 {
+        names.push(format!("{:#06x}", undefined));
+    }
+    names
+}
+
+// `{}` writes the keywords and `{:#}` the ACC_ names.
+fn fmt_flags<T: AccessFlags>(flags: T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+        write!(f, "{}", flags.acc_names())
+    } else {
+        write!(f, "{}", flags.keywords())
+    }
+}
+
+fn check_at_most_one_access<T: AccessFlags>(flags: T) -> Result<()> {
+    let access = flags.bits() & (ACC_PUBLIC | ACC_PRIVATE | ACC_PROTECTED);
+    if access.count_ones() > 1 {
+        return error(format!("{} access flags can have at most one of ACC_PUBLIC, ACC_PRIVATE and ACC_PROTECTED: {:#}", T::CONTEXT, flags));
+    }
+    Ok(())
+}
+
+// The flags have none of `others`.
+fn check_none<T: AccessFlags>(flags: T, others: u16, rule: &str) -> Result<()> {
+    if flags.bits() & others != 0 {
+        return error(format!("{} access flags {}: {:#}", T::CONTEXT, rule, flags));
+    }
+    Ok(())
+}
+
+// The flags with `flag` have none of `others`.
+fn check_none_of<T: AccessFlags>(flags: T, flag: u16, others: u16, rule: &str) -> Result<()> {
+    if flags.contains(flag) {
+        check_none(flags, others, rule)?;
+    }
+    Ok(())
+}
+
+// Debug shows the number itself, as CpIndex does, so that the structures are printed as when the flags were a u16.
+macro_rules! impl_fmt {
+    ($($name:ident),*) => {
+        $(impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_flags(*self, f)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        })*
+    };
+}
+
+// 4.1. The ClassFile Structure, Table 4.1-B
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct ClassAccessFlags(u16);
+
+impl AccessFlags for ClassAccessFlags {
+    const FLAGS: FlagTable = &[
+        (ACC_PUBLIC, "ACC_PUBLIC", "public"), (ACC_FINAL, "ACC_FINAL", "final"), (ACC_SUPER, "ACC_SUPER", "super"),
+        (ACC_INTERFACE, "ACC_INTERFACE", "interface"), (ACC_ABSTRACT, "ACC_ABSTRACT", "abstract"),
+        (ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"), (ACC_ANNOTATION, "ACC_ANNOTATION", "annotation"),
+        (ACC_ENUM, "ACC_ENUM", "enum"), (ACC_MODULE, "ACC_MODULE", "module"),
+    ];
+    const CONTEXT: &'static str = "class";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        ClassAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+impl ClassAccessFlags {
+    pub fn check(self) -> Result<()> {
+        check_none_of(self, ACC_MODULE, !ACC_MODULE, "with ACC_MODULE can have no other flags")?;
+        if self.contains(ACC_INTERFACE) {
+            if !self.contains(ACC_ABSTRACT) {
+                return error(format!("An interface must have ACC_ABSTRACT: {:#}", self));
+            }
+            check_none_of(self, ACC_INTERFACE, ACC_FINAL | ACC_SUPER | ACC_ENUM | ACC_MODULE, "with ACC_INTERFACE can not have ACC_FINAL, ACC_SUPER, ACC_ENUM or ACC_MODULE")?;
+        } else {
+            check_none(self, ACC_ANNOTATION, "without ACC_INTERFACE can not have ACC_ANNOTATION")?;
+            check_none_of(self, ACC_FINAL, ACC_ABSTRACT, "can not have both ACC_FINAL and ACC_ABSTRACT")?;
+        }
+        Ok(())
+    }
+}
+
+// 4.5. Fields, Table 4.5-A
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct FieldAccessFlags(u16);
+
+impl AccessFlags for FieldAccessFlags {
+    const FLAGS: FlagTable = &[
+        (ACC_PUBLIC, "ACC_PUBLIC", "public"), (ACC_PRIVATE, "ACC_PRIVATE", "private"), (ACC_PROTECTED, "ACC_PROTECTED", "protected"),
+        (ACC_STATIC, "ACC_STATIC", "static"), (ACC_FINAL, "ACC_FINAL", "final"), (ACC_VOLATILE, "ACC_VOLATILE", "volatile"),
+        (ACC_TRANSIENT, "ACC_TRANSIENT", "transient"), (ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"), (ACC_ENUM, "ACC_ENUM", "enum"),
+    ];
+    const CONTEXT: &'static str = "field";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        FieldAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+impl FieldAccessFlags {
+    /// `class` is the access flags of the class that declares the field.
+    pub fn check(self, class: ClassAccessFlags) -> Result<()> {
+        check_at_most_one_access(self)?;
+        check_none_of(self, ACC_FINAL, ACC_VOLATILE, "can not have both ACC_FINAL and ACC_VOLATILE")?;
+        let interface_field = ACC_PUBLIC | ACC_STATIC | ACC_FINAL;
+        if class.contains(ACC_INTERFACE) && (!self.contains(interface_field) || self.bits() & !(interface_field | ACC_SYNTHETIC) != 0) {
+            return error(format!("A field of an interface must have ACC_PUBLIC, ACC_STATIC and ACC_FINAL, and may have ACC_SYNTHETIC only: {:#}", self));
+        }
+        Ok(())
+    }
+}
+
+// 4.6. Methods, Table 4.6-A
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct MethodAccessFlags(u16);
+
+impl AccessFlags for MethodAccessFlags {
+    const FLAGS: FlagTable = &[
+        (ACC_PUBLIC, "ACC_PUBLIC", "public"), (ACC_PRIVATE, "ACC_PRIVATE", "private"), (ACC_PROTECTED, "ACC_PROTECTED", "protected"),
+        (ACC_STATIC, "ACC_STATIC", "static"), (ACC_FINAL, "ACC_FINAL", "final"), (ACC_SYNCHRONIZED, "ACC_SYNCHRONIZED", "synchronized"),
+        (ACC_BRIDGE, "ACC_BRIDGE", "bridge"), (ACC_VARARGS, "ACC_VARARGS", "varargs"), (ACC_NATIVE, "ACC_NATIVE", "native"),
+        (ACC_ABSTRACT, "ACC_ABSTRACT", "abstract"), (ACC_STRICT, "ACC_STRICT", "strictfp"), (ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"),
+    ];
+    const CONTEXT: &'static str = "method";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        MethodAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+impl MethodAccessFlags {
+    /// `class` is the access flags of the class that declares the method, and `name` is the name of the method.
+    /// Of the flags of `<clinit>`, only ACC_STATIC is checked, as the others are ignored (2.9.2).
+    pub fn check(self, class: ClassAccessFlags, name: &str, major_version: u16) -> Result<()> {
+        if name == "<clinit>" {
+            if major_version >= 51 && !self.contains(ACC_STATIC) {
+                return error(format!("<clinit> must have ACC_STATIC in 51.0 and above: {:#}", self));
+            }
+            return Ok(());
+        }
+        check_at_most_one_access(self)?;
+        if class.contains(ACC_INTERFACE) {
+            if major_version < 52 {
+                if !self.contains(ACC_PUBLIC | ACC_ABSTRACT) {
+                    return error(format!("A method of an interface before 52.0 must have ACC_PUBLIC and ACC_ABSTRACT: {:#}", self));
+                }
+            } else {
+                check_none(self, ACC_PROTECTED | ACC_FINAL | ACC_SYNCHRONIZED | ACC_NATIVE,
+                           "of an interface can not have ACC_PROTECTED, ACC_FINAL, ACC_SYNCHRONIZED or ACC_NATIVE")?;
+                if self.bits() & (ACC_PUBLIC | ACC_PRIVATE) == 0 {
+                    return error(format!("A method of an interface must have one of ACC_PUBLIC and ACC_PRIVATE: {:#}", self));
+                }
+            }
+        }
+        // ACC_STRICT is not allowed with ACC_ABSTRACT in 46.0 to 60.0, where it has a meaning.
+        let strict = if (46..61).contains(&major_version) { ACC_STRICT } else { 0 };
+        check_none_of(self, ACC_ABSTRACT, ACC_PRIVATE | ACC_STATIC | ACC_FINAL | ACC_SYNCHRONIZED | ACC_NATIVE | strict,
+                      "with ACC_ABSTRACT can not have ACC_PRIVATE, ACC_STATIC, ACC_FINAL, ACC_SYNCHRONIZED, ACC_NATIVE or ACC_STRICT")?;
+        if name == "<init>" {
+            check_none(self, !(ACC_PUBLIC | ACC_PRIVATE | ACC_PROTECTED | ACC_VARARGS | ACC_STRICT | ACC_SYNTHETIC),
+                       "of <init> can have ACC_PUBLIC, ACC_PRIVATE, ACC_PROTECTED, ACC_VARARGS, ACC_STRICT and ACC_SYNTHETIC only")?;
+        }
+        Ok(())
+    }
+}
+
+// 4.7.6. The InnerClasses Attribute, Table 4.7.6-A
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct InnerClassAccessFlags(u16);
+
+impl AccessFlags for InnerClassAccessFlags {
+    const FLAGS: FlagTable = &[
+        (ACC_PUBLIC, "ACC_PUBLIC", "public"), (ACC_PRIVATE, "ACC_PRIVATE", "private"), (ACC_PROTECTED, "ACC_PROTECTED", "protected"),
+        (ACC_STATIC, "ACC_STATIC", "static"), (ACC_FINAL, "ACC_FINAL", "final"), (ACC_INTERFACE, "ACC_INTERFACE", "interface"),
+        (ACC_ABSTRACT, "ACC_ABSTRACT", "abstract"), (ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"),
+        (ACC_ANNOTATION, "ACC_ANNOTATION", "annotation"), (ACC_ENUM, "ACC_ENUM", "enum"),
+    ];
+    const CONTEXT: &'static str = "inner class";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        InnerClassAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+impl InnerClassAccessFlags {
+    pub fn check(self) -> Result<()> {
+        check_at_most_one_access(self)?;
+        check_none_of(self, ACC_INTERFACE, ACC_FINAL | ACC_ENUM, "with ACC_INTERFACE can not have ACC_FINAL or ACC_ENUM")?;
+        if self.contains(ACC_ANNOTATION) && !self.contains(ACC_INTERFACE) {
+            return error(format!("An inner class with ACC_ANNOTATION must have ACC_INTERFACE: {:#}", self));
+        }
+        Ok(())
+    }
+}
+
+// 4.7.24. The MethodParameters Attribute
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct ParameterAccessFlags(u16);
+
+impl AccessFlags for ParameterAccessFlags {
+    const FLAGS: FlagTable = &[
+        (ACC_FINAL, "ACC_FINAL", "final"), (ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"), (ACC_MANDATED, "ACC_MANDATED", "mandated"),
+    ];
+    const CONTEXT: &'static str = "parameter";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        ParameterAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+// 4.7.25. The Module Attribute, module_flags
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct ModuleAccessFlags(u16);
+
+impl AccessFlags for ModuleAccessFlags {
+    const FLAGS: FlagTable = &[
+        (ACC_OPEN, "ACC_OPEN", "open"), (ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"), (ACC_MANDATED, "ACC_MANDATED", "mandated"),
+    ];
+    const CONTEXT: &'static str = "module";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        ModuleAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+// 4.7.25. The Module Attribute, requires_flags
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct RequiresAccessFlags(u16);
+
+impl AccessFlags for RequiresAccessFlags {
+    const FLAGS: FlagTable = &[
+        (ACC_TRANSITIVE, "ACC_TRANSITIVE", "transitive"), (ACC_STATIC_PHASE, "ACC_STATIC_PHASE", "static"),
+        (ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"), (ACC_MANDATED, "ACC_MANDATED", "mandated"),
+    ];
+    const CONTEXT: &'static str = "requires";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        RequiresAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+// 4.7.25. The Module Attribute, exports_flags and opens_flags
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct ExportsAccessFlags(u16);
+
+impl AccessFlags for ExportsAccessFlags {
+    const FLAGS: FlagTable = &[(ACC_SYNTHETIC, "ACC_SYNTHETIC", "synthetic"), (ACC_MANDATED, "ACC_MANDATED", "mandated")];
+    const CONTEXT: &'static str = "exports or opens";
+
+    fn from_bits_retain(bits: u16) -> Self {
+        ExportsAccessFlags(bits)
+    }
+
+    fn bits(self) -> u16 {
+        self.0
+    }
+}
+
+impl_fmt!(
+    ClassAccessFlags, FieldAccessFlags, MethodAccessFlags, InnerClassAccessFlags,
+    ParameterAccessFlags, ModuleAccessFlags, RequiresAccessFlags, ExportsAccessFlags
+);
+
+#[test]
+fn test_from_bits_truncate() {
+    assert_eq!(ClassAccessFlags::from_bits_truncate(0x0021).bits(), 0x0021);
+    // ACC_PRIVATE is not defined for classes.
+    assert_eq!(ClassAccessFlags::from_bits_truncate(0x0022).bits(), 0x0020);
+    // 0x0020 is ACC_SYNCHRONIZED for methods, and not defined for fields.
+    assert_eq!(MethodAccessFlags::from_bits_truncate(0x0020).bits(), 0x0020);
+    assert_eq!(FieldAccessFlags::from_bits_truncate(0x0020).bits(), 0);
+    assert_eq!(ExportsAccessFlags::from_bits_truncate(0x8020).bits(), 0x8000);
+    assert_eq!(RequiresAccessFlags::from_bits_retain(0x0021).bits(), 0x0021);
+}
+
+#[test]
+fn test_display() {
+    let flags = ClassAccessFlags::from_bits_retain(0x0421);
+    assert_eq!(format!("{}", flags), "public super abstract");
+    assert_eq!(format!("{:#}", flags), "ACC_PUBLIC | ACC_SUPER | ACC_ABSTRACT");
+    assert_eq!(format!("{}", MethodAccessFlags::from_bits_retain(0x0829)), "public static synchronized strictfp");
+    assert_eq!(format!("{:#}", RequiresAccessFlags::from_bits_retain(0x8041)), "ACC_STATIC_PHASE | ACC_MANDATED | 0x0001");
+    assert_eq!(format!("{}", ParameterAccessFlags::from_bits_retain(0)), "");
+    assert_eq!(MethodAccessFlags::flag_of_keyword("strictfp"), Some(ACC_STRICT));
+    assert_eq!(FieldAccessFlags::flag_of_keyword("super"), None);
+}
+
+#[test]
+fn test_check() {
+    let class = ClassAccessFlags::from_bits_retain(ACC_PUBLIC | ACC_SUPER);
+    let interface = ClassAccessFlags::from_bits_retain(ACC_PUBLIC | ACC_INTERFACE | ACC_ABSTRACT);
+    assert!(class.check().is_ok());
+    assert!(interface.check().is_ok());
+    assert!(ClassAccessFlags::from_bits_retain(ACC_INTERFACE).check().is_err());
+    assert!(ClassAccessFlags::from_bits_retain(ACC_FINAL | ACC_ABSTRACT).check().is_err());
+    assert!(ClassAccessFlags::from_bits_retain(ACC_MODULE | ACC_PUBLIC).check().is_err());
+
+    assert!(FieldAccessFlags::from_bits_retain(ACC_PUBLIC | ACC_PRIVATE).check(class).is_err());
+    assert!(FieldAccessFlags::from_bits_retain(ACC_FINAL | ACC_VOLATILE).check(class).is_err());
+    assert!(FieldAccessFlags::from_bits_retain(ACC_PRIVATE).check(interface).is_err());
+    assert!(FieldAccessFlags::from_bits_retain(ACC_PUBLIC | ACC_STATIC | ACC_FINAL).check(interface).is_ok());
+
+    let method = |bits: u16| MethodAccessFlags::from_bits_retain(bits);
+    assert!(method(ACC_PUBLIC | ACC_ABSTRACT).check(class, "run", 61).is_ok());
+    assert!(method(ACC_PRIVATE | ACC_ABSTRACT).check(class, "run", 61).is_err());
+    assert!(method(ACC_STRICT | ACC_ABSTRACT).check(class, "run", 52).is_err());
+    assert!(method(ACC_STRICT | ACC_ABSTRACT).check(class, "run", 61).is_ok());
+    assert!(method(ACC_PRIVATE).check(interface, "run", 61).is_ok());
+    assert!(method(ACC_PRIVATE).check(interface, "run", 51).is_err());
+    assert!(method(ACC_STATIC).check(interface, "run", 61).is_err());
+    assert!(method(ACC_PUBLIC | ACC_STATIC).check(class, "<init>", 61).is_err());
+    assert!(method(ACC_STATIC | ACC_PRIVATE).check(class, "<clinit>", 61).is_ok());
+    assert!(method(0).check(class, "<clinit>", 61).is_err());
+    assert!(method(0).check(class, "<clinit>", 50).is_ok());
+
+    assert!(InnerClassAccessFlags::from_bits_retain(ACC_STATIC | ACC_INTERFACE | ACC_ABSTRACT).check().is_ok());
+    assert!(InnerClassAccessFlags::from_bits_retain(ACC_ANNOTATION).check().is_err());
+}
@@ -0,0 +1,426 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::class_file::error::{Error, Result};
+use crate::class_file::instruction::{Instruction, decode};
+use crate::class_file::structure::attribute::CodeAttributeInfo;
+
+fn error<T>(message: String) -> Result<T> {
+    Err(Error { message: format!("Building control flow graph failed. {}", message) })
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum EdgeKind {
+    FallThrough,
+    // goto, and a conditional branch when taken
+    Jump,
+    // a case of tableswitch or lookupswitch, None for the default
+    Case(Option<i32>),
+    // jsr to a subroutine
+    Subroutine,
+    // ret back to the instruction after a jsr. Every jsr is assumed, since the return address is only known at run time.
+    Return,
+    // to an exception handler, with the catch_type of the exception table (0 for any)
+    Exception(u16),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: EdgeKind,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BasicBlock {
+    pub start_pc: u16,
+    // exclusive
+    pub end_pc: u16,
+    pub instructions: Vec<(u16, Instruction)>,
+}
+
+/// The basic blocks of a method body, in the order of their pc, and the edges between them.
+/// Blocks are also split at the bounds of the exception table ranges, so that a block is either entirely in a range or not.
+#[derive(Debug, PartialEq)]
+pub struct ControlFlowGraph {
+    pub blocks: Vec<BasicBlock>,
+    pub edges: Vec<Edge>,
+}
+
+impl ControlFlowGraph {
+    pub fn build(code: &CodeAttributeInfo) -> Result<ControlFlowGraph> {
+        let instructions = decode(&code.code)?;
+        let is_instruction_start = |pc: u16| instructions.binary_search_by_key(&pc, |(pc, _)| *pc).is_ok();
+        let code_length = code.code.len();
+
+        let mut leaders: BTreeSet<u16> = BTreeSet::from([0]);
+        for (index, (pc, instruction)) in instructions.iter().enumerate() {
+            let targets = instruction.branch_targets(*pc);
+            let ends_block = !targets.is_empty() || !instruction.falls_through();
+            leaders.extend(targets);
+            if let (true, Some((next_pc, _))) = (ends_block, instructions.get(index + 1)) {
+                leaders.insert(*next_pc);
+            }
+        }
+        for entry in &code.exception_table {
+            let is_end_valid = entry.end_pc as usize == code_length || is_instruction_start(entry.end_pc);
+            if entry.start_pc >= entry.end_pc || !is_instruction_start(entry.start_pc) || !is_end_valid || !is_instruction_start(entry.handler_pc) {
+                return error(format!("invalid exception table entry: [{}, {}) -> {}", entry.start_pc, entry.end_pc, entry.handler_pc));
+            }
+            leaders.insert(entry.start_pc);
+            leaders.insert(entry.handler_pc);
+            if (entry.end_pc as usize) < code_length {
+                leaders.insert(entry.end_pc);
+            }
+        }
+
+        let mut blocks: Vec<BasicBlock> = Vec::new();
+        for (pc, instruction) in instructions {
+            if leaders.contains(&pc) {
+                blocks.push(BasicBlock { start_pc: pc, end_pc: pc, instructions: Vec::new() });
+            }
+            blocks.last_mut().unwrap().instructions.push((pc, instruction));
+        }
+        for index in 0..blocks.len() {
+            blocks[index].end_pc = match blocks.get(index + 1) {
+                Some(next) => next.start_pc,
+                None => code_length as u16,
+            };
+        }
+        let block_of: BTreeMap<u16, usize> = blocks.iter().enumerate().map(|(index, block)| (block.start_pc, index)).collect();
+
+        // the blocks that ret may return to
+        let return_points: Vec<usize> = blocks.iter().enumerate()
+            .filter(|(_, block)| matches!(block.instructions.last(), Some((_, Instruction::Jsr(_) | Instruction::JsrW(_)))))
+            .map(|(index, _)| index + 1)
+            .filter(|index| *index < blocks.len())
+            .collect();
+
+        let mut edges: Vec<Edge> = Vec::new();
+        for (from, block) in blocks.iter().enumerate() {
+            let (pc, instruction) = block.instructions.last().unwrap();
+            let mut add = |to_pc: u16, kind: EdgeKind| edges.push(Edge { from, to: block_of[&to_pc], kind });
+            match instruction.unwide() {
+                Instruction::Tableswitch { default, low, offsets, .. } => {
+                    for (key, offset) in (*low..).zip(offsets) {
+                        add((*pc as i32 + offset) as u16, EdgeKind::Case(Some(key)));
+                    }
+                    add((*pc as i32 + default) as u16, EdgeKind::Case(None));
+                }
+                Instruction::Lookupswitch { default, pairs } => {
+                    for (key, offset) in pairs {
+                        add((*pc as i32 + offset) as u16, EdgeKind::Case(Some(*key)));
+                    }
+                    add((*pc as i32 + default) as u16, EdgeKind::Case(None));
+                }
+                Instruction::Jsr(_) | Instruction::JsrW(_) => {
+                    add(instruction.branch_targets(*pc)[0], EdgeKind::Subroutine);
+                }
+                Instruction::Ret(_) => {
+                    for to in &return_points {
+                        edges.push(Edge { from, to: *to, kind: EdgeKind::Return });
+                    }
+                }
+                _ => {
+                    for target in instruction.branch_targets(*pc) {
+                        add(target, EdgeKind::Jump);
+                    }
+                }
+            }
+            if instruction.falls_through() {
+                match blocks.get(from + 1) {
+                    Some(_) => edges.push(Edge { from, to: from + 1, kind: EdgeKind::FallThrough }),
+                    None => return error(format!("falling off the end of the code at pc {}", pc)),
+                }
+            }
+        }
+        for entry in &code.exception_table {
+            let handler = block_of[&entry.handler_pc];
+            for (from, block) in blocks.iter().enumerate() {
+                if entry.start_pc <= block.start_pc && block.start_pc < entry.end_pc {
+                    edges.push(Edge { from, to: handler, kind: EdgeKind::Exception(entry.catch_type.index()) });
+                }
+            }
+        }
+        Ok(ControlFlowGraph { blocks, edges })
+    }
+
+    /// The block containing the instruction at pc.
+    pub fn block_at(&self, pc: u16) -> Option<usize> {
+        self.blocks.iter().position(|block| block.start_pc <= pc && pc < block.end_pc)
+    }
+
+    pub fn successors(&self, block: usize) -> Vec<usize> {
+        let mut successors: Vec<usize> = self.edges.iter().filter(|edge| edge.from == block).map(|edge| edge.to).collect();
+        successors.sort();
+        successors.dedup();
+        successors
+    }
+
+    pub fn predecessors(&self, block: usize) -> Vec<usize> {
+        let mut predecessors: Vec<usize> = self.edges.iter().filter(|edge| edge.to == block).map(|edge| edge.from).collect();
+        predecessors.sort();
+        predecessors.dedup();
+        predecessors
+    }
+
+    /// Dominators from the first block, following exceptional edges as well.
+    pub fn dominators(&self) -> DominatorTree {
+        let successors: Vec<Vec<usize>> = (0..self.blocks.len()).map(|block| self.successors(block)).collect();
+        let mut immediate_dominators = immediate_dominators(&successors, 0);
+        immediate_dominators[0] = None;
+        DominatorTree { immediate_dominators }
+    }
+
+    /// Post-dominators towards a virtual exit that all blocks without successors lead to.
+    /// The blocks whose immediate post-dominator would be the virtual exit have none.
+    pub fn post_dominators(&self) -> DominatorTree {
+        let exit = self.blocks.len();
+        let mut reversed: Vec<Vec<usize>> = (0..self.blocks.len()).map(|block| self.predecessors(block)).collect();
+        reversed.push((0..self.blocks.len()).filter(|block| self.successors(*block).is_empty()).collect());
+        let mut immediate_dominators = immediate_dominators(&reversed, exit);
+        immediate_dominators.truncate(exit);
+        for immediate_dominator in immediate_dominators.iter_mut() {
+            if *immediate_dominator == Some(exit) {
+                *immediate_dominator = None;
+            }
+        }
+        DominatorTree { immediate_dominators }
+    }
+
+    /// Graphviz DOT, e.g. for `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph cfg {{").unwrap();
+        writeln!(dot, "    node [shape=box, fontname=\"monospace\"];").unwrap();
+        for (index, block) in self.blocks.iter().enumerate() {
+            let mut label = format!("B{}\\l", index);
+            for (pc, instruction) in &block.instructions {
+                let targets = instruction.branch_targets(*pc);
+                match instruction {
+                    Instruction::Tableswitch { .. } | Instruction::Lookupswitch { .. } => write!(label, "{}: {}\\l", pc, instruction.mnemonic()),
+                    _ if !targets.is_empty() => write!(label, "{}: {} {}\\l", pc, instruction.mnemonic(), targets[0]),
+                    _ => write!(label, "{}: {}\\l", pc, instruction),
+                }.unwrap();
+            }
+            writeln!(dot, "    B{} [label=\"{}\"];", index, label).unwrap();
+        }
+        for edge in &self.edges {
+            let attributes = match &edge.kind {
+                EdgeKind::FallThrough | EdgeKind::Jump => String::new(),
+                EdgeKind::Case(Some(key)) => format!(" [label=\"{}\"]", key),
+                EdgeKind::Case(None) => " [label=\"default\"]".to_string(),
+                EdgeKind::Subroutine => " [label=\"jsr\"]".to_string(),
+                EdgeKind::Return => " [label=\"ret\", style=dotted]".to_string(),
+                EdgeKind::Exception(0) => " [label=\"any\", style=dashed]".to_string(),
+                EdgeKind::Exception(catch_type) => format!(" [label=\"#{}\", style=dashed]", catch_type),
+            };
+            writeln!(dot, "    B{} -> B{}{};", edge.from, edge.to, attributes).unwrap();
+        }
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+}
+
+/// A dominator tree over the blocks of a ControlFlowGraph.
+#[derive(Debug, PartialEq)]
+pub struct DominatorTree {
+    // None for the root and for the blocks not reachable from it
+    pub immediate_dominators: Vec<Option<usize>>,
+}
+
+impl DominatorTree {
+    pub fn immediate_dominator(&self, block: usize) -> Option<usize> {
+        self.immediate_dominators[block]
+    }
+
+    /// Whether every path to `block` goes through `dominator`. A block dominates itself.
+    pub fn dominates(&self, dominator: usize, block: usize) -> bool {
+        let mut current = Some(block);
+        while let Some(b) = current {
+            if b == dominator {
+                return true;
+            }
+            current = self.immediate_dominators[b];
+        }
+        false
+    }
+
+    pub fn children(&self, block: usize) -> Vec<usize> {
+        (0..self.immediate_dominators.len()).filter(|b| self.immediate_dominators[*b] == Some(block)).collect()
+    }
+}
+
+// "A Simple, Fast Dominance Algorithm" by Cooper, Harvey and Kennedy.
+// Returns the immediate dominator of each node, the root itself for the root, and None for unreachable nodes.
+fn immediate_dominators(successors: &[Vec<usize>], root: usize) -> Vec<Option<usize>> {
+    // postorder by an iterative depth first search
+    let mut postorder: Vec<usize> = Vec::new();
+    let mut visited = vec![false; successors.len()];
+    let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+    visited[root] = true;
+    while let Some((node, next_child)) = stack.pop() {
+        match successors[node].get(next_child) {
+            Some(child) => {
+                stack.push((node, next_child + 1));
+                if !visited[*child] {
+                    visited[*child] = true;
+                    stack.push((*child, 0));
+                }
+            }
+            None => postorder.push(node),
+        }
+    }
+    let mut postorder_number = vec![usize::MAX; successors.len()];
+    for (number, node) in postorder.iter().enumerate() {
+        postorder_number[*node] = number;
+    }
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); successors.len()];
+    for (node, children) in successors.iter().enumerate() {
+        for child in children {
+            predecessors[*child].push(node);
+        }
+    }
+
+    let mut immediate_dominators: Vec<Option<usize>> = vec![None; successors.len()];
+    immediate_dominators[root] = Some(root);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for node in postorder.iter().rev().filter(|node| **node != root) {
+            let mut new_dominator: Option<usize> = None;
+            for predecessor in &predecessors[*node] {
+                if immediate_dominators[*predecessor].is_none() {
+                    continue;
+                }
+                new_dominator = Some(match new_dominator {
+                    None => *predecessor,
+                    Some(dominator) => {
+                        let (mut a, mut b) = (*predecessor, dominator);
+                        while a != b {
+                            while postorder_number[a] < postorder_number[b] {
+                                a = immediate_dominators[a].unwrap();
+                            }
+                            while postorder_number[b] < postorder_number[a] {
+                                b = immediate_dominators[b].unwrap();
+                            }
+                        }
+                        a
+                    }
+                });
+            }
+            if immediate_dominators[*node] != new_dominator {
+                immediate_dominators[*node] = new_dominator;
+                changed = true;
+            }
+        }
+    }
+    immediate_dominators
+}
+
+#[cfg(test)]
+fn code_attribute(code: Vec<u8>, exception_table: Vec<crate::class_file::structure::attribute::ExceptionTable>) -> CodeAttributeInfo {
+    CodeAttributeInfo {
+        attribute_name_index: crate::class_file::structure::constant_pool::CpIndex::new(0),
+        attribute_length: 0,
+        max_stack: 0,
+        max_locals: 0,
+        code_length: code.len() as u32,
+        code,
+        exception_table_length: exception_table.len() as u16,
+        exception_table,
+        attributes_count: 0,
+        attributes: vec![],
+    }
+}
+
+#[test]
+fn test_loop() {
+    // static int sum(int[] values) {
+    //     int total = 0;
+    //     for (int v : values) {
+    //         total += v;
+    //     }
+    //     return total;
+    // }
+    let code = code_attribute(vec![
+        0x03, 0x3c, 0x2a, 0x4d, 0x2c, 0xbe, 0x3e, 0x03, 0x36, 0x04, // 0: iconst_0 ... 8: istore 4
+        0x15, 0x04, 0x1d, 0xa2, 0x00, 0x14, // 10: iload 4, 12: iload_3, 13: if_icmpge 33
+        0x2c, 0x15, 0x04, 0x2e, 0x36, 0x05, 0x1b, 0x15, 0x05, 0x60, 0x3c, 0x84, 0x04, 0x01, 0xa7, 0xff, 0xec, // 16: aload_2 ... 30: goto 10
+        0x1b, 0xac, // 33: iload_1, 34: ireturn
+    ], vec![]);
+    let cfg = ControlFlowGraph::build(&code).unwrap();
+    assert_eq!(cfg.blocks.iter().map(|block| (block.start_pc, block.end_pc)).collect::<Vec<_>>(), vec![(0, 10), (10, 16), (16, 33), (33, 35)]);
+    assert_eq!(cfg.edges, vec![
+        Edge { from: 0, to: 1, kind: EdgeKind::FallThrough },
+        Edge { from: 1, to: 3, kind: EdgeKind::Jump },
+        Edge { from: 1, to: 2, kind: EdgeKind::FallThrough },
+        Edge { from: 2, to: 1, kind: EdgeKind::Jump },
+    ]);
+    assert_eq!(cfg.dominators().immediate_dominators, vec![None, Some(0), Some(1), Some(1)]);
+    assert_eq!(cfg.post_dominators().immediate_dominators, vec![Some(1), Some(3), Some(1), None]);
+    assert!(cfg.dominators().dominates(1, 2));
+    assert!(!cfg.dominators().dominates(2, 3));
+    assert_eq!(cfg.dominators().children(1), vec![2, 3]);
+}
+
+#[test]
+fn test_switch_with_shared_targets() {
+    // static int pick(int k) {
+    //     switch (k) {
+    //         case 0: case 2: default: return 1;
+    //         case 1: return 2;
+    //     }
+    // }
+    let code = code_attribute(vec![
+        0x1a, 0xaa, 0x00, 0x00, // 0: iload_0, 1: tableswitch
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, // default: 28, low: 0, high: 2
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1b, // 0: 28, 1: 30, 2: 28
+        0x04, 0xac, // 28: iconst_1, 29: ireturn
+        0x05, 0xac, // 30: iconst_2, 31: ireturn
+    ], vec![]);
+    let cfg = ControlFlowGraph::build(&code).unwrap();
+    assert_eq!(cfg.edges, vec![
+        Edge { from: 0, to: 1, kind: EdgeKind::Case(Some(0)) },
+        Edge { from: 0, to: 2, kind: EdgeKind::Case(Some(1)) },
+        Edge { from: 0, to: 1, kind: EdgeKind::Case(Some(2)) },
+        Edge { from: 0, to: 1, kind: EdgeKind::Case(None) },
+    ]);
+    assert_eq!(cfg.successors(0), vec![1, 2]);
+    assert_eq!(cfg.predecessors(1), vec![0]);
+    assert_eq!(cfg.dominators().immediate_dominators, vec![None, Some(0), Some(0)]);
+}
+
+#[test]
+fn test_exception_and_dot() {
+    // static String describe(Object o) {
+    //     try {
+    //         return o.toString();
+    //     } catch (RuntimeException e) {
+    //         return "error";
+    //     }
+    // }
+    let code = code_attribute(vec![
+        0x2a, 0xb6, 0x00, 0x0d, 0xb0, // 0: aload_0, 1: invokevirtual #13, 4: areturn
+        0x4c, 0x12, 0x13, 0xb0, // 5: astore_1, 6: ldc #19, 8: areturn
+    ], vec![
+        crate::class_file::structure::attribute::ExceptionTable { start_pc: 0, end_pc: 4, handler_pc: 5, catch_type: crate::class_file::structure::constant_pool::CpIndex::new(17) },
+    ]);
+    let cfg = ControlFlowGraph::build(&code).unwrap();
+    assert_eq!(cfg.edges, vec![
+        Edge { from: 0, to: 1, kind: EdgeKind::FallThrough },
+        Edge { from: 0, to: 2, kind: EdgeKind::Exception(17) },
+    ]);
+    assert_eq!(cfg.dominators().immediate_dominators, vec![None, Some(0), Some(0)]);
+    assert_eq!(cfg.post_dominators().immediate_dominators, vec![None, None, None]);
+    assert_eq!(cfg.to_dot(), [
+        "digraph cfg {",
+        "    node [shape=box, fontname=\"monospace\"];",
+        "    B0 [label=\"B0\\l0: aload_0\\l1: invokevirtual #13\\l\"];",
+        "    B1 [label=\"B1\\l4: areturn\\l\"];",
+        "    B2 [label=\"B2\\l5: astore_1\\l6: ldc #19\\l8: areturn\\l\"];",
+        "    B0 -> B1;",
+        "    B0 -> B2 [label=\"#17\", style=dashed];",
+        "",
+    ].join("\n") + "}\n");
+}
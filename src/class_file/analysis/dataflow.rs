@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+
+use crate::class_file::class_hierarchy::ClassHierarchy;
+use crate::class_file::error::{Error, Result};
+use crate::class_file::instruction::Instruction;
+use crate::class_file::structure::attribute::Attribute;
+use crate::class_file::structure::root::{ClassFile, MethodInfo};
+use crate::class_file::verifier::MethodContext;
+use crate::class_file::verifier::type_checker::stack_map_frames;
+use crate::class_file::verifier::type_inferencer::infer_frames_leniently;
+use crate::class_file::verifier::types::Frame;
+
+/// The types of the local variables and the operand stack before each instruction of a method,
+/// in the same terms as the verifier: a long or double takes two local variables but one stack entry.
+#[derive(Debug, PartialEq)]
+pub struct MethodFrames {
+    pub instructions: Vec<(u16, Instruction)>,
+    // only the reachable instructions have a frame
+    pub frames: BTreeMap<u16, Frame>,
+    // the instructions the verifier rejects. What is reached only through them has no frame.
+    pub errors: BTreeMap<u16, Error>,
+}
+
+impl MethodFrames {
+    pub fn frame_at(&self, pc: u16) -> Option<&Frame> {
+        self.frames.get(&pc)
+    }
+}
+
+/// Works out the frames of a method by the same transfer functions as the verifier.
+/// The frames of the StackMapTable, if any, are taken as given where they are declared, so that e.g.
+/// a local variable declared as an interface type stays so, and elsewhere the types are inferred.
+/// A method the verifier rejects still gets the frames up to the failing instructions, with the errors by pc;
+/// only a malformed Code or StackMapTable attribute is an error of the whole method.
+/// None for a method without code.
+pub fn analyze_method(class_file: &ClassFile, method: &MethodInfo, hierarchy: &dyn ClassHierarchy) -> Result<Option<MethodFrames>> {
+    let code = match method.attributes.iter().find_map(|attribute| match attribute {
+        Attribute::Code(code) => Some(code),
+        _ => None,
+    }) {
+        Some(code) => code,
+        None => return Ok(None),
+    };
+    let context = MethodContext::new(class_file, method, code, hierarchy)?;
+    let hints = stack_map_frames(&context, code, context.initial_locals())?;
+    let (frames, errors) = infer_frames_leniently(&context, code, &hints)?;
+    Ok(Some(MethodFrames { instructions: context.instructions, frames, errors }))
+}
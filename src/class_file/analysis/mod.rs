@@ -0,0 +1,3 @@
+// Analyses of method bodies, for tools built on top of the class file structure.
+pub mod cfg;
+pub mod dataflow;
@@ -0,0 +1,496 @@
+use std::collections::HashMap;
+
+use crate::class_file::access_flags::*;
+use crate::class_file::error::{Error, Result};
+use crate::class_file::instruction::Instruction;
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure::constant_pool::{ConstantUtf8Info, CpIndex, CpInfo};
+use crate::class_file::structure::root::*;
+use crate::class_file::writer::write_attribute;
+
+use super::grammar::*;
+
+fn error<T>(message: String) -> Result<T> {
+    Err(Error { message: format!("Assembly failed. {}", message) })
+}
+
+// The instructions without operands, looked up by their mnemonics.
+const NO_OPERANDS: &[Instruction] = {
+    use Instruction::*;
+    &[
+        Nop, AconstNull, IconstM1, Iconst0, Iconst1, Iconst2, Iconst3, Iconst4, Iconst5, Lconst0, Lconst1,
+        Fconst0, Fconst1, Fconst2, Dconst0, Dconst1, Iload0, Iload1, Iload2, Iload3, Lload0, Lload1, Lload2, Lload3,
+        Fload0, Fload1, Fload2, Fload3, Dload0, Dload1, Dload2, Dload3, Aload0, Aload1, Aload2, Aload3,
+        Iaload, Laload, Faload, Daload, Aaload, Baload, Caload, Saload, Istore0, Istore1, Istore2, Istore3,
+        Lstore0, Lstore1, Lstore2, Lstore3, Fstore0, Fstore1, Fstore2, Fstore3, Dstore0, Dstore1, Dstore2, Dstore3,
+        Astore0, Astore1, Astore2, Astore3, Iastore, Lastore, Fastore, Dastore, Aastore, Bastore, Castore, Sastore,
+        Pop, Pop2, Dup, DupX1, DupX2, Dup2, Dup2X1, Dup2X2, Swap, Iadd, Ladd, Fadd, Dadd, Isub, Lsub, Fsub, Dsub,
+        Imul, Lmul, Fmul, Dmul, Idiv, Ldiv, Fdiv, Ddiv, Irem, Lrem, Frem, Drem, Ineg, Lneg, Fneg, Dneg,
+        Ishl, Lshl, Ishr, Lshr, Iushr, Lushr, Iand, Land, Ior, Lor, Ixor, Lxor, I2l, I2f, I2d, L2i, L2f, L2d,
+        F2i, F2l, F2d, D2i, D2l, D2f, I2b, I2c, I2s, Lcmp, Fcmpl, Fcmpg, Dcmpl, Dcmpg,
+        Ireturn, Lreturn, Freturn, Dreturn, Areturn, Return, Arraylength, Athrow, Monitorenter, Monitorexit,
+    ]
+};
+
+/// The class file of the assembly text. See `disassemble` for the format.
+/// Counts and lengths are computed, and the branch offsets are computed from the labels.
+pub fn assemble(text: &str) -> Result<ClassFile> {
+    let syntax = match assembly::class(text) {
+        Ok(syntax) => syntax,
+        Err(e) => return error(format!("expected {} at line {}, column {}", e.expected, e.location.line, e.location.column)),
+    };
+    let mut constant_pool: Vec<CpInfo> = Vec::new();
+    for (index, cp_info) in syntax.constants {
+        if index as usize != constant_pool.len() + 1 {
+            return error(format!("The constant #{} has to be numbered #{}", index, constant_pool.len() + 1));
+        }
+        let takes_two_entries = matches!(cp_info, CpInfo::Long(_) | CpInfo::Double(_));
+        constant_pool.push(cp_info);
+        // The text has no line for the unusable entry after a Long or Double.
+        if takes_two_entries {
+            constant_pool.push(CpInfo::Unusable);
+        }
+    }
+    let assembler = Assembler { constant_pool: &constant_pool };
+    let fields = syntax.fields.into_iter()
+        .map(|field| Ok(FieldsInfo {
+            access_flags: FieldAccessFlags::from_bits_retain(field.access_flags),
+            name_index: field.name_index,
+            descriptor_index: field.descriptor_index,
+            attributes_count: field.attributes.len() as u16,
+            attributes: assembler.attributes(field.attributes, None)?,
+        }))
+        .collect::<Result<Vec<FieldsInfo>>>()?;
+    let methods = syntax.methods.into_iter()
+        .map(|method| Ok(MethodInfo {
+            access_flags: MethodAccessFlags::from_bits_retain(method.access_flags),
+            name_index: method.name_index,
+            descriptor_index: method.descriptor_index,
+            attributes_count: method.attributes.len() as u16,
+            attributes: assembler.attributes(method.attributes, None)?,
+        }))
+        .collect::<Result<Vec<MethodInfo>>>()?;
+    let attributes = assembler.attributes(syntax.attributes, None)?;
+    Ok(ClassFile {
+        magic: [0xca, 0xfe, 0xba, 0xbe],
+        minor_version: syntax.minor_version,
+        major_version: syntax.major_version,
+        constant_pool_count: constant_pool.len() as u16 + 1,
+        constant_pool,
+        access_flags: ClassAccessFlags::from_bits_retain(syntax.access_flags),
+        this_class: syntax.this_class,
+        super_class: syntax.super_class,
+        interfaces_count: syntax.interfaces.len() as u16,
+        interfaces: syntax.interfaces,
+        fields_count: fields.len() as u16,
+        fields,
+        methods_count: methods.len() as u16,
+        methods,
+        attributes_count: attributes.len() as u16,
+        attributes,
+    })
+}
+
+struct Assembler<'a> {
+    constant_pool: &'a Vec<CpInfo>,
+}
+
+impl Assembler<'_> {
+    // `labels` are the pcs of the labels in the Code attribute that the attributes are in.
+    fn attributes(&self, attributes: Vec<AttributeSyntax>, labels: Option<&HashMap<String, u16>>) -> Result<Vec<Attribute>> {
+        attributes.into_iter().map(|attribute| self.attribute(attribute, labels)).collect()
+    }
+
+    fn attribute(&self, syntax: AttributeSyntax, labels: Option<&HashMap<String, u16>>) -> Result<Attribute> {
+        let attribute_name_index = match syntax.name_index {
+            Some(index) => index,
+            None => self.utf8_index(&syntax.name)?,
+        };
+        let attribute_length = 0;
+        let mut attribute = match syntax.body {
+            AttributeBody::Bytes(info) => Attribute::General(AttributeInfo { attribute_name_index, attribute_length, info }),
+            AttributeBody::ConstantValue(constantvalue_index) => Attribute::ConstantValue(ConstantValueAttribute {
+                attribute_name_index,
+                attribute_length,
+                constantvalue_index,
+            }),
+            AttributeBody::Code(code) => Attribute::Code(self.code(attribute_name_index, code)?),
+            AttributeBody::StackMapTable(entries) => Attribute::StackMapTable(StackMapTableAttribute {
+                attribute_name_index,
+                attribute_length,
+                number_of_entries: entries.len() as u16,
+                entries,
+            }),
+            AttributeBody::Exceptions(exception_index_table) => Attribute::Exceptions(ExceptionsAttribute {
+                attribute_name_index,
+                attribute_length,
+                number_of_exceptions: exception_index_table.len() as u16,
+                exception_index_table,
+            }),
+            AttributeBody::InnerClasses(classes) => Attribute::InnerClasses(InnerClassesAttribute {
+                attribute_name_index,
+                attribute_length,
+                number_of_classes: classes.len() as u16,
+                classes,
+            }),
+            AttributeBody::EnclosingMethod(class_index, method_index) => Attribute::EnclosingMethod(EnclosingMethodAttribute {
+                attribute_name_index,
+                attribute_length,
+                class_index,
+                method_index,
+            }),
+            AttributeBody::Signature(signature_index) => Attribute::Signature(SignatureAttribute {
+                attribute_name_index,
+                attribute_length,
+                signature_index,
+            }),
+            AttributeBody::LocalVariableTable(variables) => {
+                let local_variable_table = variables.iter()
+                    .map(|variable| {
+                        let (start_pc, length, name_index, descriptor_index, index) = local_variable(variable, labels)?;
+                        Ok(LocalVariable { start_pc, length, name_index, descriptor_index, index })
+                    })
+                    .collect::<Result<Vec<LocalVariable>>>()?;
+                Attribute::LocalVariableTable(LocalVariableTableAttribute {
+                    attribute_name_index,
+                    attribute_length,
+                    local_variable_table_length: local_variable_table.len() as u16,
+                    local_variable_table,
+                })
+            }
+            AttributeBody::LocalVariableTypeTable(variables) => {
+                let local_variable_type_table = variables.iter()
+                    .map(|variable| {
+                        let (start_pc, length, name_index, signature_index, index) = local_variable(variable, labels)?;
+                        Ok(LocalVariableType { start_pc, length, name_index, signature_index, index })
+                    })
+                    .collect::<Result<Vec<LocalVariableType>>>()?;
+                Attribute::LocalVariableTypeTable(LocalVariableTypeTableAttribute {
+                    attribute_name_index,
+                    attribute_length,
+                    local_variable_type_table_length: local_variable_type_table.len() as u16,
+                    local_variable_type_table,
+                })
+            }
+            AttributeBody::BootstrapMethods(methods) => Attribute::BootstrapMethods(BootstrapMethodsAttribute {
+                attribute_name_index,
+                attribute_length,
+                num_bootstrap_methods: methods.len() as u16,
+                bootstrap_methods: methods.into_iter()
+                    .map(|(bootstrap_method_ref, bootstrap_arguments)| BootstrapMethod {
+                        bootstrap_method_ref,
+                        num_bootstrap_arguments: bootstrap_arguments.len() as u16,
+                        bootstrap_arguments,
+                    })
+                    .collect(),
+            }),
+            AttributeBody::MethodParameters(parameters) => Attribute::MethodParameters(MethodParametersAttribute {
+                attribute_name_index,
+                attribute_length,
+                parameters_count: parameters.len() as u8,
+                parameters,
+            }),
+            AttributeBody::Module(module) => Attribute::Module(ModuleAttribute {
+                attribute_name_index,
+                attribute_length,
+                module_name_index: module.module_name_index,
+                module_flags: ModuleAccessFlags::from_bits_retain(module.module_flags),
+                module_version_index: module.module_version_index,
+                requires_count: module.requires.len() as u16,
+                requires: module.requires,
+                exports_count: module.exports.len() as u16,
+                exports: module.exports.into_iter()
+                    .map(|(exports_index, exports_flags, to)| Export {
+                        exports_index,
+                        exports_flags: ExportsAccessFlags::from_bits_retain(exports_flags),
+                        exports_to_count: to.len() as u16,
+                        exports_to_index: to,
+                    })
+                    .collect(),
+                opens_count: module.opens.len() as u16,
+                opens: module.opens.into_iter()
+                    .map(|(opens_index, opens_flags, to)| Open {
+                        opens_index,
+                        opens_flags: ExportsAccessFlags::from_bits_retain(opens_flags),
+                        opens_to_count: to.len() as u16,
+                        opens_to_index: to,
+                    })
+                    .collect(),
+                uses_count: module.uses.len() as u16,
+                uses_index: module.uses,
+                provides_count: module.provides.len() as u16,
+                provides: module.provides.into_iter()
+                    .map(|(provides_index, with)| Provide {
+                        provides_index,
+                        provides_with_count: with.len() as u16,
+                        provides_with_index: with,
+                    })
+                    .collect(),
+            }),
+            AttributeBody::NestHost(host_class_index) => Attribute::NestHost(NestHostAttribute {
+                attribute_name_index,
+                attribute_length,
+                host_class_index,
+            }),
+            AttributeBody::NestMembers(classes) => Attribute::NestMembers(NestMembersAttribute {
+                attribute_name_index,
+                attribute_length,
+                number_of_classes: classes.len() as u16,
+                classes,
+            }),
+            AttributeBody::PermittedSubclasses(classes) => Attribute::PermittedSubclasses(PermittedSubclassesAttribute {
+                attribute_name_index,
+                attribute_length,
+                number_of_classes: classes.len() as u16,
+                classes,
+            }),
+        };
+        // attribute_name_index and attribute_length take the first 6 bytes.
+        *attribute_length_mut(&mut attribute) = write_attribute(&attribute).len() as u32 - 6;
+        Ok(attribute)
+    }
+
+    fn utf8_index(&self, name: &str) -> Result<CpIndex<ConstantUtf8Info>> {
+        let position = self.constant_pool.iter().position(|cp_info| matches!(cp_info, CpInfo::Utf8(info) if info.bytes == name.as_bytes()));
+        match position {
+            Some(position) => Ok(CpIndex::new(position as u16 + 1)),
+            None => error(format!("The name of the attribute {} is not in the constant pool", name)),
+        }
+    }
+
+    fn code(&self, attribute_name_index: CpIndex<ConstantUtf8Info>, syntax: CodeSyntax) -> Result<CodeAttributeInfo> {
+        // The pcs of the labels are found with the offsets left 0, since the length of an instruction does not depend on them.
+        let mut labels: HashMap<String, u16> = HashMap::new();
+        let mut code: Vec<u8> = Vec::new();
+        for item in &syntax.items {
+            match item {
+                CodeItem::Label(label) => {
+                    if labels.insert(label.clone(), code.len() as u16).is_some() {
+                        return error(format!("The label {} is defined twice", label));
+                    }
+                }
+                CodeItem::Instruction(instruction) => instruction_of(instruction, code.len() as u16, None)?.encode(&mut code),
+                CodeItem::Bytes(bytes) => code.extend(bytes),
+            }
+        }
+        code.clear();
+        for item in &syntax.items {
+            match item {
+                CodeItem::Label(_) => {}
+                CodeItem::Instruction(instruction) => instruction_of(instruction, code.len() as u16, Some(&labels))?.encode(&mut code),
+                CodeItem::Bytes(bytes) => code.extend(bytes),
+            }
+        }
+        if code.len() > u16::MAX as usize {
+            return error(format!("The code is too long: {} bytes", code.len()));
+        }
+        let exception_table = syntax.catches.iter()
+            .map(|(start, end, handler, catch_type)| Ok(ExceptionTable {
+                start_pc: pc(start, Some(&labels))?,
+                end_pc: pc(end, Some(&labels))?,
+                handler_pc: pc(handler, Some(&labels))?,
+                catch_type: *catch_type,
+            }))
+            .collect::<Result<Vec<ExceptionTable>>>()?;
+        let attributes = self.attributes(syntax.attributes, Some(&labels))?;
+        Ok(CodeAttributeInfo {
+            attribute_name_index,
+            attribute_length: 0,
+            max_stack: syntax.max_stack,
+            max_locals: syntax.max_locals,
+            code_length: code.len() as u32,
+            code,
+            exception_table_length: exception_table.len() as u16,
+            exception_table,
+            attributes_count: attributes.len() as u16,
+            attributes,
+        })
+    }
+}
+
+fn attribute_length_mut(attribute: &mut Attribute) -> &mut u32 {
+    match attribute {
+        Attribute::General(attribute) => &mut attribute.attribute_length,
+        Attribute::ConstantValue(attribute) => &mut attribute.attribute_length,
+        Attribute::Code(attribute) => &mut attribute.attribute_length,
+        Attribute::StackMapTable(attribute) => &mut attribute.attribute_length,
+        Attribute::Exceptions(attribute) => &mut attribute.attribute_length,
+        Attribute::InnerClasses(attribute) => &mut attribute.attribute_length,
+        Attribute::EnclosingMethod(attribute) => &mut attribute.attribute_length,
+        Attribute::Signature(attribute) => &mut attribute.attribute_length,
+        Attribute::LocalVariableTable(attribute) => &mut attribute.attribute_length,
+        Attribute::LocalVariableTypeTable(attribute) => &mut attribute.attribute_length,
+        Attribute::BootstrapMethods(attribute) => &mut attribute.attribute_length,
+        Attribute::MethodParameters(attribute) => &mut attribute.attribute_length,
+        Attribute::Module(attribute) => &mut attribute.attribute_length,
+        Attribute::NestHost(attribute) => &mut attribute.attribute_length,
+        Attribute::NestMembers(attribute) => &mut attribute.attribute_length,
+        Attribute::PermittedSubclasses(attribute) => &mut attribute.attribute_length,
+    }
+}
+
+fn pc(pc: &Pc, labels: Option<&HashMap<String, u16>>) -> Result<u16> {
+    match (pc, labels) {
+        (Pc::Number(pc), _) => Ok(*pc),
+        (Pc::Label(label), Some(labels)) => match labels.get(label) {
+            Some(pc) => Ok(*pc),
+            None => error(format!("The label {} is not defined", label)),
+        },
+        (Pc::Label(label), None) => error(format!("The label {} is used out of Code", label)),
+    }
+}
+
+// (start_pc, length, name_index, descriptor_index or signature_index, index)
+type LocalVariableEntry = (u16, u16, CpIndex<ConstantUtf8Info>, CpIndex<ConstantUtf8Info>, u16);
+
+fn local_variable(variable: &LocalVariableSyntax, labels: Option<&HashMap<String, u16>>) -> Result<LocalVariableEntry> {
+    let (start, end, name_index, descriptor_index, index) = variable;
+    let start_pc = pc(start, labels)?;
+    let end_pc = pc(end, labels)?;
+    if end_pc < start_pc {
+        return error(format!("The local variable #{} ends at {} before its start {}", name_index, end_pc, start_pc));
+    }
+    Ok((start_pc, end_pc - start_pc, *name_index, *descriptor_index, *index))
+}
+
+// The instruction at `pc`. The branch offsets are 0 until the labels are given.
+fn instruction_of(syntax: &InstructionSyntax, pc: u16, labels: Option<&HashMap<String, u16>>) -> Result<Instruction> {
+    let offset = |label: &str| -> Result<i32> {
+        match labels {
+            Some(labels) => match labels.get(label) {
+                Some(target) => Ok(*target as i32 - pc as i32),
+                None => error(format!("The label {} is not defined", label)),
+            },
+            None => Ok(0),
+        }
+    };
+    match syntax {
+        InstructionSyntax::Tableswitch { low, targets, default } => {
+            let offsets = targets.iter().map(|target| offset(target)).collect::<Result<Vec<i32>>>()?;
+            let high = match (*low as i64 + offsets.len() as i64 - 1).try_into() {
+                Ok(high) if !offsets.is_empty() => high,
+                _ => return error(format!("tableswitch from {} has to have 1 or more targets", low)),
+            };
+            Ok(Instruction::Tableswitch { default: offset(default)?, low: *low, high, offsets })
+        }
+        InstructionSyntax::Lookupswitch { pairs, default } => {
+            if pairs.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                return error("The keys of lookupswitch have to be sorted in increasing order".to_string());
+            }
+            let pairs = pairs.iter().map(|(key, target)| Ok((*key, offset(target)?))).collect::<Result<Vec<(i32, i32)>>>()?;
+            Ok(Instruction::Lookupswitch { default: offset(default)?, pairs })
+        }
+        InstructionSyntax::Wide { mnemonic, operands } => wide(mnemonic, operands),
+        InstructionSyntax::Simple { mnemonic, operands } => simple(mnemonic, operands, &offset),
+    }
+}
+
+fn operand<T: TryFrom<i64>>(mnemonic: &str, value: i64) -> Result<T> {
+    match T::try_from(value) {
+        Ok(value) => Ok(value),
+        Err(_) => error(format!("The operand {} of {} is out of range", value, mnemonic)),
+    }
+}
+
+fn simple(mnemonic: &str, operands: &[Operand], offset: &dyn Fn(&str) -> Result<i32>) -> Result<Instruction> {
+    use Instruction::*;
+    use Operand::{Index, Int, Name};
+    let branch = |label: &str| -> Result<i16> {
+        let offset = offset(label)?;
+        match i16::try_from(offset) {
+            Ok(offset) => Ok(offset),
+            Err(_) => error(format!("The branch of {} to {} is too far", mnemonic, label)),
+        }
+    };
+    let instruction = match (mnemonic, operands) {
+        ("bipush", [Int(value)]) => Bipush(operand(mnemonic, *value)?),
+        ("sipush", [Int(value)]) => Sipush(operand(mnemonic, *value)?),
+        ("ldc", [Index(index)]) => Ldc(operand(mnemonic, *index as i64)?),
+        ("ldc_w", [Index(index)]) => LdcW(*index),
+        ("ldc2_w", [Index(index)]) => Ldc2W(*index),
+        ("getstatic", [Index(index)]) => Getstatic(*index),
+        ("putstatic", [Index(index)]) => Putstatic(*index),
+        ("getfield", [Index(index)]) => Getfield(*index),
+        ("putfield", [Index(index)]) => Putfield(*index),
+        ("invokevirtual", [Index(index)]) => Invokevirtual(*index),
+        ("invokespecial", [Index(index)]) => Invokespecial(*index),
+        ("invokestatic", [Index(index)]) => Invokestatic(*index),
+        ("invokeinterface", [Index(index), Int(count)]) => Invokeinterface { index: *index, count: operand(mnemonic, *count)? },
+        ("invokedynamic", [Index(index)]) => Invokedynamic(*index),
+        ("new", [Index(index)]) => New(*index),
+        ("anewarray", [Index(index)]) => Anewarray(*index),
+        ("checkcast", [Index(index)]) => Checkcast(*index),
+        ("instanceof", [Index(index)]) => Instanceof(*index),
+        ("multianewarray", [Index(index), Int(dimensions)]) => Multianewarray { index: *index, dimensions: operand(mnemonic, *dimensions)? },
+        ("newarray", [Int(atype)]) => Newarray(operand(mnemonic, *atype)?),
+        ("iinc", [Int(index), Int(value)]) => Iinc(operand::<u8>(mnemonic, *index)? as u16, operand::<i8>(mnemonic, *value)? as i16),
+        ("goto_w", [Name(label)]) => GotoW(offset(label)?),
+        ("jsr_w", [Name(label)]) => JsrW(offset(label)?),
+        (_, [Int(index)]) => match local(mnemonic, operand::<u8>(mnemonic, *index)? as u16) {
+            Some(instruction) => instruction,
+            None => return error(format!("{} does not take the operand {}", mnemonic, index)),
+        },
+        (_, [Name(label)]) => {
+            let offset = branch(label)?;
+            match mnemonic {
+                "ifeq" => Ifeq(offset),
+                "ifne" => Ifne(offset),
+                "iflt" => Iflt(offset),
+                "ifge" => Ifge(offset),
+                "ifgt" => Ifgt(offset),
+                "ifle" => Ifle(offset),
+                "if_icmpeq" => IfIcmpeq(offset),
+                "if_icmpne" => IfIcmpne(offset),
+                "if_icmplt" => IfIcmplt(offset),
+                "if_icmpge" => IfIcmpge(offset),
+                "if_icmpgt" => IfIcmpgt(offset),
+                "if_icmple" => IfIcmple(offset),
+                "if_acmpeq" => IfAcmpeq(offset),
+                "if_acmpne" => IfAcmpne(offset),
+                "goto" => Goto(offset),
+                "jsr" => Jsr(offset),
+                "ifnull" => Ifnull(offset),
+                "ifnonnull" => Ifnonnull(offset),
+                _ => return error(format!("{} does not take the label {}", mnemonic, label)),
+            }
+        }
+        (_, []) => match NO_OPERANDS.iter().find(|instruction| instruction.mnemonic() == mnemonic) {
+            Some(instruction) => instruction.clone(),
+            None => return error(format!("{} is not an instruction without operands", mnemonic)),
+        },
+        _ => return error(format!("The operands of {} are not valid", mnemonic)),
+    };
+    Ok(instruction)
+}
+
+fn wide(mnemonic: &str, operands: &[Operand]) -> Result<Instruction> {
+    let instruction = match (mnemonic, operands) {
+        ("iinc", [Operand::Int(index), Operand::Int(value)]) => Instruction::Iinc(operand(mnemonic, *index)?, operand(mnemonic, *value)?),
+        (_, [Operand::Int(index)]) => match local(mnemonic, operand(mnemonic, *index)?) {
+            Some(instruction) => instruction,
+            None => return error(format!("{} cannot be modified by wide", mnemonic)),
+        },
+        _ => return error(format!("The operands of wide {} are not valid", mnemonic)),
+    };
+    Ok(Instruction::Wide(Box::new(instruction)))
+}
+
+// The instructions that take a local variable index.
+fn local(mnemonic: &str, index: u16) -> Option<Instruction> {
+    use Instruction::*;
+    let instruction = match mnemonic {
+        "iload" => Iload(index),
+        "lload" => Lload(index),
+        "fload" => Fload(index),
+        "dload" => Dload(index),
+        "aload" => Aload(index),
+        "istore" => Istore(index),
+        "lstore" => Lstore(index),
+        "fstore" => Fstore(index),
+        "dstore" => Dstore(index),
+        "astore" => Astore(index),
+        "ret" => Ret(index),
+        _ => return None,
+    };
+    Some(instruction)
+}
@@ -0,0 +1,543 @@
+use std::collections::BTreeSet;
+
+use crate::class_file::instruction::{decode, Instruction};
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure::constant_pool::{ConstantUtf8Info, CpIndex, CpInfo};
+use crate::class_file::structure::root::*;
+
+use crate::class_file::access_flags::*;
+
+use super::{ARRAY_TYPES, REFERENCE_KINDS};
+
+// Comments on the constant pool entries start at this column.
+const COMMENT_COLUMN: usize = 44;
+
+/// The assembly text of the class file, which `assemble` turns back into the same class file.
+pub fn disassemble(class_file: &ClassFile) -> String {
+    let mut printer = Printer { constant_pool: &class_file.constant_pool, lines: Vec::new() };
+    printer.class(class_file);
+    printer.lines.join("\n")
+}
+
+struct Printer<'a> {
+    constant_pool: &'a Vec<CpInfo>,
+    lines: Vec<String>,
+}
+
+impl Printer<'_> {
+    fn line(&mut self, depth: usize, text: String, comment: Option<String>) {
+        let text = format!("{}{}", "    ".repeat(depth), text);
+        match comment {
+            Some(comment) => self.lines.push(format!("{:<width$} // {}", text, comment, width = COMMENT_COLUMN - 1)),
+            None => self.lines.push(text),
+        }
+    }
+
+    fn class(&mut self, class_file: &ClassFile) {
+        self.line(0, format!(".version {} {}", class_file.major_version, class_file.minor_version), None);
+        self.line(0, format!(".class {}#{}", flags(class_file.access_flags), class_file.this_class), self.describe(class_file.this_class.index()));
+        self.line(0, format!(".super #{}", class_file.super_class), self.describe(class_file.super_class.index()));
+        if !class_file.interfaces.is_empty() {
+            let names = class_file.interfaces.iter().map(|index| self.describe(index.index()).unwrap_or_default()).collect::<Vec<String>>();
+            self.line(0, format!(".interfaces {}", index_list(&class_file.interfaces)), Some(names.join(", ")));
+        }
+        self.line(0, ".constants {".to_string(), None);
+        for (i, cp_info) in class_file.constant_pool.iter().enumerate() {
+            // The assembler puts it back after the Long or Double before it.
+            if let CpInfo::Unusable = cp_info {
+                continue;
+            }
+            let comment = match cp_info {
+                CpInfo::Utf8(_) | CpInfo::Integer(_) | CpInfo::Float(_) | CpInfo::Long(_) | CpInfo::Double(_) => None,
+                _ => self.describe(i as u16 + 1),
+            };
+            self.line(1, format!("#{} = {}", i + 1, constant(cp_info)), comment);
+        }
+        self.line(0, "}".to_string(), None);
+        for field in &class_file.fields {
+            let text = format!(".field {}#{} #{}", flags(field.access_flags), field.name_index, field.descriptor_index);
+            self.member(text, field.name_index, field.descriptor_index, &field.attributes);
+        }
+        for method in &class_file.methods {
+            let text = format!(".method {}#{} #{}", flags(method.access_flags), method.name_index, method.descriptor_index);
+            self.member(text, method.name_index, method.descriptor_index, &method.attributes);
+        }
+        for attribute in &class_file.attributes {
+            self.attribute(0, attribute, None);
+        }
+    }
+
+    fn member(&mut self, text: String, name_index: CpIndex<ConstantUtf8Info>, descriptor_index: CpIndex<ConstantUtf8Info>, attributes: &[Attribute]) {
+        let comment = Some(format!("{}:{}", self.utf8(name_index), self.utf8(descriptor_index)));
+        if attributes.is_empty() {
+            self.line(0, text, comment);
+        } else {
+            self.line(0, format!("{} {{", text), comment);
+            for attribute in attributes {
+                self.attribute(1, attribute, None);
+            }
+            self.line(0, "}".to_string(), None);
+        }
+    }
+
+    // `labels` are the pcs that have labels in the Code attribute that the attribute is in.
+    fn attribute(&mut self, depth: usize, attribute: &Attribute, labels: Option<&BTreeSet<u16>>) {
+        let head = self.attribute_head(attribute);
+        match attribute {
+            Attribute::General(attribute) => self.line(depth, format!("{} bytes \"{}\"", head, hex(&attribute.info)), None),
+            Attribute::ConstantValue(attribute) => {
+                self.line(depth, format!("{} #{}", head, attribute.constantvalue_index), self.describe(attribute.constantvalue_index.index()))
+            }
+            Attribute::Code(attribute) => self.code(depth, head, attribute),
+            Attribute::StackMapTable(attribute) => {
+                self.line(depth, format!("{} {{", head), None);
+                for frame in &attribute.entries {
+                    self.line(depth + 1, frame_text(frame), None);
+                }
+                self.line(depth, "}".to_string(), None);
+            }
+            Attribute::Exceptions(attribute) => {
+                let names = attribute.exception_index_table.iter().map(|index| self.describe(index.index()).unwrap_or_default()).collect::<Vec<String>>();
+                self.line(depth, format!("{} {}", head, index_list(&attribute.exception_index_table)), Some(names.join(", ")));
+            }
+            Attribute::InnerClasses(attribute) => {
+                self.line(depth, format!("{} {{", head), None);
+                for class in &attribute.classes {
+                    let text = format!(
+                        "{}#{} #{} #{}",
+                        flags(class.inner_class_access_flags),
+                        class.inner_class_info_index, class.outer_class_info_index, class.inner_name_index,
+                    );
+                    self.line(depth + 1, text, self.describe(class.inner_class_info_index.index()));
+                }
+                self.line(depth, "}".to_string(), None);
+            }
+            Attribute::EnclosingMethod(attribute) => {
+                let comment = match attribute.method_index.index() {
+                    0 => self.describe(attribute.class_index.index()),
+                    method_index => Some(format!("{}.{}", self.describe(attribute.class_index.index()).unwrap_or_default(), self.describe(method_index).unwrap_or_default())),
+                };
+                self.line(depth, format!("{} #{} #{}", head, attribute.class_index, attribute.method_index), comment);
+            }
+            Attribute::Signature(attribute) => {
+                self.line(depth, format!("{} #{}", head, attribute.signature_index), Some(self.utf8(attribute.signature_index)))
+            }
+            Attribute::LocalVariableTable(attribute) => {
+                self.line(depth, format!("{} {{", head), None);
+                for variable in &attribute.local_variable_table {
+                    let text = format!(
+                        "{} {} #{} #{} {}",
+                        pc_text(variable.start_pc, labels), end_pc_text(variable.start_pc, variable.length, labels),
+                        variable.name_index, variable.descriptor_index, variable.index,
+                    );
+                    self.line(depth + 1, text, Some(format!("{}:{}", self.utf8(variable.name_index), self.utf8(variable.descriptor_index))));
+                }
+                self.line(depth, "}".to_string(), None);
+            }
+            Attribute::LocalVariableTypeTable(attribute) => {
+                self.line(depth, format!("{} {{", head), None);
+                for variable in &attribute.local_variable_type_table {
+                    let text = format!(
+                        "{} {} #{} #{} {}",
+                        pc_text(variable.start_pc, labels), end_pc_text(variable.start_pc, variable.length, labels),
+                        variable.name_index, variable.signature_index, variable.index,
+                    );
+                    self.line(depth + 1, text, Some(format!("{}:{}", self.utf8(variable.name_index), self.utf8(variable.signature_index))));
+                }
+                self.line(depth, "}".to_string(), None);
+            }
+            Attribute::BootstrapMethods(attribute) => {
+                self.line(depth, format!("{} {{", head), None);
+                for method in &attribute.bootstrap_methods {
+                    let text = format!("#{} {}", method.bootstrap_method_ref, index_list(&method.bootstrap_arguments));
+                    self.line(depth + 1, text, self.describe(method.bootstrap_method_ref.index()));
+                }
+                self.line(depth, "}".to_string(), None);
+            }
+            Attribute::MethodParameters(attribute) => {
+                self.line(depth, format!("{} {{", head), None);
+                for parameter in &attribute.parameters {
+                    let comment = match parameter.name_index.index() {
+                        0 => None,
+                        _ => Some(self.utf8(parameter.name_index)),
+                    };
+                    self.line(depth + 1, format!("{}#{}", flags(parameter.access_flags), parameter.name_index), comment);
+                }
+                self.line(depth, "}".to_string(), None);
+            }
+            Attribute::Module(attribute) => self.module(depth, head, attribute),
+            Attribute::NestHost(attribute) => {
+                self.line(depth, format!("{} #{}", head, attribute.host_class_index), self.describe(attribute.host_class_index.index()))
+            }
+            Attribute::NestMembers(attribute) => self.line(depth, format!("{} {}", head, index_list(&attribute.classes)), None),
+            Attribute::PermittedSubclasses(attribute) => self.line(depth, format!("{} {}", head, index_list(&attribute.classes)), None),
+        }
+    }
+
+    // `.attribute Name`, with the index of the name if the assembler would not find it from the name.
+    fn attribute_head(&self, attribute: &Attribute) -> String {
+        let name_index = attribute_name_index(attribute).index();
+        let name = match self.constant_pool.get((name_index as usize).wrapping_sub(1)) {
+            Some(CpInfo::Utf8(info)) => String::from_utf8(info.bytes.clone()).ok(),
+            _ => None,
+        };
+        match name {
+            Some(name) if is_identifier(&name) => {
+                let first = self.constant_pool.iter().position(|cp_info| matches!(cp_info, CpInfo::Utf8(info) if info.bytes == name.as_bytes()));
+                if first == Some(name_index as usize - 1) {
+                    format!(".attribute {}", name)
+                } else {
+                    format!(".attribute {}(#{})", name, name_index)
+                }
+            }
+            // The name is only used to parse the body, which is bytes here.
+            _ => format!(".attribute Attribute(#{})", name_index),
+        }
+    }
+
+    fn code(&mut self, depth: usize, head: String, attribute: &CodeAttributeInfo) {
+        self.line(depth, format!("{} stack {} locals {} {{", head, attribute.max_stack, attribute.max_locals), None);
+        // Without instructions, the code is written as bytes and pcs as numbers.
+        let instructions = decode(&attribute.code).ok().filter(|instructions| {
+            let mut code = Vec::new();
+            for (_, instruction) in instructions {
+                instruction.encode(&mut code);
+            }
+            code == attribute.code
+        });
+        let labels = match &instructions {
+            Some(instructions) => {
+                let code_length = attribute.code.len() as u16;
+                let mut pcs = BTreeSet::new();
+                for (pc, instruction) in instructions {
+                    pcs.extend(instruction.branch_targets(*pc));
+                }
+                for exception in &attribute.exception_table {
+                    pcs.extend([exception.start_pc, exception.end_pc, exception.handler_pc]);
+                }
+                for attribute in &attribute.attributes {
+                    match attribute {
+                        Attribute::LocalVariableTable(table) => {
+                            for variable in &table.local_variable_table {
+                                pcs.extend([variable.start_pc, variable.start_pc.wrapping_add(variable.length)]);
+                            }
+                        }
+                        Attribute::LocalVariableTypeTable(table) => {
+                            for variable in &table.local_variable_type_table {
+                                pcs.extend([variable.start_pc, variable.start_pc.wrapping_add(variable.length)]);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                pcs.retain(|pc| *pc == code_length || instructions.binary_search_by_key(pc, |(pc, _)| *pc).is_ok());
+                pcs
+            }
+            None => BTreeSet::new(),
+        };
+        match &instructions {
+            Some(instructions) => {
+                for (pc, instruction) in instructions {
+                    self.instruction(depth + 1, *pc, instruction, &labels);
+                }
+                let code_length = attribute.code.len() as u16;
+                if labels.contains(&code_length) {
+                    self.line(0, format!("L{}:", code_length), None);
+                }
+            }
+            None => self.line(depth + 1, format!("bytes \"{}\"", hex(&attribute.code)), None),
+        }
+        for exception in &attribute.exception_table {
+            let text = format!(
+                ".catch {} {} {} #{}",
+                pc_text(exception.start_pc, Some(&labels)), pc_text(exception.end_pc, Some(&labels)),
+                pc_text(exception.handler_pc, Some(&labels)), exception.catch_type,
+            );
+            let comment = match exception.catch_type.index() {
+                0 => Some("any".to_string()),
+                catch_type => self.describe(catch_type),
+            };
+            self.line(depth + 1, text, comment);
+        }
+        for code_attribute in &attribute.attributes {
+            self.attribute(depth + 1, code_attribute, Some(&labels));
+        }
+        self.line(depth, "}".to_string(), None);
+    }
+
+    fn instruction(&mut self, depth: usize, pc: u16, instruction: &Instruction, labels: &BTreeSet<u16>) {
+        let label = |offset: &i32| format!("L{}", (pc as i32 + offset) as u16);
+        let indent = "    ".repeat(depth);
+        let prefix = match labels.contains(&pc) {
+            true => format!("{:<width$}", format!("L{}:", pc), width = indent.len().saturating_sub(1)) + " ",
+            false => indent.clone(),
+        };
+        let comment = cp_index(instruction).and_then(|index| self.describe(index));
+        match instruction {
+            Instruction::Tableswitch { default, low, offsets, .. } => {
+                self.line(0, format!("{}tableswitch {} {{", prefix, low), None);
+                for offset in offsets {
+                    self.line(depth + 1, label(offset), None);
+                }
+                self.line(depth + 1, format!("default: {}", label(default)), None);
+                self.line(depth, "}".to_string(), None);
+            }
+            Instruction::Lookupswitch { default, pairs } => {
+                self.line(0, format!("{}lookupswitch {{", prefix), None);
+                for (key, offset) in pairs {
+                    self.line(depth + 1, format!("{}: {}", key, label(offset)), None);
+                }
+                self.line(depth + 1, format!("default: {}", label(default)), None);
+                self.line(depth, "}".to_string(), None);
+            }
+            Instruction::Newarray(atype) => {
+                let text = match ARRAY_TYPES.iter().find(|(value, _)| value == atype) {
+                    Some((_, name)) => format!("newarray {}", name),
+                    None => format!("newarray {}", atype),
+                };
+                self.line(0, format!("{}{}", prefix, text), None);
+            }
+            Instruction::Wide(instruction) => self.line(0, format!("{}wide {}", prefix, instruction), None),
+            _ => {
+                let text = match instruction.branch_offsets().first() {
+                    Some(offset) => format!("{} {}", instruction.mnemonic(), label(offset)),
+                    None => instruction.to_string(),
+                };
+                self.line(0, format!("{}{}", prefix, text), comment);
+            }
+        }
+    }
+
+    fn module(&mut self, depth: usize, head: String, attribute: &ModuleAttribute) {
+        let text = format!("{} {}#{} #{} {{", head, flags(attribute.module_flags), attribute.module_name_index, attribute.module_version_index);
+        self.line(depth, text, self.describe(attribute.module_name_index.index()));
+        for require in &attribute.requires {
+            let text = format!("requires {}#{} #{}", flags(require.requires_flags), require.requires_index, require.requires_version_index);
+            self.line(depth + 1, text, self.describe(require.requires_index.index()));
+        }
+        for export in &attribute.exports {
+            let text = format!("exports {}#{} {}", flags(export.exports_flags), export.exports_index, index_list(&export.exports_to_index));
+            self.line(depth + 1, text, self.describe(export.exports_index.index()));
+        }
+        for open in &attribute.opens {
+            let text = format!("opens {}#{} {}", flags(open.opens_flags), open.opens_index, index_list(&open.opens_to_index));
+            self.line(depth + 1, text, self.describe(open.opens_index.index()));
+        }
+        for uses_index in &attribute.uses_index {
+            self.line(depth + 1, format!("uses #{}", uses_index), self.describe(uses_index.index()));
+        }
+        for provide in &attribute.provides {
+            let text = format!("provides #{} {}", provide.provides_index, index_list(&provide.provides_with_index));
+            self.line(depth + 1, text, self.describe(provide.provides_index.index()));
+        }
+        self.line(depth, "}".to_string(), None);
+    }
+
+    fn utf8(&self, index: CpIndex<ConstantUtf8Info>) -> String {
+        match self.constant_pool.get((index.index() as usize).wrapping_sub(1)) {
+            Some(CpInfo::Utf8(info)) => escape(&String::from_utf8_lossy(&info.bytes)),
+            _ => "?".to_string(),
+        }
+    }
+
+    // What the entry refers to, for comments.
+    fn describe(&self, index: u16) -> Option<String> {
+        self.describe_within(index, 3)
+    }
+
+    fn describe_within(&self, index: u16, depth: usize) -> Option<String> {
+        let cp_info = self.constant_pool.get((index as usize).wrapping_sub(1))?;
+        if depth == 0 {
+            return None;
+        }
+        let describe = |index: u16| self.describe_within(index, depth - 1).unwrap_or_else(|| "?".to_string());
+        let description = match cp_info {
+            CpInfo::Utf8(_) => self.utf8(CpIndex::new(index)),
+            CpInfo::Integer(info) => i32::from_be_bytes(info.bytes).to_string(),
+            CpInfo::Float(info) => format!("{:?}f", f32::from_be_bytes(info.bytes)),
+            CpInfo::Long(info) => format!("{}L", long_bits(info.high_bytes, info.low_bytes) as i64),
+            CpInfo::Double(info) => format!("{:?}", f64::from_bits(long_bits(info.high_bytes, info.low_bytes))),
+            CpInfo::Class(info) => describe(info.name_index.index()),
+            CpInfo::String(info) => format!("\"{}\"", describe(info.string_index.index())),
+            CpInfo::Fieldref(info) => format!("{}.{}", describe(info.class_index.index()), describe(info.name_and_type_index.index())),
+            CpInfo::Methodref(info) => format!("{}.{}", describe(info.class_index.index()), describe(info.name_and_type_index.index())),
+            CpInfo::InterfaceMethodref(info) => format!("{}.{}", describe(info.class_index.index()), describe(info.name_and_type_index.index())),
+            CpInfo::NameAndType(info) => format!("{}:{}", describe(info.name_index.index()), describe(info.descriptor_index.index())),
+            CpInfo::MethodHandle(info) => format!("{} {}", reference_kind(info.reference_kind), describe(info.reference_index.index())),
+            CpInfo::MethodType(info) => describe(info.descriptor_index.index()),
+            CpInfo::Dynamic(info) => format!("{}:{}", info.bootstrap_method_attr_index, describe(info.name_and_type_index.index())),
+            CpInfo::InvokeDynamic(info) => format!("{}:{}", info.bootstrap_method_attr_index, describe(info.name_and_type_index.index())),
+            CpInfo::Module(info) => describe(info.name_index.index()),
+            CpInfo::Package(info) => describe(info.name_index.index()),
+            CpInfo::Unusable => return None,
+        };
+        Some(description)
+    }
+}
+
+fn constant(cp_info: &CpInfo) -> String {
+    match cp_info {
+        CpInfo::Utf8(info) => match std::str::from_utf8(&info.bytes) {
+            Ok(string) => format!("Utf8 \"{}\"", escape(string)),
+            Err(_) => format!("Utf8 bytes \"{}\"", hex(&info.bytes)),
+        },
+        CpInfo::Integer(info) => format!("Integer {}", i32::from_be_bytes(info.bytes)),
+        CpInfo::Float(info) => {
+            let value = f32::from_be_bytes(info.bytes);
+            match format!("{:?}", value) {
+                text if text.parse::<f32>().map(f32::to_bits) == Ok(value.to_bits()) => format!("Float {}", text),
+                _ => format!("Float bits 0x{:08x}", value.to_bits()),
+            }
+        }
+        CpInfo::Long(info) => format!("Long {}", long_bits(info.high_bytes, info.low_bytes) as i64),
+        CpInfo::Double(info) => {
+            let bits = long_bits(info.high_bytes, info.low_bytes);
+            match format!("{:?}", f64::from_bits(bits)) {
+                text if text.parse::<f64>().map(f64::to_bits) == Ok(bits) => format!("Double {}", text),
+                _ => format!("Double bits 0x{:016x}", bits),
+            }
+        }
+        CpInfo::Class(info) => format!("Class #{}", info.name_index),
+        CpInfo::String(info) => format!("String #{}", info.string_index),
+        CpInfo::Fieldref(info) => format!("Fieldref #{} #{}", info.class_index, info.name_and_type_index),
+        CpInfo::Methodref(info) => format!("Methodref #{} #{}", info.class_index, info.name_and_type_index),
+        CpInfo::InterfaceMethodref(info) => format!("InterfaceMethodref #{} #{}", info.class_index, info.name_and_type_index),
+        CpInfo::NameAndType(info) => format!("NameAndType #{} #{}", info.name_index, info.descriptor_index),
+        CpInfo::MethodHandle(info) => format!("MethodHandle {} #{}", reference_kind(info.reference_kind), info.reference_index),
+        CpInfo::MethodType(info) => format!("MethodType #{}", info.descriptor_index),
+        CpInfo::Dynamic(info) => format!("Dynamic {} #{}", info.bootstrap_method_attr_index, info.name_and_type_index),
+        CpInfo::InvokeDynamic(info) => format!("InvokeDynamic {} #{}", info.bootstrap_method_attr_index, info.name_and_type_index),
+        CpInfo::Module(info) => format!("Module #{}", info.name_index),
+        CpInfo::Package(info) => format!("Package #{}", info.name_index),
+        // `class` skips it.
+        CpInfo::Unusable => unreachable!(),
+    }
+}
+
+fn frame_text(frame: &StackMapFrame) -> String {
+    match frame {
+        StackMapFrame::SameFrame { frame_type } => format!("same {}", frame_type),
+        StackMapFrame::SameLocals1StackItemFrame { frame_type, stack } => {
+            format!("same_locals_1_stack_item {} {}", frame_type - 64, verification_types(stack))
+        }
+        StackMapFrame::SameLocals1StackItemFrameExtended { offset_delta, stack, .. } => {
+            format!("same_locals_1_stack_item_extended {} {}", offset_delta, verification_types(stack))
+        }
+        StackMapFrame::ChopFrame { frame_type, offset_delta } => format!("chop {} {}", 251 - frame_type, offset_delta),
+        StackMapFrame::SameFrameExtended { offset_delta, .. } => format!("same_extended {}", offset_delta),
+        StackMapFrame::AppendFrame { offset_delta, locals, .. } => format!("append {} [{}]", offset_delta, verification_types(locals)),
+        StackMapFrame::FullFrame { offset_delta, locals, stack, .. } => {
+            format!("full {} [{}] [{}]", offset_delta, verification_types(locals), verification_types(stack))
+        }
+    }
+}
+
+fn verification_types(types: &[VerificationTypeInfo]) -> String {
+    types.iter()
+        .map(|verification_type| match verification_type {
+            VerificationTypeInfo::TopVariableInfo { .. } => "Top".to_string(),
+            VerificationTypeInfo::IntegerVariableInfo { .. } => "Integer".to_string(),
+            VerificationTypeInfo::FloatVariableInfo { .. } => "Float".to_string(),
+            VerificationTypeInfo::DoubleVariableInfo { .. } => "Double".to_string(),
+            VerificationTypeInfo::LongVariableInfo { .. } => "Long".to_string(),
+            VerificationTypeInfo::NullVariableInfo { .. } => "Null".to_string(),
+            VerificationTypeInfo::UninitializedThisVariableInfo { .. } => "UninitializedThis".to_string(),
+            VerificationTypeInfo::ObjectVariableInfo { cpool_index, .. } => format!("Object #{}", cpool_index),
+            VerificationTypeInfo::UninitializedVariableInfo { offset, .. } => format!("Uninitialized {}", offset),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn attribute_name_index(attribute: &Attribute) -> CpIndex<ConstantUtf8Info> {
+    match attribute {
+        Attribute::General(attribute) => attribute.attribute_name_index,
+        Attribute::ConstantValue(attribute) => attribute.attribute_name_index,
+        Attribute::Code(attribute) => attribute.attribute_name_index,
+        Attribute::StackMapTable(attribute) => attribute.attribute_name_index,
+        Attribute::Exceptions(attribute) => attribute.attribute_name_index,
+        Attribute::InnerClasses(attribute) => attribute.attribute_name_index,
+        Attribute::EnclosingMethod(attribute) => attribute.attribute_name_index,
+        Attribute::Signature(attribute) => attribute.attribute_name_index,
+        Attribute::LocalVariableTable(attribute) => attribute.attribute_name_index,
+        Attribute::LocalVariableTypeTable(attribute) => attribute.attribute_name_index,
+        Attribute::BootstrapMethods(attribute) => attribute.attribute_name_index,
+        Attribute::MethodParameters(attribute) => attribute.attribute_name_index,
+        Attribute::Module(attribute) => attribute.attribute_name_index,
+        Attribute::NestHost(attribute) => attribute.attribute_name_index,
+        Attribute::NestMembers(attribute) => attribute.attribute_name_index,
+        Attribute::PermittedSubclasses(attribute) => attribute.attribute_name_index,
+    }
+}
+
+// The constant pool index that the instruction refers to.
+fn cp_index(instruction: &Instruction) -> Option<u16> {
+    use Instruction::*;
+    match instruction {
+        Ldc(index) => Some(*index as u16),
+        LdcW(index) | Ldc2W(index) | Getstatic(index) | Putstatic(index) | Getfield(index) | Putfield(index) |
+        Invokevirtual(index) | Invokespecial(index) | Invokestatic(index) | Invokedynamic(index) |
+        New(index) | Anewarray(index) | Checkcast(index) | Instanceof(index) => Some(*index),
+        Invokeinterface { index, .. } | Multianewarray { index, .. } => Some(*index),
+        _ => None,
+    }
+}
+
+fn pc_text(pc: u16, labels: Option<&BTreeSet<u16>>) -> String {
+    match labels {
+        Some(labels) if labels.contains(&pc) => format!("L{}", pc),
+        _ => pc.to_string(),
+    }
+}
+
+fn end_pc_text(start_pc: u16, length: u16, labels: Option<&BTreeSet<u16>>) -> String {
+    pc_text(start_pc.wrapping_add(length), labels)
+}
+
+// The keywords followed by a space, or nothing if no flag is set.
+fn flags(access_flags: impl AccessFlags) -> String {
+    match access_flags.bits() {
+        0 => String::new(),
+        _ => format!("{} ", access_flags.keywords()),
+    }
+}
+
+fn reference_kind(kind: u8) -> String {
+    match REFERENCE_KINDS.iter().find(|(value, _)| *value == kind) {
+        Some((_, name)) => name.to_string(),
+        None => kind.to_string(),
+    }
+}
+
+fn index_list<T>(indexes: &[CpIndex<T>]) -> String {
+    let indexes = indexes.iter().map(|index| format!("#{}", index)).collect::<Vec<String>>();
+    format!("[{}]", indexes.join(" "))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn long_bits(high_bytes: [u8; 4], low_bytes: [u8; 4]) -> u64 {
+    ((u32::from_be_bytes(high_bytes) as u64) << 32) | u32::from_be_bytes(low_bytes) as u64
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn escape(string: &str) -> String {
+    string.chars()
+        .map(|c| match c {
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            c if c.is_control() => format!("\\u{:04x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,419 @@
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure::constant_pool::*;
+
+use crate::class_file::access_flags::*;
+
+use super::{ARRAY_TYPES, REFERENCE_KINDS};
+
+// The syntax tree of the text. Constant pool indexes are kept as they are written,
+// and the names of attributes and the labels are resolved by the assembler.
+
+pub(super) struct ClassSyntax {
+    pub minor_version: u16,
+    pub major_version: u16,
+    pub access_flags: u16,
+    pub this_class: CpIndex<ConstantClassInfo>,
+    pub super_class: CpIndex<ConstantClassInfo>,
+    pub interfaces: Vec<CpIndex<ConstantClassInfo>>,
+    pub constants: Vec<(u16, CpInfo)>,
+    pub fields: Vec<MemberSyntax>,
+    pub methods: Vec<MemberSyntax>,
+    pub attributes: Vec<AttributeSyntax>,
+}
+
+pub(super) struct MemberSyntax {
+    pub access_flags: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
+    pub descriptor_index: CpIndex<ConstantUtf8Info>,
+    pub attributes: Vec<AttributeSyntax>,
+}
+
+pub(super) struct AttributeSyntax {
+    pub name: String,
+    pub name_index: Option<CpIndex<ConstantUtf8Info>>,
+    pub body: AttributeBody,
+}
+
+pub(super) enum AttributeBody {
+    Bytes(Vec<u8>),
+    ConstantValue(CpIndex<CpInfo>),
+    Code(CodeSyntax),
+    StackMapTable(Vec<StackMapFrame>),
+    Exceptions(Vec<CpIndex<ConstantClassInfo>>),
+    InnerClasses(Vec<InnerClassesAttributeClass>),
+    EnclosingMethod(CpIndex<ConstantClassInfo>, CpIndex<ConstantNameAndTypeInfo>),
+    Signature(CpIndex<ConstantUtf8Info>),
+    LocalVariableTable(Vec<LocalVariableSyntax>),
+    LocalVariableTypeTable(Vec<LocalVariableSyntax>),
+    BootstrapMethods(Vec<(CpIndex<ConstantMethodHandleInfo>, Vec<CpIndex<CpInfo>>)>),
+    MethodParameters(Vec<Parameter>),
+    Module(ModuleSyntax),
+    NestHost(CpIndex<ConstantClassInfo>),
+    NestMembers(Vec<CpIndex<ConstantClassInfo>>),
+    PermittedSubclasses(Vec<CpIndex<ConstantClassInfo>>),
+}
+
+pub(super) struct CodeSyntax {
+    pub max_stack: u16,
+    pub max_locals: u16,
+    pub items: Vec<CodeItem>,
+    pub catches: Vec<(Pc, Pc, Pc, CpIndex<ConstantClassInfo>)>,
+    pub attributes: Vec<AttributeSyntax>,
+}
+
+pub(super) enum CodeItem {
+    Label(String),
+    Instruction(InstructionSyntax),
+    Bytes(Vec<u8>),
+}
+
+pub(super) enum InstructionSyntax {
+    Simple { mnemonic: String, operands: Vec<Operand> },
+    Wide { mnemonic: String, operands: Vec<Operand> },
+    Tableswitch { low: i32, targets: Vec<String>, default: String },
+    Lookupswitch { pairs: Vec<(i32, String)>, default: String },
+}
+
+pub(super) enum Operand {
+    Index(u16),
+    Int(i64),
+    Name(String),
+}
+
+// A label, or a pc as a number where there is no instruction to put a label on.
+pub(super) enum Pc {
+    Label(String),
+    Number(u16),
+}
+
+// (start, end, name_index, descriptor_index or signature_index, index)
+pub(super) type LocalVariableSyntax = (Pc, Pc, CpIndex<ConstantUtf8Info>, CpIndex<ConstantUtf8Info>, u16);
+
+pub(super) struct ModuleSyntax {
+    pub module_name_index: CpIndex<ConstantModuleInfo>,
+    pub module_flags: u16,
+    pub module_version_index: CpIndex<ConstantUtf8Info>,
+    pub requires: Vec<Require>,
+    // (index, flags, to)
+    pub exports: Vec<(CpIndex<ConstantPackageInfo>, u16, Vec<CpIndex<ConstantModuleInfo>>)>,
+    pub opens: Vec<(CpIndex<ConstantPackageInfo>, u16, Vec<CpIndex<ConstantModuleInfo>>)>,
+    pub uses: Vec<CpIndex<ConstantClassInfo>>,
+    // (index, with)
+    pub provides: Vec<(CpIndex<ConstantClassInfo>, Vec<CpIndex<ConstantClassInfo>>)>,
+}
+
+enum Item {
+    Field(MemberSyntax),
+    Method(MemberSyntax),
+    Attribute(AttributeSyntax),
+}
+
+enum ModuleStatement {
+    Requires(Require),
+    Exports(CpIndex<ConstantPackageInfo>, u16, Vec<CpIndex<ConstantModuleInfo>>),
+    Opens(CpIndex<ConstantPackageInfo>, u16, Vec<CpIndex<ConstantModuleInfo>>),
+    Uses(CpIndex<ConstantClassInfo>),
+    Provides(CpIndex<ConstantClassInfo>, Vec<CpIndex<ConstantClassInfo>>),
+}
+
+fn hex_bytes(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok()).collect()
+}
+
+fn utf8(bytes: Vec<u8>) -> CpInfo {
+    CpInfo::Utf8(ConstantUtf8Info { tag: CONSTANT_UTF8, length: bytes.len() as u16, bytes })
+}
+
+fn long_bytes(value: u64) -> ([u8; 4], [u8; 4]) {
+    let bytes = value.to_be_bytes();
+    (bytes[0..4].try_into().unwrap(), bytes[4..8].try_into().unwrap())
+}
+
+// e.g. ClassAccessFlags::flag_of_keyword
+type FlagOfKeyword = fn(&str) -> Option<u16>;
+
+fn lookup<T: Copy>(table: &[(T, &str)], name: &str) -> Option<T> {
+    table.iter().find(|(_, keyword)| *keyword == name).map(|(value, _)| *value)
+}
+
+peg::parser! {
+    pub(super) grammar assembly() for str {
+        rule _ = quiet!{[' ' | '\t']*}
+
+        rule __ = quiet!{([' ' | '\t' | '\r' | '\n'] / "//" (!['\n'] [_])*)*}
+
+        rule ident() -> &'input str
+            = quiet!{$(['a'..='z' | 'A'..='Z' | '_' | '$'] ['a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '$']*)} / expected!("identifier")
+
+        rule word(expected: &'static str)
+            = i:ident() {? if i == expected { Ok(()) } else { Err(expected) } }
+
+        rule is(name: &str, expected: &'static str)
+            = "" {? if name == expected { Ok(()) } else { Err(expected) } }
+
+        rule int() -> i64
+            = quiet!{"0x" h:$(['0'..='9' | 'a'..='f' | 'A'..='F']+) {? i64::from_str_radix(h, 16).or(Err("integer")) }
+              / n:$("-"? ['0'..='9']+) {? n.parse().or(Err("integer")) }}
+            / expected!("integer")
+
+        rule u8() -> u8 = n:int() {? u8::try_from(n).or(Err("u1 value")) }
+
+        rule u16() -> u16 = n:int() {? u16::try_from(n).or(Err("u2 value")) }
+
+        rule i32() -> i32 = n:int() {? i32::try_from(n).or(Err("int value")) }
+
+        rule index() -> u16 = "#" i:u16() { i }
+
+        rule cp_index_list<T>() -> Vec<CpIndex<T>> = "[" l:(__ i:cp_index() { i })* __ "]" { l }
+
+        rule hex() -> Vec<u8>
+            = "\"" h:$(['0'..='9' | 'a'..='f' | 'A'..='F']*) "\"" {? hex_bytes(h).ok_or("bytes in hex") }
+
+        rule string() -> String
+            = "\"" cs:string_char()* "\"" { cs.into_iter().collect() }
+
+        rule string_char() -> char
+            = "\\u" h:$(['0'..='9' | 'a'..='f' | 'A'..='F']*<4>) {? char::from_u32(u32::from_str_radix(h, 16).unwrap()).ok_or("character") }
+            / "\\n" { '\n' } / "\\r" { '\r' } / "\\t" { '\t' } / "\\\"" { '"' } / "\\\\" { '\\' }
+            / !['"' | '\\'] c:[_] { c }
+
+        rule float() -> &'input str
+            = quiet!{$("-"? (['0'..='9']+ ("." ['0'..='9']+)? (['e' | 'E'] "-"? ['0'..='9']+)? / "inf" / "NaN"))} / expected!("floating-point number")
+
+        rule flags(flag_of_keyword: FlagOfKeyword) -> u16
+            = fs:(f:flag(flag_of_keyword) _ { f })* { fs.iter().fold(0, |flags, flag| flags | flag) }
+
+        rule flag(flag_of_keyword: FlagOfKeyword) -> u16
+            = "0x" h:$(['0'..='9' | 'a'..='f' | 'A'..='F']+) {? u16::from_str_radix(h, 16).or(Err("access flags")) }
+            / i:ident() {? flag_of_keyword(i).ok_or("access flag") }
+
+        pub rule class() -> ClassSyntax
+            = __ ".version" _ major_version:u16() _ minor_version:u16()
+              __ ".class" _ access_flags:flags(ClassAccessFlags::flag_of_keyword) this_class:cp_index()
+              __ ".super" _ super_class:cp_index()
+              interfaces:(__ ".interfaces" _ l:cp_index_list() { l })?
+              __ ".constants" _ "{" constants:(__ c:constant() { c })* __ "}"
+              items:(__ i:item() { i })* __ ![_]
+            {
+                let mut fields = Vec::new();
+                let mut methods = Vec::new();
+                let mut attributes = Vec::new();
+                for item in items {
+                    match item {
+                        Item::Field(field) => fields.push(field),
+                        Item::Method(method) => methods.push(method),
+                        Item::Attribute(attribute) => attributes.push(attribute),
+                    }
+                }
+                ClassSyntax {
+                    minor_version,
+                    major_version,
+                    access_flags,
+                    this_class,
+                    super_class,
+                    interfaces: interfaces.unwrap_or_default(),
+                    constants,
+                    fields,
+                    methods,
+                    attributes,
+                }
+            }
+
+        rule constant() -> (u16, CpInfo) = "#" i:u16() _ "=" _ c:cp_info() { (i, c) }
+
+        rule cp_info() -> CpInfo
+            = word("Utf8") _ s:string() { utf8(s.into_bytes()) }
+            / word("Utf8") _ word("bytes") _ b:hex() { utf8(b) }
+            / word("Integer") _ n:i32() { CpInfo::Integer(ConstantIntegerInfo { tag: CONSTANT_INTEGER, bytes: n.to_be_bytes() }) }
+            / word("Float") _ word("bits") _ n:int() {? u32::try_from(n).map(|bits| CpInfo::Float(ConstantFloatInfo { tag: CONSTANT_FLOAT, bytes: bits.to_be_bytes() })).or(Err("u4 value")) }
+            / word("Float") _ f:float() {? f.parse::<f32>().map(|value| CpInfo::Float(ConstantFloatInfo { tag: CONSTANT_FLOAT, bytes: value.to_be_bytes() })).or(Err("float")) }
+            / word("Long") _ n:int() { let (high_bytes, low_bytes) = long_bytes(n as u64); CpInfo::Long(ConstantLongInfo { tag: CONSTANT_LONG, high_bytes, low_bytes }) }
+            / word("Double") _ word("bits") _ n:$("0x" ['0'..='9' | 'a'..='f' | 'A'..='F']+) {?
+                match u64::from_str_radix(&n[2..], 16) {
+                    Ok(bits) => { let (high_bytes, low_bytes) = long_bytes(bits); Ok(CpInfo::Double(ConstantDoubleInfo { tag: CONSTANT_DOUBLE, high_bytes, low_bytes })) }
+                    Err(_) => Err("u8 value"),
+                }
+            }
+            / word("Double") _ f:float() {?
+                match f.parse::<f64>() {
+                    Ok(value) => { let (high_bytes, low_bytes) = long_bytes(value.to_bits()); Ok(CpInfo::Double(ConstantDoubleInfo { tag: CONSTANT_DOUBLE, high_bytes, low_bytes })) }
+                    Err(_) => Err("double"),
+                }
+            }
+            / word("Class") _ name_index:cp_index() { CpInfo::Class(ConstantClassInfo { tag: CONSTANT_CLASS, name_index }) }
+            / word("String") _ string_index:cp_index() { CpInfo::String(ConstantStringInfo { tag: CONSTANT_STRING, string_index }) }
+            / word("Fieldref") _ class_index:cp_index() _ name_and_type_index:cp_index() { CpInfo::Fieldref(ConstantFieldrefInfo { tag: CONSTANT_FIELDREF, class_index, name_and_type_index }) }
+            / word("Methodref") _ class_index:cp_index() _ name_and_type_index:cp_index() { CpInfo::Methodref(ConstantMethodrefInfo { tag: CONSTANT_METHODREF, class_index, name_and_type_index }) }
+            / word("InterfaceMethodref") _ class_index:cp_index() _ name_and_type_index:cp_index() { CpInfo::InterfaceMethodref(ConstantInterfaceMethodrefInfo { tag: CONSTANT_INTERFACE_METHODREF, class_index, name_and_type_index }) }
+            / word("NameAndType") _ name_index:cp_index() _ descriptor_index:cp_index() { CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: CONSTANT_NAME_AND_TYPE, name_index, descriptor_index }) }
+            / word("MethodHandle") _ reference_kind:reference_kind() _ reference_index:cp_index() { CpInfo::MethodHandle(ConstantMethodHandleInfo { tag: CONSTANT_METHOD_HANDLE, reference_kind, reference_index }) }
+            / word("MethodType") _ descriptor_index:cp_index() { CpInfo::MethodType(ConstantMethodTypeInfo { tag: CONSTANT_METHOD_TYPE, descriptor_index }) }
+            / word("Dynamic") _ bootstrap_method_attr_index:u16() _ name_and_type_index:cp_index() { CpInfo::Dynamic(ConstantDynamicInfo { tag: CONSTANT_DYNAMIC, bootstrap_method_attr_index, name_and_type_index }) }
+            / word("InvokeDynamic") _ bootstrap_method_attr_index:u16() _ name_and_type_index:cp_index() { CpInfo::InvokeDynamic(ConstantInvokeDynamicInfo { tag: CONSTANT_INVOKE_DYNAMIC, bootstrap_method_attr_index, name_and_type_index }) }
+            / word("Module") _ name_index:cp_index() { CpInfo::Module(ConstantModuleInfo { tag: CONSTANT_MODULE, name_index }) }
+            / word("Package") _ name_index:cp_index() { CpInfo::Package(ConstantPackageInfo { tag: CONSTANT_PACKAGE, name_index }) }
+
+        rule cp_index<T>() -> CpIndex<T> = i:index() { CpIndex::new(i) }
+
+        rule reference_kind() -> u8
+            = u8() / i:ident() {? lookup(REFERENCE_KINDS, i).ok_or("reference kind") }
+
+        rule item() -> Item
+            = ".field" _ access_flags:flags(FieldAccessFlags::flag_of_keyword) name_index:cp_index() _ descriptor_index:cp_index() attributes:attribute_block()
+              { Item::Field(MemberSyntax { access_flags, name_index, descriptor_index, attributes }) }
+            / ".method" _ access_flags:flags(MethodAccessFlags::flag_of_keyword) name_index:cp_index() _ descriptor_index:cp_index() attributes:attribute_block()
+              { Item::Method(MemberSyntax { access_flags, name_index, descriptor_index, attributes }) }
+            / a:attribute() { Item::Attribute(a) }
+
+        rule attribute_block() -> Vec<AttributeSyntax>
+            = _ "{" a:(__ a:attribute() { a })* __ "}" { a }
+            / "" { vec![] }
+
+        rule attribute() -> AttributeSyntax
+            = ".attribute" _ name:ident() name_index:("(" i:cp_index() ")" { i })? _ body:attribute_body(name)
+              { AttributeSyntax { name: name.to_string(), name_index, body } }
+
+        rule attribute_body(name: &str) -> AttributeBody
+            = word("bytes") _ b:hex() { AttributeBody::Bytes(b) }
+            / is(name, "ConstantValue") i:cp_index() { AttributeBody::ConstantValue(i) }
+            / is(name, "Code") c:code() { AttributeBody::Code(c) }
+            / is(name, "StackMapTable") "{" frames:(__ f:frame() { f })* __ "}" { AttributeBody::StackMapTable(frames) }
+            / is(name, "Exceptions") l:cp_index_list() { AttributeBody::Exceptions(l) }
+            / is(name, "InnerClasses") "{" classes:(__ c:inner_class() { c })* __ "}" { AttributeBody::InnerClasses(classes) }
+            / is(name, "EnclosingMethod") class_index:cp_index() _ method_index:cp_index() { AttributeBody::EnclosingMethod(class_index, method_index) }
+            / is(name, "Signature") i:cp_index() { AttributeBody::Signature(i) }
+            / is(name, "LocalVariableTable") "{" l:(__ v:local_variable() { v })* __ "}" { AttributeBody::LocalVariableTable(l) }
+            / is(name, "LocalVariableTypeTable") "{" l:(__ v:local_variable() { v })* __ "}" { AttributeBody::LocalVariableTypeTable(l) }
+            / is(name, "BootstrapMethods") "{" l:(__ r:cp_index() _ arguments:cp_index_list() { (r, arguments) })* __ "}" { AttributeBody::BootstrapMethods(l) }
+            / is(name, "MethodParameters") "{" l:(__ access_flags:flags(ParameterAccessFlags::flag_of_keyword) name_index:cp_index() { Parameter { name_index, access_flags: ParameterAccessFlags::from_bits_retain(access_flags) } })* __ "}" { AttributeBody::MethodParameters(l) }
+            / is(name, "Module") m:module() { AttributeBody::Module(m) }
+            / is(name, "NestHost") i:cp_index() { AttributeBody::NestHost(i) }
+            / is(name, "NestMembers") l:cp_index_list() { AttributeBody::NestMembers(l) }
+            / is(name, "PermittedSubclasses") l:cp_index_list() { AttributeBody::PermittedSubclasses(l) }
+
+        rule inner_class() -> InnerClassesAttributeClass
+            = inner_class_access_flags:flags(InnerClassAccessFlags::flag_of_keyword) inner_class_info_index:cp_index() _ outer_class_info_index:cp_index() _ inner_name_index:cp_index()
+              { InnerClassesAttributeClass { inner_class_info_index, outer_class_info_index, inner_name_index, inner_class_access_flags: InnerClassAccessFlags::from_bits_retain(inner_class_access_flags) } }
+
+        rule local_variable() -> LocalVariableSyntax
+            = start:pc() _ end:pc() _ name_index:cp_index() _ descriptor_index:cp_index() _ index:u16() { (start, end, name_index, descriptor_index, index) }
+
+        rule pc() -> Pc = n:u16() { Pc::Number(n) } / l:ident() { Pc::Label(l.to_string()) }
+
+        rule frame() -> StackMapFrame
+            = word("same_locals_1_stack_item_extended") _ offset_delta:u16() _ t:verification_type()
+              { StackMapFrame::SameLocals1StackItemFrameExtended { frame_type: 247, offset_delta, stack: vec![t] } }
+            / word("same_locals_1_stack_item") _ offset_delta:u8() _ t:verification_type() {?
+                if offset_delta <= 63 { Ok(StackMapFrame::SameLocals1StackItemFrame { frame_type: 64 + offset_delta, stack: vec![t] }) } else { Err("offset_delta up to 63") }
+            }
+            / word("same_extended") _ offset_delta:u16() { StackMapFrame::SameFrameExtended { frame_type: 251, offset_delta } }
+            / word("same") _ offset_delta:u8() {?
+                if offset_delta <= 63 { Ok(StackMapFrame::SameFrame { frame_type: offset_delta }) } else { Err("offset_delta up to 63") }
+            }
+            / word("chop") _ k:u8() _ offset_delta:u16() {?
+                if (1..=3).contains(&k) { Ok(StackMapFrame::ChopFrame { frame_type: 251 - k, offset_delta }) } else { Err("1 to 3 locals to chop") }
+            }
+            / word("append") _ offset_delta:u16() _ locals:verification_types() {?
+                if (1..=3).contains(&locals.len()) { Ok(StackMapFrame::AppendFrame { frame_type: 251 + locals.len() as u8, offset_delta, locals }) } else { Err("1 to 3 locals to append") }
+            }
+            / word("full") _ offset_delta:u16() _ locals:verification_types() _ stack:verification_types() {
+                StackMapFrame::FullFrame {
+                    frame_type: 255,
+                    offset_delta,
+                    number_of_locals: locals.len() as u16,
+                    locals,
+                    number_of_stack_items: stack.len() as u16,
+                    stack,
+                }
+            }
+
+        rule verification_types() -> Vec<VerificationTypeInfo> = "[" l:(_ t:verification_type() { t })* _ "]" { l }
+
+        rule verification_type() -> VerificationTypeInfo
+            = word("Top") { VerificationTypeInfo::TopVariableInfo { tag: 0 } }
+            / word("Integer") { VerificationTypeInfo::IntegerVariableInfo { tag: 1 } }
+            / word("Float") { VerificationTypeInfo::FloatVariableInfo { tag: 2 } }
+            / word("Double") { VerificationTypeInfo::DoubleVariableInfo { tag: 3 } }
+            / word("Long") { VerificationTypeInfo::LongVariableInfo { tag: 4 } }
+            / word("Null") { VerificationTypeInfo::NullVariableInfo { tag: 5 } }
+            / word("UninitializedThis") { VerificationTypeInfo::UninitializedThisVariableInfo { tag: 6 } }
+            / word("Object") _ cpool_index:cp_index() { VerificationTypeInfo::ObjectVariableInfo { tag: 7, cpool_index } }
+            / word("Uninitialized") _ offset:u16() { VerificationTypeInfo::UninitializedVariableInfo { tag: 8, offset } }
+
+        rule module() -> ModuleSyntax
+            = module_flags:flags(ModuleAccessFlags::flag_of_keyword) module_name_index:cp_index() _ module_version_index:cp_index() _ "{" statements:(__ s:module_statement() { s })* __ "}" {
+                let mut module = ModuleSyntax {
+                    module_name_index,
+                    module_flags,
+                    module_version_index,
+                    requires: Vec::new(),
+                    exports: Vec::new(),
+                    opens: Vec::new(),
+                    uses: Vec::new(),
+                    provides: Vec::new(),
+                };
+                for statement in statements {
+                    match statement {
+                        ModuleStatement::Requires(require) => module.requires.push(require),
+                        ModuleStatement::Exports(index, flags, to) => module.exports.push((index, flags, to)),
+                        ModuleStatement::Opens(index, flags, to) => module.opens.push((index, flags, to)),
+                        ModuleStatement::Uses(index) => module.uses.push(index),
+                        ModuleStatement::Provides(index, with) => module.provides.push((index, with)),
+                    }
+                }
+                module
+            }
+
+        rule module_statement() -> ModuleStatement
+            = word("requires") _ requires_flags:flags(RequiresAccessFlags::flag_of_keyword) requires_index:cp_index() _ requires_version_index:cp_index()
+              { ModuleStatement::Requires(Require { requires_index, requires_flags: RequiresAccessFlags::from_bits_retain(requires_flags), requires_version_index }) }
+            / word("exports") _ flags:flags(ExportsAccessFlags::flag_of_keyword) i:cp_index() _ to:cp_index_list() { ModuleStatement::Exports(i, flags, to) }
+            / word("opens") _ flags:flags(ExportsAccessFlags::flag_of_keyword) i:cp_index() _ to:cp_index_list() { ModuleStatement::Opens(i, flags, to) }
+            / word("uses") _ i:cp_index() { ModuleStatement::Uses(i) }
+            / word("provides") _ i:cp_index() _ with:cp_index_list() { ModuleStatement::Provides(i, with) }
+
+        rule code() -> CodeSyntax
+            = word("stack") _ max_stack:u16() _ word("locals") _ max_locals:u16() _ "{" elements:(__ e:code_element() { e })* __ "}" {
+                let mut code = CodeSyntax { max_stack, max_locals, items: Vec::new(), catches: Vec::new(), attributes: Vec::new() };
+                for element in elements {
+                    match element {
+                        CodeElement::Item(item) => code.items.push(item),
+                        CodeElement::Catch(catch) => code.catches.push(catch),
+                        CodeElement::Attribute(attribute) => code.attributes.push(attribute),
+                    }
+                }
+                code
+            }
+
+        rule code_element() -> CodeElement
+            = l:ident() _ ":" { CodeElement::Item(CodeItem::Label(l.to_string())) }
+            / ".catch" _ start:pc() _ end:pc() _ handler:pc() _ catch_type:cp_index() { CodeElement::Catch((start, end, handler, catch_type)) }
+            / a:attribute() { CodeElement::Attribute(a) }
+            / word("bytes") _ b:hex() { CodeElement::Item(CodeItem::Bytes(b)) }
+            / i:instruction() { CodeElement::Item(CodeItem::Instruction(i)) }
+
+        rule instruction() -> InstructionSyntax
+            = word("tableswitch") _ low:i32() _ "{" targets:(__ !word("default") l:ident() { l.to_string() })* __ word("default") _ ":" _ default:ident() __ "}"
+              { InstructionSyntax::Tableswitch { low, targets, default: default.to_string() } }
+            / word("lookupswitch") _ "{" pairs:(__ k:i32() _ ":" _ l:ident() { (k, l.to_string()) })* __ word("default") _ ":" _ default:ident() __ "}"
+              { InstructionSyntax::Lookupswitch { pairs, default: default.to_string() } }
+            / word("wide") _ mnemonic:ident() operands:operands() { InstructionSyntax::Wide { mnemonic: mnemonic.to_string(), operands } }
+            / mnemonic:ident() operands:operands() { InstructionSyntax::Simple { mnemonic: mnemonic.to_string(), operands } }
+
+        rule operands() -> Vec<Operand> = (_ ","? _ o:operand() { o })*
+
+        rule operand() -> Operand
+            = i:index() { Operand::Index(i) }
+            / n:int() { Operand::Int(n) }
+            / i:ident() { lookup(ARRAY_TYPES, i).map_or(Operand::Name(i.to_string()), |atype| Operand::Int(atype as i64)) }
+    }
+}
+
+enum CodeElement {
+    Item(CodeItem),
+    Catch((Pc, Pc, Pc, CpIndex<ConstantClassInfo>)),
+    Attribute(AttributeSyntax),
+}
@@ -0,0 +1,44 @@
+// A text format of class files to be read and edited by hand, and the assembler and disassembler of it.
+// Every part of a class file is written out, so that a class file disassembled and assembled again is the same.
+//
+//     .version 61 0
+//     .class super #7                             // Hello
+//     .super #2                                   // java/lang/Object
+//     .constants {
+//         #1 = Methodref #2 #3                    // java/lang/Object.<init>:()V
+//         #2 = Class #4                           // java/lang/Object
+//         #4 = Utf8 "java/lang/Object"
+//         ...
+//     }
+//     .method static #11 #12 {                    // main:([Ljava/lang/String;)V
+//         .attribute Code stack 2 locals 1 {
+//             getstatic #13                       // java/lang/System.out:Ljava/io/PrintStream;
+//             ldc #19                             // "hello"
+//             invokevirtual #21                   // java/io/PrintStream.println:(Ljava/lang/String;)V
+//             return
+//         }
+//     }
+//     .attribute SourceFile bytes "0019"
+//
+// The constant pool is listed as it is, and the other parts refer to its entries by `#index`.
+// Access flags are written with keywords of the context, e.g. `.class public super #7` and `requires static #12 #0`.
+// Branches, exception handlers and local variables in Code refer to instructions by labels, e.g. `L12:`.
+// Attributes are named by the first CONSTANT_Utf8 entry of the name, or explicitly, e.g. `.attribute Signature(#40) #41`.
+// Attributes that the reader does not parse are written as bytes.
+mod grammar;
+mod assembler;
+mod disassembler;
+
+pub use assembler::assemble;
+pub use disassembler::disassemble;
+
+// 5.4.3.5. Method Type and Method Handle Resolution
+const REFERENCE_KINDS: &[(u8, &str)] = &[
+    (1, "getField"), (2, "getStatic"), (3, "putField"), (4, "putStatic"), (5, "invokeVirtual"),
+    (6, "invokeStatic"), (7, "invokeSpecial"), (8, "newInvokeSpecial"), (9, "invokeInterface"),
+];
+
+// atype of newarray
+const ARRAY_TYPES: &[(u8, &str)] = &[
+    (4, "boolean"), (5, "char"), (6, "float"), (7, "double"), (8, "byte"), (9, "short"), (10, "int"), (11, "long"),
+];
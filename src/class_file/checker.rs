@@ -2,11 +2,12 @@ use crate::class_file::error::{Error, Result};
 use crate::class_file::structure::root::*;
 use crate::class_file::structure::constant_pool::*;
 
-#[allow(unused_imports)]
 use crate::class_file::structure::attribute::*;
 
 use crate::class_file::structure_utils::cp_accessor::*;
 
+use crate::class_file::access_flags::*;
+
 #[allow(unused)]
 use super::descriptor::{MethodType, ReturnType, parse_field_type, parse_method_descriptor};
 
@@ -17,6 +18,23 @@ fn error<T>(message: String) -> Result<T> {
 
 // checkers
 
+// 4.2.2. Unqualified Names
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.2.2
+fn check_unqualified_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.contains(['.', ';', '[', '/']) {
+        return error(format!("invalid unqualified name: {}", name));
+    }
+    Ok(())
+}
+
+fn check_unqualified_method_name(name: &str) -> Result<()> {
+    check_unqualified_name(name)?;
+    if name.contains(['<', '>']) {
+        return error(format!("invalid unqualified method name: {}", name));
+    }
+    Ok(())
+}
+
 pub fn check_magic(magic: &[u8; 4]) -> Result<()> {
     match magic {
         [0xca, 0xfe, 0xba, 0xbe] => Ok(()),
@@ -54,15 +72,17 @@ pub(crate) fn check_constant_pool(constant_pool: &Vec<CpInfo>, major_version: u1
             }
             CpInfo::Long(_) => {
                 constant_pool.access_as_long(cp_index).bytes_as_long()?;
+                check_unusable_entry_after(constant_pool, cp_index)?;
             }
             CpInfo::Double(_) => {
                 constant_pool.access_as_double(cp_index).bytes_as_double()?;
+                check_unusable_entry_after(constant_pool, cp_index)?;
             }
             CpInfo::Class(_) => {
                 constant_pool.access_as_class(cp_index).name().info_or_err?;
             }
             CpInfo::String(_) => {
-                constant_pool.access_as_string(cp_index).name().info_or_err?;
+                constant_pool.access_as_string(cp_index).string().info_or_err?;
             }
             CpInfo::Fieldref(_) => {
                 constant_pool.access_as_fieldref(cp_index).class().info_or_err?;
@@ -84,8 +104,8 @@ pub(crate) fn check_constant_pool(constant_pool: &Vec<CpInfo>, major_version: u1
             }
             CpInfo::InterfaceMethodref(_) => {
                 constant_pool.access_as_interface_methodref(cp_index).class().info_or_err?;
-                let descriptor = constant_pool.access_as_methodref(cp_index).name_and_type().descriptor().bytes_as_string()?;
-                parse_field_type(&descriptor)?;
+                let descriptor = constant_pool.access_as_interface_methodref(cp_index).name_and_type().descriptor().bytes_as_string()?;
+                parse_method_descriptor(&descriptor)?;
             }
             CpInfo::NameAndType(_) => {
                 constant_pool.access_as_name_and_type(cp_index).name().info_or_err?;
@@ -157,21 +177,23 @@ pub(crate) fn check_constant_pool(constant_pool: &Vec<CpInfo>, major_version: u1
                 let accessor = constant_pool.access_as_dynamic(cp_index);
                 // The value of the bootstrap_method_attr_index item must be a valid index into the bootstrap_methods array of the bootstrap method table of this class file (§4.7.23).
                 let _bootstrap_method_attr_index = accessor.get_bootstrap_method_attr_index()?;
-                // don't check here
+                // don't check here, see check_bootstrap_method_attr_indexes
 
+                // The name is an unqualified name and the descriptor is a field descriptor (§4.4.10).
                 let name = accessor.name_and_type().name().bytes_as_string()?;
-                parse_field_type(&name)?;
+                check_unqualified_name(&name)?;
                 let descriptor = accessor.name_and_type().descriptor().bytes_as_string()?;
-                parse_method_descriptor(&descriptor)?;
+                parse_field_type(&descriptor)?;
             }
             CpInfo::InvokeDynamic(_) => {
                 let accessor = constant_pool.access_as_invoke_dynamic(cp_index);
                 // The value of the bootstrap_method_attr_index item must be a valid index into the bootstrap_methods array of the bootstrap method table of this class file (§4.7.23).
                 let _bootstrap_method_attr_index = accessor.get_bootstrap_method_attr_index()?;
-                // don't check here
+                // don't check here, see check_bootstrap_method_attr_indexes
 
+                // The name is the call site's method name and the descriptor is its method descriptor (§4.4.10).
                 let name = accessor.name_and_type().name().bytes_as_string()?;
-                parse_field_type(&name)?;
+                check_unqualified_method_name(&name)?;
                 let descriptor = accessor.name_and_type().descriptor().bytes_as_string()?;
                 parse_method_descriptor(&descriptor)?;
             }
@@ -183,11 +205,25 @@ pub(crate) fn check_constant_pool(constant_pool: &Vec<CpInfo>, major_version: u1
                 let _package_name = constant_pool.access_as_package(cp_index).name().bytes_as_string()?;
                 // TODO: check valid package name
             }
+            CpInfo::Unusable => {
+                if !matches!(i.checked_sub(1).map(|i| &constant_pool[i]), Some(CpInfo::Long(_) | CpInfo::Double(_))) {
+                    return error(format!("The unusable constant_pool entry must follow CONSTANT_Long_info or CONSTANT_Double_info. index: {}", cp_index));
+                }
+            }
         }
     }
     Ok(())
 }
 
+// 4.4.5. The CONSTANT_Long_info and CONSTANT_Double_Info Structures
+// The entry after a CONSTANT_Long_info or CONSTANT_Double_info is valid but unusable.
+fn check_unusable_entry_after(constant_pool: &[CpInfo], cp_index: u16) -> Result<()> {
+    match constant_pool.get(cp_index as usize) {
+        Some(CpInfo::Unusable) => Ok(()),
+        _ => error(format!("The constant_pool entry after CONSTANT_Long_info or CONSTANT_Double_info must be unusable. index: {}", cp_index + 1)),
+    }
+}
+
 
 // original constant_pool table is indexed from 1 to constant_pool_count - 1.
 // Note that the Vec of this cp_infos structure is indexed from 0.
@@ -225,6 +261,54 @@ pub(crate) fn check_constant_pool(constant_pool: &Vec<CpInfo>, major_version: u1
 // }
 
 
+// The bootstrap_method_attr_index of CONSTANT_Dynamic_info and CONSTANT_InvokeDynamic_info
+// must be a valid index into the bootstrap_methods array of the BootstrapMethods attribute (§4.4.10, §4.7.23).
+// It can only be checked after the attributes of the class file are read.
+fn check_bootstrap_method_attr_indexes(class_file: &ClassFile) -> Result<()> {
+    let num_bootstrap_methods = class_file.attributes.iter().find_map(|attribute| match attribute {
+        Attribute::BootstrapMethods(attribute) => Some(attribute.num_bootstrap_methods),
+        _ => None
+    });
+    class_file.constant_pool.iter().try_for_each(|cp_info| {
+        let bootstrap_method_attr_index = match cp_info {
+            CpInfo::Dynamic(info) => info.bootstrap_method_attr_index,
+            CpInfo::InvokeDynamic(info) => info.bootstrap_method_attr_index,
+            _ => return Ok(())
+        };
+        match num_bootstrap_methods {
+            Some(n) if bootstrap_method_attr_index < n => Ok(()),
+            Some(n) => error(format!("bootstrap_method_attr_index must be less than num_bootstrap_methods. bootstrap_method_attr_index: {}, num_bootstrap_methods: {}", bootstrap_method_attr_index, n)),
+            None => error("A class file with CONSTANT_Dynamic_info or CONSTANT_InvokeDynamic_info must have a BootstrapMethods attribute.".to_string())
+        }
+    })
+}
+
+// The bits of access flags that are not defined are ignored (4.1, 4.5, 4.6),
+// and the combinations of the flags of classes, fields, methods and inner classes must be valid.
+fn check_attribute_access_flags(attribute: &Attribute) -> Result<()> {
+    match attribute {
+        Attribute::InnerClasses(attribute) => attribute.classes.iter().try_for_each(|class| {
+            InnerClassAccessFlags::from_bits_truncate(class.inner_class_access_flags.bits()).check()
+        }),
+        _ => Ok(())
+    }
+}
+
+fn check_access_flags(class_file: &ClassFile) -> Result<()> {
+    let constant_pool = &class_file.constant_pool;
+    let class = ClassAccessFlags::from_bits_truncate(class_file.access_flags.bits());
+    class.check()?;
+    class_file.fields.iter().try_for_each(|field| {
+        FieldAccessFlags::from_bits_truncate(field.access_flags.bits()).check(class)
+    })?;
+    class_file.methods.iter().try_for_each(|method| {
+        let name = constant_pool.access(method.name_index).bytes_as_string()?;
+        MethodAccessFlags::from_bits_truncate(method.access_flags.bits()).check(class, &name, class_file.major_version)?;
+        method.attributes.iter().try_for_each(check_attribute_access_flags)
+    })?;
+    class_file.attributes.iter().try_for_each(check_attribute_access_flags)
+}
+
 // 4.8. Format Checking
 // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.8
 pub fn check_class_file(class_file: &ClassFile) -> Result<()> {
@@ -232,9 +316,96 @@ pub fn check_class_file(class_file: &ClassFile) -> Result<()> {
 
     check_constant_pool(&class_file.constant_pool, class_file.major_version)?;
 
+    check_bootstrap_method_attr_indexes(class_file)?;
+
+    check_access_flags(class_file)?;
+
     // check_attributes(&class_file.attributes, &class_file.constant_pool)?;
     // check_fields(&class_file.fields, &class_file.constant_pool)?;
     // check_methods(&class_file.methods, &class_file.constant_pool)?;
 
     Ok(())
 }
+
+#[test]
+fn test_check_interface_methodref() {
+    // Runnable r = ...; r.run();
+    let constant_pool: &Vec<CpInfo> = &vec![
+        CpInfo::InterfaceMethodref(ConstantInterfaceMethodrefInfo { tag: 11, class_index: CpIndex::new(2), name_and_type_index: CpIndex::new(3) }),
+        CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(4) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(5), descriptor_index: CpIndex::new(6) }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 18, bytes: "java/lang/Runnable".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "run".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()V".as_bytes().to_vec() }),
+    ];
+    assert_eq!(check_constant_pool(constant_pool, 61), Ok(()));
+}
+
+#[test]
+fn test_check_invoke_dynamic() {
+    // Runnable r = () -> {};
+    let constant_pool: &Vec<CpInfo> = &vec![
+        CpInfo::InvokeDynamic(ConstantInvokeDynamicInfo { tag: 18, bootstrap_method_attr_index: 0, name_and_type_index: CpIndex::new(2) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(3), descriptor_index: CpIndex::new(4) }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "run".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 22, bytes: "()Ljava/lang/Runnable;".as_bytes().to_vec() }),
+    ];
+    assert_eq!(check_constant_pool(constant_pool, 61), Ok(()));
+
+    let constant_pool: &Vec<CpInfo> = &vec![
+        CpInfo::InvokeDynamic(ConstantInvokeDynamicInfo { tag: 18, bootstrap_method_attr_index: 0, name_and_type_index: CpIndex::new(2) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(3), descriptor_index: CpIndex::new(4) }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "<init>".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()V".as_bytes().to_vec() }),
+    ];
+    assert!(check_constant_pool(constant_pool, 61).is_err());
+}
+
+#[test]
+fn test_check_dynamic() {
+    // a dynamic constant `_` of type int, as produced by ConstantBootstraps
+    let constant_pool: Vec<CpInfo> = vec![
+        CpInfo::Dynamic(ConstantDynamicInfo { tag: 17, bootstrap_method_attr_index: 0, name_and_type_index: CpIndex::new(2) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(3), descriptor_index: CpIndex::new(4) }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "_".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "I".as_bytes().to_vec() }),
+    ];
+    assert_eq!(check_constant_pool(&constant_pool, 61), Ok(()));
+
+    let mut class_file = ClassFile {
+        magic: [0xca, 0xfe, 0xba, 0xbe],
+        minor_version: 0,
+        major_version: 61,
+        constant_pool_count: 5,
+        constant_pool,
+        access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+        this_class: CpIndex::new(0),
+        super_class: CpIndex::new(0),
+        interfaces_count: 0,
+        interfaces: vec![],
+        fields_count: 0,
+        fields: vec![],
+        methods_count: 0,
+        methods: vec![],
+        attributes_count: 0,
+        attributes: vec![],
+    };
+    assert!(check_bootstrap_method_attr_indexes(&class_file).is_err());
+
+    class_file.attributes.push(Attribute::BootstrapMethods(BootstrapMethodsAttribute {
+        attribute_name_index: CpIndex::new(0),
+        attribute_length: 6,
+        num_bootstrap_methods: 1,
+        bootstrap_methods: vec![BootstrapMethod { bootstrap_method_ref: CpIndex::new(0), num_bootstrap_arguments: 0, bootstrap_arguments: vec![] }],
+    }));
+    assert_eq!(check_bootstrap_method_attr_indexes(&class_file), Ok(()));
+
+    // the descriptor of a dynamic constant is a field descriptor, not a method descriptor.
+    let constant_pool: &Vec<CpInfo> = &vec![
+        CpInfo::Dynamic(ConstantDynamicInfo { tag: 17, bootstrap_method_attr_index: 0, name_and_type_index: CpIndex::new(2) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(3), descriptor_index: CpIndex::new(4) }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "_".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()I".as_bytes().to_vec() }),
+    ];
+    assert!(check_constant_pool(constant_pool, 61).is_err());
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use crate::class_file::access_flags::{AccessFlags, ACC_INTERFACE, ACC_PROTECTED};
+use crate::class_file::error::{Error, Result};
+use crate::class_file::structure::root::ClassFile;
+use crate::class_file::structure_utils::cp_accessor::*;
+
+fn error<T>(message: String) -> Result<T> {
+    Err(Error { message: format!("Class hierarchy lookup failed. {}", message) })
+}
+
+/// The subtyping information that the verifier and the analyses need about classes other than the one at hand.
+/// Class names are in the internal form (4.2.1), e.g. `java/lang/Object`.
+pub trait ClassHierarchy {
+    /// Returns the direct superclass, or None for `java/lang/Object`.
+    fn super_class(&self, class_name: &str) -> Result<Option<String>>;
+    fn is_interface(&self, class_name: &str) -> Result<bool>;
+    /// Whether the field or method that the class declares is protected, or None if the class does not declare it,
+    /// for the protected check of 4.10.1.8. Inherited members are not declared by the class.
+    /// `member_descriptor` is the field descriptor or the method descriptor.
+    fn declared_protected(&self, class_name: &str, member_name: &str, member_descriptor: &str) -> Result<Option<bool>>;
+}
+
+// The protected members that java/lang/Object declares. The others are public.
+pub fn is_protected_in_object(member_name: &str, member_descriptor: &str) -> bool {
+    matches!((member_name, member_descriptor), ("clone", "()Ljava/lang/Object;") | ("finalize", "()V"))
+}
+
+/// A class hierarchy made of the given class files.
+/// `java/lang/Object` is always known; any other class must be one of the class files.
+pub struct ClassFileHierarchy<'a> {
+    class_files: HashMap<String, &'a ClassFile>,
+}
+
+impl<'a> ClassFileHierarchy<'a> {
+    pub fn new(class_files: &[&'a ClassFile]) -> Result<ClassFileHierarchy<'a>> {
+        let mut map = HashMap::new();
+        for class_file in class_files {
+            let name = (&class_file.constant_pool).access(class_file.this_class).name().bytes_as_string()?;
+            map.insert(name, *class_file);
+        }
+        Ok(ClassFileHierarchy { class_files: map })
+    }
+
+    fn get(&self, class_name: &str) -> Result<&'a ClassFile> {
+        match self.class_files.get(class_name) {
+            Some(class_file) => Ok(class_file),
+            None => error(format!("class not found: {}", class_name)),
+        }
+    }
+}
+
+impl ClassHierarchy for ClassFileHierarchy<'_> {
+    fn super_class(&self, class_name: &str) -> Result<Option<String>> {
+        if class_name == "java/lang/Object" {
+            return Ok(None);
+        }
+        let class_file = self.get(class_name)?;
+        if class_file.super_class.index() == 0 {
+            return error(format!("class other than java/lang/Object has no superclass: {}", class_name));
+        }
+        let super_name = (&class_file.constant_pool).access(class_file.super_class).name().bytes_as_string()?;
+        Ok(Some(super_name))
+    }
+
+    fn is_interface(&self, class_name: &str) -> Result<bool> {
+        if class_name == "java/lang/Object" {
+            return Ok(false);
+        }
+        Ok(self.get(class_name)?.access_flags.contains(ACC_INTERFACE))
+    }
+
+    fn declared_protected(&self, class_name: &str, member_name: &str, member_descriptor: &str) -> Result<Option<bool>> {
+        if class_name == "java/lang/Object" {
+            return Ok(is_protected_in_object(member_name, member_descriptor).then_some(true));
+        }
+        let class_file = self.get(class_name)?;
+        let constant_pool = &class_file.constant_pool;
+        let members = class_file.fields.iter().map(|field| (field.access_flags.bits(), field.name_index, field.descriptor_index))
+            .chain(class_file.methods.iter().map(|method| (method.access_flags.bits(), method.name_index, method.descriptor_index)));
+        for (access_flags, name_index, descriptor_index) in members {
+            if constant_pool.access(name_index).bytes_as_string()? == member_name
+                && constant_pool.access(descriptor_index).bytes_as_string()? == member_descriptor {
+                return Ok(Some(access_flags & ACC_PROTECTED != 0));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,287 @@
+use crate::class_file::descriptor::FieldType;
+
+// Operator precedence of Java, from the loosest. An operand binding looser than its operator is parenthesized.
+pub(super) const ASSIGNMENT: u8 = 1;
+pub(super) const TERNARY: u8 = 2;
+pub(super) const OR: u8 = 3;
+pub(super) const AND: u8 = 4;
+pub(super) const BIT_OR: u8 = 5;
+pub(super) const BIT_XOR: u8 = 6;
+pub(super) const BIT_AND: u8 = 7;
+pub(super) const EQUALITY: u8 = 8;
+pub(super) const RELATIONAL: u8 = 9;
+pub(super) const SHIFT: u8 = 10;
+pub(super) const ADDITIVE: u8 = 11;
+pub(super) const MULTIPLICATIVE: u8 = 12;
+// unary operators and casts
+pub(super) const UNARY: u8 = 13;
+pub(super) const POSTFIX: u8 = 14;
+// names, literals, method calls, field and array accesses
+pub(super) const PRIMARY: u8 = 15;
+
+/// A value on the operand stack, as a Java expression.
+#[derive(Debug, Clone)]
+pub(super) struct Expr {
+    // The copies made by dup share the id.
+    pub id: usize,
+    pub kind: ExprKind,
+    // None for null, and when unknown
+    pub ty: Option<FieldType>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum ExprKind {
+    // source text, with its precedence
+    Text(String, u8),
+    // an int constant, which may stand for a boolean or a char
+    Int(i32),
+    Local { slot: u16, name: String },
+    Cast(String, Box<Expr>),
+    Binary { left: Box<Expr>, operator: &'static str, right: Box<Expr>, precedence: u8 },
+    // `new C` before the constructor is called
+    Uninitialized(String),
+    // `new T[length]`, and the elements stored right after it as in an array initializer
+    NewArray { element: String, length: Box<Expr>, elements: Vec<Expr> },
+    // lcmp, fcmpl, fcmpg, dcmpl and dcmpg, which the next if compares with 0
+    Compare(Box<Expr>, Box<Expr>),
+    Ternary(Box<Condition>, Box<Expr>, Box<Expr>),
+    // the exception at the start of a handler
+    Caught,
+    // an assignment used as a value, e.g. `y = 0` in `x = y = 0`, or `++x`, with its precedence
+    Assignment(String, u8),
+}
+
+impl Expr {
+    pub fn text(&self) -> (String, u8) {
+        match &self.kind {
+            ExprKind::Text(text, precedence) => (text.clone(), *precedence),
+            ExprKind::Int(value) => (value.to_string(), if *value < 0 { UNARY } else { PRIMARY }),
+            ExprKind::Local { name, .. } => (name.clone(), PRIMARY),
+            ExprKind::Cast(ty, operand) => (format!("({}) {}", ty, operand.wrap(UNARY)), UNARY),
+            ExprKind::Binary { left, operator, right, precedence } => {
+                (format!("{} {} {}", left.wrap(*precedence), operator, right.wrap(*precedence + 1)), *precedence)
+            }
+            ExprKind::Uninitialized(class) => (format!("new {}", class), PRIMARY),
+            ExprKind::NewArray { element, length, elements } => {
+                // e.g. `String[]` as the element of `new String[n][]`
+                let (base, dimensions) = match element.find('[') {
+                    Some(i) => element.split_at(i),
+                    None => (element.as_str(), ""),
+                };
+                if elements.is_empty() {
+                    (format!("new {}[{}]{}", base, length.wrap(ASSIGNMENT), dimensions), PRIMARY)
+                } else {
+                    let elements: Vec<String> = elements.iter().map(|element| element.wrap(ASSIGNMENT)).collect();
+                    (format!("new {}[]{}{{{}}}", base, dimensions, elements.join(", ")), PRIMARY)
+                }
+            }
+            ExprKind::Compare(left, right) => (format!("compare({}, {})", left.wrap(ASSIGNMENT), right.wrap(ASSIGNMENT)), PRIMARY),
+            ExprKind::Ternary(condition, then, otherwise) => {
+                (format!("{} ? {} : {}", condition.wrap(OR), then.wrap(OR), otherwise.wrap(TERNARY)), TERNARY)
+            }
+            ExprKind::Caught => ("exception".to_string(), PRIMARY),
+            ExprKind::Assignment(text, precedence) => (text.clone(), *precedence),
+        }
+    }
+
+    /// The text, parenthesized if it binds looser than `precedence`.
+    pub fn wrap(&self, precedence: u8) -> String {
+        let (text, own) = self.text();
+        parenthesize(text, own, precedence)
+    }
+
+    pub fn is_category2(&self) -> bool {
+        matches!(self.ty, Some(FieldType::Long) | Some(FieldType::Double))
+    }
+}
+
+fn parenthesize(text: String, own: u8, precedence: u8) -> String {
+    if own < precedence { format!("({})", text) } else { text }
+}
+
+/// The condition of a branch, kept apart from expressions so that it can be negated.
+#[derive(Debug, Clone)]
+pub(super) enum Condition {
+    Compare(Expr, &'static str, Expr),
+    // a boolean expression, or its negation if false
+    Truth(Expr, bool),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Condition {
+    pub fn negate(self) -> Condition {
+        match self {
+            Condition::Compare(left, operator, right) => {
+                let negated = match operator {
+                    "==" => "!=",
+                    "!=" => "==",
+                    "<" => ">=",
+                    ">=" => "<",
+                    ">" => "<=",
+                    _ => ">",
+                };
+                Condition::Compare(left, negated, right)
+            }
+            Condition::Truth(expr, truth) => Condition::Truth(expr, !truth),
+            Condition::And(left, right) => Condition::Or(Box::new(left.negate()), Box::new(right.negate())),
+            Condition::Or(left, right) => Condition::And(Box::new(left.negate()), Box::new(right.negate())),
+        }
+    }
+
+    pub fn text(&self) -> (String, u8) {
+        match self {
+            Condition::Compare(left, operator, right) => {
+                let precedence = if *operator == "==" || *operator == "!=" { EQUALITY } else { RELATIONAL };
+                (format!("{} {} {}", left.wrap(precedence), operator, right.wrap(precedence + 1)), precedence)
+            }
+            Condition::Truth(expr, true) => expr.text(),
+            Condition::Truth(expr, false) => (format!("!{}", expr.wrap(UNARY)), UNARY),
+            Condition::And(left, right) => (format!("{} && {}", left.wrap(AND), right.wrap(AND + 1)), AND),
+            Condition::Or(left, right) => (format!("{} || {}", left.wrap(OR), right.wrap(OR + 1)), OR),
+        }
+    }
+
+    pub fn wrap(&self, precedence: u8) -> String {
+        let (text, own) = self.text();
+        parenthesize(text, own, precedence)
+    }
+
+    /// The condition as a boolean expression with the given id.
+    pub fn into_expr(self, id: usize) -> Expr {
+        match self {
+            Condition::Truth(expr, true) => expr,
+            condition => {
+                let (text, precedence) = condition.text();
+                Expr { id, kind: ExprKind::Text(text, precedence), ty: Some(FieldType::Boolean) }
+            }
+        }
+    }
+}
+
+/// Adapts an int expression to where a boolean or a char is expected, e.g. `1` to `true`,
+/// since the JVM has neither and javac compiles them to int constants and branches.
+pub(super) fn coerce(expr: Expr, ty: &FieldType) -> Expr {
+    match (ty, expr.kind) {
+        (FieldType::Boolean, ExprKind::Int(value @ (0 | 1))) => {
+            let text = if value == 1 { "true" } else { "false" };
+            Expr { id: expr.id, kind: ExprKind::Text(text.to_string(), PRIMARY), ty: Some(FieldType::Boolean) }
+        }
+        (FieldType::Boolean, ExprKind::Ternary(condition, then, otherwise)) => {
+            let then = coerce(*then, ty);
+            let otherwise = coerce(*otherwise, ty);
+            match (boolean_literal(&then), boolean_literal(&otherwise)) {
+                (Some(true), Some(false)) => condition.into_expr(expr.id),
+                (Some(false), Some(true)) => condition.negate().into_expr(expr.id),
+                _ => Expr { id: expr.id, kind: ExprKind::Ternary(condition, Box::new(then), Box::new(otherwise)), ty: Some(FieldType::Boolean) },
+            }
+        }
+        (FieldType::Char, ExprKind::Int(value)) if (0..=0xffff).contains(&value) => {
+            Expr { id: expr.id, kind: ExprKind::Text(char_literal(value as u16), PRIMARY), ty: Some(FieldType::Char) }
+        }
+        (FieldType::Char, ExprKind::Ternary(condition, then, otherwise)) => {
+            let kind = ExprKind::Ternary(condition, Box::new(coerce(*then, ty)), Box::new(coerce(*otherwise, ty)));
+            Expr { id: expr.id, kind, ty: Some(ty.clone()) }
+        }
+        (_, kind) => Expr { id: expr.id, kind, ty: expr.ty },
+    }
+}
+
+fn boolean_literal(expr: &Expr) -> Option<bool> {
+    match &expr.kind {
+        ExprKind::Text(text, _) if text == "true" => Some(true),
+        ExprKind::Text(text, _) if text == "false" => Some(false),
+        _ => None,
+    }
+}
+
+pub(super) fn string_literal(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if (c as u32) < 0x20 || c as u32 == 0x7f => literal.push_str(&format!("\\u{:04x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+pub(super) fn char_literal(value: u16) -> String {
+    match char::from_u32(value as u32) {
+        Some('\'') => "'\\''".to_string(),
+        Some('\\') => "'\\\\'".to_string(),
+        Some('\n') => "'\\n'".to_string(),
+        Some('\r') => "'\\r'".to_string(),
+        Some('\t') => "'\\t'".to_string(),
+        Some(c) if (0x20..0x7f).contains(&value) => format!("'{}'", c),
+        _ => format!("'\\u{:04x}'", value),
+    }
+}
+
+pub(super) fn long_literal(value: i64) -> String {
+    format!("{}L", value)
+}
+
+pub(super) fn float_literal(value: f32) -> String {
+    if value.is_nan() {
+        "Float.NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "Float.POSITIVE_INFINITY".to_string() } else { "Float.NEGATIVE_INFINITY".to_string() }
+    } else {
+        format!("{:?}F", value)
+    }
+}
+
+pub(super) fn double_literal(value: f64) -> String {
+    if value.is_nan() {
+        "Double.NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "Double.POSITIVE_INFINITY".to_string() } else { "Double.NEGATIVE_INFINITY".to_string() }
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+#[test]
+fn test_condition() {
+    let local = |name: &str| Expr { id: 0, kind: ExprKind::Local { slot: 0, name: name.to_string() }, ty: Some(FieldType::Int) };
+    let zero = Expr { id: 0, kind: ExprKind::Int(0), ty: Some(FieldType::Int) };
+    // a < 0 || b >= 10
+    let condition = Condition::Or(
+        Box::new(Condition::Compare(local("a"), "<", zero)),
+        Box::new(Condition::Compare(local("b"), ">=", Expr { id: 0, kind: ExprKind::Int(10), ty: Some(FieldType::Int) })),
+    );
+    assert_eq!(condition.text().0, "a < 0 || b >= 10");
+    assert_eq!(condition.clone().negate().text().0, "a >= 0 && b < 10");
+    assert_eq!(Condition::And(Box::new(condition.clone()), Box::new(condition)).text().0, "(a < 0 || b >= 10) && (a < 0 || b >= 10)");
+
+    let ternary = Expr {
+        id: 1,
+        kind: ExprKind::Ternary(
+            Box::new(Condition::Truth(local("flag"), false)),
+            Box::new(Expr { id: 2, kind: ExprKind::Int(1), ty: Some(FieldType::Int) }),
+            Box::new(Expr { id: 3, kind: ExprKind::Int(0), ty: Some(FieldType::Int) }),
+        ),
+        ty: Some(FieldType::Int),
+    };
+    assert_eq!(ternary.text().0, "!flag ? 1 : 0");
+    assert_eq!(coerce(ternary, &FieldType::Boolean).text().0, "!flag");
+}
+
+#[test]
+fn test_literals() {
+    assert_eq!(string_literal("a\"b\\c\n\u{1}"), "\"a\\\"b\\\\c\\n\\u0001\"");
+    assert_eq!(char_literal('x' as u16), "'x'");
+    assert_eq!(char_literal('\'' as u16), "'\\''");
+    assert_eq!(char_literal(0x3042), "'\\u3042'");
+    assert_eq!(float_literal(1.5), "1.5F");
+    assert_eq!(float_literal(1.0), "1.0F");
+    assert_eq!(double_literal(f64::NEG_INFINITY), "Double.NEGATIVE_INFINITY");
+    assert_eq!(long_literal(-3), "-3L");
+}
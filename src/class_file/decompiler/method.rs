@@ -0,0 +1,1965 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::class_file::access_flags::{AccessFlags, ACC_ENUM, ACC_STATIC, ACC_SYNTHETIC};
+use crate::class_file::analysis::cfg::{ControlFlowGraph, EdgeKind};
+use crate::class_file::descriptor::{FieldType, MethodType, ReturnType, parse_field_type, parse_method_descriptor};
+use crate::class_file::error::Result;
+use crate::class_file::instruction::{Instruction, decode};
+use crate::class_file::signature::{JavaTypeSignature, parse_field_signature};
+use crate::class_file::structure::attribute::{Attribute, CodeAttributeInfo};
+use crate::class_file::structure::constant_pool::CpInfo;
+use crate::class_file::structure::root::MethodInfo;
+use crate::class_file::structure_utils::cp_accessor::*;
+
+use super::expression::*;
+use super::statement::{self, Catch, Stmt, make_for_loops};
+use super::{ClassDecompiler, error};
+
+// An entry of LocalVariableTable, with the generic type from LocalVariableTypeTable if any.
+struct Variable {
+    start_pc: u16,
+    // exclusive, and u32 since the scope may end at the end of the code
+    end_pc: u32,
+    slot: u16,
+    name: String,
+    field_type: FieldType,
+    signature: Option<JavaTypeSignature>,
+}
+
+// A loop or a switch, which `break` leaves.
+struct Breakable {
+    exit: u16,
+    // the head of a while loop, where `continue` goes
+    head: Option<u16>,
+    // the head of a do-while loop, whose condition ends the walk of the body
+    do_while_head: Option<u16>,
+    // for `break` and `continue` from a nested loop or switch, which sets is_labeled
+    label: String,
+    is_labeled: bool,
+}
+
+impl Breakable {
+    fn new(pc: u16, exit: u16, head: Option<u16>, do_while_head: Option<u16>) -> Breakable {
+        Breakable { exit, head, do_while_head, label: format!("label{}", pc), is_labeled: false }
+    }
+
+    // The statement, with the label if a jump uses it.
+    fn wrap(self, stmt: Stmt) -> Stmt {
+        if self.is_labeled { Stmt::Labeled { label: self.label, body: Box::new(stmt) } } else { stmt }
+    }
+}
+
+// The local variables declared so far. Restored when a speculative walk is given up.
+#[derive(Clone)]
+struct Declarations {
+    // indexes of `variables`
+    declared: BTreeSet<usize>,
+    // the variables that LocalVariableTable does not name, by the names given to them
+    unnamed: BTreeMap<String, FieldType>,
+    // (type, name) of the variables declared at the start of the method,
+    // since they are assigned before the scope that LocalVariableTable gives, e.g. in both branches of an if
+    hoisted: Vec<(String, String)>,
+}
+
+/// Decompiles a method body by running the code on a stack of expressions,
+/// and by matching the jumps with the shapes of the statements that javac compiles.
+pub(super) struct MethodDecompiler<'a> {
+    class: &'a ClassDecompiler<'a>,
+    code: &'a CodeAttributeInfo,
+    method_name: String,
+    return_type: ReturnType,
+    is_static: bool,
+    pub instructions: Vec<(u16, Instruction)>,
+    // jump target -> pcs of the jumps, with u16::MAX for the start of an exception handler
+    jump_sources: BTreeMap<u16, Vec<u16>>,
+    // loop head -> pc of the last jump back to it
+    loops: BTreeMap<u16, u16>,
+    variables: Vec<Variable>,
+    // (slot, name) of each parameter
+    parameters: Vec<(u16, String)>,
+    // the binary name of the outer class, for a constructor of an inner class, which takes the outer instance in slot 1
+    outer_instance: Option<String>,
+    // slot -> the value captured by a lambda expression, for the body of the lambda
+    captures: BTreeMap<u16, Expr>,
+    declarations: Declarations,
+    // (start_pc, end_pc) of the exception table entries already made into try statements
+    handled_tries: BTreeSet<(u16, u16)>,
+    breakables: Vec<Breakable>,
+    next_id: usize,
+    // the number of temporary variables named so far
+    temporaries: usize,
+    // nesting of regions, 1 for the statements of the method body
+    depth: usize,
+}
+
+impl<'a> MethodDecompiler<'a> {
+    pub fn new(class: &'a ClassDecompiler<'a>, method: &MethodInfo, method_type: &MethodType, code: &'a CodeAttributeInfo) -> Result<MethodDecompiler<'a>> {
+        let constant_pool = &class.class_file.constant_pool;
+        let method_name = constant_pool.access(method.name_index).bytes_as_string()?;
+        let instructions = decode(&code.code)?;
+
+        let mut jump_sources: BTreeMap<u16, Vec<u16>> = BTreeMap::new();
+        for (pc, instruction) in &instructions {
+            for target in instruction.branch_targets(*pc) {
+                jump_sources.entry(target).or_default().push(*pc);
+            }
+        }
+        for entry in &code.exception_table {
+            jump_sources.entry(entry.handler_pc).or_default().push(u16::MAX);
+        }
+
+        // A jump to a block that dominates the jump goes back to the head of a loop.
+        let cfg = ControlFlowGraph::build(code)?;
+        let dominators = cfg.dominators();
+        let mut loops: BTreeMap<u16, u16> = BTreeMap::new();
+        for edge in cfg.edges.iter().filter(|edge| edge.kind == EdgeKind::Jump && dominators.dominates(edge.to, edge.from)) {
+            if let Some((latch, _)) = cfg.blocks[edge.from].instructions.last() {
+                let last = loops.entry(cfg.blocks[edge.to].start_pc).or_insert(*latch);
+                *last = (*last).max(*latch);
+            }
+        }
+
+        let mut signatures = BTreeMap::new();
+        for attribute in &code.attributes {
+            if let Attribute::LocalVariableTypeTable(table) = attribute {
+                for entry in &table.local_variable_type_table {
+                    let signature = constant_pool.access(entry.signature_index).bytes_as_string()?;
+                    signatures.insert((entry.start_pc, entry.index), parse_field_signature(&signature)?);
+                }
+            }
+        }
+        let mut variables = Vec::new();
+        for attribute in &code.attributes {
+            if let Attribute::LocalVariableTable(table) = attribute {
+                for entry in &table.local_variable_table {
+                    let name = constant_pool.access(entry.name_index).bytes_as_string()?;
+                    let descriptor = constant_pool.access(entry.descriptor_index).bytes_as_string()?;
+                    variables.push(Variable {
+                        start_pc: entry.start_pc,
+                        end_pc: entry.start_pc as u32 + entry.length as u32,
+                        slot: entry.index,
+                        name,
+                        field_type: parse_field_type(&descriptor)?,
+                        signature: signatures.remove(&(entry.start_pc, entry.index)),
+                    });
+                }
+            }
+        }
+
+        let is_static = method.access_flags.contains(ACC_STATIC);
+        let mut declarations = Declarations { declared: BTreeSet::new(), unnamed: BTreeMap::new(), hoisted: Vec::new() };
+        let mut parameters = Vec::new();
+        let mut slot = if is_static { 0 } else { 1 };
+        let fallback_names = class.parameter_names(method, method_type.parameter_types.len())?;
+        for (parameter_type, fallback_name) in method_type.parameter_types.iter().zip(fallback_names) {
+            let name = match variables.iter().position(|variable| variable.slot == slot && variable.start_pc == 0) {
+                Some(i) => {
+                    declarations.declared.insert(i);
+                    variables[i].name.clone()
+                }
+                None => {
+                    declarations.unnamed.insert(fallback_name.clone(), parameter_type.clone());
+                    fallback_name
+                }
+            };
+            parameters.push((slot, name));
+            slot += if matches!(parameter_type, FieldType::Long | FieldType::Double) { 2 } else { 1 };
+        }
+        if !is_static {
+            if let Some(i) = variables.iter().position(|variable| variable.slot == 0 && variable.start_pc == 0) {
+                declarations.declared.insert(i);
+            }
+        }
+        let outer_instance = class.outer_class()
+            .filter(|outer| method_name == "<init>" && method_type.parameter_types.first() == Some(&FieldType::Class { name: outer.clone() }));
+
+        Ok(MethodDecompiler {
+            class,
+            code,
+            method_name,
+            return_type: method_type.return_type.clone(),
+            is_static,
+            instructions,
+            jump_sources,
+            loops,
+            variables,
+            parameters,
+            outer_instance,
+            captures: BTreeMap::new(),
+            declarations,
+            handled_tries: BTreeSet::new(),
+            breakables: Vec::new(),
+            next_id: 0,
+            temporaries: 0,
+            depth: 0,
+        })
+    }
+
+    pub fn parameter_names(&self) -> Vec<String> {
+        self.parameters.iter().map(|(_, name)| name.clone()).collect()
+    }
+
+    pub fn body(&mut self) -> Result<Vec<Stmt>> {
+        let code_length = self.code.code.len() as u16;
+        let mut stmts = self.region(0, code_length, code_length)?;
+        // The call of the super constructor without arguments, or of Enum's, is implicit, and any other has to come first.
+        let mut body = Vec::new();
+        if self.method_name == "<init>" {
+            // An inner class stores the outer instance, and a local class the captured values, in synthetic fields before the call.
+            // The outer instance is implicit, and the captured values are stored after the call instead.
+            let prologue = stmts.iter().take_while(|stmt| matches!(stmt, Stmt::Expr(text) if self.is_synthetic_store(text))).count();
+            let captured: Vec<Stmt> = stmts.drain(..prologue).filter(|stmt| !matches!(stmt, Stmt::Expr(text) if text.ends_with(".this"))).collect();
+            match stmts.first() {
+                Some(Stmt::Expr(text)) if text == "super()" || self.class.class_file.access_flags.contains(ACC_ENUM) && text.starts_with("super(") => {
+                    stmts.remove(0);
+                }
+                Some(Stmt::Expr(text)) if text.starts_with("super(") || text.starts_with("this(") => body.push(stmts.remove(0)),
+                _ => {}
+            }
+            body.extend(captured);
+        }
+        if self.method_name == "<clinit>" {
+            // The field of the assert statements is initialized by `$assertionsDisabled = !C.class.desiredAssertionStatus()`.
+            stmts.retain(|stmt| !matches!(stmt, Stmt::Expr(text) if text.split_once(" = ").is_some_and(|(field, _)| self.is_assertions_disabled(field))));
+        }
+        for (ty, name) in &self.declarations.hoisted {
+            body.push(Stmt::Declare { ty: ty.clone(), name: name.clone(), value: None });
+        }
+        body.extend(stmts);
+        Ok(body)
+    }
+
+    // Whether the statement is `this.f = ...` for a synthetic field f of this class.
+    fn is_synthetic_store(&self, text: &str) -> bool {
+        let Some((name, _)) = text.strip_prefix("this.").and_then(|text| text.split_once(" = ")) else {
+            return false;
+        };
+        let class_file = self.class.class_file;
+        class_file.fields.iter().any(|field| {
+            field.access_flags.contains(ACC_SYNTHETIC)
+                && (&class_file.constant_pool).access(field.name_index).bytes_as_string().is_ok_and(|field_name| field_name == name)
+        })
+    }
+
+    // The statements of the code in [start, end), after which the execution continues at `follow`.
+    fn region(&mut self, start: u16, end: u16, follow: u16) -> Result<Vec<Stmt>> {
+        let mut stack = Vec::new();
+        let mut stmts = Vec::new();
+        self.depth += 1;
+        let result = self.walk(start, end, follow, &mut stack, &mut stmts);
+        self.depth -= 1;
+        if result?.is_some() {
+            return error(format!("The condition of a do-while loop is found out of the loop, pc: {}", start));
+        }
+        if !stack.is_empty() {
+            return error(format!("Values are left on the operand stack at the end of the statements from pc {} to {}", start, end));
+        }
+        Ok(make_for_loops(stmts))
+    }
+
+    // Walks the code in [start, end) and appends the statements.
+    // Returns the condition at the end of a do-while loop, where the walk of its body stops.
+    fn walk(&mut self, start: u16, end: u16, follow: u16, stack: &mut Vec<Expr>, stmts: &mut Vec<Stmt>) -> Result<Option<Condition>> {
+        let mut pc = start;
+        while pc < end {
+            if stack.is_empty() {
+                if let Some(next_pc) = self.loop_statement(pc, end, stmts)? {
+                    pc = next_pc;
+                    continue;
+                }
+                if let Some(next_pc) = self.try_statement(pc, end, stmts)? {
+                    pc = next_pc;
+                    continue;
+                }
+            }
+            let index = self.index_of(pc)?;
+            let instruction = self.instructions[index].1.clone();
+            let next_pc = self.next_pc(index);
+            match instruction.unwide() {
+                instruction if is_conditional(instruction) => {
+                    let branch = self.condition(pc, stack)?;
+                    if let Some(breakable) = self.breakables.last() {
+                        if breakable.do_while_head == Some(branch.1) && breakable.exit == branch.2 {
+                            return Ok(Some(branch.0));
+                        }
+                    }
+                    pc = self.if_statement(branch, end, follow, stack, stmts)?;
+                }
+                Instruction::Goto(_) | Instruction::GotoW(_) => {
+                    let target = instruction.branch_targets(pc)[0];
+                    if !(target == follow && next_pc >= end) {
+                        let jump = self.jump(pc, target)?;
+                        stmts.push(jump);
+                    }
+                    pc = next_pc;
+                }
+                Instruction::Tableswitch { .. } | Instruction::Lookupswitch { .. } => {
+                    pc = self.switch_statement(pc, &instruction, end, stack, stmts)?;
+                }
+                Instruction::Ireturn | Instruction::Lreturn | Instruction::Freturn | Instruction::Dreturn | Instruction::Areturn => {
+                    let mut value = pop(stack)?;
+                    if let ReturnType::Field { value: return_type } = &self.return_type {
+                        value = coerce(value, return_type);
+                    }
+                    stmts.push(Stmt::Return(Some(value.wrap(ASSIGNMENT))));
+                    pc = next_pc;
+                }
+                Instruction::Return => {
+                    if !(self.depth == 1 && next_pc as usize >= self.code.code.len()) {
+                        stmts.push(Stmt::Return(None));
+                    }
+                    pc = next_pc;
+                }
+                Instruction::Athrow => {
+                    let value = pop(stack)?;
+                    stmts.push(Stmt::Throw(value.wrap(ASSIGNMENT)));
+                    pc = next_pc;
+                }
+                Instruction::Jsr(_) | Instruction::JsrW(_) | Instruction::Ret(_) => {
+                    return error(format!("Subroutines of jsr and ret are not supported, pc: {}", pc));
+                }
+                _ => {
+                    self.execute(pc, next_pc, &instruction, stack, stmts)?;
+                    pc = next_pc;
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    // `break` or `continue` for the jump at pc, with the label of an outer loop or switch where needed.
+    fn jump(&mut self, pc: u16, target: u16) -> Result<Stmt> {
+        if self.breakables.last().is_some_and(|breakable| breakable.exit == target) {
+            return Ok(Stmt::Break(None));
+        }
+        let innermost_loop = self.breakables.iter().rev().find(|breakable| breakable.head.is_some() || breakable.do_while_head.is_some());
+        if innermost_loop.is_some_and(|breakable| breakable.head == Some(target)) {
+            return Ok(Stmt::Continue(None));
+        }
+        for breakable in self.breakables.iter_mut().rev() {
+            if breakable.exit == target {
+                breakable.is_labeled = true;
+                return Ok(Stmt::Break(Some(breakable.label.clone())));
+            }
+            if breakable.head == Some(target) {
+                breakable.is_labeled = true;
+                return Ok(Stmt::Continue(Some(breakable.label.clone())));
+            }
+        }
+        error(format!("The jump to {} does not fit the statements, pc: {}", target, pc))
+    }
+
+    // An if statement or a ternary for a branch from `condition`.
+    fn if_statement(&mut self, branch: (Condition, u16, u16), end: u16, follow: u16, stack: &mut Vec<Expr>, stmts: &mut Vec<Stmt>) -> Result<u16> {
+        let (condition, target, fallthrough) = branch;
+        if target == fallthrough {
+            return Ok(fallthrough);
+        }
+        if target < fallthrough || target > end {
+            if target == follow && target > end {
+                // the rest of the region is skipped when the condition holds
+                let then = self.region(fallthrough, end, follow)?;
+                stmts.push(self.if_then(condition, then));
+                return Ok(end);
+            }
+            let branch_pc = self.instructions[self.index_before(fallthrough)?].0;
+            let jump = self.jump(branch_pc, target)?;
+            stmts.push(Stmt::If { condition: condition.text().0, then: vec![jump], otherwise: vec![] });
+            return Ok(fallthrough);
+        }
+
+        // The then part of an if-else ends with a jump over the else part.
+        let before_target = self.index_before(target)?;
+        let (last_pc, last) = self.instructions[before_target].clone();
+        if last_pc >= fallthrough && matches!(last, Instruction::Goto(_) | Instruction::GotoW(_)) {
+            let join = last.branch_targets(last_pc)[0];
+            if join > target && join <= end {
+                if let Some(value) = self.ternary(&condition, fallthrough, last_pc, target, join, stack)? {
+                    stack.push(value);
+                    return Ok(join);
+                }
+                let then = self.region(fallthrough, last_pc, join)?;
+                let otherwise = self.region(target, join, join)?;
+                stmts.push(Stmt::If { condition: condition.negate().text().0, then, otherwise });
+                return Ok(join);
+            }
+            if join == follow && (join > end || join <= fallthrough) {
+                // both parts go on to where the region does
+                let then = self.region(fallthrough, last_pc, follow)?;
+                let otherwise = self.region(target, end, follow)?;
+                stmts.push(Stmt::If { condition: condition.negate().text().0, then, otherwise });
+                return Ok(end);
+            }
+        }
+        let then = self.region(fallthrough, target, target)?;
+        stmts.push(self.if_then(condition, then));
+        Ok(target)
+    }
+
+    // `if (!skip) { then }`, or an assert statement, which javac compiles to
+    // `if (!$assertionsDisabled && !condition) { throw new AssertionError(message); }`.
+    // The condition of an if statement around the assert statement may be joined with it,
+    // as in `if (a && !$assertionsDisabled && !condition)`.
+    fn if_then(&self, skip: Condition, then: Vec<Stmt>) -> Stmt {
+        if let [Stmt::Throw(value)] = then.as_slice() {
+            let message = value.strip_prefix("new AssertionError(").and_then(|rest| rest.strip_suffix(')'));
+            let mut alternatives = Vec::new();
+            flatten_or(skip.clone(), &mut alternatives);
+            let disabled = alternatives.iter().position(|alternative| {
+                matches!(alternative, Condition::Truth(expr, true) if self.is_assertions_disabled(&expr.text().0))
+            });
+            if let (Some(message), Some(i)) = (message, disabled.filter(|i| i + 1 < alternatives.len())) {
+                let message = Some(message.to_string()).filter(|message| !message.is_empty());
+                let condition = join_or(alternatives.split_off(i + 1)).text().0;
+                alternatives.pop();
+                let assert = Stmt::Assert { condition, message };
+                if alternatives.is_empty() {
+                    return assert;
+                }
+                return Stmt::If { condition: join_or(alternatives).negate().text().0, then: vec![assert], otherwise: vec![] };
+            }
+        }
+        Stmt::If { condition: skip.negate().text().0, then, otherwise: vec![] }
+    }
+
+    // Whether the text is the synthetic field of this class that javac adds for assert statements.
+    fn is_assertions_disabled(&self, text: &str) -> bool {
+        let class_file = self.class.class_file;
+        (text == "$assertionsDisabled" || text.ends_with(".$assertionsDisabled")) && class_file.fields.iter().any(|field| {
+            field.access_flags.bits() & (ACC_STATIC | ACC_SYNTHETIC) == ACC_STATIC | ACC_SYNTHETIC
+                && (&class_file.constant_pool).access(field.name_index).bytes_as_string().is_ok_and(|name| name == "$assertionsDisabled")
+        })
+    }
+
+    // `condition ? then : otherwise` where both parts only push a value.
+    fn ternary(&mut self, condition: &Condition, then_start: u16, then_end: u16, otherwise_start: u16, join: u16, stack: &[Expr]) -> Result<Option<Expr>> {
+        let declarations = self.declarations.clone();
+        let handled_tries = self.handled_tries.clone();
+        let then = self.pure_value(then_start, then_end, join, stack);
+        let otherwise = match then {
+            Some(_) => self.pure_value(otherwise_start, join, join, stack),
+            None => None,
+        };
+        match (then, otherwise) {
+            (Some(then), Some(otherwise)) => {
+                let ty = then.ty.clone().or(otherwise.ty.clone());
+                let kind = ExprKind::Ternary(Box::new(condition.clone().negate()), Box::new(then), Box::new(otherwise));
+                Ok(Some(self.expr(kind, ty)))
+            }
+            _ => {
+                self.declarations = declarations;
+                self.handled_tries = handled_tries;
+                Ok(None)
+            }
+        }
+    }
+
+    // The value that the code in [start, end) pushes without any statement.
+    fn pure_value(&mut self, start: u16, end: u16, follow: u16, stack: &[Expr]) -> Option<Expr> {
+        let mut scratch = stack.to_vec();
+        let mut stmts = Vec::new();
+        self.depth += 1;
+        let result = self.walk(start, end, follow, &mut scratch, &mut stmts);
+        self.depth -= 1;
+        let keeps_stack = scratch.len() == stack.len() + 1 && scratch.iter().zip(stack).all(|(after, before)| after.id == before.id);
+        match result {
+            Ok(None) if stmts.is_empty() && keeps_stack => scratch.pop(),
+            _ => None,
+        }
+    }
+
+    // A loop whose head is at pc, as javac compiles while, for and do-while loops.
+    fn loop_statement(&mut self, pc: u16, end: u16, stmts: &mut Vec<Stmt>) -> Result<Option<u16>> {
+        let latch = match self.loops.get(&pc) {
+            Some(latch) if *latch < end => *latch,
+            _ => return Ok(None),
+        };
+        if self.breakables.iter().any(|breakable| breakable.head == Some(pc) || breakable.do_while_head == Some(pc)) {
+            return Ok(None);
+        }
+        // a try statement around the whole loop comes first
+        if self.code.exception_table.iter().any(|entry| entry.start_pc == pc && entry.end_pc > latch && !self.handled_tries.contains(&(entry.start_pc, entry.end_pc))) {
+            return Ok(None);
+        }
+        let latch_index = self.index_of(latch)?;
+        let latch_instruction = self.instructions[latch_index].1.clone();
+        let exit = self.next_pc(latch_index);
+
+        if is_conditional(&latch_instruction) {
+            self.breakables.push(Breakable::new(pc, exit, None, Some(pc)));
+            let mut stack = Vec::new();
+            let mut body = Vec::new();
+            self.depth += 1;
+            let result = self.walk(pc, exit, exit, &mut stack, &mut body);
+            self.depth -= 1;
+            let breakable = self.breakables.pop().unwrap();
+            return match result? {
+                Some(condition) => {
+                    stmts.push(breakable.wrap(Stmt::DoWhile { body: make_for_loops(body), condition: condition.text().0 }));
+                    Ok(Some(exit))
+                }
+                None => error(format!("The condition of the do-while loop at pc {} is not found", pc)),
+            };
+        }
+
+        // while (condition), where the condition at the head leaves the loop to right after the jump back
+        let declarations = self.declarations.clone();
+        let mut stack = Vec::new();
+        if let Some(branch_pc) = self.pure_prefix(pc, &mut stack)? {
+            let (condition, target, fallthrough) = self.condition(branch_pc, &mut stack)?;
+            if target == exit && fallthrough <= latch && stack.is_empty() {
+                self.breakables.push(Breakable::new(pc, exit, Some(pc), None));
+                let body = self.region(fallthrough, latch, pc);
+                let breakable = self.breakables.pop().unwrap();
+                stmts.push(breakable.wrap(Stmt::While { condition: condition.negate().text().0, body: body? }));
+                return Ok(Some(exit));
+            }
+        }
+        self.declarations = declarations;
+
+        self.breakables.push(Breakable::new(pc, exit, Some(pc), None));
+        let body = self.region(pc, latch, pc);
+        let breakable = self.breakables.pop().unwrap();
+        stmts.push(breakable.wrap(Stmt::While { condition: "true".to_string(), body: body? }));
+        Ok(Some(exit))
+    }
+
+    fn switch_statement(&mut self, pc: u16, instruction: &Instruction, end: u16, stack: &mut Vec<Expr>, stmts: &mut Vec<Stmt>) -> Result<u16> {
+        let value = pop(stack)?;
+        let targets = instruction.branch_targets(pc);
+        let default = targets[0];
+        let keys: Vec<i32> = match instruction {
+            Instruction::Tableswitch { low, offsets, .. } => (0..offsets.len() as i32).map(|i| low + i).collect(),
+            Instruction::Lookupswitch { pairs, .. } => pairs.iter().map(|(key, _)| *key).collect(),
+            _ => vec![],
+        };
+        let cases: Vec<(i32, u16)> = keys.into_iter().zip(targets[1..].iter().copied()).collect();
+        let last_case = cases.iter().map(|(_, target)| *target).max().unwrap_or(default);
+        let first = targets.iter().copied().min().unwrap_or(default);
+        let last = last_case.max(default);
+
+        // `break` jumps to the end of the switch, or else a default after all the cases may be the end.
+        let exit = self.instructions.iter()
+            .filter(|(pc, instruction)| *pc >= first && *pc < last && matches!(instruction, Instruction::Goto(_) | Instruction::GotoW(_)))
+            .map(|(pc, instruction)| instruction.branch_targets(*pc)[0])
+            .filter(|target| *target >= last && *target <= end)
+            .min()
+            .unwrap_or(if default >= last_case { default } else { end });
+
+        let mut starts: Vec<u16> = targets.iter().copied().filter(|target| *target < exit).collect();
+        starts.sort();
+        starts.dedup();
+        let mut groups = Vec::new();
+        self.breakables.push(Breakable::new(pc, exit, None, None));
+        for (i, start) in starts.iter().enumerate() {
+            let mut labels: Vec<Option<String>> = Vec::new();
+            for (key, _) in cases.iter().filter(|(_, target)| target == start) {
+                let label = self.expr(ExprKind::Int(*key), Some(FieldType::Int));
+                let label = match &value.ty {
+                    Some(ty) => coerce(label, ty),
+                    None => label,
+                };
+                labels.push(Some(label.text().0));
+            }
+            if default == *start {
+                labels.push(None);
+            }
+            let case_end = starts.get(i + 1).copied().unwrap_or(exit);
+            match self.region(*start, case_end, case_end) {
+                Ok(body) => groups.push((labels, body)),
+                Err(e) => {
+                    self.breakables.pop();
+                    return Err(e);
+                }
+            }
+        }
+        let breakable = self.breakables.pop().unwrap();
+        stmts.push(breakable.wrap(Stmt::Switch { value: value.wrap(ASSIGNMENT), cases: groups }));
+        Ok(exit)
+    }
+
+    // A try statement over the exception table entries that start at pc.
+    fn try_statement(&mut self, pc: u16, end: u16, stmts: &mut Vec<Stmt>) -> Result<Option<u16>> {
+        let range_end = match self.code.exception_table.iter()
+            .filter(|entry| entry.start_pc == pc && !self.handled_tries.contains(&(entry.start_pc, entry.end_pc)))
+            .map(|entry| entry.end_pc)
+            .max() {
+            Some(range_end) => range_end,
+            None => return Ok(None),
+        };
+        let entries: Vec<(u16, u16)> = self.code.exception_table.iter()
+            .filter(|entry| entry.start_pc == pc && entry.end_pc == range_end)
+            .map(|entry| (entry.handler_pc, entry.catch_type.index()))
+            .collect();
+        let mut handlers: Vec<u16> = entries.iter().map(|(handler_pc, _)| *handler_pc).collect();
+        handlers.sort();
+        handlers.dedup();
+        if handlers[0] < range_end || handlers[0] >= end {
+            return Ok(None);
+        }
+        self.handled_tries.insert((pc, range_end));
+
+        // The try block and each catch block but the last end with a jump to the statement after them.
+        let mut body_end = handlers[0];
+        let mut join: Option<u16> = None;
+        for (i, handler) in handlers.iter().enumerate() {
+            let (last_pc, last) = self.instructions[self.index_before(*handler)?].clone();
+            if matches!(last, Instruction::Goto(_) | Instruction::GotoW(_)) {
+                let target = last.branch_targets(last_pc)[0];
+                if target > *handler {
+                    join = Some(join.map_or(target, |join| join.max(target)));
+                    if i == 0 {
+                        body_end = last_pc;
+                    }
+                }
+            }
+        }
+        let join = join.map_or(end, |join| join.min(end));
+
+        // The code after the range, as javac leaves a copy of a finally block, runs after the try statement,
+        // unless the range ends within a statement, as before the return instruction of `return value;`,
+        // or within a nested try statement.
+        let mut protected_end = range_end.min(body_end);
+        let nested_inside = self.code.exception_table.iter()
+            .filter(|entry| (pc..protected_end).contains(&entry.start_pc) && (entry.start_pc, entry.end_pc) != (pc, range_end))
+            .all(|entry| entry.end_pc <= protected_end && entry.handler_pc < protected_end);
+        let mut body = None;
+        if protected_end < body_end && nested_inside {
+            let (declarations, handled_tries, breakables) = (self.declarations.clone(), self.handled_tries.clone(), self.breakables.len());
+            body = self.region(pc, protected_end, protected_end).ok();
+            if body.is_none() {
+                (self.declarations, self.handled_tries) = (declarations, handled_tries);
+                self.breakables.truncate(breakables);
+            }
+        }
+        let body = match body {
+            Some(body) => body,
+            None => {
+                protected_end = body_end;
+                self.region(pc, body_end, join)?
+            }
+        };
+        let mut catches = Vec::new();
+        for (i, handler) in handlers.iter().enumerate() {
+            let handler_end = handlers.get(i + 1).copied().unwrap_or(join);
+            let mut types = Vec::new();
+            for (_, catch_type) in entries.iter().filter(|(handler_pc, _)| handler_pc == handler) {
+                let class_name = match catch_type {
+                    0 => "java/lang/Throwable".to_string(),
+                    index => (&self.class.class_file.constant_pool).access_as_class(*index).name().bytes_as_string()?,
+                };
+                types.push(self.class.names.borrow_mut().class(&class_name));
+            }
+
+            // The handler stores the exception to the variable of the catch clause.
+            let index = self.index_of(*handler)?;
+            let next_pc = self.next_pc(index);
+            let (name, body) = match local_access(&self.instructions[index].1) {
+                Some((slot, 'a', true)) => {
+                    let name = match self.variable_for_store(slot, *handler, next_pc) {
+                        Some(i) => {
+                            self.declarations.declared.insert(i);
+                            self.variables[i].name.clone()
+                        }
+                        None => {
+                            let name = self.unnamed_name(slot, 'a');
+                            self.declarations.unnamed.insert(name.clone(), FieldType::Class { name: "java/lang/Throwable".to_string() });
+                            name
+                        }
+                    };
+                    (name, self.region(next_pc, handler_end, join)?)
+                }
+                _ if self.instructions[index].1 == Instruction::Pop => ("ignored".to_string(), self.region(next_pc, handler_end, join)?),
+                _ => {
+                    let mut stack = vec![self.expr(ExprKind::Caught, None)];
+                    let mut body = Vec::new();
+                    self.depth += 1;
+                    let result = self.walk(*handler, handler_end, join, &mut stack, &mut body);
+                    self.depth -= 1;
+                    result?;
+                    ("exception".to_string(), make_for_loops(body))
+                }
+            };
+            catches.push(Catch { types, name, body });
+        }
+        let unprotected = self.region(protected_end, body_end, join)?;
+        if !unprotected.is_empty() && catches.iter().any(|catch| completes_normally(&catch.body)) {
+            return error(format!("The code after the try block at pc {} is not protected by its handlers", range_end));
+        }
+        stmts.push(Stmt::Try { body, catches });
+        stmts.extend(unprotected);
+        Ok(Some(join))
+    }
+
+    // The condition of the branch at pc, joined with the branches right after it into `&&` and `||`.
+    // Returns the condition under which the jump is taken, the target and the pc after the branches.
+    fn condition(&mut self, pc: u16, stack: &mut Vec<Expr>) -> Result<(Condition, u16, u16)> {
+        let index = self.index_of(pc)?;
+        let instruction = self.instructions[index].1.clone();
+        let first = self.branch_condition(&instruction, stack)?;
+        // (condition, target, fallthrough) of each branch, and the stack after it
+        let mut chain = vec![(first, instruction.branch_targets(pc)[0], self.next_pc(index))];
+        let mut stacks = vec![stack.clone()];
+        loop {
+            let mut scratch = stack.clone();
+            let start = chain.last().unwrap().2;
+            match self.pure_branch(start, pc, &mut scratch)? {
+                Some(branch) if scratch.len() == stack.len() => {
+                    chain.push(branch);
+                    stacks.push(scratch);
+                }
+                _ => break,
+            }
+        }
+        // The longest chain that makes one condition, e.g. for `if (a) { if (b) x(); else y(); }` only `a`.
+        while !chain.is_empty() {
+            if let Some(condition) = join_conditions(chain.clone()) {
+                *stack = stacks[chain.len() - 1].clone();
+                return Ok(condition);
+            }
+            chain.pop();
+        }
+        error(format!("Illegal condition at pc {}", pc))
+    }
+
+    // A block at start that only computes a condition, and is entered from the chain of conditions only.
+    fn pure_branch(&mut self, start: u16, chain_start: u16, stack: &mut Vec<Expr>) -> Result<Option<(Condition, u16, u16)>> {
+        let from_outside = self.jump_sources.get(&start).is_some_and(|sources| sources.iter().any(|source| *source < chain_start || *source >= start));
+        if from_outside || self.code.exception_table.iter().any(|entry| entry.start_pc == start) {
+            return Ok(None);
+        }
+        let branch_pc = match self.pure_prefix(start, stack)? {
+            Some(branch_pc) => branch_pc,
+            None => return Ok(None),
+        };
+        let index = self.index_of(branch_pc)?;
+        let instruction = self.instructions[index].1.clone();
+        let condition = self.branch_condition(&instruction, stack)?;
+        Ok(Some((condition, instruction.branch_targets(branch_pc)[0], self.next_pc(index))))
+    }
+
+    // Runs the code from start up to a conditional branch, and returns its pc if it comes without any statement.
+    fn pure_prefix(&mut self, start: u16, stack: &mut Vec<Expr>) -> Result<Option<u16>> {
+        let declarations = self.declarations.clone();
+        let mut pc = start;
+        loop {
+            if pc != start && self.jump_sources.contains_key(&pc) {
+                break;
+            }
+            let index = match self.index_of(pc) {
+                Ok(index) => index,
+                Err(_) => break,
+            };
+            let instruction = self.instructions[index].1.clone();
+            if is_conditional(&instruction) {
+                return Ok(Some(pc));
+            }
+            if !instruction.falls_through() || matches!(instruction, Instruction::Tableswitch { .. } | Instruction::Lookupswitch { .. }) {
+                break;
+            }
+            let next_pc = self.next_pc(index);
+            let mut stmts = Vec::new();
+            if self.execute(pc, next_pc, &instruction, stack, &mut stmts).is_err() || !stmts.is_empty() {
+                break;
+            }
+            pc = next_pc;
+        }
+        self.declarations = declarations;
+        Ok(None)
+    }
+
+    // The condition under which a conditional branch jumps.
+    fn branch_condition(&mut self, instruction: &Instruction, stack: &mut Vec<Expr>) -> Result<Condition> {
+        use Instruction::*;
+        let operator = match instruction {
+            Ifeq(_) | IfIcmpeq(_) | IfAcmpeq(_) | Ifnull(_) => "==",
+            Ifne(_) | IfIcmpne(_) | IfAcmpne(_) | Ifnonnull(_) => "!=",
+            Iflt(_) | IfIcmplt(_) => "<",
+            Ifge(_) | IfIcmpge(_) => ">=",
+            Ifgt(_) | IfIcmpgt(_) => ">",
+            _ => "<=",
+        };
+        Ok(match instruction {
+            Ifeq(_) | Ifne(_) | Iflt(_) | Ifge(_) | Ifgt(_) | Ifle(_) => {
+                let value = pop(stack)?;
+                match value.kind {
+                    ExprKind::Compare(left, right) => Condition::Compare(*left, operator, *right),
+                    _ if value.ty == Some(FieldType::Boolean) && (operator == "==" || operator == "!=") => Condition::Truth(value, operator == "!="),
+                    _ => Condition::Compare(value, operator, self.expr(ExprKind::Int(0), Some(FieldType::Int))),
+                }
+            }
+            Ifnull(_) | Ifnonnull(_) => {
+                let value = pop(stack)?;
+                Condition::Compare(value, operator, self.text("null".to_string(), PRIMARY, None))
+            }
+            _ => {
+                let right = pop(stack)?;
+                let left = pop(stack)?;
+                match (left.ty.clone(), right.ty.clone()) {
+                    (Some(ty @ (FieldType::Boolean | FieldType::Char)), _) => Condition::Compare(left, operator, coerce(right, &ty)),
+                    (_, Some(ty @ (FieldType::Boolean | FieldType::Char))) => Condition::Compare(coerce(left, &ty), operator, right),
+                    _ => Condition::Compare(left, operator, right),
+                }
+            }
+        })
+    }
+
+    // Runs an instruction that neither jumps nor returns.
+    fn execute(&mut self, pc: u16, next_pc: u16, instruction: &Instruction, stack: &mut Vec<Expr>, stmts: &mut Vec<Stmt>) -> Result<()> {
+        use Instruction::*;
+        if let Some((slot, kind, is_store)) = local_access(instruction) {
+            if is_store {
+                self.store(slot, (pc, next_pc), kind, stack, stmts)?;
+            } else {
+                let value = self.load(slot, pc, kind);
+                stack.push(value);
+            }
+            return Ok(());
+        }
+        match instruction.unwide() {
+            Nop => {}
+            AconstNull => push_text(self, stack, "null", PRIMARY, None),
+            IconstM1 | Iconst0 | Iconst1 | Iconst2 | Iconst3 | Iconst4 | Iconst5 => {
+                let value = instruction.opcode() as i32 - Iconst0.opcode() as i32;
+                let value = self.expr(ExprKind::Int(value), Some(FieldType::Int));
+                stack.push(value);
+            }
+            Lconst0 => push_text(self, stack, "0L", PRIMARY, Some(FieldType::Long)),
+            Lconst1 => push_text(self, stack, "1L", PRIMARY, Some(FieldType::Long)),
+            Fconst0 => push_text(self, stack, "0.0F", PRIMARY, Some(FieldType::Float)),
+            Fconst1 => push_text(self, stack, "1.0F", PRIMARY, Some(FieldType::Float)),
+            Fconst2 => push_text(self, stack, "2.0F", PRIMARY, Some(FieldType::Float)),
+            Dconst0 => push_text(self, stack, "0.0", PRIMARY, Some(FieldType::Double)),
+            Dconst1 => push_text(self, stack, "1.0", PRIMARY, Some(FieldType::Double)),
+            Bipush(value) => {
+                let value = self.expr(ExprKind::Int(*value as i32), Some(FieldType::Int));
+                stack.push(value);
+            }
+            Sipush(value) => {
+                let value = self.expr(ExprKind::Int(*value as i32), Some(FieldType::Int));
+                stack.push(value);
+            }
+            Ldc(index) => {
+                let value = self.constant(*index as u16)?;
+                stack.push(value);
+            }
+            LdcW(index) | Ldc2W(index) => {
+                let value = self.constant(*index)?;
+                stack.push(value);
+            }
+            Iaload | Laload | Faload | Daload | Aaload | Baload | Caload | Saload => {
+                let index = pop(stack)?;
+                let array = pop(stack)?;
+                let ty = match &array.ty {
+                    Some(FieldType::Array { value }) => Some(value.as_ref().clone()),
+                    _ => array_element_type(instruction),
+                };
+                let text = format!("{}[{}]", array.wrap(PRIMARY), index.wrap(ASSIGNMENT));
+                push_text(self, stack, &text, PRIMARY, ty);
+            }
+            Iastore | Lastore | Fastore | Dastore | Aastore | Bastore | Castore | Sastore => {
+                let value = pop(stack)?;
+                let index = pop(stack)?;
+                let array = pop(stack)?;
+                let component = match &array.ty {
+                    Some(FieldType::Array { value: component }) => Some(component.as_ref().clone()),
+                    _ => array_element_type(instruction),
+                };
+                let value = match &component {
+                    Some(component) => coerce(value, component),
+                    None => value,
+                };
+                // An array initializer stores each element right after `new`, with a copy of the array left on the stack.
+                if let (Some(top), ExprKind::Int(i)) = (stack.last_mut(), &index.kind) {
+                    if top.id == array.id {
+                        if let ExprKind::NewArray { length, elements, .. } = &mut top.kind {
+                            if matches!(length.kind, ExprKind::Int(n) if *i as usize == elements.len() && *i < n) {
+                                elements.push(value);
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+                let target = format!("{}[{}]", array.wrap(PRIMARY), index.wrap(ASSIGNMENT));
+                self.assign(target, value, component, stack, stmts)?;
+            }
+            Pop => {
+                let value = pop(stack)?;
+                side_effect(value, stmts);
+            }
+            Pop2 => {
+                let value = pop(stack)?;
+                let is_category2 = value.is_category2();
+                side_effect(value, stmts);
+                if !is_category2 {
+                    let value = pop(stack)?;
+                    side_effect(value, stmts);
+                }
+            }
+            Dup => {
+                let value = pop(stack)?;
+                stack.push(value.clone());
+                stack.push(value);
+            }
+            DupX1 => {
+                let value1 = pop(stack)?;
+                let value2 = pop(stack)?;
+                stack.extend([value1.clone(), value2, value1]);
+            }
+            DupX2 => {
+                let value1 = pop(stack)?;
+                let value2 = pop(stack)?;
+                if value2.is_category2() {
+                    stack.extend([value1.clone(), value2, value1]);
+                } else {
+                    let value3 = pop(stack)?;
+                    stack.extend([value1.clone(), value3, value2, value1]);
+                }
+            }
+            Dup2 => {
+                let value1 = pop(stack)?;
+                if value1.is_category2() {
+                    stack.extend([value1.clone(), value1]);
+                } else {
+                    let value2 = pop(stack)?;
+                    stack.extend([value2.clone(), value1.clone(), value2, value1]);
+                }
+            }
+            Dup2X1 => {
+                let value1 = pop(stack)?;
+                let value2 = pop(stack)?;
+                if value1.is_category2() {
+                    stack.extend([value1.clone(), value2, value1]);
+                } else {
+                    let value3 = pop(stack)?;
+                    stack.extend([value2.clone(), value1.clone(), value3, value2, value1]);
+                }
+            }
+            Dup2X2 => {
+                let value1 = pop(stack)?;
+                let value2 = pop(stack)?;
+                match (value1.is_category2(), value2.is_category2()) {
+                    (true, true) => stack.extend([value1.clone(), value2, value1]),
+                    (true, false) => {
+                        let value3 = pop(stack)?;
+                        stack.extend([value1.clone(), value3, value2, value1]);
+                    }
+                    (false, _) => {
+                        let value3 = pop(stack)?;
+                        if value3.is_category2() {
+                            stack.extend([value2.clone(), value1.clone(), value3, value2, value1]);
+                        } else {
+                            let value4 = pop(stack)?;
+                            stack.extend([value2.clone(), value1.clone(), value4, value3, value2, value1]);
+                        }
+                    }
+                }
+            }
+            Swap => {
+                let value1 = pop(stack)?;
+                let value2 = pop(stack)?;
+                stack.extend([value1, value2]);
+            }
+            Iadd | Ladd | Fadd | Dadd => self.binary(stack, "+", ADDITIVE, instruction)?,
+            Isub | Lsub | Fsub | Dsub => self.binary(stack, "-", ADDITIVE, instruction)?,
+            Imul | Lmul | Fmul | Dmul => self.binary(stack, "*", MULTIPLICATIVE, instruction)?,
+            Idiv | Ldiv | Fdiv | Ddiv => self.binary(stack, "/", MULTIPLICATIVE, instruction)?,
+            Irem | Lrem | Frem | Drem => self.binary(stack, "%", MULTIPLICATIVE, instruction)?,
+            Ishl | Lshl => self.binary(stack, "<<", SHIFT, instruction)?,
+            Ishr | Lshr => self.binary(stack, ">>", SHIFT, instruction)?,
+            Iushr | Lushr => self.binary(stack, ">>>", SHIFT, instruction)?,
+            Iand | Land => self.binary(stack, "&", BIT_AND, instruction)?,
+            Ior | Lor => self.binary(stack, "|", BIT_OR, instruction)?,
+            Ixor | Lxor => {
+                if matches!(stack.last(), Some(Expr { kind: ExprKind::Int(-1), .. })) {
+                    pop(stack)?;
+                    let value = pop(stack)?;
+                    let text = format!("~{}", value.wrap(UNARY));
+                    push_text(self, stack, &text, UNARY, value.ty);
+                } else {
+                    self.binary(stack, "^", BIT_XOR, instruction)?;
+                }
+            }
+            Ineg | Lneg | Fneg | Dneg => {
+                let value = pop(stack)?;
+                let text = format!("-{}", value.wrap(UNARY));
+                push_text(self, stack, &text, UNARY, value.ty);
+            }
+            Iinc(slot, delta) => {
+                let local = self.load(*slot, pc, 'i');
+                let name = local.text().0;
+                let update = match delta {
+                    1 => format!("{}++", name),
+                    -1 => format!("{}--", name),
+                    delta if *delta > 0 => format!("{} += {}", name, delta),
+                    delta => format!("{} -= {}", name, -(*delta as i32)),
+                };
+                // `i++` in an expression loads the variable before it is incremented, and nothing after the load reads it.
+                let loaded = stack.iter().rposition(|expr| matches!(&expr.kind, ExprKind::Local { slot: loaded, .. } if loaded == slot));
+                match loaded {
+                    Some(position) if (*delta == 1 || *delta == -1) && !stack[position + 1..].iter().any(|expr| reads(&expr.text().0, &name)) => {
+                        stack[position] = self.text(update, POSTFIX, local.ty);
+                    }
+                    _ => {
+                        self.spill(&name, None, stack, stmts)?;
+                        stmts.push(Stmt::Expr(update));
+                    }
+                }
+            }
+            I2l | F2l | D2l => self.cast(stack, FieldType::Long)?,
+            I2f | L2f | D2f => self.cast(stack, FieldType::Float)?,
+            I2d | L2d | F2d => self.cast(stack, FieldType::Double)?,
+            L2i | F2i | D2i => self.cast(stack, FieldType::Int)?,
+            I2b => self.cast(stack, FieldType::Byte)?,
+            I2c => self.cast(stack, FieldType::Char)?,
+            I2s => self.cast(stack, FieldType::Short)?,
+            Lcmp | Fcmpl | Fcmpg | Dcmpl | Dcmpg => {
+                let right = pop(stack)?;
+                let left = pop(stack)?;
+                let value = self.expr(ExprKind::Compare(Box::new(left), Box::new(right)), Some(FieldType::Int));
+                stack.push(value);
+            }
+            Getstatic(index) => {
+                let (class_name, name, field_type) = self.field_ref(*index)?;
+                let class = self.class.names.borrow_mut().class(&class_name);
+                push_text(self, stack, &format!("{}.{}", class, name), PRIMARY, Some(field_type));
+            }
+            Putstatic(index) => {
+                let (class_name, name, field_type) = self.field_ref(*index)?;
+                let value = coerce(pop(stack)?, &field_type);
+                // A static final field is assigned only by its simple name in the static initializer.
+                let target = if class_name == self.class.class_name && self.method_name == "<clinit>" {
+                    name
+                } else {
+                    format!("{}.{}", self.class.names.borrow_mut().class(&class_name), name)
+                };
+                self.assign(target, value, Some(field_type), stack, stmts)?;
+            }
+            Getfield(index) => {
+                let (_, name, field_type) = self.field_ref(*index)?;
+                let object = pop(stack)?;
+                // javac keeps the outer instance of an inner class in a synthetic field this$N.
+                let is_enclosing = self.is_this(&object) || matches!(&object.kind, ExprKind::Text(text, _) if text.ends_with(".this"));
+                let text = match &field_type {
+                    FieldType::Class { name: outer } if name.starts_with("this$") && is_enclosing => {
+                        format!("{}.this", self.class.names.borrow_mut().class(outer))
+                    }
+                    _ => format!("{}.{}", object.wrap(PRIMARY), name),
+                };
+                push_text(self, stack, &text, PRIMARY, Some(field_type));
+            }
+            Putfield(index) => {
+                let (_, name, field_type) = self.field_ref(*index)?;
+                let value = coerce(pop(stack)?, &field_type);
+                let object = pop(stack)?;
+                let target = format!("{}.{}", object.wrap(PRIMARY), name);
+                self.assign(target, value, Some(field_type), stack, stmts)?;
+            }
+            Invokevirtual(index) | Invokespecial(index) | Invokestatic(index) | Invokeinterface { index, .. } => {
+                self.invoke(instruction, *index, stack, stmts)?;
+            }
+            Invokedynamic(index) => self.invoke_dynamic(*index, stack, stmts)?,
+            New(index) => {
+                let class_name = (&self.class.class_file.constant_pool).access_as_class(*index).name().bytes_as_string()?;
+                let class = self.class.names.borrow_mut().class(&class_name);
+                let value = self.expr(ExprKind::Uninitialized(class), Some(FieldType::Class { name: class_name }));
+                stack.push(value);
+            }
+            Newarray(atype) => {
+                let element = match atype {
+                    4 => FieldType::Boolean,
+                    5 => FieldType::Char,
+                    6 => FieldType::Float,
+                    7 => FieldType::Double,
+                    8 => FieldType::Byte,
+                    9 => FieldType::Short,
+                    10 => FieldType::Int,
+                    11 => FieldType::Long,
+                    _ => return error(format!("Illegal atype of newarray: {}", atype)),
+                };
+                let length = pop(stack)?;
+                let text = self.class.names.borrow_mut().field_type(&element);
+                let kind = ExprKind::NewArray { element: text, length: Box::new(length), elements: vec![] };
+                let value = self.expr(kind, Some(FieldType::Array { value: Box::new(element) }));
+                stack.push(value);
+            }
+            Anewarray(index) => {
+                let class_name = (&self.class.class_file.constant_pool).access_as_class(*index).name().bytes_as_string()?;
+                let element = class_type(&class_name)?;
+                let length = pop(stack)?;
+                let text = self.class.names.borrow_mut().field_type(&element);
+                let kind = ExprKind::NewArray { element: text, length: Box::new(length), elements: vec![] };
+                let value = self.expr(kind, Some(FieldType::Array { value: Box::new(element) }));
+                stack.push(value);
+            }
+            Multianewarray { index, dimensions } => {
+                let class_name = (&self.class.class_file.constant_pool).access_as_class(*index).name().bytes_as_string()?;
+                let array_type = class_type(&class_name)?;
+                let mut lengths = Vec::new();
+                for _ in 0..*dimensions {
+                    lengths.push(pop(stack)?);
+                }
+                lengths.reverse();
+                let mut element = &array_type;
+                let mut rank = 0;
+                while let FieldType::Array { value } = element {
+                    element = value;
+                    rank += 1;
+                }
+                let mut text = format!("new {}", self.class.names.borrow_mut().field_type(element));
+                for length in &lengths {
+                    text.push_str(&format!("[{}]", length.wrap(ASSIGNMENT)));
+                }
+                text.push_str(&"[]".repeat(rank - lengths.len()));
+                push_text(self, stack, &text, PRIMARY, Some(array_type));
+            }
+            Arraylength => {
+                let array = pop(stack)?;
+                push_text(self, stack, &format!("{}.length", array.wrap(PRIMARY)), PRIMARY, Some(FieldType::Int));
+            }
+            Checkcast(index) => {
+                let class_name = (&self.class.class_file.constant_pool).access_as_class(*index).name().bytes_as_string()?;
+                let value = pop(stack)?;
+                let text = self.class.names.borrow_mut().class_or_array(&class_name);
+                let value = self.expr(ExprKind::Cast(text, Box::new(value)), Some(class_type(&class_name)?));
+                stack.push(value);
+            }
+            Instanceof(index) => {
+                let class_name = (&self.class.class_file.constant_pool).access_as_class(*index).name().bytes_as_string()?;
+                let value = pop(stack)?;
+                let text = self.class.names.borrow_mut().class_or_array(&class_name);
+                push_text(self, stack, &format!("{} instanceof {}", value.wrap(RELATIONAL), text), RELATIONAL, Some(FieldType::Boolean));
+            }
+            Monitorenter | Monitorexit => return error(format!("synchronized statements are not supported, pc: {}", pc)),
+            other => return error(format!("Unexpected {} at pc {}", other.mnemonic(), pc)),
+        }
+        Ok(())
+    }
+
+    fn expr(&mut self, kind: ExprKind, ty: Option<FieldType>) -> Expr {
+        self.next_id += 1;
+        Expr { id: self.next_id, kind, ty }
+    }
+
+    fn text(&mut self, text: String, precedence: u8, ty: Option<FieldType>) -> Expr {
+        self.expr(ExprKind::Text(text, precedence), ty)
+    }
+
+    fn binary(&mut self, stack: &mut Vec<Expr>, operator: &'static str, precedence: u8, instruction: &Instruction) -> Result<()> {
+        let right = pop(stack)?;
+        let left = pop(stack)?;
+        let is_boolean = left.ty == Some(FieldType::Boolean) || right.ty == Some(FieldType::Boolean);
+        let (left, right, ty) = if is_boolean && matches!(operator, "&" | "|" | "^") {
+            (coerce(left, &FieldType::Boolean), coerce(right, &FieldType::Boolean), FieldType::Boolean)
+        } else {
+            (left, right, arithmetic_type(instruction))
+        };
+        let value = self.expr(ExprKind::Binary { left: Box::new(left), operator, right: Box::new(right), precedence }, Some(ty));
+        stack.push(value);
+        Ok(())
+    }
+
+    fn cast(&mut self, stack: &mut Vec<Expr>, ty: FieldType) -> Result<()> {
+        let value = pop(stack)?;
+        let text = self.class.names.borrow_mut().field_type(&ty);
+        let value = self.expr(ExprKind::Cast(text, Box::new(value)), Some(ty));
+        stack.push(value);
+        Ok(())
+    }
+
+    fn constant(&mut self, index: u16) -> Result<Expr> {
+        let constant_pool = &self.class.class_file.constant_pool;
+        match constant_pool.get::<CpInfo>(index).ok() {
+            Some(CpInfo::Integer(_)) => {
+                let value = constant_pool.access_as_integer(index).bytes_as_integer()?;
+                Ok(self.expr(ExprKind::Int(value), Some(FieldType::Int)))
+            }
+            Some(CpInfo::Float(_)) => {
+                let value = constant_pool.access_as_float(index).bytes_as_float()?;
+                let precedence = if value.is_sign_negative() && value.is_finite() { UNARY } else { PRIMARY };
+                Ok(self.text(float_literal(value), precedence, Some(FieldType::Float)))
+            }
+            Some(CpInfo::Long(_)) => {
+                let value = constant_pool.access_as_long(index).bytes_as_long()?;
+                Ok(self.text(long_literal(value), if value < 0 { UNARY } else { PRIMARY }, Some(FieldType::Long)))
+            }
+            Some(CpInfo::Double(_)) => {
+                let value = constant_pool.access_as_double(index).bytes_as_double()?;
+                let precedence = if value.is_sign_negative() && value.is_finite() { UNARY } else { PRIMARY };
+                Ok(self.text(double_literal(value), precedence, Some(FieldType::Double)))
+            }
+            Some(CpInfo::String(_)) => {
+                let value = constant_pool.access_as_string(index).string().bytes_as_string()?;
+                Ok(self.text(string_literal(&value), PRIMARY, Some(FieldType::Class { name: "java/lang/String".to_string() })))
+            }
+            Some(CpInfo::Class(_)) => {
+                let class_name = constant_pool.access_as_class(index).name().bytes_as_string()?;
+                let text = format!("{}.class", self.class.names.borrow_mut().class_or_array(&class_name));
+                Ok(self.text(text, PRIMARY, Some(FieldType::Class { name: "java/lang/Class".to_string() })))
+            }
+            _ => error(format!("Unsupported constant for ldc, index: {}", index)),
+        }
+    }
+
+    // (class, name, type) of a CONSTANT_Fieldref.
+    fn field_ref(&self, index: u16) -> Result<(String, String, FieldType)> {
+        let constant_pool = &self.class.class_file.constant_pool;
+        let fieldref = constant_pool.access_as_fieldref(index);
+        let class = fieldref.class();
+        let name_and_type = fieldref.name_and_type();
+        let descriptor = name_and_type.descriptor().bytes_as_string()?;
+        Ok((class.name().bytes_as_string()?, name_and_type.name().bytes_as_string()?, parse_field_type(&descriptor)?))
+    }
+
+    // (class, name, type) of a CONSTANT_Methodref or a CONSTANT_InterfaceMethodref.
+    fn method_ref(&self, index: u16) -> Result<(String, String, MethodType)> {
+        let constant_pool = &self.class.class_file.constant_pool;
+        let (class, name, descriptor) = match constant_pool.get::<CpInfo>(index).ok() {
+            Some(CpInfo::InterfaceMethodref(_)) => {
+                let methodref = constant_pool.access_as_interface_methodref(index);
+                let class = methodref.class();
+                let name_and_type = methodref.name_and_type();
+                (class.name().bytes_as_string()?, name_and_type.name().bytes_as_string()?, name_and_type.descriptor().bytes_as_string()?)
+            }
+            _ => {
+                let methodref = constant_pool.access_as_methodref(index);
+                let class = methodref.class();
+                let name_and_type = methodref.name_and_type();
+                (class.name().bytes_as_string()?, name_and_type.name().bytes_as_string()?, name_and_type.descriptor().bytes_as_string()?)
+            }
+        };
+        Ok((class, name, parse_method_descriptor(&descriptor)?))
+    }
+
+    // (class, name) that a CONSTANT_MethodHandle refers to.
+    fn method_handle(&self, index: u16) -> Result<(String, String)> {
+        let constant_pool = &self.class.class_file.constant_pool;
+        let method_handle = constant_pool.access_as_method_handle(index);
+        let (class, name_and_type) = match method_handle.reference()? {
+            MethodHandleReference::Fieldref(reference) => (reference.class().name().bytes_as_string()?, reference.name_and_type().name().bytes_as_string()?),
+            MethodHandleReference::Methodref(reference) => (reference.class().name().bytes_as_string()?, reference.name_and_type().name().bytes_as_string()?),
+            MethodHandleReference::InterfaceMethodref(reference) => (reference.class().name().bytes_as_string()?, reference.name_and_type().name().bytes_as_string()?),
+        };
+        Ok((class, name_and_type))
+    }
+
+    fn arguments(&self, parameter_types: &[FieldType], stack: &mut Vec<Expr>) -> Result<Vec<Expr>> {
+        let mut arguments = Vec::new();
+        for parameter_type in parameter_types.iter().rev() {
+            arguments.push(coerce(pop(stack)?, parameter_type));
+        }
+        arguments.reverse();
+        Ok(arguments)
+    }
+
+    fn invoke(&mut self, instruction: &Instruction, index: u16, stack: &mut Vec<Expr>, stmts: &mut Vec<Stmt>) -> Result<()> {
+        let (class_name, name, method_type) = self.method_ref(index)?;
+        let mut arguments = self.arguments(&method_type.parameter_types, stack)?;
+        let outer = match instruction {
+            Instruction::Invokespecial(_) if name == "<init>" && self.class.names.borrow().inner_classes.contains(&class_name) && !arguments.is_empty() => {
+                Some(arguments.remove(0))
+            }
+            _ => None,
+        };
+        let arguments = join_arguments(&arguments);
+        let text = match instruction {
+            Instruction::Invokestatic(_) => {
+                let class = self.class.names.borrow_mut().class(&class_name);
+                format!("{}.{}({})", class, name, arguments)
+            }
+            Instruction::Invokespecial(_) if name == "<init>" => {
+                let receiver = pop(stack)?;
+                match &receiver.kind {
+                    ExprKind::Uninitialized(class) => {
+                        // the outer instance of an inner class is implicit for this, and qualifies `new` otherwise
+                        let text = match outer {
+                            Some(outer) if !self.is_this(&outer) => {
+                                let simple_name = self.class.names.borrow().member_classes[&class_name].1.clone();
+                                format!("{}.new {}({})", outer.wrap(PRIMARY), simple_name, arguments)
+                            }
+                            _ => format!("new {}({})", class, arguments),
+                        };
+                        let created = Expr { id: receiver.id, kind: ExprKind::Text(text, PRIMARY), ty: receiver.ty.clone() };
+                        let mut used = false;
+                        for expr in stack.iter_mut().filter(|expr| expr.id == receiver.id) {
+                            *expr = created.clone();
+                            used = true;
+                        }
+                        if !used {
+                            stmts.push(Stmt::Expr(created.text().0));
+                        }
+                    }
+                    ExprKind::Local { slot: 0, .. } if !self.is_static && self.method_name == "<init>" => {
+                        let callee = if class_name == self.class.class_name { "this" } else { "super" };
+                        // an enclosing instance is passed implicitly, and any other qualifies the call
+                        let callee = match outer {
+                            Some(outer) if !matches!(&outer.kind, ExprKind::Text(text, _) if text.ends_with(".this")) => {
+                                format!("{}.{}", outer.wrap(PRIMARY), callee)
+                            }
+                            _ => callee.to_string(),
+                        };
+                        stmts.push(Stmt::Expr(format!("{}({})", callee, arguments)));
+                    }
+                    _ => return error(format!("Illegal receiver of a constructor of {}", class_name)),
+                }
+                return Ok(());
+            }
+            _ => {
+                let receiver = pop(stack)?;
+                if matches!(instruction, Instruction::Invokespecial(_)) && self.is_this(&receiver) && class_name != self.class.class_name {
+                    format!("super.{}({})", name, arguments)
+                } else {
+                    format!("{}.{}({})", receiver.wrap(PRIMARY), name, arguments)
+                }
+            }
+        };
+        match method_type.return_type {
+            ReturnType::Void => stmts.push(Stmt::Expr(text)),
+            ReturnType::Field { value } => push_text(self, stack, &text, PRIMARY, Some(value)),
+        }
+        Ok(())
+    }
+
+    // String concatenation and lambdas by method references, the uses of invokedynamic that javac makes.
+    fn invoke_dynamic(&mut self, index: u16, stack: &mut Vec<Expr>, stmts: &mut Vec<Stmt>) -> Result<()> {
+        let class_file = self.class.class_file;
+        let constant_pool = &class_file.constant_pool;
+        let invoke_dynamic = constant_pool.access_as_invoke_dynamic(index);
+        let bootstrap_index = invoke_dynamic.get_bootstrap_method_attr_index()?;
+        let name_and_type = invoke_dynamic.name_and_type();
+        let name = name_and_type.name().bytes_as_string()?;
+        let method_type = parse_method_descriptor(&name_and_type.descriptor().bytes_as_string()?)?;
+        let arguments = self.arguments(&method_type.parameter_types, stack)?;
+        let bootstrap_method = match class_file.attributes.iter().find_map(|attribute| match attribute {
+            Attribute::BootstrapMethods(bootstrap_methods) => bootstrap_methods.bootstrap_methods.get(bootstrap_index as usize),
+            _ => None,
+        }) {
+            Some(bootstrap_method) => bootstrap_method,
+            None => return error(format!("The bootstrap method {} is not found", bootstrap_index)),
+        };
+        let (owner, bootstrap_name) = self.method_handle(bootstrap_method.bootstrap_method_ref.index())?;
+        let static_arguments = &bootstrap_method.bootstrap_arguments;
+
+        let (text, precedence) = match (owner.as_str(), bootstrap_name.as_str()) {
+            ("java/lang/invoke/StringConcatFactory", "makeConcatWithConstants" | "makeConcat") => {
+                // In the recipe, \1 stands for an argument and \2 for a constant.
+                let recipe = match static_arguments.first() {
+                    Some(recipe) if bootstrap_name == "makeConcatWithConstants" => constant_pool.access_as_string(recipe.index()).string().bytes_as_string()?,
+                    _ => "\u{1}".repeat(arguments.len()),
+                };
+                let mut arguments = arguments.into_iter();
+                let mut constants = static_arguments.iter().skip(1);
+                // (text, whether a String)
+                let mut pieces: Vec<(String, bool)> = Vec::new();
+                let mut literal = String::new();
+                for c in recipe.chars() {
+                    if c != '\u{1}' && c != '\u{2}' {
+                        literal.push(c);
+                        continue;
+                    }
+                    if !literal.is_empty() {
+                        pieces.push((string_literal(&literal), true));
+                        literal.clear();
+                    }
+                    if c == '\u{1}' {
+                        let argument = match arguments.next() {
+                            Some(argument) => argument,
+                            None => return error(format!("Too few arguments for the recipe {}", string_literal(&recipe))),
+                        };
+                        let is_string = argument.ty == Some(FieldType::Class { name: "java/lang/String".to_string() });
+                        pieces.push((argument.wrap(MULTIPLICATIVE), is_string));
+                    } else {
+                        let constant = match constants.next() {
+                            Some(constant) => constant_pool.access_as_string(constant.index()).string().bytes_as_string()?,
+                            None => return error(format!("Too few constants for the recipe {}", string_literal(&recipe))),
+                        };
+                        pieces.push((string_literal(&constant), true));
+                    }
+                }
+                if !literal.is_empty() {
+                    pieces.push((string_literal(&literal), true));
+                }
+                // `+` adds numbers until either operand is a String.
+                if !pieces.first().is_some_and(|(_, is_string)| *is_string) && !pieces.get(1).is_some_and(|(_, is_string)| *is_string) {
+                    pieces.insert(0, ("\"\"".to_string(), true));
+                }
+                let pieces: Vec<String> = pieces.into_iter().map(|(text, _)| text).collect();
+                (pieces.join(" + "), ADDITIVE)
+            }
+            ("java/lang/invoke/LambdaMetafactory", _) if static_arguments.len() >= 3 => {
+                let implementation = static_arguments[1].index();
+                let reference_kind = constant_pool.access_as_method_handle(implementation).reference_kind()?;
+                let (class_name, method_name) = self.method_handle(implementation)?;
+                if method_name.starts_with("lambda$") {
+                    if class_name != self.class.class_name {
+                        return error(format!("The body of a lambda expression is in another class {}", class_name));
+                    }
+                    (self.lambda(&method_name, &arguments)?, ASSIGNMENT)
+                } else {
+                    let instantiated_type = parse_method_descriptor(&constant_pool.access_as_method_type(static_arguments[2].index()).descriptor().bytes_as_string()?)?;
+                    let mut names = self.class.names.borrow_mut();
+                    match (reference_kind, arguments.as_slice()) {
+                        // REF_newInvokeSpecial
+                        (8, []) => (format!("{}::new", names.class_or_array(&class_name)), PRIMARY),
+                        // REF_invokeVirtual, REF_invokeSpecial and REF_invokeInterface on a captured receiver
+                        (5 | 7 | 9, [receiver]) => (format!("{}::{}", receiver.wrap(PRIMARY), method_name), PRIMARY),
+                        (_, []) => (format!("{}::{}", names.class_or_array(&class_name), method_name), PRIMARY),
+                        // a method reference capturing values is written as a lambda passing them along with its own parameters
+                        (_, captures) => {
+                            let parameters: Vec<String> = (0..instantiated_type.parameter_types.len()).map(|i| format!("lambdaArg{}", i)).collect();
+                            let mut values: Vec<String> = captures.iter().map(|capture| capture.wrap(ASSIGNMENT)).collect();
+                            values.extend(parameters.iter().cloned());
+                            let call = match reference_kind {
+                                8 => format!("new {}({})", names.class_or_array(&class_name), values.join(", ")),
+                                5 | 7 | 9 => format!("{}.{}({})", captures[0].wrap(PRIMARY), method_name, values[1..].join(", ")),
+                                _ => format!("{}.{}({})", names.class_or_array(&class_name), method_name, values.join(", ")),
+                            };
+                            (format!("({}) -> {}", parameters.join(", "), call), ASSIGNMENT)
+                        }
+                    }
+                }
+            }
+            _ => (format!("{}({})", name, join_arguments(&arguments)), PRIMARY),
+        };
+        match method_type.return_type {
+            ReturnType::Void => stmts.push(Stmt::Expr(text)),
+            ReturnType::Field { value } => push_text(self, stack, &text, precedence, Some(value)),
+        }
+        Ok(())
+    }
+
+    // A lambda expression whose body is the synthetic method `name` of this class,
+    // which takes the captured values first, after `this` for an instance method.
+    fn lambda(&self, name: &str, captures: &[Expr]) -> Result<String> {
+        let class_file = self.class.class_file;
+        let constant_pool = &class_file.constant_pool;
+        let method = match class_file.methods.iter().find(|method| constant_pool.access(method.name_index).bytes_as_string().is_ok_and(|n| n == name)) {
+            Some(method) => method,
+            None => return error(format!("The body of a lambda expression {} is not found", name)),
+        };
+        let code = match method.attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Code(code) => Some(code),
+            _ => None,
+        }) {
+            Some(code) => code,
+            None => return error(format!("The body of a lambda expression {} has no code", name)),
+        };
+        let method_type = parse_method_descriptor(&constant_pool.access(method.descriptor_index).bytes_as_string()?)?;
+        let captures = match captures.split_first() {
+            Some((receiver, captures)) if !method.access_flags.contains(ACC_STATIC) && self.is_this(receiver) => captures,
+            _ if !method.access_flags.contains(ACC_STATIC) => return error(format!("The lambda expression {} is not on this", name)),
+            _ => captures,
+        };
+        let mut decompiler = MethodDecompiler::new(self.class, method, &method_type, code)?;
+        if captures.len() > decompiler.parameters.len() {
+            return error(format!("Too many values are captured by the lambda expression {}", name));
+        }
+        // the body reads the captured variables, which are effectively final
+        for ((slot, _), capture) in decompiler.parameters.iter().zip(captures) {
+            if !matches!(&capture.kind, ExprKind::Local { .. }) && !matches!(&capture.kind, ExprKind::Text(text, _) if text.ends_with(".this")) {
+                return error(format!("The lambda expression {} captures a value that is not a variable: {}", name, capture.text().0));
+            }
+            decompiler.captures.insert(*slot, capture.clone());
+        }
+        let parameters: Vec<String> = decompiler.parameters[captures.len()..].iter().map(|(_, name)| name.clone()).collect();
+        let body = decompiler.body()?;
+        let parameters = match parameters.as_slice() {
+            [parameter] => parameter.clone(),
+            _ => format!("({})", parameters.join(", ")),
+        };
+        let body = match body.as_slice() {
+            [Stmt::Return(Some(value))] => value.clone(),
+            [Stmt::Expr(text)] if matches!(method_type.return_type, ReturnType::Void) => text.clone(),
+            _ => {
+                let mut text = "{\n".to_string();
+                statement::render(&body, 1, &mut text);
+                text.push('}');
+                text
+            }
+        };
+        Ok(format!("{} -> {}", parameters, body))
+    }
+
+    // An assignment to a field or an array element.
+    fn assign(&mut self, target: String, value: Expr, ty: Option<FieldType>, stack: &mut [Expr], stmts: &mut Vec<Stmt>) -> Result<()> {
+        if postfix(&target, &value, stack) || in_place(&target, &value, stack) {
+            return Ok(());
+        }
+        self.spill(&target, Some(value.id), stack, stmts)?;
+        // the other copies left by dup read the target afterwards
+        for expr in stack.iter_mut().filter(|expr| expr.id == value.id) {
+            *expr = Expr { id: expr.id, kind: ExprKind::Text(target.clone(), PRIMARY), ty: ty.clone() };
+        }
+        stmts.push(Stmt::Expr(assignment(&target, &value)));
+        Ok(())
+    }
+
+    // Before an assignment as a statement, moves the values on the stack that read its target to temporary variables,
+    // along with those under them that may have side effects, so that they are still computed first.
+    // The copies of the assigned value read the target afterwards, and stay.
+    fn spill(&mut self, target: &str, assigned: Option<usize>, stack: &mut [Expr], stmts: &mut Vec<Stmt>) -> Result<()> {
+        let is_copy = |expr: &Expr| Some(expr.id) == assigned;
+        let last = match stack.iter().rposition(|expr| !is_copy(expr) && reads(&expr.text().0, target)) {
+            Some(last) => last,
+            None => return Ok(()),
+        };
+        for i in 0..=last {
+            let (text, _) = stack[i].text();
+            let stays = is_copy(&stack[i])
+                || matches!(&stack[i].kind, ExprKind::Int(_) | ExprKind::Uninitialized(_))
+                || matches!(&stack[i].kind, ExprKind::Local { name, .. } if name != target)
+                || !(reads(&text, target) || has_side_effect(&text));
+            if stays {
+                continue;
+            }
+            let ty = match &stack[i].ty {
+                Some(ty) => ty.clone(),
+                None => return error(format!("The type of {} is unknown to compute it before the assignment to {}", text, target)),
+            };
+            let name = self.temporary_name();
+            let ty_text = self.class.names.borrow_mut().field_type(&ty);
+            stmts.push(Stmt::Declare { ty: ty_text, name: name.clone(), value: Some(stack[i].wrap(ASSIGNMENT)) });
+            let id = stack[i].id;
+            for expr in stack.iter_mut().filter(|expr| expr.id == id) {
+                *expr = Expr { id, kind: ExprKind::Text(name.clone(), PRIMARY), ty: Some(ty.clone()) };
+            }
+        }
+        Ok(())
+    }
+
+    // A name for a temporary variable that no other variable has.
+    fn temporary_name(&mut self) -> String {
+        loop {
+            let name = format!("tmp{}", self.temporaries);
+            self.temporaries += 1;
+            let is_taken = self.variables.iter().any(|variable| variable.name == name)
+                || self.parameters.iter().any(|(_, parameter)| *parameter == name)
+                || self.declarations.unnamed.contains_key(&name);
+            if !is_taken {
+                return name;
+            }
+        }
+    }
+
+    fn is_this(&self, expr: &Expr) -> bool {
+        !self.is_static && matches!(expr.kind, ExprKind::Local { slot: 0, .. })
+    }
+
+    fn load(&mut self, slot: u16, pc: u16, kind: char) -> Expr {
+        if !self.is_static && slot == 0 {
+            let ty = FieldType::Class { name: self.class.class_name.clone() };
+            return self.expr(ExprKind::Local { slot, name: "this".to_string() }, Some(ty));
+        }
+        if let Some(capture) = self.captures.get(&slot) {
+            let (kind, ty) = (capture.kind.clone(), capture.ty.clone());
+            return self.expr(kind, ty);
+        }
+        if let Some(outer) = self.outer_instance.clone().filter(|_| slot == 1) {
+            let text = format!("{}.this", self.class.names.borrow_mut().class(&outer));
+            return self.expr(ExprKind::Text(text, PRIMARY), Some(FieldType::Class { name: outer }));
+        }
+        let (name, ty) = match self.variable_at(slot, pc) {
+            Some(i) => (self.variables[i].name.clone(), self.variables[i].field_type.clone()),
+            None => {
+                let name = self.unnamed_name(slot, kind);
+                let ty = self.declarations.unnamed.get(&name).cloned().unwrap_or_else(|| kind_type(kind));
+                (name, ty)
+            }
+        };
+        self.expr(ExprKind::Local { slot, name }, Some(ty))
+    }
+
+    fn store(&mut self, slot: u16, (pc, next_pc): (u16, u16), kind: char, stack: &mut Vec<Expr>, stmts: &mut Vec<Stmt>) -> Result<()> {
+        let value = pop(stack)?;
+        let (name, ty, declaration) = match self.variable_for_store(slot, pc, next_pc) {
+            Some(i) => {
+                let name = self.variables[i].name.clone();
+                let declaration = if self.declarations.declared.insert(i) {
+                    let ty_text = {
+                        let variable = &self.variables[i];
+                        let mut names = self.class.names.borrow_mut();
+                        match &variable.signature {
+                            Some(signature) => names.signature(signature),
+                            None => names.field_type(&variable.field_type),
+                        }
+                    };
+                    if self.variables[i].start_pc == next_pc && !self.assigned_in_branches(i) {
+                        Some(ty_text)
+                    } else {
+                        self.declarations.hoisted.push((ty_text, name.clone()));
+                        None
+                    }
+                } else {
+                    None
+                };
+                (name, self.variables[i].field_type.clone(), declaration)
+            }
+            None => {
+                let name = self.unnamed_name(slot, kind);
+                let declaration = if self.declarations.unnamed.contains_key(&name) {
+                    None
+                } else {
+                    let ty = match &value.ty {
+                        Some(ty) => ty.clone(),
+                        None => kind_type(kind),
+                    };
+                    let ty_text = self.class.names.borrow_mut().field_type(&ty);
+                    self.declarations.unnamed.insert(name.clone(), ty);
+                    if self.depth == 1 {
+                        Some(ty_text)
+                    } else {
+                        self.declarations.hoisted.push((ty_text, name.clone()));
+                        None
+                    }
+                };
+                let ty = self.declarations.unnamed[&name].clone();
+                (name, ty, declaration)
+            }
+        };
+        let mut value = coerce(value, &ty);
+        // The cast that javac puts to the erasure of a generic type, e.g. `(Comparable)` for `T`, is to the generic type in the source.
+        if let (ExprKind::Cast(cast, _), Some(i)) = (&mut value.kind, self.variable_at(slot, next_pc)) {
+            if let Some(signature) = &self.variables[i].signature {
+                *cast = self.class.names.borrow_mut().signature(signature);
+            }
+        }
+        if declaration.is_none() {
+            if postfix(&name, &value, stack) || in_place(&name, &value, stack) {
+                return Ok(());
+            }
+            self.spill(&name, Some(value.id), stack, stmts)?;
+        }
+        for expr in stack.iter_mut().filter(|expr| expr.id == value.id) {
+            *expr = Expr { id: expr.id, kind: ExprKind::Local { slot, name: name.clone() }, ty: Some(ty.clone()) };
+        }
+        stmts.push(match declaration {
+            Some(ty_text) => Stmt::Declare { ty: ty_text, name, value: Some(value.wrap(ASSIGNMENT)) },
+            None => Stmt::Expr(assignment(&name, &value)),
+        });
+        Ok(())
+    }
+
+    // The entry of LocalVariableTable in scope at pc.
+    fn variable_at(&self, slot: u16, pc: u16) -> Option<usize> {
+        self.variables.iter().position(|variable| variable.slot == slot && variable.start_pc <= pc && (pc as u32) < variable.end_pc)
+    }
+
+    // The entry of LocalVariableTable that a store at pc assigns. The scope starts after the first store,
+    // or later where the variable is first definitely assigned, e.g. after both branches of an if.
+    fn variable_for_store(&self, slot: u16, pc: u16, next_pc: u16) -> Option<usize> {
+        if let Some(i) = self.variables.iter().position(|variable| variable.slot == slot && variable.start_pc == next_pc) {
+            return Some(i);
+        }
+        if let Some(i) = self.variable_at(slot, pc) {
+            return Some(i);
+        }
+        let (i, variable) = self.variables.iter().enumerate()
+            .filter(|(_, variable)| variable.slot == slot && variable.start_pc > pc)
+            .min_by_key(|(_, variable)| variable.start_pc)?;
+        // Not if the slot is read before that scope, as by the hidden variables of a for-each loop.
+        let read_before = self.instructions.iter()
+            .filter(|(load_pc, _)| *load_pc > pc && *load_pc < variable.start_pc)
+            .any(|(_, instruction)| matches!(local_access(instruction), Some((loaded, _, false)) if loaded == slot) || matches!(instruction.unwide(), Instruction::Iinc(incremented, _) if *incremented == slot));
+        if read_before { None } else { Some(i) }
+    }
+
+    // Whether the variable is assigned in each branch of an if or a switch, for which javac gives
+    // an entry of LocalVariableTable to each branch and one more from where the branches join.
+    // All of them are declared together.
+    fn assigned_in_branches(&mut self, i: usize) -> bool {
+        let variable = &self.variables[i];
+        let same = |other: &Variable| other.slot == variable.slot && other.name == variable.name && other.field_type == variable.field_type;
+        let continuation = self.variables.iter()
+            .filter(|other| same(other) && other.start_pc > variable.start_pc)
+            .filter(|other| self.jump_sources.get(&other.start_pc).is_some_and(|sources| sources.iter().any(|source| *source < other.start_pc)))
+            .map(|other| other.end_pc)
+            .min();
+        let end_pc = match continuation {
+            Some(end_pc) => end_pc,
+            None => return false,
+        };
+        let branches: Vec<usize> = (0..self.variables.len())
+            .filter(|j| same(&self.variables[*j]) && self.variables[*j].start_pc >= variable.start_pc && self.variables[*j].end_pc <= end_pc)
+            .collect();
+        self.declarations.declared.extend(branches);
+        true
+    }
+
+    // The name of a local variable that LocalVariableTable does not name.
+    fn unnamed_name(&self, slot: u16, kind: char) -> String {
+        match self.parameters.iter().find(|(parameter_slot, _)| *parameter_slot == slot) {
+            Some((_, name)) => name.clone(),
+            None => format!("{}{}", kind, slot),
+        }
+    }
+
+    fn index_of(&self, pc: u16) -> Result<usize> {
+        self.instructions.binary_search_by_key(&pc, |(pc, _)| *pc).or(error(format!("No instruction starts at pc {}", pc)))
+    }
+
+    // The index of the last instruction before pc.
+    fn index_before(&self, pc: u16) -> Result<usize> {
+        match self.instructions.partition_point(|(instruction_pc, _)| *instruction_pc < pc) {
+            0 => error(format!("No instruction before pc {}", pc)),
+            i => Ok(i - 1),
+        }
+    }
+
+    fn next_pc(&self, index: usize) -> u16 {
+        self.instructions.get(index + 1).map_or(self.code.code.len() as u16, |(pc, _)| *pc)
+    }
+}
+
+fn pop(stack: &mut Vec<Expr>) -> Result<Expr> {
+    match stack.pop() {
+        Some(value) => Ok(value),
+        None => error("Operand stack underflow".to_string()),
+    }
+}
+
+fn push_text(decompiler: &mut MethodDecompiler, stack: &mut Vec<Expr>, text: &str, precedence: u8, ty: Option<FieldType>) {
+    let value = decompiler.text(text.to_string(), precedence, ty);
+    stack.push(value);
+}
+
+// A discarded value, kept as a statement if it is a method call, `new` or `i++`.
+fn side_effect(value: Expr, stmts: &mut Vec<Stmt>) {
+    let (text, precedence) = value.text();
+    let is_statement = match value.kind {
+        ExprKind::Text(..) => (precedence == PRIMARY && text.ends_with(')')) || precedence == POSTFIX,
+        ExprKind::Assignment(..) => true,
+        _ => false,
+    };
+    if is_statement {
+        stmts.push(Stmt::Expr(text));
+    }
+}
+
+fn join_arguments(arguments: &[Expr]) -> String {
+    arguments.iter().map(|argument| argument.wrap(ASSIGNMENT)).collect::<Vec<_>>().join(", ")
+}
+
+// `x = value`, or `x += n`, `x++` and so on where the value is computed from x.
+fn assignment(target: &str, value: &Expr) -> String {
+    if let ExprKind::Binary { left, operator, right, .. } = &value.kind {
+        if left.text().0 == target {
+            return match *operator {
+                "+" | "-" if is_one(right) => format!("{}{}{}", target, operator, operator),
+                _ => format!("{} {}= {}", target, operator, right.wrap(ASSIGNMENT)),
+            };
+        }
+    }
+    format!("{} = {}", target, value.wrap(ASSIGNMENT))
+}
+
+// `x++` or `x--` in an expression, for which a copy of the old value of x is left on the stack before the assignment.
+fn postfix(target: &str, value: &Expr, stack: &mut [Expr]) -> bool {
+    if let ExprKind::Binary { left, operator: operator @ ("+" | "-"), right, .. } = &value.kind {
+        if is_one(right) && left.text().0 == target {
+            if let Some(old) = stack.iter_mut().rev().find(|expr| expr.id == left.id) {
+                *old = Expr { id: old.id, kind: ExprKind::Text(format!("{}{}{}", target, operator, operator), POSTFIX), ty: old.ty.clone() };
+                return true;
+            }
+        }
+    }
+    false
+}
+
+// An assignment used as a value, for which a copy of the new value is left on top of the stack,
+// e.g. by dup_x1 for `g(this.f = 0)`. `++x` and `--x` as well.
+fn in_place(target: &str, value: &Expr, stack: &mut [Expr]) -> bool {
+    if stack.iter().filter(|expr| expr.id == value.id).count() != 1 {
+        return false;
+    }
+    let top = match stack.last_mut() {
+        Some(top) if top.id == value.id => top,
+        _ => return false,
+    };
+    let (text, precedence) = match &value.kind {
+        ExprKind::Binary { left, operator: operator @ ("+" | "-"), right, .. } if is_one(right) && left.text().0 == target => {
+            (format!("{}{}{}", operator, operator, target), UNARY)
+        }
+        _ => (assignment(target, value), ASSIGNMENT),
+    };
+    *top = Expr { id: top.id, kind: ExprKind::Assignment(text, precedence), ty: top.ty.clone() };
+    true
+}
+
+// Whether an expression may read the target of an assignment: a local variable by its name,
+// a field by its name on any object, and an array element by any array access. A method call may read the latter two.
+fn reads(text: &str, target: &str) -> bool {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    if target.ends_with(']') {
+        return text.contains('[') || text.contains('(');
+    }
+    let pattern = match target.rfind('.') {
+        Some(_) if text.contains('(') => return true,
+        Some(i) => &target[i..],
+        None => target,
+    };
+    text.match_indices(pattern).any(|(i, _)| {
+        let before = text[..i].chars().last();
+        let after = text[i + pattern.len()..].chars().next();
+        (pattern.starts_with('.') || !before.is_some_and(|c| is_name_char(c) || c == '.')) && !after.is_some_and(is_name_char)
+    })
+}
+
+// Whether computing an expression may change anything: a method call, `new`, or an assignment.
+fn has_side_effect(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    // `=` but not `==`, `!=`, `<=` nor `>=`, where `<<=`, `>>=` and `>>>=` are assignments
+    let is_assignment = |i: usize| {
+        let before = if i > 0 { bytes[i - 1] } else { b' ' };
+        bytes.get(i + 1) != Some(&b'=') && before != b'=' && before != b'!' && (!matches!(before, b'<' | b'>') || (i > 1 && bytes[i - 2] == before))
+    };
+    text.contains('(') || text.contains("new ") || text.contains("++") || text.contains("--") || (0..bytes.len()).any(|i| bytes[i] == b'=' && is_assignment(i))
+}
+
+// Whether the execution may go on after the statements, by their last one.
+fn completes_normally(stmts: &[Stmt]) -> bool {
+    !matches!(stmts.last(), Some(Stmt::Return(_) | Stmt::Throw(_) | Stmt::Break(_) | Stmt::Continue(_)))
+}
+
+fn is_one(expr: &Expr) -> bool {
+    matches!(&expr.kind, ExprKind::Int(1)) || matches!(&expr.kind, ExprKind::Text(text, _) if text == "1L")
+}
+
+// Joins adjacent branches until one is left: `a || b` where both jump to the same target,
+// and `a && b` where a jumps past b when it fails. None if the branches do not make one condition.
+fn join_conditions(mut chain: Vec<(Condition, u16, u16)>) -> Option<(Condition, u16, u16)> {
+    while chain.len() > 1 {
+        let i = (0..chain.len() - 1).find(|i| chain[*i].1 == chain[i + 1].1 || chain[*i].1 == chain[i + 1].2)?;
+        let (right, target, fallthrough) = chain.remove(i + 1);
+        let (left, left_target, _) = chain.remove(i);
+        let condition = if left_target == target {
+            Condition::Or(Box::new(left), Box::new(right))
+        } else {
+            Condition::And(Box::new(left.negate()), Box::new(right))
+        };
+        chain.insert(i, (condition, target, fallthrough));
+    }
+    chain.pop()
+}
+
+// `a || b || c` as [a, b, c].
+fn flatten_or(condition: Condition, alternatives: &mut Vec<Condition>) {
+    match condition {
+        Condition::Or(left, right) => {
+            flatten_or(*left, alternatives);
+            flatten_or(*right, alternatives);
+        }
+        condition => alternatives.push(condition),
+    }
+}
+
+// The reverse of flatten_or, for a non-empty list.
+fn join_or(alternatives: Vec<Condition>) -> Condition {
+    alternatives.into_iter().reduce(|left, right| Condition::Or(Box::new(left), Box::new(right))).unwrap()
+}
+
+fn is_conditional(instruction: &Instruction) -> bool {
+    use Instruction::*;
+    matches!(
+        instruction,
+        Ifeq(_) | Ifne(_) | Iflt(_) | Ifge(_) | Ifgt(_) | Ifle(_) | IfIcmpeq(_) | IfIcmpne(_) | IfIcmplt(_) |
+        IfIcmpge(_) | IfIcmpgt(_) | IfIcmple(_) | IfAcmpeq(_) | IfAcmpne(_) | Ifnull(_) | Ifnonnull(_)
+    )
+}
+
+// (slot, kind, whether a store) of a load or a store of a local variable, where the kind is one of `i`, `l`, `f`, `d` and `a`.
+fn local_access(instruction: &Instruction) -> Option<(u16, char, bool)> {
+    use Instruction::*;
+    Some(match instruction.unwide() {
+        Iload(n) => (*n, 'i', false),
+        Lload(n) => (*n, 'l', false),
+        Fload(n) => (*n, 'f', false),
+        Dload(n) => (*n, 'd', false),
+        Aload(n) => (*n, 'a', false),
+        Iload0 => (0, 'i', false),
+        Iload1 => (1, 'i', false),
+        Iload2 => (2, 'i', false),
+        Iload3 => (3, 'i', false),
+        Lload0 => (0, 'l', false),
+        Lload1 => (1, 'l', false),
+        Lload2 => (2, 'l', false),
+        Lload3 => (3, 'l', false),
+        Fload0 => (0, 'f', false),
+        Fload1 => (1, 'f', false),
+        Fload2 => (2, 'f', false),
+        Fload3 => (3, 'f', false),
+        Dload0 => (0, 'd', false),
+        Dload1 => (1, 'd', false),
+        Dload2 => (2, 'd', false),
+        Dload3 => (3, 'd', false),
+        Aload0 => (0, 'a', false),
+        Aload1 => (1, 'a', false),
+        Aload2 => (2, 'a', false),
+        Aload3 => (3, 'a', false),
+        Istore(n) => (*n, 'i', true),
+        Lstore(n) => (*n, 'l', true),
+        Fstore(n) => (*n, 'f', true),
+        Dstore(n) => (*n, 'd', true),
+        Astore(n) => (*n, 'a', true),
+        Istore0 => (0, 'i', true),
+        Istore1 => (1, 'i', true),
+        Istore2 => (2, 'i', true),
+        Istore3 => (3, 'i', true),
+        Lstore0 => (0, 'l', true),
+        Lstore1 => (1, 'l', true),
+        Lstore2 => (2, 'l', true),
+        Lstore3 => (3, 'l', true),
+        Fstore0 => (0, 'f', true),
+        Fstore1 => (1, 'f', true),
+        Fstore2 => (2, 'f', true),
+        Fstore3 => (3, 'f', true),
+        Dstore0 => (0, 'd', true),
+        Dstore1 => (1, 'd', true),
+        Dstore2 => (2, 'd', true),
+        Dstore3 => (3, 'd', true),
+        Astore0 => (0, 'a', true),
+        Astore1 => (1, 'a', true),
+        Astore2 => (2, 'a', true),
+        Astore3 => (3, 'a', true),
+        _ => return None,
+    })
+}
+
+fn kind_type(kind: char) -> FieldType {
+    match kind {
+        'i' => FieldType::Int,
+        'l' => FieldType::Long,
+        'f' => FieldType::Float,
+        'd' => FieldType::Double,
+        _ => FieldType::Class { name: "java/lang/Object".to_string() },
+    }
+}
+
+fn arithmetic_type(instruction: &Instruction) -> FieldType {
+    match instruction.mnemonic().as_bytes()[0] {
+        b'l' => FieldType::Long,
+        b'f' => FieldType::Float,
+        b'd' => FieldType::Double,
+        _ => FieldType::Int,
+    }
+}
+
+fn array_element_type(instruction: &Instruction) -> Option<FieldType> {
+    match instruction {
+        Instruction::Iaload => Some(FieldType::Int),
+        Instruction::Laload => Some(FieldType::Long),
+        Instruction::Faload => Some(FieldType::Float),
+        Instruction::Daload => Some(FieldType::Double),
+        Instruction::Caload => Some(FieldType::Char),
+        Instruction::Saload => Some(FieldType::Short),
+        _ => None,
+    }
+}
+
+// The type that a CONSTANT_Class names: a class, or an array by its descriptor.
+fn class_type(class_name: &str) -> Result<FieldType> {
+    if class_name.starts_with('[') {
+        parse_field_type(class_name)
+    } else {
+        Ok(FieldType::Class { name: class_name.to_string() })
+    }
+}
@@ -0,0 +1,576 @@
+// A decompiler from class files to Java source, for reading classes without their sources.
+// It recognizes the shapes of code that javac generates. A method that it cannot structure is shown
+// by its instructions in comments, with a body that throws.
+mod expression;
+mod method;
+mod statement;
+
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::class_file::access_flags::*;
+use crate::class_file::descriptor::{FieldType, ReturnType, parse_field_type, parse_method_descriptor};
+use crate::class_file::error::{Error, Result};
+use crate::class_file::signature::*;
+use crate::class_file::structure::attribute::Attribute;
+use crate::class_file::structure::constant_pool::CpInfo;
+use crate::class_file::structure::root::{ClassFile, FieldsInfo, MethodInfo};
+use crate::class_file::structure_utils::cp_accessor::*;
+
+use expression::{coerce, double_literal, float_literal, long_literal, string_literal};
+
+fn error<T>(message: String) -> Result<T> {
+    Err(Error { message: format!("Decompilation failed. {}", message) })
+}
+
+/// Java source of a class, with its member classes nested in it.
+/// `find_class` looks up the class files of the member classes by their binary names, e.g. `a/b/Outer$Inner`.
+/// The member classes that it cannot find are left out.
+pub fn decompile_class(class_file: &ClassFile, find_class: &dyn Fn(&str) -> Option<ClassFile>) -> Result<String> {
+    if class_file.access_flags.contains(ACC_MODULE) {
+        return error("module-info is not a class.".to_string());
+    }
+    let class_name = (&class_file.constant_pool).access(class_file.this_class).name().bytes_as_string()?;
+    let names = RefCell::new(Names::new(&class_name));
+    let mut body = String::new();
+    ClassDecompiler { class_file, class_name: class_name.clone(), names: &names, find_class }.class(None, 0, &mut body)?;
+
+    let names = names.into_inner();
+    let mut out = String::new();
+    if !names.this_package.is_empty() {
+        writeln!(out, "package {};", names.this_package.replace('/', ".")).unwrap();
+        writeln!(out).unwrap();
+    }
+    for import in &names.imports {
+        writeln!(out, "import {};", import).unwrap();
+    }
+    if !names.imports.is_empty() {
+        writeln!(out).unwrap();
+    }
+    out.push_str(&body);
+    Ok(out)
+}
+
+/// How class names are written: by the simple name where it is unambiguous, with an import if needed.
+pub(super) struct Names {
+    this_package: String,
+    // simple name -> binary name
+    simple_names: HashMap<String, String>,
+    imports: BTreeSet<String>,
+    // binary name -> (binary name of the outer class, simple name), from InnerClasses
+    member_classes: HashMap<String, (String, String)>,
+    // binary names of the member classes that are not static, whose instances have an outer instance
+    inner_classes: HashSet<String>,
+}
+
+impl Names {
+    fn new(class_name: &str) -> Names {
+        let (package, simple_name) = split_package(class_name);
+        Names {
+            this_package: package.to_string(),
+            simple_names: HashMap::from([(simple_name.to_string(), class_name.to_string())]),
+            imports: BTreeSet::new(),
+            member_classes: HashMap::new(),
+            inner_classes: HashSet::new(),
+        }
+    }
+
+    fn add_member_classes(&mut self, class_file: &ClassFile) -> Result<()> {
+        let constant_pool = &class_file.constant_pool;
+        for attribute in &class_file.attributes {
+            if let Attribute::InnerClasses(inner_classes) = attribute {
+                for class in &inner_classes.classes {
+                    if class.outer_class_info_index.index() != 0 && class.inner_name_index.index() != 0 {
+                        let inner = constant_pool.access(class.inner_class_info_index).name().bytes_as_string()?;
+                        let outer = constant_pool.access(class.outer_class_info_index).name().bytes_as_string()?;
+                        let simple_name = constant_pool.access(class.inner_name_index).bytes_as_string()?;
+                        if !class.inner_class_access_flags.contains(ACC_STATIC) {
+                            self.inner_classes.insert(inner.clone());
+                        }
+                        self.member_classes.insert(inner, (outer, simple_name));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// A class by its binary name in internal form, e.g. `java/util/Map$Entry` as `Map.Entry`.
+    pub fn class(&mut self, class_name: &str) -> String {
+        if let Some((outer, simple_name)) = self.member_classes.get(class_name).cloned() {
+            return format!("{}.{}", self.class(&outer), simple_name);
+        }
+        let (package, simple_name) = split_package(class_name);
+        match self.simple_names.get(simple_name) {
+            Some(claimed) if claimed == class_name => return simple_name.to_string(),
+            Some(_) => return class_name.replace(['/', '$'], "."),
+            None => {}
+        }
+        self.simple_names.insert(simple_name.to_string(), class_name.to_string());
+        if package != self.this_package && package != "java/lang" && !package.is_empty() {
+            self.imports.insert(class_name.replace('/', "."));
+        }
+        simple_name.to_string()
+    }
+
+    pub fn field_type(&mut self, field_type: &FieldType) -> String {
+        match field_type {
+            FieldType::Byte => "byte".to_string(),
+            FieldType::Char => "char".to_string(),
+            FieldType::Double => "double".to_string(),
+            FieldType::Float => "float".to_string(),
+            FieldType::Int => "int".to_string(),
+            FieldType::Long => "long".to_string(),
+            FieldType::Class { name } => self.class(name),
+            FieldType::Short => "short".to_string(),
+            FieldType::Boolean => "boolean".to_string(),
+            FieldType::Array { value } => format!("{}[]", self.field_type(value)),
+        }
+    }
+
+    pub fn return_type(&mut self, return_type: &ReturnType) -> String {
+        match return_type {
+            ReturnType::Field { value } => self.field_type(value),
+            ReturnType::Void => "void".to_string(),
+        }
+    }
+
+    /// A class name or an array descriptor, as CONSTANT_Class_info has.
+    pub fn class_or_array(&mut self, name: &str) -> String {
+        match parse_field_type(name) {
+            Ok(field_type) if name.starts_with('[') => self.field_type(&field_type),
+            _ => self.class(name),
+        }
+    }
+
+    pub fn signature(&mut self, signature: &JavaTypeSignature) -> String {
+        match signature {
+            JavaTypeSignature::Base(field_type) => self.field_type(field_type),
+            JavaTypeSignature::TypeVariable(name) => name.clone(),
+            JavaTypeSignature::Array(component) => format!("{}[]", self.signature(component)),
+            JavaTypeSignature::Class(class) => self.class_signature(class),
+        }
+    }
+
+    fn class_signature(&mut self, class: &ClassTypeSignature) -> String {
+        // Outer<A>.Inner<B> is written from the outer class with type arguments, the others by the name of the whole.
+        let last = class.classes.len() - 1;
+        let split = class.classes[..last].iter().rposition(|class| !class.type_arguments.is_empty()).unwrap_or(last);
+        let names: Vec<&str> = class.classes[..=split].iter().map(|class| class.name.as_str()).collect();
+        let mut text = self.class(&format!("{}{}", class.package, names.join("$")));
+        text.push_str(&self.type_arguments(&class.classes[split].type_arguments));
+        for simple in &class.classes[split + 1..] {
+            text.push('.');
+            text.push_str(&simple.name);
+            text.push_str(&self.type_arguments(&simple.type_arguments));
+        }
+        text
+    }
+
+    fn type_arguments(&mut self, type_arguments: &[TypeArgument]) -> String {
+        if type_arguments.is_empty() {
+            return String::new();
+        }
+        let arguments: Vec<String> = type_arguments.iter().map(|argument| match argument {
+            TypeArgument::Any => "?".to_string(),
+            TypeArgument::Exact(signature) => self.signature(signature),
+            TypeArgument::Extends(signature) => format!("? extends {}", self.signature(signature)),
+            TypeArgument::Super(signature) => format!("? super {}", self.signature(signature)),
+        }).collect();
+        format!("<{}>", arguments.join(", "))
+    }
+
+    fn type_parameters(&mut self, type_parameters: &[TypeParameter]) -> String {
+        if type_parameters.is_empty() {
+            return String::new();
+        }
+        let parameters: Vec<String> = type_parameters.iter().map(|parameter| {
+            let mut bounds: Vec<String> = Vec::new();
+            if let Some(class_bound) = &parameter.class_bound {
+                let bound = self.signature(class_bound);
+                if bound != "Object" || !parameter.interface_bounds.is_empty() {
+                    bounds.push(bound);
+                }
+            }
+            for interface_bound in &parameter.interface_bounds {
+                bounds.push(self.signature(interface_bound));
+            }
+            if bounds.is_empty() { parameter.name.clone() } else { format!("{} extends {}", parameter.name, bounds.join(" & ")) }
+        }).collect();
+        format!("<{}>", parameters.join(", "))
+    }
+}
+
+fn split_package(class_name: &str) -> (&str, &str) {
+    match class_name.rfind('/') {
+        Some(i) => (&class_name[..i], &class_name[i + 1..]),
+        None => ("", class_name),
+    }
+}
+
+fn modifiers(access_flags: u16, table: &[(u16, &str)]) -> String {
+    table.iter().filter(|(flag, _)| access_flags & flag != 0).map(|(_, name)| format!("{} ", name)).collect()
+}
+
+fn signature_of(class_file: &ClassFile, attributes: &[Attribute]) -> Result<Option<String>> {
+    for attribute in attributes {
+        if let Attribute::Signature(signature) = attribute {
+            return (&class_file.constant_pool).access(signature.signature_index).bytes_as_string().map(Some);
+        }
+    }
+    Ok(None)
+}
+
+pub(super) struct ClassDecompiler<'a> {
+    pub class_file: &'a ClassFile,
+    // binary name in internal form
+    pub class_name: String,
+    pub names: &'a RefCell<Names>,
+    find_class: &'a dyn Fn(&str) -> Option<ClassFile>,
+}
+
+impl ClassDecompiler<'_> {
+    // `inner_access_flags` are the flags from InnerClasses for a member class.
+    fn class(&self, inner_access_flags: Option<u16>, indent: usize, out: &mut String) -> Result<()> {
+        let class_file = self.class_file;
+        let constant_pool = &class_file.constant_pool;
+        self.names.borrow_mut().add_member_classes(class_file)?;
+        let padding = "    ".repeat(indent);
+        let access_flags = inner_access_flags.unwrap_or(class_file.access_flags.bits());
+        let is_interface = access_flags & ACC_INTERFACE != 0;
+        let is_enum = access_flags & ACC_ENUM != 0;
+
+        let (simple_name, _) = self.simple_name();
+        let super_class = match class_file.super_class.index() {
+            0 => None,
+            _ => Some(constant_pool.access(class_file.super_class).name().bytes_as_string()?),
+        };
+        let mut interfaces = Vec::new();
+        for index in &class_file.interfaces {
+            interfaces.push(constant_pool.access(*index).name().bytes_as_string()?);
+        }
+
+        let mut header = padding.clone();
+        let mut class_modifiers = vec![(ACC_PUBLIC, "public"), (ACC_PRIVATE, "private"), (ACC_PROTECTED, "protected")];
+        if !is_interface {
+            class_modifiers.push((ACC_ABSTRACT, "abstract"));
+        }
+        class_modifiers.push((ACC_STATIC, "static"));
+        if !is_enum {
+            class_modifiers.push((ACC_FINAL, "final"));
+        }
+        header.push_str(&modifiers(access_flags, &class_modifiers));
+        header.push_str(if access_flags & ACC_ANNOTATION != 0 {
+            "@interface "
+        } else if is_interface {
+            "interface "
+        } else if is_enum {
+            "enum "
+        } else {
+            "class "
+        });
+        header.push_str(&simple_name);
+
+        let mut names = self.names.borrow_mut();
+        let (super_text, interface_texts) = match signature_of(class_file, &class_file.attributes)? {
+            Some(signature) => {
+                let signature = parse_class_signature(&signature)?;
+                header.push_str(&names.type_parameters(&signature.type_parameters));
+                let super_class = signature.super_class.name();
+                let super_text = names.class_signature(&signature.super_class);
+                let interfaces: Vec<String> = signature.super_interfaces.iter().map(|interface| names.class_signature(interface)).collect();
+                (Some((super_class, super_text)), interfaces)
+            }
+            None => {
+                let super_text = super_class.map(|super_class| (super_class.clone(), names.class(&super_class)));
+                let interfaces: Vec<String> = interfaces.iter().map(|interface| names.class(interface)).collect();
+                (super_text, interfaces)
+            }
+        };
+        if let Some((super_class, super_text)) = super_text {
+            if super_class != "java/lang/Object" && !(is_enum && super_class == "java/lang/Enum") {
+                write!(header, " extends {}", super_text).unwrap();
+            }
+        }
+        if !interface_texts.is_empty() && access_flags & ACC_ANNOTATION == 0 {
+            write!(header, " {} {}", if is_interface { "extends" } else { "implements" }, interface_texts.join(", ")).unwrap();
+        }
+        drop(names);
+        writeln!(out, "{} {{", header).unwrap();
+
+        let mut sections: Vec<String> = Vec::new();
+        let member_padding = "    ".repeat(indent + 1);
+        // enum constants
+        let constants: Vec<String> = class_file.fields.iter()
+            .filter(|field| field.access_flags.contains(ACC_ENUM))
+            .map(|field| constant_pool.access(field.name_index).bytes_as_string())
+            .collect::<Result<_>>()?;
+        if is_enum {
+            sections.push(format!("{}{};\n", member_padding, constants.join(", ")));
+        }
+        let mut fields = String::new();
+        for field in class_file.fields.iter().filter(|field| field.access_flags.bits() & (ACC_SYNTHETIC | ACC_ENUM) == 0) {
+            self.field(field, &member_padding, &mut fields)?;
+        }
+        if !fields.is_empty() {
+            sections.push(fields);
+        }
+        for method in &class_file.methods {
+            let name = constant_pool.access(method.name_index).bytes_as_string()?;
+            // the bodies of lambda expressions are written in the lambdas
+            if method.access_flags.bits() & (ACC_SYNTHETIC | ACC_BRIDGE) != 0 {
+                continue;
+            }
+            let descriptor = constant_pool.access(method.descriptor_index).bytes_as_string()?;
+            let enum_method = format!("{}{}", name, descriptor);
+            if is_enum && (name == "<clinit>" || enum_method == format!("values()[L{};", self.class_name) || enum_method == format!("valueOf(Ljava/lang/String;)L{};", self.class_name)) {
+                continue;
+            }
+            let mut text = String::new();
+            self.method(method, is_interface, indent + 1, &mut text)?;
+            if !text.is_empty() {
+                sections.push(text);
+            }
+        }
+        for member_class in self.member_classes()? {
+            if let Some(member_class_file) = (self.find_class)(&member_class.0) {
+                let decompiler = ClassDecompiler { class_file: &member_class_file, class_name: member_class.0.clone(), names: self.names, find_class: self.find_class };
+                let mut text = String::new();
+                decompiler.class(Some(member_class.1), indent + 1, &mut text)?;
+                sections.push(text);
+            }
+        }
+        out.push_str(&sections.join("\n"));
+        writeln!(out, "{}}}", padding).unwrap();
+        Ok(())
+    }
+
+    // The simple name, and whether the class is a member class as InnerClasses says.
+    fn simple_name(&self) -> (String, bool) {
+        if let Some((_, simple_name)) = self.names.borrow().member_classes.get(&self.class_name) {
+            return (simple_name.clone(), true);
+        }
+        (split_package(&self.class_name).1.to_string(), false)
+    }
+
+    // The binary name of the outer class, for an inner class.
+    fn outer_class(&self) -> Option<String> {
+        let names = self.names.borrow();
+        if !names.inner_classes.contains(&self.class_name) {
+            return None;
+        }
+        names.member_classes.get(&self.class_name).map(|(outer, _)| outer.clone())
+    }
+
+    // (binary name, access flags) of the member classes declared in this class
+    fn member_classes(&self) -> Result<Vec<(String, u16)>> {
+        let constant_pool = &self.class_file.constant_pool;
+        let mut member_classes = Vec::new();
+        for attribute in &self.class_file.attributes {
+            if let Attribute::InnerClasses(inner_classes) = attribute {
+                for class in &inner_classes.classes {
+                    if class.outer_class_info_index == self.class_file.this_class && class.inner_name_index.index() != 0 {
+                        let inner = constant_pool.access(class.inner_class_info_index).name().bytes_as_string()?;
+                        member_classes.push((inner, class.inner_class_access_flags.bits()));
+                    }
+                }
+            }
+        }
+        Ok(member_classes)
+    }
+
+    fn field(&self, field: &FieldsInfo, padding: &str, out: &mut String) -> Result<()> {
+        let constant_pool = &self.class_file.constant_pool;
+        let name = constant_pool.access(field.name_index).bytes_as_string()?;
+        let descriptor = constant_pool.access(field.descriptor_index).bytes_as_string()?;
+        let field_type = parse_field_type(&descriptor)?;
+        let mut names = self.names.borrow_mut();
+        let ty = match signature_of(self.class_file, &field.attributes)? {
+            Some(signature) => names.signature(&parse_field_signature(&signature)?),
+            None => names.field_type(&field_type),
+        };
+        let field_modifiers = [
+            (ACC_PUBLIC, "public"), (ACC_PRIVATE, "private"), (ACC_PROTECTED, "protected"), (ACC_STATIC, "static"),
+            (ACC_FINAL, "final"), (ACC_TRANSIENT, "transient"), (ACC_VOLATILE, "volatile"),
+        ];
+        write!(out, "{}{}{} {}", padding, modifiers(field.access_flags.bits(), &field_modifiers), ty, name).unwrap();
+        for attribute in &field.attributes {
+            if let Attribute::ConstantValue(constant_value) = attribute {
+                let index = constant_value.constantvalue_index.index();
+                let value = match constant_pool.get::<CpInfo>(index).ok() {
+                    Some(CpInfo::Integer(_)) => {
+                        let value = constant_pool.access_as_integer(index).bytes_as_integer()?;
+                        let expr = expression::Expr { id: 0, kind: expression::ExprKind::Int(value), ty: Some(FieldType::Int) };
+                        coerce(expr, &field_type).text().0
+                    }
+                    Some(CpInfo::Float(_)) => float_literal(constant_pool.access_as_float(index).bytes_as_float()?),
+                    Some(CpInfo::Long(_)) => long_literal(constant_pool.access_as_long(index).bytes_as_long()?),
+                    Some(CpInfo::Double(_)) => double_literal(constant_pool.access_as_double(index).bytes_as_double()?),
+                    Some(CpInfo::String(_)) => string_literal(&constant_pool.access_as_string(index).string().bytes_as_string()?),
+                    _ => return error(format!("Illegal ConstantValue of the field {}", name)),
+                };
+                write!(out, " = {}", value).unwrap();
+            }
+        }
+        writeln!(out, ";").unwrap();
+        Ok(())
+    }
+
+    fn method(&self, method: &MethodInfo, is_interface: bool, indent: usize, out: &mut String) -> Result<()> {
+        let constant_pool = &self.class_file.constant_pool;
+        let padding = "    ".repeat(indent);
+        let name = constant_pool.access(method.name_index).bytes_as_string()?;
+        let descriptor = constant_pool.access(method.descriptor_index).bytes_as_string()?;
+        let method_type = parse_method_descriptor(&descriptor)?;
+        let access_flags = method.access_flags;
+        let is_static = access_flags.contains(ACC_STATIC);
+        let is_abstract = access_flags.contains(ACC_ABSTRACT);
+
+        let code = method.attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Code(code) => Some(code),
+            _ => None,
+        });
+        let mut decompiler = match code {
+            Some(code) => Some(method::MethodDecompiler::new(self, method, &method_type, code)?),
+            None => None,
+        };
+        // An enum constructor takes the name and the ordinal first, which are passed to Enum implicitly,
+        // and a constructor of an inner class takes the outer instance first, which `new` passes implicitly.
+        let is_enum_constructor = name == "<init>" && self.class_file.access_flags.contains(ACC_ENUM);
+        let is_inner_constructor = name == "<init>"
+            && self.outer_class().is_some_and(|outer| method_type.parameter_types.first() == Some(&FieldType::Class { name: outer }));
+        let skipped = if is_enum_constructor { 2 } else if is_inner_constructor { 1 } else { 0 };
+        let body = decompiler.as_mut().map(|decompiler| decompiler.body());
+        if is_enum_constructor && descriptor == "(Ljava/lang/String;I)V" && matches!(&body, Some(Ok(body)) if body.is_empty()) {
+            return Ok(());
+        }
+        // nothing is left of a static initializer that only initializes the field of the assert statements
+        if name == "<clinit>" && matches!(&body, Some(Ok(body)) if body.is_empty()) {
+            return Ok(());
+        }
+
+        if name == "<clinit>" {
+            writeln!(out, "{}static {{", padding).unwrap();
+        } else {
+            let mut method_modifiers = vec![(ACC_PUBLIC, "public"), (ACC_PRIVATE, "private"), (ACC_PROTECTED, "protected")];
+            if !is_interface {
+                method_modifiers.push((ACC_ABSTRACT, "abstract"));
+            }
+            method_modifiers.extend([(ACC_STATIC, "static"), (ACC_FINAL, "final"), (ACC_SYNCHRONIZED, "synchronized"), (ACC_NATIVE, "native")]);
+            let mut header = format!("{}{}", padding, modifiers(access_flags.bits(), &method_modifiers));
+            if is_interface && !is_abstract && !is_static && !access_flags.contains(ACC_PRIVATE) {
+                header.push_str("default ");
+            }
+
+            let mut names = self.names.borrow_mut();
+            // The Signature of a constructor of an inner class or an enum may leave out the synthetic parameters.
+            let signature = match signature_of(self.class_file, &method.attributes)? {
+                Some(signature) => Some(parse_method_signature(&signature)?).filter(|signature| {
+                    let count = method_type.parameter_types.len();
+                    signature.parameter_types.len() == count || signature.parameter_types.len() + skipped == count
+                }),
+                None => None,
+            };
+            let mut parameter_types: Vec<String> = match &signature {
+                Some(signature) => {
+                    header.push_str(&names.type_parameters(&signature.type_parameters));
+                    if !signature.type_parameters.is_empty() {
+                        header.push(' ');
+                    }
+                    // the left out parameters are skipped below
+                    let mut parameter_types = vec![String::new(); method_type.parameter_types.len() - signature.parameter_types.len()];
+                    parameter_types.extend(signature.parameter_types.iter().map(|parameter| names.signature(parameter)));
+                    parameter_types
+                }
+                None => method_type.parameter_types.iter().map(|parameter| names.field_type(parameter)).collect(),
+            };
+            if access_flags.contains(ACC_VARARGS) {
+                if let Some(last) = parameter_types.last_mut() {
+                    if last.ends_with("[]") {
+                        last.truncate(last.len() - 2);
+                        last.push_str("...");
+                    }
+                }
+            }
+            if name == "<init>" {
+                drop(names);
+                header.push_str(&self.simple_name().0);
+                names = self.names.borrow_mut();
+            } else {
+                let return_type = match &signature {
+                    Some(signature) => signature.result.as_ref().map_or("void".to_string(), |result| names.signature(result)),
+                    None => names.return_type(&method_type.return_type),
+                };
+                write!(header, "{} {}", return_type, name).unwrap();
+            }
+            drop(names);
+
+            let parameter_names = match &decompiler {
+                Some(decompiler) => decompiler.parameter_names(),
+                None => self.parameter_names(method, method_type.parameter_types.len())?,
+            };
+            let parameters: Vec<String> = parameter_types.iter().zip(parameter_names.iter()).skip(skipped)
+                .map(|(ty, name)| format!("{} {}", ty, name))
+                .collect();
+            write!(header, "({})", parameters.join(", ")).unwrap();
+
+            let mut exceptions = Vec::new();
+            match signature.filter(|signature| !signature.throws.is_empty()) {
+                Some(signature) => {
+                    let mut names = self.names.borrow_mut();
+                    exceptions.extend(signature.throws.iter().map(|exception| names.signature(exception)));
+                }
+                None => {
+                    for attribute in &method.attributes {
+                        if let Attribute::Exceptions(exceptions_attribute) = attribute {
+                            for index in &exceptions_attribute.exception_index_table {
+                                let exception = constant_pool.access(*index).name().bytes_as_string()?;
+                                exceptions.push(self.names.borrow_mut().class(&exception));
+                            }
+                        }
+                    }
+                }
+            }
+            if !exceptions.is_empty() {
+                write!(header, " throws {}", exceptions.join(", ")).unwrap();
+            }
+            if decompiler.is_none() {
+                writeln!(out, "{};", header).unwrap();
+                return Ok(());
+            }
+            writeln!(out, "{} {{", header).unwrap();
+        }
+
+        if let (Some(decompiler), Some(body)) = (&decompiler, body) {
+            match body {
+                Ok(body) => statement::render(&body, indent + 1, out),
+                Err(e) => {
+                    writeln!(out, "{}    // {}", padding, e.message).unwrap();
+                    for (pc, instruction) in &decompiler.instructions {
+                        writeln!(out, "{}    // {}: {}", padding, pc, instruction).unwrap();
+                    }
+                    writeln!(out, "{}    throw new UnsupportedOperationException();", padding).unwrap();
+                }
+            }
+        }
+        writeln!(out, "{}}}", padding).unwrap();
+        Ok(())
+    }
+
+    // For a method without code, from MethodParameters if any.
+    fn parameter_names(&self, method: &MethodInfo, count: usize) -> Result<Vec<String>> {
+        let constant_pool = &self.class_file.constant_pool;
+        let mut names: Vec<String> = (0..count).map(|i| format!("arg{}", i)).collect();
+        for attribute in &method.attributes {
+            if let Attribute::MethodParameters(method_parameters) = attribute {
+                for (i, parameter) in method_parameters.parameters.iter().enumerate().take(count) {
+                    if parameter.name_index.index() != 0 {
+                        names[i] = constant_pool.access(parameter.name_index).bytes_as_string()?;
+                    }
+                }
+            }
+        }
+        Ok(names)
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt::Write;
+
+#[derive(Debug, PartialEq, Clone)]
+pub(super) enum Stmt {
+    // an expression statement without `;`, e.g. `i++` or `list.clear()`
+    Expr(String),
+    Declare { ty: String, name: String, value: Option<String> },
+    Return(Option<String>),
+    Throw(String),
+    Assert { condition: String, message: Option<String> },
+    If { condition: String, then: Vec<Stmt>, otherwise: Vec<Stmt> },
+    While { condition: String, body: Vec<Stmt> },
+    DoWhile { body: Vec<Stmt>, condition: String },
+    For { init: Box<Stmt>, condition: String, update: Box<Stmt>, body: Vec<Stmt> },
+    // the labels of each group of cases, None for `default`
+    Switch { value: String, cases: Vec<(Vec<Option<String>>, Vec<Stmt>)> },
+    Try { body: Vec<Stmt>, catches: Vec<Catch> },
+    // a loop or a switch that a nested statement breaks or continues by the label
+    Labeled { label: String, body: Box<Stmt> },
+    // with the label of the statement, if not the innermost one
+    Break(Option<String>),
+    Continue(Option<String>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(super) struct Catch {
+    pub types: Vec<String>,
+    pub name: String,
+    pub body: Vec<Stmt>,
+}
+
+impl Stmt {
+    // The local variable that a statement assigns, for the init and update of a for loop.
+    fn assigned_variable(&self) -> Option<&str> {
+        match self {
+            Stmt::Declare { name, value: Some(_), .. } => Some(name),
+            Stmt::Expr(text) => {
+                let name_length = text.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$')).unwrap_or(text.len());
+                let (name, rest) = text.split_at(name_length);
+                let is_assignment = rest == "++" || rest == "--" || (rest.starts_with(' ') && rest[1..].split(' ').next().is_some_and(|operator| operator.ends_with('=') && operator != "=="));
+                if !name.is_empty() && is_assignment { Some(name) } else { None }
+            }
+            _ => None,
+        }
+    }
+
+    // Whether the statements continue the loop around them, which has the label if any:
+    // by `continue` outside of nested loops, or by `continue label` anywhere.
+    fn continues(stmts: &[Stmt], label: Option<&str>) -> bool {
+        fn visit(stmts: &[Stmt], label: Option<&str>, nested: bool) -> bool {
+            stmts.iter().any(|stmt| match stmt {
+                Stmt::Continue(None) => !nested,
+                Stmt::Continue(Some(target)) => label == Some(target.as_str()),
+                Stmt::If { then, otherwise, .. } => visit(then, label, nested) || visit(otherwise, label, nested),
+                Stmt::Switch { cases, .. } => cases.iter().any(|(_, body)| visit(body, label, nested)),
+                Stmt::Try { body, catches } => visit(body, label, nested) || catches.iter().any(|catch| visit(&catch.body, label, nested)),
+                Stmt::While { body, .. } | Stmt::DoWhile { body, .. } | Stmt::For { body, .. } => visit(body, label, true),
+                Stmt::Labeled { body, .. } => visit(std::slice::from_ref(body), label, nested),
+                _ => false,
+            })
+        }
+        visit(stmts, label, false)
+    }
+}
+
+fn mentions(text: &str, name: &str) -> bool {
+    text.match_indices(name).any(|(i, _)| {
+        let is_boundary = |c: Option<char>| !c.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$' || c == '.');
+        is_boundary(text[..i].chars().last()) && is_boundary(text[i + name.len()..].chars().next())
+    })
+}
+
+/// Turns `init; while (condition) { ...; update }` into a for loop where the three are about the same variable.
+/// A variable declared by the init goes out of scope after the loop, so it must not be used there.
+pub(super) fn make_for_loops(stmts: Vec<Stmt>) -> Vec<Stmt> {
+    let mut result: Vec<Stmt> = Vec::new();
+    let mut rest = stmts.into_iter();
+    while let Some(stmt) = rest.next() {
+        let (label, stmt) = match stmt {
+            Stmt::Labeled { label, body } => (Some(label), *body),
+            stmt => (None, stmt),
+        };
+        let labeled = |stmt: Stmt| match &label {
+            Some(label) => Stmt::Labeled { label: label.clone(), body: Box::new(stmt) },
+            None => stmt,
+        };
+        if let Stmt::While { condition, mut body } = stmt {
+            let variable = result.last().and_then(|init| init.assigned_variable()).map(|name| name.to_string());
+            let declares = matches!(result.last(), Some(Stmt::Declare { .. }));
+            let matches = variable.is_some_and(|variable| {
+                mentions(&condition, &variable)
+                    && body.last().and_then(|update| update.assigned_variable()) == Some(variable.as_str())
+                    && !Stmt::continues(&body, label.as_deref())
+                    && !(declares && {
+                        let mut after = String::new();
+                        render(rest.as_slice(), 0, &mut after);
+                        mentions(&after, &variable)
+                    })
+            });
+            if matches {
+                let update = body.pop().unwrap();
+                let init = result.pop().unwrap();
+                result.push(labeled(Stmt::For { init: Box::new(init), condition, update: Box::new(update), body }));
+            } else {
+                result.push(labeled(Stmt::While { condition, body }));
+            }
+        } else {
+            result.push(labeled(stmt));
+        }
+    }
+    result
+}
+
+pub(super) fn render(stmts: &[Stmt], indent: usize, out: &mut String) {
+    for stmt in stmts {
+        render_stmt(stmt, indent, out);
+    }
+}
+
+fn render_stmt(stmt: &Stmt, indent: usize, out: &mut String) {
+    let padding = "    ".repeat(indent);
+    // the lines of the body of a lambda expression follow the indentation of the statement
+    let indented = |text: &str| text.replace('\n', &format!("\n{}", padding));
+    match stmt {
+        Stmt::Expr(_) | Stmt::Declare { .. } => writeln!(out, "{}{};", padding, indented(&inline(stmt))).unwrap(),
+        Stmt::Return(None) => writeln!(out, "{}return;", padding).unwrap(),
+        Stmt::Return(Some(value)) => writeln!(out, "{}return {};", padding, indented(value)).unwrap(),
+        Stmt::Throw(value) => writeln!(out, "{}throw {};", padding, indented(value)).unwrap(),
+        Stmt::Assert { condition, message: None } => writeln!(out, "{}assert {};", padding, indented(condition)).unwrap(),
+        Stmt::Assert { condition, message: Some(message) } => writeln!(out, "{}assert {} : {};", padding, indented(condition), indented(message)).unwrap(),
+        Stmt::If { condition, then, otherwise } => {
+            writeln!(out, "{}if ({}) {{", padding, indented(condition)).unwrap();
+            render(then, indent + 1, out);
+            let mut otherwise = otherwise;
+            // else if
+            while let [Stmt::If { condition, then, otherwise: next }] = otherwise.as_slice() {
+                writeln!(out, "{}}} else if ({}) {{", padding, indented(condition)).unwrap();
+                render(then, indent + 1, out);
+                otherwise = next;
+            }
+            if !otherwise.is_empty() {
+                writeln!(out, "{}}} else {{", padding).unwrap();
+                render(otherwise, indent + 1, out);
+            }
+            writeln!(out, "{}}}", padding).unwrap();
+        }
+        Stmt::While { condition, body } => {
+            writeln!(out, "{}while ({}) {{", padding, indented(condition)).unwrap();
+            render(body, indent + 1, out);
+            writeln!(out, "{}}}", padding).unwrap();
+        }
+        Stmt::DoWhile { body, condition } => {
+            writeln!(out, "{}do {{", padding).unwrap();
+            render(body, indent + 1, out);
+            writeln!(out, "{}}} while ({});", padding, indented(condition)).unwrap();
+        }
+        Stmt::For { init, condition, update, body } => {
+            writeln!(out, "{}for ({}; {}; {}) {{", padding, indented(&inline(init)), indented(condition), indented(&inline(update))).unwrap();
+            render(body, indent + 1, out);
+            writeln!(out, "{}}}", padding).unwrap();
+        }
+        Stmt::Switch { value, cases } => {
+            writeln!(out, "{}switch ({}) {{", padding, indented(value)).unwrap();
+            for (labels, body) in cases {
+                for label in labels {
+                    match label {
+                        Some(label) => writeln!(out, "{}    case {}:", padding, label).unwrap(),
+                        None => writeln!(out, "{}    default:", padding).unwrap(),
+                    }
+                }
+                render(body, indent + 2, out);
+            }
+            writeln!(out, "{}}}", padding).unwrap();
+        }
+        Stmt::Try { body, catches } => {
+            writeln!(out, "{}try {{", padding).unwrap();
+            render(body, indent + 1, out);
+            for catch in catches {
+                writeln!(out, "{}}} catch ({} {}) {{", padding, catch.types.join(" | "), catch.name).unwrap();
+                render(&catch.body, indent + 1, out);
+            }
+            writeln!(out, "{}}}", padding).unwrap();
+        }
+        Stmt::Labeled { label, body } => {
+            let mut statement = String::new();
+            render_stmt(body, indent, &mut statement);
+            write!(out, "{}{}: {}", padding, label, &statement[padding.len()..]).unwrap();
+        }
+        Stmt::Break(None) => writeln!(out, "{}break;", padding).unwrap(),
+        Stmt::Break(Some(label)) => writeln!(out, "{}break {};", padding, label).unwrap(),
+        Stmt::Continue(None) => writeln!(out, "{}continue;", padding).unwrap(),
+        Stmt::Continue(Some(label)) => writeln!(out, "{}continue {};", padding, label).unwrap(),
+    }
+}
+
+// A declaration or an expression statement without `;`, as in the header of a for loop.
+fn inline(stmt: &Stmt) -> String {
+    match stmt {
+        Stmt::Expr(text) => text.clone(),
+        Stmt::Declare { ty, name, value: Some(value) } => format!("{} {} = {}", ty, name, value),
+        Stmt::Declare { ty, name, value: None } => format!("{} {}", ty, name),
+        _ => String::new(),
+    }
+}
+
+#[test]
+fn test_make_for_loops() {
+    let stmts = vec![
+        Stmt::Declare { ty: "int".to_string(), name: "i".to_string(), value: Some("0".to_string()) },
+        Stmt::While {
+            condition: "i < values.length".to_string(),
+            body: vec![Stmt::Expr("total += values[i]".to_string()), Stmt::Expr("i++".to_string())],
+        },
+        Stmt::Expr("n = 0".to_string()),
+        Stmt::While {
+            condition: "count > 0".to_string(),
+            body: vec![Stmt::Expr("n++".to_string())],
+        },
+        // j is used after the loop
+        Stmt::Declare { ty: "int".to_string(), name: "j".to_string(), value: Some("0".to_string()) },
+        Stmt::While {
+            condition: "j < n".to_string(),
+            body: vec![Stmt::Expr("j++".to_string())],
+        },
+        Stmt::Return(Some("j".to_string())),
+    ];
+    let stmts = make_for_loops(stmts);
+    let mut out = String::new();
+    render(&stmts, 0, &mut out);
+    assert_eq!(out, [
+        "for (int i = 0; i < values.length; i++) {",
+        "    total += values[i];",
+        "}",
+        "n = 0;",
+        "while (count > 0) {",
+        "    n++;",
+        "}",
+        "int j = 0;",
+        "while (j < n) {",
+        "    j++;",
+        "}",
+        "return j;",
+        "",
+    ].join("\n"));
+}
@@ -1,12 +1,12 @@
 use crate::class_file::error::{Result, error};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct MethodType {
     pub parameter_types: Vec<FieldType>,
     pub return_type: ReturnType,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum FieldType {
     Byte,
     Char,
@@ -20,7 +20,25 @@ pub enum FieldType {
     Array { value: Box<FieldType> },
 }
 
-#[derive(Debug, PartialEq)]
+impl FieldType {
+    /// The field descriptor (4.3.2) of this type, e.g. `[Ljava/lang/String;`.
+    pub fn descriptor(&self) -> String {
+        match self {
+            FieldType::Byte => "B".to_string(),
+            FieldType::Char => "C".to_string(),
+            FieldType::Double => "D".to_string(),
+            FieldType::Float => "F".to_string(),
+            FieldType::Int => "I".to_string(),
+            FieldType::Long => "J".to_string(),
+            FieldType::Class { name } => format!("L{};", name),
+            FieldType::Short => "S".to_string(),
+            FieldType::Boolean => "Z".to_string(),
+            FieldType::Array { value } => format!("[{}", value.descriptor()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum ReturnType {
     Field { value: FieldType },
     Void,
@@ -44,7 +62,7 @@ peg::parser! {
               "I" { FieldType::Int } /
               "J" { FieldType::Long } /
               "L" str:class_name() ";" { FieldType::Class { name: str } } /
-              "S" { FieldType::Char } /
+              "S" { FieldType::Short } /
               "Z" { FieldType::Boolean } /
               "[" ft:field_type() { FieldType::Array { value: Box::new(ft)} }
 
@@ -79,6 +97,10 @@ fn test_parse_field_type() {
         parse_field_type("[[[D"),
         Ok(FieldType::Array { value: Box::new(FieldType::Array { value: Box::new(FieldType::Array { value: Box::new(FieldType::Double) }) }) })
     );
+    assert_eq!(
+        parse_field_type("[S"),
+        Ok(FieldType::Array { value: Box::new(FieldType::Short) })
+    );
     assert_eq!(
         parse_field_type("BBB"),
         error("invalid parameter descriptor: BBB".to_string())
@@ -108,4 +130,11 @@ fn test_parse_method_descriptor() {
             return_type: ReturnType::Void,
         })
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_field_type_descriptor() {
+    for descriptor in ["Z", "S", "Ljava/lang/String;", "[[D", "[Ljava/lang/Object;"] {
+        assert_eq!(parse_field_type(descriptor).unwrap().descriptor(), descriptor);
+    }
+}
@@ -0,0 +1,1196 @@
+use std::fmt;
+
+use crate::class_file::error::{Error, Result};
+
+// 6.5. Instructions
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5
+// Each variant is one opcode. Operands keep the form they have in the code array:
+// constant pool indexes stay raw, and branch offsets stay relative to the pc of the instruction.
+// Local variable indexes are widened to u16 so that the instruction wrapped by `Wide` can share the variant.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Instruction {
+    Nop,
+    AconstNull,
+    IconstM1,
+    Iconst0,
+    Iconst1,
+    Iconst2,
+    Iconst3,
+    Iconst4,
+    Iconst5,
+    Lconst0,
+    Lconst1,
+    Fconst0,
+    Fconst1,
+    Fconst2,
+    Dconst0,
+    Dconst1,
+    Bipush(i8),
+    Sipush(i16),
+    Ldc(u8),
+    LdcW(u16),
+    Ldc2W(u16),
+    Iload(u16),
+    Lload(u16),
+    Fload(u16),
+    Dload(u16),
+    Aload(u16),
+    Iload0,
+    Iload1,
+    Iload2,
+    Iload3,
+    Lload0,
+    Lload1,
+    Lload2,
+    Lload3,
+    Fload0,
+    Fload1,
+    Fload2,
+    Fload3,
+    Dload0,
+    Dload1,
+    Dload2,
+    Dload3,
+    Aload0,
+    Aload1,
+    Aload2,
+    Aload3,
+    Iaload,
+    Laload,
+    Faload,
+    Daload,
+    Aaload,
+    Baload,
+    Caload,
+    Saload,
+    Istore(u16),
+    Lstore(u16),
+    Fstore(u16),
+    Dstore(u16),
+    Astore(u16),
+    Istore0,
+    Istore1,
+    Istore2,
+    Istore3,
+    Lstore0,
+    Lstore1,
+    Lstore2,
+    Lstore3,
+    Fstore0,
+    Fstore1,
+    Fstore2,
+    Fstore3,
+    Dstore0,
+    Dstore1,
+    Dstore2,
+    Dstore3,
+    Astore0,
+    Astore1,
+    Astore2,
+    Astore3,
+    Iastore,
+    Lastore,
+    Fastore,
+    Dastore,
+    Aastore,
+    Bastore,
+    Castore,
+    Sastore,
+    Pop,
+    Pop2,
+    Dup,
+    DupX1,
+    DupX2,
+    Dup2,
+    Dup2X1,
+    Dup2X2,
+    Swap,
+    Iadd,
+    Ladd,
+    Fadd,
+    Dadd,
+    Isub,
+    Lsub,
+    Fsub,
+    Dsub,
+    Imul,
+    Lmul,
+    Fmul,
+    Dmul,
+    Idiv,
+    Ldiv,
+    Fdiv,
+    Ddiv,
+    Irem,
+    Lrem,
+    Frem,
+    Drem,
+    Ineg,
+    Lneg,
+    Fneg,
+    Dneg,
+    Ishl,
+    Lshl,
+    Ishr,
+    Lshr,
+    Iushr,
+    Lushr,
+    Iand,
+    Land,
+    Ior,
+    Lor,
+    Ixor,
+    Lxor,
+    Iinc(u16, i16),
+    I2l,
+    I2f,
+    I2d,
+    L2i,
+    L2f,
+    L2d,
+    F2i,
+    F2l,
+    F2d,
+    D2i,
+    D2l,
+    D2f,
+    I2b,
+    I2c,
+    I2s,
+    Lcmp,
+    Fcmpl,
+    Fcmpg,
+    Dcmpl,
+    Dcmpg,
+    Ifeq(i16),
+    Ifne(i16),
+    Iflt(i16),
+    Ifge(i16),
+    Ifgt(i16),
+    Ifle(i16),
+    IfIcmpeq(i16),
+    IfIcmpne(i16),
+    IfIcmplt(i16),
+    IfIcmpge(i16),
+    IfIcmpgt(i16),
+    IfIcmple(i16),
+    IfAcmpeq(i16),
+    IfAcmpne(i16),
+    Goto(i16),
+    Jsr(i16),
+    Ret(u16),
+    Tableswitch { default: i32, low: i32, high: i32, offsets: Vec<i32> },
+    Lookupswitch { default: i32, pairs: Vec<(i32, i32)> },
+    Ireturn,
+    Lreturn,
+    Freturn,
+    Dreturn,
+    Areturn,
+    Return,
+    Getstatic(u16),
+    Putstatic(u16),
+    Getfield(u16),
+    Putfield(u16),
+    Invokevirtual(u16),
+    Invokespecial(u16),
+    Invokestatic(u16),
+    Invokeinterface { index: u16, count: u8 },
+    Invokedynamic(u16),
+    New(u16),
+    Newarray(u8),
+    Anewarray(u16),
+    Arraylength,
+    Athrow,
+    Checkcast(u16),
+    Instanceof(u16),
+    Monitorenter,
+    Monitorexit,
+    Wide(Box<Instruction>),
+    Multianewarray { index: u16, dimensions: u8 },
+    Ifnull(i16),
+    Ifnonnull(i16),
+    GotoW(i32),
+    JsrW(i32),
+}
+
+impl Instruction {
+    pub fn opcode(&self) -> u8 {
+        match self {
+            Instruction::Nop => 0x00,
+            Instruction::AconstNull => 0x01,
+            Instruction::IconstM1 => 0x02,
+            Instruction::Iconst0 => 0x03,
+            Instruction::Iconst1 => 0x04,
+            Instruction::Iconst2 => 0x05,
+            Instruction::Iconst3 => 0x06,
+            Instruction::Iconst4 => 0x07,
+            Instruction::Iconst5 => 0x08,
+            Instruction::Lconst0 => 0x09,
+            Instruction::Lconst1 => 0x0a,
+            Instruction::Fconst0 => 0x0b,
+            Instruction::Fconst1 => 0x0c,
+            Instruction::Fconst2 => 0x0d,
+            Instruction::Dconst0 => 0x0e,
+            Instruction::Dconst1 => 0x0f,
+            Instruction::Bipush(..) => 0x10,
+            Instruction::Sipush(..) => 0x11,
+            Instruction::Ldc(..) => 0x12,
+            Instruction::LdcW(..) => 0x13,
+            Instruction::Ldc2W(..) => 0x14,
+            Instruction::Iload(..) => 0x15,
+            Instruction::Lload(..) => 0x16,
+            Instruction::Fload(..) => 0x17,
+            Instruction::Dload(..) => 0x18,
+            Instruction::Aload(..) => 0x19,
+            Instruction::Iload0 => 0x1a,
+            Instruction::Iload1 => 0x1b,
+            Instruction::Iload2 => 0x1c,
+            Instruction::Iload3 => 0x1d,
+            Instruction::Lload0 => 0x1e,
+            Instruction::Lload1 => 0x1f,
+            Instruction::Lload2 => 0x20,
+            Instruction::Lload3 => 0x21,
+            Instruction::Fload0 => 0x22,
+            Instruction::Fload1 => 0x23,
+            Instruction::Fload2 => 0x24,
+            Instruction::Fload3 => 0x25,
+            Instruction::Dload0 => 0x26,
+            Instruction::Dload1 => 0x27,
+            Instruction::Dload2 => 0x28,
+            Instruction::Dload3 => 0x29,
+            Instruction::Aload0 => 0x2a,
+            Instruction::Aload1 => 0x2b,
+            Instruction::Aload2 => 0x2c,
+            Instruction::Aload3 => 0x2d,
+            Instruction::Iaload => 0x2e,
+            Instruction::Laload => 0x2f,
+            Instruction::Faload => 0x30,
+            Instruction::Daload => 0x31,
+            Instruction::Aaload => 0x32,
+            Instruction::Baload => 0x33,
+            Instruction::Caload => 0x34,
+            Instruction::Saload => 0x35,
+            Instruction::Istore(..) => 0x36,
+            Instruction::Lstore(..) => 0x37,
+            Instruction::Fstore(..) => 0x38,
+            Instruction::Dstore(..) => 0x39,
+            Instruction::Astore(..) => 0x3a,
+            Instruction::Istore0 => 0x3b,
+            Instruction::Istore1 => 0x3c,
+            Instruction::Istore2 => 0x3d,
+            Instruction::Istore3 => 0x3e,
+            Instruction::Lstore0 => 0x3f,
+            Instruction::Lstore1 => 0x40,
+            Instruction::Lstore2 => 0x41,
+            Instruction::Lstore3 => 0x42,
+            Instruction::Fstore0 => 0x43,
+            Instruction::Fstore1 => 0x44,
+            Instruction::Fstore2 => 0x45,
+            Instruction::Fstore3 => 0x46,
+            Instruction::Dstore0 => 0x47,
+            Instruction::Dstore1 => 0x48,
+            Instruction::Dstore2 => 0x49,
+            Instruction::Dstore3 => 0x4a,
+            Instruction::Astore0 => 0x4b,
+            Instruction::Astore1 => 0x4c,
+            Instruction::Astore2 => 0x4d,
+            Instruction::Astore3 => 0x4e,
+            Instruction::Iastore => 0x4f,
+            Instruction::Lastore => 0x50,
+            Instruction::Fastore => 0x51,
+            Instruction::Dastore => 0x52,
+            Instruction::Aastore => 0x53,
+            Instruction::Bastore => 0x54,
+            Instruction::Castore => 0x55,
+            Instruction::Sastore => 0x56,
+            Instruction::Pop => 0x57,
+            Instruction::Pop2 => 0x58,
+            Instruction::Dup => 0x59,
+            Instruction::DupX1 => 0x5a,
+            Instruction::DupX2 => 0x5b,
+            Instruction::Dup2 => 0x5c,
+            Instruction::Dup2X1 => 0x5d,
+            Instruction::Dup2X2 => 0x5e,
+            Instruction::Swap => 0x5f,
+            Instruction::Iadd => 0x60,
+            Instruction::Ladd => 0x61,
+            Instruction::Fadd => 0x62,
+            Instruction::Dadd => 0x63,
+            Instruction::Isub => 0x64,
+            Instruction::Lsub => 0x65,
+            Instruction::Fsub => 0x66,
+            Instruction::Dsub => 0x67,
+            Instruction::Imul => 0x68,
+            Instruction::Lmul => 0x69,
+            Instruction::Fmul => 0x6a,
+            Instruction::Dmul => 0x6b,
+            Instruction::Idiv => 0x6c,
+            Instruction::Ldiv => 0x6d,
+            Instruction::Fdiv => 0x6e,
+            Instruction::Ddiv => 0x6f,
+            Instruction::Irem => 0x70,
+            Instruction::Lrem => 0x71,
+            Instruction::Frem => 0x72,
+            Instruction::Drem => 0x73,
+            Instruction::Ineg => 0x74,
+            Instruction::Lneg => 0x75,
+            Instruction::Fneg => 0x76,
+            Instruction::Dneg => 0x77,
+            Instruction::Ishl => 0x78,
+            Instruction::Lshl => 0x79,
+            Instruction::Ishr => 0x7a,
+            Instruction::Lshr => 0x7b,
+            Instruction::Iushr => 0x7c,
+            Instruction::Lushr => 0x7d,
+            Instruction::Iand => 0x7e,
+            Instruction::Land => 0x7f,
+            Instruction::Ior => 0x80,
+            Instruction::Lor => 0x81,
+            Instruction::Ixor => 0x82,
+            Instruction::Lxor => 0x83,
+            Instruction::Iinc(..) => 0x84,
+            Instruction::I2l => 0x85,
+            Instruction::I2f => 0x86,
+            Instruction::I2d => 0x87,
+            Instruction::L2i => 0x88,
+            Instruction::L2f => 0x89,
+            Instruction::L2d => 0x8a,
+            Instruction::F2i => 0x8b,
+            Instruction::F2l => 0x8c,
+            Instruction::F2d => 0x8d,
+            Instruction::D2i => 0x8e,
+            Instruction::D2l => 0x8f,
+            Instruction::D2f => 0x90,
+            Instruction::I2b => 0x91,
+            Instruction::I2c => 0x92,
+            Instruction::I2s => 0x93,
+            Instruction::Lcmp => 0x94,
+            Instruction::Fcmpl => 0x95,
+            Instruction::Fcmpg => 0x96,
+            Instruction::Dcmpl => 0x97,
+            Instruction::Dcmpg => 0x98,
+            Instruction::Ifeq(..) => 0x99,
+            Instruction::Ifne(..) => 0x9a,
+            Instruction::Iflt(..) => 0x9b,
+            Instruction::Ifge(..) => 0x9c,
+            Instruction::Ifgt(..) => 0x9d,
+            Instruction::Ifle(..) => 0x9e,
+            Instruction::IfIcmpeq(..) => 0x9f,
+            Instruction::IfIcmpne(..) => 0xa0,
+            Instruction::IfIcmplt(..) => 0xa1,
+            Instruction::IfIcmpge(..) => 0xa2,
+            Instruction::IfIcmpgt(..) => 0xa3,
+            Instruction::IfIcmple(..) => 0xa4,
+            Instruction::IfAcmpeq(..) => 0xa5,
+            Instruction::IfAcmpne(..) => 0xa6,
+            Instruction::Goto(..) => 0xa7,
+            Instruction::Jsr(..) => 0xa8,
+            Instruction::Ret(..) => 0xa9,
+            Instruction::Tableswitch { .. } => 0xaa,
+            Instruction::Lookupswitch { .. } => 0xab,
+            Instruction::Ireturn => 0xac,
+            Instruction::Lreturn => 0xad,
+            Instruction::Freturn => 0xae,
+            Instruction::Dreturn => 0xaf,
+            Instruction::Areturn => 0xb0,
+            Instruction::Return => 0xb1,
+            Instruction::Getstatic(..) => 0xb2,
+            Instruction::Putstatic(..) => 0xb3,
+            Instruction::Getfield(..) => 0xb4,
+            Instruction::Putfield(..) => 0xb5,
+            Instruction::Invokevirtual(..) => 0xb6,
+            Instruction::Invokespecial(..) => 0xb7,
+            Instruction::Invokestatic(..) => 0xb8,
+            Instruction::Invokeinterface { .. } => 0xb9,
+            Instruction::Invokedynamic(..) => 0xba,
+            Instruction::New(..) => 0xbb,
+            Instruction::Newarray(..) => 0xbc,
+            Instruction::Anewarray(..) => 0xbd,
+            Instruction::Arraylength => 0xbe,
+            Instruction::Athrow => 0xbf,
+            Instruction::Checkcast(..) => 0xc0,
+            Instruction::Instanceof(..) => 0xc1,
+            Instruction::Monitorenter => 0xc2,
+            Instruction::Monitorexit => 0xc3,
+            Instruction::Wide(..) => 0xc4,
+            Instruction::Multianewarray { .. } => 0xc5,
+            Instruction::Ifnull(..) => 0xc6,
+            Instruction::Ifnonnull(..) => 0xc7,
+            Instruction::GotoW(..) => 0xc8,
+            Instruction::JsrW(..) => 0xc9,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Nop => "nop",
+            Instruction::AconstNull => "aconst_null",
+            Instruction::IconstM1 => "iconst_m1",
+            Instruction::Iconst0 => "iconst_0",
+            Instruction::Iconst1 => "iconst_1",
+            Instruction::Iconst2 => "iconst_2",
+            Instruction::Iconst3 => "iconst_3",
+            Instruction::Iconst4 => "iconst_4",
+            Instruction::Iconst5 => "iconst_5",
+            Instruction::Lconst0 => "lconst_0",
+            Instruction::Lconst1 => "lconst_1",
+            Instruction::Fconst0 => "fconst_0",
+            Instruction::Fconst1 => "fconst_1",
+            Instruction::Fconst2 => "fconst_2",
+            Instruction::Dconst0 => "dconst_0",
+            Instruction::Dconst1 => "dconst_1",
+            Instruction::Bipush(..) => "bipush",
+            Instruction::Sipush(..) => "sipush",
+            Instruction::Ldc(..) => "ldc",
+            Instruction::LdcW(..) => "ldc_w",
+            Instruction::Ldc2W(..) => "ldc2_w",
+            Instruction::Iload(..) => "iload",
+            Instruction::Lload(..) => "lload",
+            Instruction::Fload(..) => "fload",
+            Instruction::Dload(..) => "dload",
+            Instruction::Aload(..) => "aload",
+            Instruction::Iload0 => "iload_0",
+            Instruction::Iload1 => "iload_1",
+            Instruction::Iload2 => "iload_2",
+            Instruction::Iload3 => "iload_3",
+            Instruction::Lload0 => "lload_0",
+            Instruction::Lload1 => "lload_1",
+            Instruction::Lload2 => "lload_2",
+            Instruction::Lload3 => "lload_3",
+            Instruction::Fload0 => "fload_0",
+            Instruction::Fload1 => "fload_1",
+            Instruction::Fload2 => "fload_2",
+            Instruction::Fload3 => "fload_3",
+            Instruction::Dload0 => "dload_0",
+            Instruction::Dload1 => "dload_1",
+            Instruction::Dload2 => "dload_2",
+            Instruction::Dload3 => "dload_3",
+            Instruction::Aload0 => "aload_0",
+            Instruction::Aload1 => "aload_1",
+            Instruction::Aload2 => "aload_2",
+            Instruction::Aload3 => "aload_3",
+            Instruction::Iaload => "iaload",
+            Instruction::Laload => "laload",
+            Instruction::Faload => "faload",
+            Instruction::Daload => "daload",
+            Instruction::Aaload => "aaload",
+            Instruction::Baload => "baload",
+            Instruction::Caload => "caload",
+            Instruction::Saload => "saload",
+            Instruction::Istore(..) => "istore",
+            Instruction::Lstore(..) => "lstore",
+            Instruction::Fstore(..) => "fstore",
+            Instruction::Dstore(..) => "dstore",
+            Instruction::Astore(..) => "astore",
+            Instruction::Istore0 => "istore_0",
+            Instruction::Istore1 => "istore_1",
+            Instruction::Istore2 => "istore_2",
+            Instruction::Istore3 => "istore_3",
+            Instruction::Lstore0 => "lstore_0",
+            Instruction::Lstore1 => "lstore_1",
+            Instruction::Lstore2 => "lstore_2",
+            Instruction::Lstore3 => "lstore_3",
+            Instruction::Fstore0 => "fstore_0",
+            Instruction::Fstore1 => "fstore_1",
+            Instruction::Fstore2 => "fstore_2",
+            Instruction::Fstore3 => "fstore_3",
+            Instruction::Dstore0 => "dstore_0",
+            Instruction::Dstore1 => "dstore_1",
+            Instruction::Dstore2 => "dstore_2",
+            Instruction::Dstore3 => "dstore_3",
+            Instruction::Astore0 => "astore_0",
+            Instruction::Astore1 => "astore_1",
+            Instruction::Astore2 => "astore_2",
+            Instruction::Astore3 => "astore_3",
+            Instruction::Iastore => "iastore",
+            Instruction::Lastore => "lastore",
+            Instruction::Fastore => "fastore",
+            Instruction::Dastore => "dastore",
+            Instruction::Aastore => "aastore",
+            Instruction::Bastore => "bastore",
+            Instruction::Castore => "castore",
+            Instruction::Sastore => "sastore",
+            Instruction::Pop => "pop",
+            Instruction::Pop2 => "pop2",
+            Instruction::Dup => "dup",
+            Instruction::DupX1 => "dup_x1",
+            Instruction::DupX2 => "dup_x2",
+            Instruction::Dup2 => "dup2",
+            Instruction::Dup2X1 => "dup2_x1",
+            Instruction::Dup2X2 => "dup2_x2",
+            Instruction::Swap => "swap",
+            Instruction::Iadd => "iadd",
+            Instruction::Ladd => "ladd",
+            Instruction::Fadd => "fadd",
+            Instruction::Dadd => "dadd",
+            Instruction::Isub => "isub",
+            Instruction::Lsub => "lsub",
+            Instruction::Fsub => "fsub",
+            Instruction::Dsub => "dsub",
+            Instruction::Imul => "imul",
+            Instruction::Lmul => "lmul",
+            Instruction::Fmul => "fmul",
+            Instruction::Dmul => "dmul",
+            Instruction::Idiv => "idiv",
+            Instruction::Ldiv => "ldiv",
+            Instruction::Fdiv => "fdiv",
+            Instruction::Ddiv => "ddiv",
+            Instruction::Irem => "irem",
+            Instruction::Lrem => "lrem",
+            Instruction::Frem => "frem",
+            Instruction::Drem => "drem",
+            Instruction::Ineg => "ineg",
+            Instruction::Lneg => "lneg",
+            Instruction::Fneg => "fneg",
+            Instruction::Dneg => "dneg",
+            Instruction::Ishl => "ishl",
+            Instruction::Lshl => "lshl",
+            Instruction::Ishr => "ishr",
+            Instruction::Lshr => "lshr",
+            Instruction::Iushr => "iushr",
+            Instruction::Lushr => "lushr",
+            Instruction::Iand => "iand",
+            Instruction::Land => "land",
+            Instruction::Ior => "ior",
+            Instruction::Lor => "lor",
+            Instruction::Ixor => "ixor",
+            Instruction::Lxor => "lxor",
+            Instruction::Iinc(..) => "iinc",
+            Instruction::I2l => "i2l",
+            Instruction::I2f => "i2f",
+            Instruction::I2d => "i2d",
+            Instruction::L2i => "l2i",
+            Instruction::L2f => "l2f",
+            Instruction::L2d => "l2d",
+            Instruction::F2i => "f2i",
+            Instruction::F2l => "f2l",
+            Instruction::F2d => "f2d",
+            Instruction::D2i => "d2i",
+            Instruction::D2l => "d2l",
+            Instruction::D2f => "d2f",
+            Instruction::I2b => "i2b",
+            Instruction::I2c => "i2c",
+            Instruction::I2s => "i2s",
+            Instruction::Lcmp => "lcmp",
+            Instruction::Fcmpl => "fcmpl",
+            Instruction::Fcmpg => "fcmpg",
+            Instruction::Dcmpl => "dcmpl",
+            Instruction::Dcmpg => "dcmpg",
+            Instruction::Ifeq(..) => "ifeq",
+            Instruction::Ifne(..) => "ifne",
+            Instruction::Iflt(..) => "iflt",
+            Instruction::Ifge(..) => "ifge",
+            Instruction::Ifgt(..) => "ifgt",
+            Instruction::Ifle(..) => "ifle",
+            Instruction::IfIcmpeq(..) => "if_icmpeq",
+            Instruction::IfIcmpne(..) => "if_icmpne",
+            Instruction::IfIcmplt(..) => "if_icmplt",
+            Instruction::IfIcmpge(..) => "if_icmpge",
+            Instruction::IfIcmpgt(..) => "if_icmpgt",
+            Instruction::IfIcmple(..) => "if_icmple",
+            Instruction::IfAcmpeq(..) => "if_acmpeq",
+            Instruction::IfAcmpne(..) => "if_acmpne",
+            Instruction::Goto(..) => "goto",
+            Instruction::Jsr(..) => "jsr",
+            Instruction::Ret(..) => "ret",
+            Instruction::Tableswitch { .. } => "tableswitch",
+            Instruction::Lookupswitch { .. } => "lookupswitch",
+            Instruction::Ireturn => "ireturn",
+            Instruction::Lreturn => "lreturn",
+            Instruction::Freturn => "freturn",
+            Instruction::Dreturn => "dreturn",
+            Instruction::Areturn => "areturn",
+            Instruction::Return => "return",
+            Instruction::Getstatic(..) => "getstatic",
+            Instruction::Putstatic(..) => "putstatic",
+            Instruction::Getfield(..) => "getfield",
+            Instruction::Putfield(..) => "putfield",
+            Instruction::Invokevirtual(..) => "invokevirtual",
+            Instruction::Invokespecial(..) => "invokespecial",
+            Instruction::Invokestatic(..) => "invokestatic",
+            Instruction::Invokeinterface { .. } => "invokeinterface",
+            Instruction::Invokedynamic(..) => "invokedynamic",
+            Instruction::New(..) => "new",
+            Instruction::Newarray(..) => "newarray",
+            Instruction::Anewarray(..) => "anewarray",
+            Instruction::Arraylength => "arraylength",
+            Instruction::Athrow => "athrow",
+            Instruction::Checkcast(..) => "checkcast",
+            Instruction::Instanceof(..) => "instanceof",
+            Instruction::Monitorenter => "monitorenter",
+            Instruction::Monitorexit => "monitorexit",
+            Instruction::Wide(..) => "wide",
+            Instruction::Multianewarray { .. } => "multianewarray",
+            Instruction::Ifnull(..) => "ifnull",
+            Instruction::Ifnonnull(..) => "ifnonnull",
+            Instruction::GotoW(..) => "goto_w",
+            Instruction::JsrW(..) => "jsr_w",
+        }
+    }
+    /// Appends this instruction to the code array, the reverse of `decode`.
+    /// The length of the code so far is the pc, on which the padding of tableswitch and lookupswitch depends.
+    pub fn encode(&self, code: &mut Vec<u8>) {
+        use Instruction::*;
+        code.push(self.opcode());
+        match self {
+            Bipush(value) => code.push(*value as u8),
+            Sipush(value) => code.extend(value.to_be_bytes()),
+            Ldc(index) => code.push(*index),
+            LdcW(index) | Ldc2W(index) | Getstatic(index) | Putstatic(index) | Getfield(index) | Putfield(index) |
+            Invokevirtual(index) | Invokespecial(index) | Invokestatic(index) |
+            New(index) | Anewarray(index) | Checkcast(index) | Instanceof(index) => code.extend(index.to_be_bytes()),
+            Iload(index) | Lload(index) | Fload(index) | Dload(index) | Aload(index) |
+            Istore(index) | Lstore(index) | Fstore(index) | Dstore(index) | Astore(index) | Ret(index) => code.push(*index as u8),
+            Iinc(index, value) => code.extend([*index as u8, *value as i8 as u8]),
+            Ifeq(offset) | Ifne(offset) | Iflt(offset) | Ifge(offset) | Ifgt(offset) | Ifle(offset) |
+            IfIcmpeq(offset) | IfIcmpne(offset) | IfIcmplt(offset) | IfIcmpge(offset) | IfIcmpgt(offset) | IfIcmple(offset) |
+            IfAcmpeq(offset) | IfAcmpne(offset) | Goto(offset) | Jsr(offset) | Ifnull(offset) | Ifnonnull(offset) => code.extend(offset.to_be_bytes()),
+            GotoW(offset) | JsrW(offset) => code.extend(offset.to_be_bytes()),
+            Tableswitch { default, low, high, offsets } => {
+                while !code.len().is_multiple_of(4) {
+                    code.push(0);
+                }
+                for value in [*default, *low, *high].iter().chain(offsets) {
+                    code.extend(value.to_be_bytes());
+                }
+            }
+            Lookupswitch { default, pairs } => {
+                while !code.len().is_multiple_of(4) {
+                    code.push(0);
+                }
+                code.extend(default.to_be_bytes());
+                code.extend((pairs.len() as i32).to_be_bytes());
+                for (key, offset) in pairs {
+                    code.extend(key.to_be_bytes());
+                    code.extend(offset.to_be_bytes());
+                }
+            }
+            Invokeinterface { index, count } => {
+                code.extend(index.to_be_bytes());
+                code.extend([*count, 0]);
+            }
+            Invokedynamic(index) => {
+                code.extend(index.to_be_bytes());
+                code.extend([0, 0]);
+            }
+            Newarray(atype) => code.push(*atype),
+            Multianewarray { index, dimensions } => {
+                code.extend(index.to_be_bytes());
+                code.push(*dimensions);
+            }
+            Wide(instruction) => {
+                code.push(instruction.opcode());
+                match instruction.as_ref() {
+                    Iinc(index, value) => {
+                        code.extend(index.to_be_bytes());
+                        code.extend(value.to_be_bytes());
+                    }
+                    Iload(index) | Lload(index) | Fload(index) | Dload(index) | Aload(index) |
+                    Istore(index) | Lstore(index) | Fstore(index) | Dstore(index) | Astore(index) | Ret(index) => code.extend(index.to_be_bytes()),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the instruction wrapped by `wide`, or the instruction itself.
+    pub fn unwide(&self) -> &Instruction {
+        match self {
+            Instruction::Wide(instruction) => instruction,
+            _ => self,
+        }
+    }
+
+    /// Branch offsets relative to the pc of this instruction, including the default of switches.
+    pub fn branch_offsets(&self) -> Vec<i32> {
+        match self {
+            Instruction::Ifeq(offset) | Instruction::Ifne(offset) | Instruction::Iflt(offset) |
+            Instruction::Ifge(offset) | Instruction::Ifgt(offset) | Instruction::Ifle(offset) |
+            Instruction::IfIcmpeq(offset) | Instruction::IfIcmpne(offset) | Instruction::IfIcmplt(offset) |
+            Instruction::IfIcmpge(offset) | Instruction::IfIcmpgt(offset) | Instruction::IfIcmple(offset) |
+            Instruction::IfAcmpeq(offset) | Instruction::IfAcmpne(offset) | Instruction::Goto(offset) |
+            Instruction::Jsr(offset) | Instruction::Ifnull(offset) | Instruction::Ifnonnull(offset) => vec![*offset as i32],
+            Instruction::GotoW(offset) | Instruction::JsrW(offset) => vec![*offset],
+            Instruction::Tableswitch { default, offsets, .. } => {
+                let mut all = vec![*default];
+                all.extend(offsets);
+                all
+            }
+            Instruction::Lookupswitch { default, pairs } => {
+                let mut all = vec![*default];
+                all.extend(pairs.iter().map(|(_, offset)| *offset));
+                all
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Absolute branch targets. Only meaningful for instructions returned by `decode`, which checks them.
+    pub fn branch_targets(&self, pc: u16) -> Vec<u16> {
+        self.branch_offsets().into_iter().map(|offset| (pc as i32 + offset) as u16).collect()
+    }
+
+    /// Whether the execution can continue with the next instruction.
+    /// `jsr` is treated as a jump; the return to the next instruction happens via `ret`.
+    pub fn falls_through(&self) -> bool {
+        !matches!(
+            self.unwide(),
+            Instruction::Goto(_) | Instruction::GotoW(_) | Instruction::Jsr(_) | Instruction::JsrW(_) |
+            Instruction::Ret(_) | Instruction::Tableswitch { .. } | Instruction::Lookupswitch { .. } |
+            Instruction::Ireturn | Instruction::Lreturn | Instruction::Freturn | Instruction::Dreturn |
+            Instruction::Areturn | Instruction::Return | Instruction::Athrow
+        )
+    }
+}
+
+// Operands as they are in the code array, e.g. `iinc 4, 1`, `invokevirtual #13` and `goto -20`.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Instruction::*;
+        write!(f, "{}", self.mnemonic())?;
+        match self {
+            Bipush(value) => write!(f, " {}", value),
+            Sipush(value) => write!(f, " {}", value),
+            Ldc(index) => write!(f, " #{}", index),
+            LdcW(index) | Ldc2W(index) | Getstatic(index) | Putstatic(index) | Getfield(index) | Putfield(index) |
+            Invokevirtual(index) | Invokespecial(index) | Invokestatic(index) | Invokedynamic(index) |
+            New(index) | Anewarray(index) | Checkcast(index) | Instanceof(index) => write!(f, " #{}", index),
+            Iload(index) | Lload(index) | Fload(index) | Dload(index) | Aload(index) |
+            Istore(index) | Lstore(index) | Fstore(index) | Dstore(index) | Astore(index) | Ret(index) => write!(f, " {}", index),
+            Iinc(index, value) => write!(f, " {}, {}", index, value),
+            Invokeinterface { index, count } => write!(f, " #{}, {}", index, count),
+            Newarray(atype) => write!(f, " {}", atype),
+            Multianewarray { index, dimensions } => write!(f, " #{}, {}", index, dimensions),
+            Tableswitch { default, low, high, offsets } => {
+                write!(f, " {{ ")?;
+                for (key, offset) in (*low..=*high).zip(offsets) {
+                    write!(f, "{}: {:+}, ", key, offset)?;
+                }
+                write!(f, "default: {:+} }}", default)
+            }
+            Lookupswitch { default, pairs } => {
+                write!(f, " {{ ")?;
+                for (key, offset) in pairs {
+                    write!(f, "{}: {:+}, ", key, offset)?;
+                }
+                write!(f, "default: {:+} }}", default)
+            }
+            Wide(instruction) => write!(f, " {}", instruction),
+            _ => match self.branch_offsets().first() {
+                Some(offset) => write!(f, " {:+}", offset),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+fn error<T>(message: String, pc: usize) -> Result<T> {
+    Err(Error { message: format!("Invalid code. {}, pc: {}", message, pc) })
+}
+
+struct CodeReader<'a> {
+    code: &'a [u8],
+    offset: usize,
+    pc: usize,
+}
+
+impl CodeReader<'_> {
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        match self.code.get(self.offset..self.offset + N) {
+            Some(bytes) => {
+                self.offset += N;
+                Ok(bytes.try_into().unwrap())
+            }
+            None => error("The instruction is truncated.".to_string(), self.pc),
+        }
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(u8::from_be_bytes(self.bytes()?))
+    }
+
+    fn i8(&mut self) -> Result<i8> {
+        Ok(i8::from_be_bytes(self.bytes()?))
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.bytes()?))
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.bytes()?))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.bytes()?))
+    }
+
+    // tableswitch and lookupswitch have 0 to 3 bytes of padding so that the operands start at a multiple of 4.
+    fn skip_padding(&mut self) -> Result<()> {
+        while !self.offset.is_multiple_of(4) {
+            self.u8()?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the code array of a Code attribute into instructions paired with their pc.
+/// Branch targets are checked to be the start of an instruction (4.9.1).
+pub fn decode(code: &[u8]) -> Result<Vec<(u16, Instruction)>> {
+    // 4.7.3. The value of the code_length item must be greater than zero and less than 65536.
+    if code.is_empty() || code.len() > u16::MAX as usize {
+        return error(format!("invalid code length: {}", code.len()), 0);
+    }
+    let mut reader = CodeReader { code, offset: 0, pc: 0 };
+    let mut instructions: Vec<(u16, Instruction)> = Vec::new();
+    while reader.offset < code.len() {
+        reader.pc = reader.offset;
+        let instruction = decode_instruction(&mut reader)?;
+        instructions.push((reader.pc as u16, instruction));
+    }
+    for (pc, instruction) in &instructions {
+        for offset in instruction.branch_offsets() {
+            let target = *pc as i64 + offset as i64;
+            let is_instruction_start = instructions.binary_search_by_key(&target, |(pc, _)| *pc as i64).is_ok();
+            if !is_instruction_start {
+                return error(format!("branch target {} is not the start of an instruction", target), *pc as usize);
+            }
+        }
+    }
+    Ok(instructions)
+}
+
+fn decode_instruction(reader: &mut CodeReader) -> Result<Instruction> {
+    let opcode = reader.u8()?;
+    let instruction = match opcode {
+        0x00 => Instruction::Nop,
+        0x01 => Instruction::AconstNull,
+        0x02 => Instruction::IconstM1,
+        0x03 => Instruction::Iconst0,
+        0x04 => Instruction::Iconst1,
+        0x05 => Instruction::Iconst2,
+        0x06 => Instruction::Iconst3,
+        0x07 => Instruction::Iconst4,
+        0x08 => Instruction::Iconst5,
+        0x09 => Instruction::Lconst0,
+        0x0a => Instruction::Lconst1,
+        0x0b => Instruction::Fconst0,
+        0x0c => Instruction::Fconst1,
+        0x0d => Instruction::Fconst2,
+        0x0e => Instruction::Dconst0,
+        0x0f => Instruction::Dconst1,
+        0x1a => Instruction::Iload0,
+        0x1b => Instruction::Iload1,
+        0x1c => Instruction::Iload2,
+        0x1d => Instruction::Iload3,
+        0x1e => Instruction::Lload0,
+        0x1f => Instruction::Lload1,
+        0x20 => Instruction::Lload2,
+        0x21 => Instruction::Lload3,
+        0x22 => Instruction::Fload0,
+        0x23 => Instruction::Fload1,
+        0x24 => Instruction::Fload2,
+        0x25 => Instruction::Fload3,
+        0x26 => Instruction::Dload0,
+        0x27 => Instruction::Dload1,
+        0x28 => Instruction::Dload2,
+        0x29 => Instruction::Dload3,
+        0x2a => Instruction::Aload0,
+        0x2b => Instruction::Aload1,
+        0x2c => Instruction::Aload2,
+        0x2d => Instruction::Aload3,
+        0x2e => Instruction::Iaload,
+        0x2f => Instruction::Laload,
+        0x30 => Instruction::Faload,
+        0x31 => Instruction::Daload,
+        0x32 => Instruction::Aaload,
+        0x33 => Instruction::Baload,
+        0x34 => Instruction::Caload,
+        0x35 => Instruction::Saload,
+        0x3b => Instruction::Istore0,
+        0x3c => Instruction::Istore1,
+        0x3d => Instruction::Istore2,
+        0x3e => Instruction::Istore3,
+        0x3f => Instruction::Lstore0,
+        0x40 => Instruction::Lstore1,
+        0x41 => Instruction::Lstore2,
+        0x42 => Instruction::Lstore3,
+        0x43 => Instruction::Fstore0,
+        0x44 => Instruction::Fstore1,
+        0x45 => Instruction::Fstore2,
+        0x46 => Instruction::Fstore3,
+        0x47 => Instruction::Dstore0,
+        0x48 => Instruction::Dstore1,
+        0x49 => Instruction::Dstore2,
+        0x4a => Instruction::Dstore3,
+        0x4b => Instruction::Astore0,
+        0x4c => Instruction::Astore1,
+        0x4d => Instruction::Astore2,
+        0x4e => Instruction::Astore3,
+        0x4f => Instruction::Iastore,
+        0x50 => Instruction::Lastore,
+        0x51 => Instruction::Fastore,
+        0x52 => Instruction::Dastore,
+        0x53 => Instruction::Aastore,
+        0x54 => Instruction::Bastore,
+        0x55 => Instruction::Castore,
+        0x56 => Instruction::Sastore,
+        0x57 => Instruction::Pop,
+        0x58 => Instruction::Pop2,
+        0x59 => Instruction::Dup,
+        0x5a => Instruction::DupX1,
+        0x5b => Instruction::DupX2,
+        0x5c => Instruction::Dup2,
+        0x5d => Instruction::Dup2X1,
+        0x5e => Instruction::Dup2X2,
+        0x5f => Instruction::Swap,
+        0x60 => Instruction::Iadd,
+        0x61 => Instruction::Ladd,
+        0x62 => Instruction::Fadd,
+        0x63 => Instruction::Dadd,
+        0x64 => Instruction::Isub,
+        0x65 => Instruction::Lsub,
+        0x66 => Instruction::Fsub,
+        0x67 => Instruction::Dsub,
+        0x68 => Instruction::Imul,
+        0x69 => Instruction::Lmul,
+        0x6a => Instruction::Fmul,
+        0x6b => Instruction::Dmul,
+        0x6c => Instruction::Idiv,
+        0x6d => Instruction::Ldiv,
+        0x6e => Instruction::Fdiv,
+        0x6f => Instruction::Ddiv,
+        0x70 => Instruction::Irem,
+        0x71 => Instruction::Lrem,
+        0x72 => Instruction::Frem,
+        0x73 => Instruction::Drem,
+        0x74 => Instruction::Ineg,
+        0x75 => Instruction::Lneg,
+        0x76 => Instruction::Fneg,
+        0x77 => Instruction::Dneg,
+        0x78 => Instruction::Ishl,
+        0x79 => Instruction::Lshl,
+        0x7a => Instruction::Ishr,
+        0x7b => Instruction::Lshr,
+        0x7c => Instruction::Iushr,
+        0x7d => Instruction::Lushr,
+        0x7e => Instruction::Iand,
+        0x7f => Instruction::Land,
+        0x80 => Instruction::Ior,
+        0x81 => Instruction::Lor,
+        0x82 => Instruction::Ixor,
+        0x83 => Instruction::Lxor,
+        0x85 => Instruction::I2l,
+        0x86 => Instruction::I2f,
+        0x87 => Instruction::I2d,
+        0x88 => Instruction::L2i,
+        0x89 => Instruction::L2f,
+        0x8a => Instruction::L2d,
+        0x8b => Instruction::F2i,
+        0x8c => Instruction::F2l,
+        0x8d => Instruction::F2d,
+        0x8e => Instruction::D2i,
+        0x8f => Instruction::D2l,
+        0x90 => Instruction::D2f,
+        0x91 => Instruction::I2b,
+        0x92 => Instruction::I2c,
+        0x93 => Instruction::I2s,
+        0x94 => Instruction::Lcmp,
+        0x95 => Instruction::Fcmpl,
+        0x96 => Instruction::Fcmpg,
+        0x97 => Instruction::Dcmpl,
+        0x98 => Instruction::Dcmpg,
+        0xac => Instruction::Ireturn,
+        0xad => Instruction::Lreturn,
+        0xae => Instruction::Freturn,
+        0xaf => Instruction::Dreturn,
+        0xb0 => Instruction::Areturn,
+        0xb1 => Instruction::Return,
+        0xbe => Instruction::Arraylength,
+        0xbf => Instruction::Athrow,
+        0xc2 => Instruction::Monitorenter,
+        0xc3 => Instruction::Monitorexit,
+        0x10 => Instruction::Bipush(reader.i8()?),
+        0x11 => Instruction::Sipush(reader.i16()?),
+        0x12 => Instruction::Ldc(reader.u8()?),
+        0x13 => Instruction::LdcW(reader.u16()?),
+        0x14 => Instruction::Ldc2W(reader.u16()?),
+        0xb2 => Instruction::Getstatic(reader.u16()?),
+        0xb3 => Instruction::Putstatic(reader.u16()?),
+        0xb4 => Instruction::Getfield(reader.u16()?),
+        0xb5 => Instruction::Putfield(reader.u16()?),
+        0xb6 => Instruction::Invokevirtual(reader.u16()?),
+        0xb7 => Instruction::Invokespecial(reader.u16()?),
+        0xb8 => Instruction::Invokestatic(reader.u16()?),
+        0xbb => Instruction::New(reader.u16()?),
+        0xbd => Instruction::Anewarray(reader.u16()?),
+        0xc0 => Instruction::Checkcast(reader.u16()?),
+        0xc1 => Instruction::Instanceof(reader.u16()?),
+        0x15 => Instruction::Iload(reader.u8()? as u16),
+        0x16 => Instruction::Lload(reader.u8()? as u16),
+        0x17 => Instruction::Fload(reader.u8()? as u16),
+        0x18 => Instruction::Dload(reader.u8()? as u16),
+        0x19 => Instruction::Aload(reader.u8()? as u16),
+        0x36 => Instruction::Istore(reader.u8()? as u16),
+        0x37 => Instruction::Lstore(reader.u8()? as u16),
+        0x38 => Instruction::Fstore(reader.u8()? as u16),
+        0x39 => Instruction::Dstore(reader.u8()? as u16),
+        0x3a => Instruction::Astore(reader.u8()? as u16),
+        0xa9 => Instruction::Ret(reader.u8()? as u16),
+        0x84 => Instruction::Iinc(reader.u8()? as u16, reader.i8()? as i16),
+        0x99 => Instruction::Ifeq(reader.i16()?),
+        0x9a => Instruction::Ifne(reader.i16()?),
+        0x9b => Instruction::Iflt(reader.i16()?),
+        0x9c => Instruction::Ifge(reader.i16()?),
+        0x9d => Instruction::Ifgt(reader.i16()?),
+        0x9e => Instruction::Ifle(reader.i16()?),
+        0x9f => Instruction::IfIcmpeq(reader.i16()?),
+        0xa0 => Instruction::IfIcmpne(reader.i16()?),
+        0xa1 => Instruction::IfIcmplt(reader.i16()?),
+        0xa2 => Instruction::IfIcmpge(reader.i16()?),
+        0xa3 => Instruction::IfIcmpgt(reader.i16()?),
+        0xa4 => Instruction::IfIcmple(reader.i16()?),
+        0xa5 => Instruction::IfAcmpeq(reader.i16()?),
+        0xa6 => Instruction::IfAcmpne(reader.i16()?),
+        0xa7 => Instruction::Goto(reader.i16()?),
+        0xa8 => Instruction::Jsr(reader.i16()?),
+        0xc6 => Instruction::Ifnull(reader.i16()?),
+        0xc7 => Instruction::Ifnonnull(reader.i16()?),
+        0xc8 => Instruction::GotoW(reader.i32()?),
+        0xc9 => Instruction::JsrW(reader.i32()?),
+        0xaa => {
+            reader.skip_padding()?;
+            let default = reader.i32()?;
+            let low = reader.i32()?;
+            let high = reader.i32()?;
+            if low > high {
+                return error(format!("tableswitch low {} is greater than high {}", low, high), reader.pc);
+            }
+            let mut offsets: Vec<i32> = Vec::new();
+            for _ in low..=high {
+                offsets.push(reader.i32()?);
+            }
+            Instruction::Tableswitch { default, low, high, offsets }
+        }
+        0xab => {
+            reader.skip_padding()?;
+            let default = reader.i32()?;
+            let npairs = reader.i32()?;
+            if npairs < 0 {
+                return error(format!("lookupswitch npairs must not be negative: {}", npairs), reader.pc);
+            }
+            let mut pairs: Vec<(i32, i32)> = Vec::new();
+            for _ in 0..npairs {
+                let key = reader.i32()?;
+                if pairs.last().is_some_and(|(last, _)| *last >= key) {
+                    return error("lookupswitch keys must be sorted in increasing order".to_string(), reader.pc);
+                }
+                pairs.push((key, reader.i32()?));
+            }
+            Instruction::Lookupswitch { default, pairs }
+        }
+        0xb9 => {
+            let index = reader.u16()?;
+            let count = reader.u8()?;
+            if count == 0 || reader.u8()? != 0 {
+                return error("invokeinterface must have a non-zero count and a zero fourth operand byte".to_string(), reader.pc);
+            }
+            Instruction::Invokeinterface { index, count }
+        }
+        0xba => {
+            let index = reader.u16()?;
+            if reader.u16()? != 0 {
+                return error("invokedynamic must have zero third and fourth operand bytes".to_string(), reader.pc);
+            }
+            Instruction::Invokedynamic(index)
+        }
+        0xbc => Instruction::Newarray(reader.u8()?),
+        0xc4 => decode_wide(reader)?,
+        0xc5 => {
+            let index = reader.u16()?;
+            let dimensions = reader.u8()?;
+            Instruction::Multianewarray { index, dimensions }
+        }
+        _ => return error(format!("unknown opcode: 0x{:02x}", opcode), reader.pc),
+    };
+    Ok(instruction)
+}
+
+fn decode_wide(reader: &mut CodeReader) -> Result<Instruction> {
+    let opcode = reader.u8()?;
+    let instruction = match opcode {
+        0x15 => Instruction::Iload(reader.u16()?),
+        0x16 => Instruction::Lload(reader.u16()?),
+        0x17 => Instruction::Fload(reader.u16()?),
+        0x18 => Instruction::Dload(reader.u16()?),
+        0x19 => Instruction::Aload(reader.u16()?),
+        0x36 => Instruction::Istore(reader.u16()?),
+        0x37 => Instruction::Lstore(reader.u16()?),
+        0x38 => Instruction::Fstore(reader.u16()?),
+        0x39 => Instruction::Dstore(reader.u16()?),
+        0x3a => Instruction::Astore(reader.u16()?),
+        0xa9 => Instruction::Ret(reader.u16()?),
+        0x84 => Instruction::Iinc(reader.u16()?, reader.i16()?),
+        _ => return error(format!("opcode 0x{:02x} cannot be modified by wide", opcode), reader.pc),
+    };
+    Ok(Instruction::Wide(Box::new(instruction)))
+}
+
+#[test]
+fn test_decode() {
+    let code: Vec<u8> = vec![
+        0x03, // iconst_0
+        0xaa, 0x00, 0x00, // tableswitch with 2 bytes of padding
+        0x00, 0x00, 0x00, 0x13, // default: 1 + 19
+        0x00, 0x00, 0x00, 0x00, // low
+        0x00, 0x00, 0x00, 0x00, // high
+        0x00, 0x00, 0x00, 0x13, // 0: 1 + 19
+        0xc4, 0x84, 0x01, 0x00, 0xff, 0xff, // wide iinc 256, -1
+        0xb1, // return
+    ];
+    assert_eq!(
+        decode(&code),
+        Ok(vec![
+            (0, Instruction::Iconst0),
+            (1, Instruction::Tableswitch { default: 19, low: 0, high: 0, offsets: vec![19] }),
+            (20, Instruction::Wide(Box::new(Instruction::Iinc(256, -1)))),
+            (26, Instruction::Return),
+        ])
+    );
+    assert_eq!(
+        decode(&[0xa7, 0x00, 0x01, 0xb1]),
+        Err(Error { message: "Invalid code. branch target 1 is not the start of an instruction, pc: 0".to_string() })
+    );
+}
+
+#[test]
+fn test_encode() {
+    let instructions = vec![
+        Instruction::Iconst0,
+        Instruction::Lookupswitch { default: 27, pairs: vec![(-1, 27), (7, 27)] },
+        Instruction::Wide(Box::new(Instruction::Iinc(256, -1))),
+        Instruction::Invokeinterface { index: 9, count: 2 },
+        Instruction::Return,
+    ];
+    let mut code: Vec<u8> = Vec::new();
+    for instruction in &instructions {
+        instruction.encode(&mut code);
+    }
+    assert_eq!(
+        code,
+        vec![
+            0x03, // iconst_0
+            0xab, 0x00, 0x00, // lookupswitch with 2 bytes of padding
+            0x00, 0x00, 0x00, 0x1b, // default: 1 + 27
+            0x00, 0x00, 0x00, 0x02, // npairs
+            0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x1b, // -1: 1 + 27
+            0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x1b, // 7: 1 + 27
+            0xc4, 0x84, 0x01, 0x00, 0xff, 0xff, // wide iinc 256, -1
+            0xb9, 0x00, 0x09, 0x02, 0x00, // invokeinterface #9, 2
+            0xb1, // return
+        ]
+    );
+    let decoded: Vec<Instruction> = decode(&code).unwrap().into_iter().map(|(_, instruction)| instruction).collect();
+    assert_eq!(decoded, instructions);
+}
@@ -1,8 +1,17 @@
 pub mod structure;
 pub mod structure_utils;
 pub mod descriptor;
+pub mod signature;
+pub mod instruction;
+pub mod class_hierarchy;
+pub mod verifier;
+pub mod analysis;
+pub mod decompiler;
 pub mod reader;
+pub mod writer;
+pub mod assembly;
 pub mod checker;
+pub mod access_flags;
 pub mod error;
 
 #[cfg(test)]
@@ -2,11 +2,12 @@ use crate::class_file::structure::root::*;
 use crate::class_file::structure::constant_pool::*;
 use crate::class_file::structure::attribute::*;
 use super::structure_utils::cp_accessor::*;
+use super::access_flags::AccessFlags;
 use super::error::{Error, Result};
 use super::checker;
 
 pub fn read_class_file(bytes: Vec<u8>) -> Result<ClassFile> {
-    let class_file: ClassFile = Reader::read(&bytes, &mut (0 as usize))?;
+    let class_file: ClassFile = Reader::read(&bytes, &mut 0)?;
     Ok(class_file)
 }
 
@@ -46,30 +47,44 @@ impl<const N: usize> Reader for [u8; N] {
 
 impl Reader for u8 {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Self> {
-        let a: [u8; 1] = Reader::read(&bytes, &mut *offset)?;
+        let a: [u8; 1] = Reader::read(bytes, &mut *offset)?;
         Ok(u8::from_be_bytes(a))
     }
 }
 
 impl Reader for u16 {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Self> {
-        let a: [u8; 2] = Reader::read(&bytes, &mut *offset)?;
+        let a: [u8; 2] = Reader::read(bytes, &mut *offset)?;
         Ok(u16::from_be_bytes(a))
     }
 }
 
 impl Reader for u32 {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Self> {
-        let a: [u8; 4] = Reader::read(&bytes, &mut *offset)?;
+        let a: [u8; 4] = Reader::read(bytes, &mut *offset)?;
         Ok(u32::from_be_bytes(a))
     }
 }
 
+impl<T> Reader for CpIndex<T> {
+    fn read(bytes: &[u8], offset: &mut usize) -> Result<Self> {
+        let index: u16 = Reader::read(bytes, &mut *offset)?;
+        Ok(CpIndex::new(index))
+    }
+}
+
+impl<T: AccessFlags> Reader for T {
+    fn read(bytes: &[u8], offset: &mut usize) -> Result<Self> {
+        let bits: u16 = Reader::read(bytes, &mut *offset)?;
+        Ok(T::from_bits_retain(bits))
+    }
+}
+
 impl<T> VecReader for T where T: Reader {
     fn read(bytes: &[u8], offset: &mut usize, num_of_items: usize) -> Result<Vec<T>> where Self: Sized {
         let mut items: Vec<T> = Vec::new();
         for _ in 0..num_of_items {
-            items.push(T::read(&bytes, &mut *offset)?);
+            items.push(T::read(bytes, &mut *offset)?);
         };
         Ok(items)
     }
@@ -79,7 +94,7 @@ impl<T> VecReaderWithCp for T where T: ReaderWithCp {
     fn read(bytes: &[u8], offset: &mut usize, constant_pool: &Vec<CpInfo>, num_of_items: usize) -> Result<Vec<T>> where Self: Sized {
         let mut items: Vec<T> = Vec::new();
         for _ in 0..num_of_items {
-            items.push(T::read(&bytes, &mut *offset, constant_pool)?);
+            items.push(T::read(bytes, &mut *offset, constant_pool)?);
         };
         Ok(items)
     }
@@ -87,31 +102,31 @@ impl<T> VecReaderWithCp for T where T: ReaderWithCp {
 
 impl Reader for ClassFile {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ClassFile> {
-        let magic: [u8; 4] = Reader::read(&bytes, &mut *offset)?;
+        let magic: [u8; 4] = Reader::read(bytes, &mut *offset)?;
         // check the magic item `cafebabe` at the first early.
         checker::check_magic(&magic).or_else(|e| error(e.message, offset))?;
-        let minor_version: u16 = Reader::read(&bytes, &mut *offset)?;
-        let major_version: u16 = Reader::read(&bytes, &mut *offset)?;
+        let minor_version: u16 = Reader::read(bytes, &mut *offset)?;
+        let major_version: u16 = Reader::read(bytes, &mut *offset)?;
         // check the class file version early.
         checker::check_version(minor_version, major_version).or_else(|e| error(e.message, offset))?;
         // The rest of the checking done by the class file reader is only checking
         // whether all the bytes at the end have been consumed, and the rest is left to ClassFileChecker
-        let constant_pool_count: u16 = Reader::read(&bytes, &mut *offset)?;
+        let constant_pool_count: u16 = Reader::read(bytes, &mut *offset)?;
         // The constant_pool table is indexed from 1 to constant_pool_count - 1.
         // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.1
-        let constant_pool: Vec<CpInfo> = VecReader::read(&bytes, &mut *offset, (constant_pool_count - 1) as usize)?;
+        let constant_pool: Vec<CpInfo> = read_constant_pool(bytes, &mut *offset, constant_pool_count)?;
         checker::check_constant_pool(&constant_pool, major_version)?;
-        let access_flags: u16 = Reader::read(&bytes, &mut *offset)?;
-        let this_class: u16 = Reader::read(&bytes, &mut *offset)?;
-        let super_class: u16 = Reader::read(&bytes, &mut *offset)?;
-        let interfaces_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let interfaces: Vec<u16> = VecReader::read(&bytes, &mut *offset, interfaces_count as usize)?;
-        let fields_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let fields: Vec<FieldsInfo> = VecReaderWithCp::read(&bytes, &mut *offset, &constant_pool, fields_count as usize)?;
-        let methods_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let methods: Vec<MethodInfo> = VecReaderWithCp::read(&bytes, &mut *offset, &constant_pool, methods_count as usize)?;
-        let attributes_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let attributes: Vec<Attribute> = VecReaderWithCp::read(&bytes, &mut *offset, &constant_pool, attributes_count as usize)?;
+        let access_flags = Reader::read(bytes, &mut *offset)?;
+        let this_class: CpIndex<ConstantClassInfo> = Reader::read(bytes, &mut *offset)?;
+        let super_class: CpIndex<ConstantClassInfo> = Reader::read(bytes, &mut *offset)?;
+        let interfaces_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let interfaces: Vec<CpIndex<ConstantClassInfo>> = VecReader::read(bytes, &mut *offset, interfaces_count as usize)?;
+        let fields_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let fields: Vec<FieldsInfo> = VecReaderWithCp::read(bytes, &mut *offset, &constant_pool, fields_count as usize)?;
+        let methods_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let methods: Vec<MethodInfo> = VecReaderWithCp::read(bytes, &mut *offset, &constant_pool, methods_count as usize)?;
+        let attributes_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let attributes: Vec<Attribute> = VecReaderWithCp::read(bytes, &mut *offset, &constant_pool, attributes_count as usize)?;
 
         // 4.8. Format Checking
         // The class file must not be truncated or have extra bytes at the end.
@@ -140,13 +155,33 @@ impl Reader for ClassFile {
     }
 }
 
+// The entry after a CONSTANT_Long_info or CONSTANT_Double_info is unusable and is not in the bytes,
+// so that it is put in the Vec to keep the entries at their indexes.
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.4.5
+fn read_constant_pool(bytes: &[u8], offset: &mut usize, constant_pool_count: u16) -> Result<Vec<CpInfo>> {
+    let num_of_items = (constant_pool_count as usize).saturating_sub(1);
+    let mut constant_pool: Vec<CpInfo> = Vec::new();
+    while constant_pool.len() < num_of_items {
+        let cp_info: CpInfo = Reader::read(bytes, &mut *offset)?;
+        let takes_two_entries = matches!(cp_info, CpInfo::Long(_) | CpInfo::Double(_));
+        constant_pool.push(cp_info);
+        if takes_two_entries {
+            if constant_pool.len() == num_of_items {
+                return error(format!("CONSTANT_Long_info or CONSTANT_Double_info at the last index {} has no room for its second entry.", num_of_items), offset);
+            }
+            constant_pool.push(CpInfo::Unusable);
+        }
+    }
+    Ok(constant_pool)
+}
+
 impl Reader for ConstantUtf8Info {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantUtf8Info> {
-        let length = Reader::read(&bytes, &mut *offset)?;
+        let length = Reader::read(bytes, &mut *offset)?;
         Ok(ConstantUtf8Info {
             tag: CONSTANT_UTF8,
             length,
-            bytes: VecReader::read(&bytes, &mut *offset, length as usize)?,
+            bytes: VecReader::read(bytes, &mut *offset, length as usize)?,
         })
     }
 }
@@ -155,7 +190,7 @@ impl Reader for ConstantIntegerInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantIntegerInfo> {
         Ok(ConstantIntegerInfo {
             tag: CONSTANT_INTEGER,
-            bytes: Reader::read(&bytes, &mut *offset)?,
+            bytes: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -164,7 +199,7 @@ impl Reader for ConstantFloatInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantFloatInfo> {
         Ok(ConstantFloatInfo {
             tag: CONSTANT_FLOAT,
-            bytes: Reader::read(&bytes, &mut *offset)?,
+            bytes: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -173,8 +208,8 @@ impl Reader for ConstantLongInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantLongInfo> {
         Ok(ConstantLongInfo {
             tag: CONSTANT_LONG,
-            high_bytes: Reader::read(&bytes, &mut *offset)?,
-            low_bytes: Reader::read(&bytes, &mut *offset)?,
+            high_bytes: Reader::read(bytes, &mut *offset)?,
+            low_bytes: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -183,8 +218,8 @@ impl Reader for ConstantDoubleInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantDoubleInfo> {
         Ok(ConstantDoubleInfo {
             tag: CONSTANT_DOUBLE,
-            high_bytes: Reader::read(&bytes, &mut *offset)?,
-            low_bytes: Reader::read(&bytes, &mut *offset)?,
+            high_bytes: Reader::read(bytes, &mut *offset)?,
+            low_bytes: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -193,7 +228,7 @@ impl Reader for ConstantClassInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantClassInfo> {
         Ok(ConstantClassInfo {
             tag: CONSTANT_CLASS,
-            name_index: Reader::read(&bytes, &mut *offset)?,
+            name_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -202,7 +237,7 @@ impl Reader for ConstantStringInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantStringInfo> {
         Ok(ConstantStringInfo {
             tag: CONSTANT_STRING,
-            string_index: Reader::read(&bytes, &mut *offset)?,
+            string_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -211,8 +246,8 @@ impl Reader for ConstantFieldrefInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantFieldrefInfo> {
         Ok(ConstantFieldrefInfo {
             tag: CONSTANT_FIELDREF,
-            class_index: Reader::read(&bytes, &mut *offset)?,
-            name_and_type_index: Reader::read(&bytes, &mut *offset)?,
+            class_index: Reader::read(bytes, &mut *offset)?,
+            name_and_type_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -221,8 +256,8 @@ impl Reader for ConstantMethodrefInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantMethodrefInfo> {
         Ok(ConstantMethodrefInfo {
             tag: CONSTANT_METHODREF,
-            class_index: Reader::read(&bytes, &mut *offset)?,
-            name_and_type_index: Reader::read(&bytes, &mut *offset)?,
+            class_index: Reader::read(bytes, &mut *offset)?,
+            name_and_type_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -231,8 +266,8 @@ impl Reader for ConstantInterfaceMethodrefInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantInterfaceMethodrefInfo> {
         Ok(ConstantInterfaceMethodrefInfo {
             tag: CONSTANT_INTERFACE_METHODREF,
-            class_index: Reader::read(&bytes, &mut *offset)?,
-            name_and_type_index: Reader::read(&bytes, &mut *offset)?,
+            class_index: Reader::read(bytes, &mut *offset)?,
+            name_and_type_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -241,8 +276,8 @@ impl Reader for ConstantNameAndTypeInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantNameAndTypeInfo> {
         Ok(ConstantNameAndTypeInfo {
             tag: CONSTANT_NAME_AND_TYPE,
-            name_index: Reader::read(&bytes, &mut *offset)?,
-            descriptor_index: Reader::read(&bytes, &mut *offset)?,
+            name_index: Reader::read(bytes, &mut *offset)?,
+            descriptor_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -251,8 +286,8 @@ impl Reader for ConstantMethodHandleInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantMethodHandleInfo> {
         Ok(ConstantMethodHandleInfo {
             tag: CONSTANT_METHOD_HANDLE,
-            reference_kind: Reader::read(&bytes, &mut *offset)?,
-            reference_index: Reader::read(&bytes, &mut *offset)?,
+            reference_kind: Reader::read(bytes, &mut *offset)?,
+            reference_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -261,7 +296,7 @@ impl Reader for ConstantMethodTypeInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantMethodTypeInfo> {
         Ok(ConstantMethodTypeInfo {
             tag: CONSTANT_METHOD_TYPE,
-            descriptor_index: Reader::read(&bytes, &mut *offset)?,
+            descriptor_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -270,8 +305,8 @@ impl Reader for ConstantDynamicInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantDynamicInfo> {
         Ok(ConstantDynamicInfo {
             tag: CONSTANT_DYNAMIC,
-            bootstrap_method_attr_index: Reader::read(&bytes, &mut *offset)?,
-            name_and_type_index: Reader::read(&bytes, &mut *offset)?,
+            bootstrap_method_attr_index: Reader::read(bytes, &mut *offset)?,
+            name_and_type_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -280,8 +315,8 @@ impl Reader for ConstantInvokeDynamicInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantInvokeDynamicInfo> {
         Ok(ConstantInvokeDynamicInfo {
             tag: CONSTANT_INVOKE_DYNAMIC,
-            bootstrap_method_attr_index: Reader::read(&bytes, &mut *offset)?,
-            name_and_type_index: Reader::read(&bytes, &mut *offset)?,
+            bootstrap_method_attr_index: Reader::read(bytes, &mut *offset)?,
+            name_and_type_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
@@ -290,7 +325,7 @@ impl Reader for ConstantModuleInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantModuleInfo> {
         Ok(ConstantModuleInfo {
             tag: CONSTANT_MODULE,
-            name_index: Reader::read(&bytes, &mut *offset)?,
+            name_index: Reader::read(bytes, &mut *offset)?,
 
         })
     }
@@ -300,32 +335,32 @@ impl Reader for ConstantPackageInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ConstantPackageInfo> {
         Ok(ConstantPackageInfo {
             tag: CONSTANT_PACKAGE,
-            name_index: Reader::read(&bytes, &mut *offset)?,
+            name_index: Reader::read(bytes, &mut *offset)?,
         })
     }
 }
 
 impl Reader for CpInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<CpInfo> {
-        let tag: CpInfoTag = Reader::read(&bytes, &mut *offset)?;
+        let tag: CpInfoTag = Reader::read(bytes, &mut *offset)?;
         let cp_info = match tag {
-            CONSTANT_UTF8 => CpInfo::Utf8(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_INTEGER => CpInfo::Integer(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_FLOAT => CpInfo::Float(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_LONG => CpInfo::Long(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_DOUBLE => CpInfo::Double(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_CLASS => CpInfo::Class(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_STRING => CpInfo::String(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_FIELDREF => CpInfo::Fieldref(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_METHODREF => CpInfo::Methodref(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_INTERFACE_METHODREF => CpInfo::InterfaceMethodref(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_NAME_AND_TYPE => CpInfo::NameAndType(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_METHOD_HANDLE => CpInfo::MethodHandle(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_METHOD_TYPE => CpInfo::MethodType(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_DYNAMIC => CpInfo::Dynamic(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_INVOKE_DYNAMIC => CpInfo::InvokeDynamic(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_MODULE => CpInfo::Module(Reader::read(&bytes, &mut *offset)?),
-            CONSTANT_PACKAGE => CpInfo::Package(Reader::read(&bytes, &mut *offset)?),
+            CONSTANT_UTF8 => CpInfo::Utf8(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_INTEGER => CpInfo::Integer(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_FLOAT => CpInfo::Float(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_LONG => CpInfo::Long(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_DOUBLE => CpInfo::Double(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_CLASS => CpInfo::Class(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_STRING => CpInfo::String(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_FIELDREF => CpInfo::Fieldref(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_METHODREF => CpInfo::Methodref(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_INTERFACE_METHODREF => CpInfo::InterfaceMethodref(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_NAME_AND_TYPE => CpInfo::NameAndType(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_METHOD_HANDLE => CpInfo::MethodHandle(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_METHOD_TYPE => CpInfo::MethodType(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_DYNAMIC => CpInfo::Dynamic(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_INVOKE_DYNAMIC => CpInfo::InvokeDynamic(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_MODULE => CpInfo::Module(Reader::read(bytes, &mut *offset)?),
+            CONSTANT_PACKAGE => CpInfo::Package(Reader::read(bytes, &mut *offset)?),
             _ => return error(format!("unsupported tag {}", tag), offset)
         };
         Ok(cp_info)
@@ -334,11 +369,11 @@ impl Reader for CpInfo {
 
 impl ReaderWithCp for FieldsInfo {
     fn read(bytes: &[u8], offset: &mut usize, constant_pool: &Vec<CpInfo>) -> Result<FieldsInfo> {
-        let access_flags: u16 = Reader::read(&bytes, &mut *offset)?;
-        let name_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let descriptor_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let attributes_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let attributes: Vec<Attribute> = VecReaderWithCp::read(&bytes, &mut *offset, constant_pool, attributes_count as usize)?;
+        let access_flags = Reader::read(bytes, &mut *offset)?;
+        let name_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let descriptor_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let attributes_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let attributes: Vec<Attribute> = VecReaderWithCp::read(bytes, &mut *offset, constant_pool, attributes_count as usize)?;
         Ok(FieldsInfo {
             access_flags,
             name_index,
@@ -351,11 +386,11 @@ impl ReaderWithCp for FieldsInfo {
 
 impl ReaderWithCp for MethodInfo {
     fn read(bytes: &[u8], offset: &mut usize, constant_pool: &Vec<CpInfo>) -> Result<MethodInfo> {
-        let access_flags: u16 = Reader::read(&bytes, &mut *offset)?;
-        let name_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let descriptor_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let attributes_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let attributes: Vec<Attribute> = VecReaderWithCp::read(&bytes, &mut *offset, constant_pool, attributes_count as usize)?;
+        let access_flags = Reader::read(bytes, &mut *offset)?;
+        let name_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let descriptor_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let attributes_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let attributes: Vec<Attribute> = VecReaderWithCp::read(bytes, &mut *offset, constant_pool, attributes_count as usize)?;
         Ok(MethodInfo {
             access_flags,
             name_index,
@@ -368,12 +403,12 @@ impl ReaderWithCp for MethodInfo {
 
 impl ReaderWithCp for Attribute {
     fn read(bytes: &[u8], offset: &mut usize, constant_pool: &Vec<CpInfo>) -> Result<Attribute> {
-        let attribute_name_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let attribute_length: u32 = Reader::read(&bytes, &mut *offset)?;
-        let attribute_name = constant_pool.access_as_utf8(attribute_name_index).bytes_as_string()?;
+        let attribute_name_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let attribute_length: u32 = Reader::read(bytes, &mut *offset)?;
+        let attribute_name = constant_pool.access(attribute_name_index).bytes_as_string()?;
         let attribute = match attribute_name.as_str() {
             "ConstantValue" => {
-                let constantvalue_index: u16 = Reader::read(&bytes, &mut *offset)?;
+                let constantvalue_index: CpIndex<CpInfo> = Reader::read(bytes, &mut *offset)?;
                 Attribute::ConstantValue(ConstantValueAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -381,14 +416,14 @@ impl ReaderWithCp for Attribute {
                 })
             }
             "Code" => {
-                let max_stack: u16 = Reader::read(&bytes, &mut *offset)?;
-                let max_locals: u16 = Reader::read(&bytes, &mut *offset)?;
-                let code_length: u32 = Reader::read(&bytes, &mut *offset)?;
-                let code: Vec<u8> = VecReader::read(&bytes, &mut *offset, code_length as usize)?;
-                let exception_table_length: u16 = Reader::read(&bytes, &mut *offset)?;
-                let exception_table: Vec<ExceptionTable> = VecReader::read(&bytes, &mut *offset, exception_table_length as usize)?;
-                let attributes_count: u16 = Reader::read(&bytes, &mut *offset)?;
-                let attributes: Vec<Attribute> = VecReaderWithCp::read(&bytes, &mut *offset, &constant_pool, attributes_count as usize)?;
+                let max_stack: u16 = Reader::read(bytes, &mut *offset)?;
+                let max_locals: u16 = Reader::read(bytes, &mut *offset)?;
+                let code_length: u32 = Reader::read(bytes, &mut *offset)?;
+                let code: Vec<u8> = VecReader::read(bytes, &mut *offset, code_length as usize)?;
+                let exception_table_length: u16 = Reader::read(bytes, &mut *offset)?;
+                let exception_table: Vec<ExceptionTable> = VecReader::read(bytes, &mut *offset, exception_table_length as usize)?;
+                let attributes_count: u16 = Reader::read(bytes, &mut *offset)?;
+                let attributes: Vec<Attribute> = VecReaderWithCp::read(bytes, &mut *offset, constant_pool, attributes_count as usize)?;
                 Attribute::Code(CodeAttributeInfo {
                     attribute_name_index,
                     attribute_length,
@@ -403,8 +438,8 @@ impl ReaderWithCp for Attribute {
                 })
             }
             "StackMapTable" => {
-                let number_of_entries: u16 = Reader::read(&bytes, &mut *offset)?;
-                let entries: Vec<StackMapFrame> = VecReader::read(&bytes, &mut *offset, number_of_entries as usize)?;
+                let number_of_entries: u16 = Reader::read(bytes, &mut *offset)?;
+                let entries: Vec<StackMapFrame> = VecReader::read(bytes, &mut *offset, number_of_entries as usize)?;
                 Attribute::StackMapTable(StackMapTableAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -413,8 +448,8 @@ impl ReaderWithCp for Attribute {
                 })
             }
             "Exceptions" => {
-                let number_of_exceptions: u16 = Reader::read(&bytes, &mut *offset)?;
-                let exception_index_table: Vec<u16> = VecReader::read(&bytes, &mut *offset, number_of_exceptions as usize)?;
+                let number_of_exceptions: u16 = Reader::read(bytes, &mut *offset)?;
+                let exception_index_table: Vec<CpIndex<ConstantClassInfo>> = VecReader::read(bytes, &mut *offset, number_of_exceptions as usize)?;
                 Attribute::Exceptions(ExceptionsAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -423,8 +458,8 @@ impl ReaderWithCp for Attribute {
                 })
             }
             "InnerClasses" => {
-                let number_of_classes: u16 =  Reader::read(&bytes, &mut *offset)?;
-                let classes:  Vec<InnerClassesAttributeClass> = VecReader::read(&bytes, &mut *offset, number_of_classes as usize)?;
+                let number_of_classes: u16 =  Reader::read(bytes, &mut *offset)?;
+                let classes:  Vec<InnerClassesAttributeClass> = VecReader::read(bytes, &mut *offset, number_of_classes as usize)?;
                 Attribute::InnerClasses(InnerClassesAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -433,8 +468,8 @@ impl ReaderWithCp for Attribute {
                 })
             }
             "EnclosingMethod" => {
-                let class_index: u16 =  Reader::read(&bytes, &mut *offset)?;
-                let method_index: u16 =  Reader::read(&bytes, &mut *offset)?;
+                let class_index: CpIndex<ConstantClassInfo> =  Reader::read(bytes, &mut *offset)?;
+                let method_index: CpIndex<ConstantNameAndTypeInfo> =  Reader::read(bytes, &mut *offset)?;
                 Attribute::EnclosingMethod(EnclosingMethodAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -442,9 +477,37 @@ impl ReaderWithCp for Attribute {
                     method_index,
                 })
             }
+            "Signature" => {
+                let signature_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+                Attribute::Signature(SignatureAttribute {
+                    attribute_name_index,
+                    attribute_length,
+                    signature_index,
+                })
+            }
+            "LocalVariableTable" => {
+                let local_variable_table_length: u16 = Reader::read(bytes, &mut *offset)?;
+                let local_variable_table: Vec<LocalVariable> = VecReader::read(bytes, &mut *offset, local_variable_table_length as usize)?;
+                Attribute::LocalVariableTable(LocalVariableTableAttribute {
+                    attribute_name_index,
+                    attribute_length,
+                    local_variable_table_length,
+                    local_variable_table,
+                })
+            }
+            "LocalVariableTypeTable" => {
+                let local_variable_type_table_length: u16 = Reader::read(bytes, &mut *offset)?;
+                let local_variable_type_table: Vec<LocalVariableType> = VecReader::read(bytes, &mut *offset, local_variable_type_table_length as usize)?;
+                Attribute::LocalVariableTypeTable(LocalVariableTypeTableAttribute {
+                    attribute_name_index,
+                    attribute_length,
+                    local_variable_type_table_length,
+                    local_variable_type_table,
+                })
+            }
             "BootstrapMethods" => {
-                let num_bootstrap_methods: u16 = Reader::read(&bytes, &mut *offset)?;
-                let bootstrap_methods: Vec<BootstrapMethod> = VecReader::read(&bytes, &mut *offset, num_bootstrap_methods as usize)?;
+                let num_bootstrap_methods: u16 = Reader::read(bytes, &mut *offset)?;
+                let bootstrap_methods: Vec<BootstrapMethod> = VecReader::read(bytes, &mut *offset, num_bootstrap_methods as usize)?;
                 Attribute::BootstrapMethods(BootstrapMethodsAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -453,8 +516,8 @@ impl ReaderWithCp for Attribute {
                 })
             }
             "MethodParameters" => {
-                let parameters_count: u8 = Reader::read(&bytes, &mut *offset)?;
-                let parameters: Vec<Parameter> = VecReader::read(&bytes, &mut *offset, parameters_count as usize)?;
+                let parameters_count: u8 = Reader::read(bytes, &mut *offset)?;
+                let parameters: Vec<Parameter> = VecReader::read(bytes, &mut *offset, parameters_count as usize)?;
                 Attribute::MethodParameters(MethodParametersAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -463,19 +526,19 @@ impl ReaderWithCp for Attribute {
                 })
             }
             "Module" => {
-                let module_name_index: u16 = Reader::read(&bytes, &mut *offset)?;
-                let module_flags: u16 = Reader::read(&bytes, &mut *offset)?;
-                let module_version_index: u16 = Reader::read(&bytes, &mut *offset)?;
-                let requires_count: u16 = Reader::read(&bytes, &mut *offset)?;
-                let requires: Vec<Require> = VecReader::read(&bytes, &mut *offset, requires_count as usize)?;
-                let exports_count: u16 = Reader::read(&bytes, &mut *offset)?;
-                let exports: Vec<Export> = VecReader::read(&bytes, &mut *offset, exports_count as usize)?;
-                let opens_count: u16 = Reader::read(&bytes, &mut *offset)?;
-                let opens: Vec<Open> = VecReader::read(&bytes, &mut *offset, opens_count as usize)?;
-                let uses_count: u16 = Reader::read(&bytes, &mut *offset)?;
-                let uses_index: Vec<u16> = VecReader::read(&bytes, &mut *offset, uses_count as usize)?;
-                let provides_count: u16 = Reader::read(&bytes, &mut *offset)?;
-                let provides: Vec<Provide> = VecReader::read(&bytes, &mut *offset, provides_count as usize)?;
+                let module_name_index: CpIndex<ConstantModuleInfo> = Reader::read(bytes, &mut *offset)?;
+                let module_flags = Reader::read(bytes, &mut *offset)?;
+                let module_version_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+                let requires_count: u16 = Reader::read(bytes, &mut *offset)?;
+                let requires: Vec<Require> = VecReader::read(bytes, &mut *offset, requires_count as usize)?;
+                let exports_count: u16 = Reader::read(bytes, &mut *offset)?;
+                let exports: Vec<Export> = VecReader::read(bytes, &mut *offset, exports_count as usize)?;
+                let opens_count: u16 = Reader::read(bytes, &mut *offset)?;
+                let opens: Vec<Open> = VecReader::read(bytes, &mut *offset, opens_count as usize)?;
+                let uses_count: u16 = Reader::read(bytes, &mut *offset)?;
+                let uses_index: Vec<CpIndex<ConstantClassInfo>> = VecReader::read(bytes, &mut *offset, uses_count as usize)?;
+                let provides_count: u16 = Reader::read(bytes, &mut *offset)?;
+                let provides: Vec<Provide> = VecReader::read(bytes, &mut *offset, provides_count as usize)?;
                 Attribute::Module(ModuleAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -495,7 +558,7 @@ impl ReaderWithCp for Attribute {
                 })
             },
             "NestHost" => {
-                let host_class_index: u16 =   Reader::read(&bytes, &mut *offset)?;
+                let host_class_index: CpIndex<ConstantClassInfo> =   Reader::read(bytes, &mut *offset)?;
                 Attribute::NestHost(NestHostAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -503,8 +566,8 @@ impl ReaderWithCp for Attribute {
                 })
             },
             "NestMembers" => {
-                let number_of_classes: u16 =   Reader::read(&bytes, &mut *offset)?;
-                let classes: Vec<u16> = VecReader::read(&bytes, &mut *offset, number_of_classes as usize)?;
+                let number_of_classes: u16 =   Reader::read(bytes, &mut *offset)?;
+                let classes: Vec<CpIndex<ConstantClassInfo>> = VecReader::read(bytes, &mut *offset, number_of_classes as usize)?;
                 Attribute::NestMembers(NestMembersAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -513,8 +576,8 @@ impl ReaderWithCp for Attribute {
                 })
             },
             "PermittedSubclasses" => {
-                let number_of_classes: u16 =   Reader::read(&bytes, &mut *offset)?;
-                let classes: Vec<u16> = VecReader::read(&bytes, &mut *offset, number_of_classes as usize)?;
+                let number_of_classes: u16 =   Reader::read(bytes, &mut *offset)?;
+                let classes: Vec<CpIndex<ConstantClassInfo>> = VecReader::read(bytes, &mut *offset, number_of_classes as usize)?;
                 Attribute::PermittedSubclasses(PermittedSubclassesAttribute {
                     attribute_name_index,
                     attribute_length,
@@ -523,7 +586,7 @@ impl ReaderWithCp for Attribute {
                 })
             },
             _ => {
-                let info: Vec<u8> = VecReader::read(&bytes, &mut *offset, attribute_length as usize)?;
+                let info: Vec<u8> = VecReader::read(bytes, &mut *offset, attribute_length as usize)?;
                 Attribute::General(AttributeInfo {
                     attribute_name_index,
                     attribute_length,
@@ -538,37 +601,37 @@ impl ReaderWithCp for Attribute {
 
 impl Reader for StackMapFrame {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<StackMapFrame> {
-        let frame_type: u8 = Reader::read(&bytes, &mut *offset)?;
+        let frame_type: u8 = Reader::read(bytes, &mut *offset)?;
         let stack_map_frame = match frame_type {
             0..=63 => StackMapFrame::SameFrame { frame_type },
             64..=127 => StackMapFrame::SameLocals1StackItemFrame {
                 frame_type,
-                stack: VecReader::read(&bytes, &mut *offset, 1)?,
+                stack: VecReader::read(bytes, &mut *offset, 1)?,
             },
             247 => StackMapFrame::SameLocals1StackItemFrameExtended {
                 frame_type,
-                offset_delta: Reader::read(&bytes, &mut *offset)?,
-                stack: VecReader::read(&bytes, &mut *offset, 1)?,
+                offset_delta: Reader::read(bytes, &mut *offset)?,
+                stack: VecReader::read(bytes, &mut *offset, 1)?,
             },
             248..=250 => StackMapFrame::ChopFrame {
                 frame_type,
-                offset_delta: Reader::read(&bytes, &mut *offset)?,
+                offset_delta: Reader::read(bytes, &mut *offset)?,
             },
             251 => StackMapFrame::SameFrameExtended {
                 frame_type,
-                offset_delta: Reader::read(&bytes, &mut *offset)?,
+                offset_delta: Reader::read(bytes, &mut *offset)?,
             },
             252..=254 => StackMapFrame::AppendFrame {
                 frame_type,
-                offset_delta: Reader::read(&bytes, &mut *offset)?,
-                locals: VecReader::read(&bytes, &mut *offset, (frame_type - 251) as usize)?,
+                offset_delta: Reader::read(bytes, &mut *offset)?,
+                locals: VecReader::read(bytes, &mut *offset, (frame_type - 251) as usize)?,
             },
             255 => {
-                let offset_delta = Reader::read(&bytes, &mut *offset)?;
-                let number_of_locals = Reader::read(&bytes, &mut *offset)?;
-                let locals = VecReader::read(&bytes, &mut *offset, number_of_locals as u16 as usize)?;
-                let number_of_stack_items = Reader::read(&bytes, &mut *offset)?;
-                let stack = VecReader::read(&bytes, &mut *offset, number_of_stack_items as u16 as usize)?;
+                let offset_delta = Reader::read(bytes, &mut *offset)?;
+                let number_of_locals = Reader::read(bytes, &mut *offset)?;
+                let locals = VecReader::read(bytes, &mut *offset, number_of_locals as usize)?;
+                let number_of_stack_items = Reader::read(bytes, &mut *offset)?;
+                let stack = VecReader::read(bytes, &mut *offset, number_of_stack_items as usize)?;
                 StackMapFrame::FullFrame {
                     frame_type,
                     offset_delta,
@@ -587,7 +650,7 @@ impl Reader for StackMapFrame {
 #[allow(dead_code)]
 impl Reader for VerificationTypeInfo {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Self> where Self: Sized {
-        let tag: u8 = Reader::read(&bytes, &mut *offset)?;
+        let tag: u8 = Reader::read(bytes, &mut *offset)?;
         let item = match tag {
             0 => VerificationTypeInfo::TopVariableInfo { tag },
             1 => VerificationTypeInfo::IntegerVariableInfo { tag },
@@ -598,11 +661,11 @@ impl Reader for VerificationTypeInfo {
             6 => VerificationTypeInfo::UninitializedThisVariableInfo { tag },
             7 => VerificationTypeInfo::ObjectVariableInfo {
                 tag,
-                cpool_index: Reader::read(&bytes, &mut *offset)?,
+                cpool_index: Reader::read(bytes, &mut *offset)?,
             },
             8 => VerificationTypeInfo::UninitializedVariableInfo {
                 tag,
-                offset: Reader::read(&bytes, &mut *offset)?,
+                offset: Reader::read(bytes, &mut *offset)?,
             },
             _ => return error(format!("Verification type's tag must be 0..8 !. tag: {}", tag), offset)
         };
@@ -612,10 +675,10 @@ impl Reader for VerificationTypeInfo {
 
 impl Reader for ExceptionTable {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<ExceptionTable> {
-        let start_pc: u16 = Reader::read(&bytes, &mut *offset)?;
-        let end_pc: u16 = Reader::read(&bytes, &mut *offset)?;
-        let handler_pc: u16 = Reader::read(&bytes, &mut *offset)?;
-        let catch_type: u16 = Reader::read(&bytes, &mut *offset)?;
+        let start_pc: u16 = Reader::read(bytes, &mut *offset)?;
+        let end_pc: u16 = Reader::read(bytes, &mut *offset)?;
+        let handler_pc: u16 = Reader::read(bytes, &mut *offset)?;
+        let catch_type: CpIndex<ConstantClassInfo> = Reader::read(bytes, &mut *offset)?;
         Ok(ExceptionTable {
             start_pc,
             end_pc,
@@ -627,10 +690,10 @@ impl Reader for ExceptionTable {
 
 impl Reader for InnerClassesAttributeClass {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<InnerClassesAttributeClass> {
-        let inner_class_info_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let outer_class_info_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let inner_name_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let inner_class_access_flags: u16 = Reader::read(&bytes, &mut *offset)?;
+        let inner_class_info_index: CpIndex<ConstantClassInfo> = Reader::read(bytes, &mut *offset)?;
+        let outer_class_info_index: CpIndex<ConstantClassInfo> = Reader::read(bytes, &mut *offset)?;
+        let inner_name_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let inner_class_access_flags = Reader::read(bytes, &mut *offset)?;
         Ok(InnerClassesAttributeClass {
             inner_class_info_index,
             outer_class_info_index,
@@ -642,9 +705,9 @@ impl Reader for InnerClassesAttributeClass {
 
 impl Reader for BootstrapMethod {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<BootstrapMethod> {
-        let bootstrap_method_ref: u16 = Reader::read(&bytes, &mut *offset)?;
-        let num_bootstrap_arguments: u16 = Reader::read(&bytes, &mut *offset)?;
-        let bootstrap_arguments: Vec<u16> = VecReader::read(&bytes, &mut *offset, num_bootstrap_arguments as u16 as usize)?;
+        let bootstrap_method_ref: CpIndex<ConstantMethodHandleInfo> = Reader::read(bytes, &mut *offset)?;
+        let num_bootstrap_arguments: u16 = Reader::read(bytes, &mut *offset)?;
+        let bootstrap_arguments: Vec<CpIndex<CpInfo>> = VecReader::read(bytes, &mut *offset, num_bootstrap_arguments as usize)?;
         Ok(BootstrapMethod {
             bootstrap_method_ref,
             num_bootstrap_arguments,
@@ -655,8 +718,8 @@ impl Reader for BootstrapMethod {
 
 impl Reader for Parameter {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Parameter> {
-        let name_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let access_flags: u16 = Reader::read(&bytes, &mut *offset)?;
+        let name_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let access_flags = Reader::read(bytes, &mut *offset)?;
         Ok(Parameter {
             name_index,
             access_flags,
@@ -664,13 +727,51 @@ impl Reader for Parameter {
     }
 }
 
+// for LocalVariableTableAttribute
+
+impl Reader for LocalVariable {
+    fn read(bytes: &[u8], offset: &mut usize) -> Result<LocalVariable> {
+        let start_pc: u16 = Reader::read(bytes, &mut *offset)?;
+        let length: u16 = Reader::read(bytes, &mut *offset)?;
+        let name_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let descriptor_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let index: u16 = Reader::read(bytes, &mut *offset)?;
+        Ok(LocalVariable {
+            start_pc,
+            length,
+            name_index,
+            descriptor_index,
+            index,
+        })
+    }
+}
+
+// for LocalVariableTypeTableAttribute
+
+impl Reader for LocalVariableType {
+    fn read(bytes: &[u8], offset: &mut usize) -> Result<LocalVariableType> {
+        let start_pc: u16 = Reader::read(bytes, &mut *offset)?;
+        let length: u16 = Reader::read(bytes, &mut *offset)?;
+        let name_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let signature_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
+        let index: u16 = Reader::read(bytes, &mut *offset)?;
+        Ok(LocalVariableType {
+            start_pc,
+            length,
+            name_index,
+            signature_index,
+            index,
+        })
+    }
+}
+
 // for ModuleAttribute
 
 impl Reader for Require {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Require> {
-        let requires_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let requires_flags: u16 = Reader::read(&bytes, &mut *offset)?;
-        let requires_version_index: u16 = Reader::read(&bytes, &mut *offset)?;
+        let requires_index: CpIndex<ConstantModuleInfo> = Reader::read(bytes, &mut *offset)?;
+        let requires_flags = Reader::read(bytes, &mut *offset)?;
+        let requires_version_index: CpIndex<ConstantUtf8Info> = Reader::read(bytes, &mut *offset)?;
         Ok(Require {
             requires_index,
             requires_flags,
@@ -681,10 +782,10 @@ impl Reader for Require {
 
 impl Reader for Export {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Export> {
-        let exports_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let exports_flags: u16 = Reader::read(&bytes, &mut *offset)?;
-        let exports_to_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let exports_to_index: Vec<u16> = VecReader::read(&bytes, &mut *offset, exports_to_count as u16 as usize)?;
+        let exports_index: CpIndex<ConstantPackageInfo> = Reader::read(bytes, &mut *offset)?;
+        let exports_flags = Reader::read(bytes, &mut *offset)?;
+        let exports_to_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let exports_to_index: Vec<CpIndex<ConstantModuleInfo>> = VecReader::read(bytes, &mut *offset, exports_to_count as usize)?;
         Ok(Export {
             exports_index,
             exports_flags,
@@ -696,10 +797,10 @@ impl Reader for Export {
 
 impl Reader for Open {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Open> {
-        let opens_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let opens_flags: u16 = Reader::read(&bytes, &mut *offset)?;
-        let opens_to_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let opens_to_index: Vec<u16> = VecReader::read(&bytes, &mut *offset, opens_to_count as u16 as usize)?;
+        let opens_index: CpIndex<ConstantPackageInfo> = Reader::read(bytes, &mut *offset)?;
+        let opens_flags = Reader::read(bytes, &mut *offset)?;
+        let opens_to_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let opens_to_index: Vec<CpIndex<ConstantModuleInfo>> = VecReader::read(bytes, &mut *offset, opens_to_count as usize)?;
         Ok(Open {
             opens_index,
             opens_flags,
@@ -711,9 +812,9 @@ impl Reader for Open {
 
 impl Reader for Provide {
     fn read(bytes: &[u8], offset: &mut usize) -> Result<Provide> {
-        let provides_index: u16 = Reader::read(&bytes, &mut *offset)?;
-        let provides_with_count: u16 = Reader::read(&bytes, &mut *offset)?;
-        let provides_with_index: Vec<u16> = VecReader::read(&bytes, &mut *offset, provides_with_count as u16 as usize)?;
+        let provides_index: CpIndex<ConstantClassInfo> = Reader::read(bytes, &mut *offset)?;
+        let provides_with_count: u16 = Reader::read(bytes, &mut *offset)?;
+        let provides_with_index: Vec<CpIndex<ConstantClassInfo>> = VecReader::read(bytes, &mut *offset, provides_with_count as usize)?;
         Ok(Provide {
             provides_index,
             provides_with_count,
@@ -0,0 +1,224 @@
+use crate::class_file::descriptor::FieldType;
+use crate::class_file::error::{Result, error};
+
+// 4.7.9.1. Signatures
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.9.1
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum JavaTypeSignature {
+    // only the primitive types of FieldType
+    Base(FieldType),
+    Class(ClassTypeSignature),
+    TypeVariable(String),
+    Array(Box<JavaTypeSignature>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ClassTypeSignature {
+    // e.g. `java/util/`, or empty for the unnamed package
+    pub package: String,
+    // the outermost class first, e.g. `Map` and `Entry` for `java/util/Map$Entry`
+    pub classes: Vec<SimpleClassTypeSignature>,
+}
+
+impl ClassTypeSignature {
+    /// The binary name in internal form, e.g. `java/util/Map$Entry`.
+    pub fn name(&self) -> String {
+        let names: Vec<&str> = self.classes.iter().map(|class| class.name.as_str()).collect();
+        format!("{}{}", self.package, names.join("$"))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SimpleClassTypeSignature {
+    pub name: String,
+    pub type_arguments: Vec<TypeArgument>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TypeArgument {
+    // `*`, i.e. `?`
+    Any,
+    Exact(JavaTypeSignature),
+    // `+`, i.e. `? extends`
+    Extends(JavaTypeSignature),
+    // `-`, i.e. `? super`
+    Super(JavaTypeSignature),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TypeParameter {
+    pub name: String,
+    pub class_bound: Option<JavaTypeSignature>,
+    pub interface_bounds: Vec<JavaTypeSignature>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ClassSignature {
+    pub type_parameters: Vec<TypeParameter>,
+    pub super_class: ClassTypeSignature,
+    pub super_interfaces: Vec<ClassTypeSignature>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MethodSignature {
+    pub type_parameters: Vec<TypeParameter>,
+    pub parameter_types: Vec<JavaTypeSignature>,
+    // None for void
+    pub result: Option<JavaTypeSignature>,
+    pub throws: Vec<JavaTypeSignature>,
+}
+
+peg::parser! {
+    grammar signature_parser() for str {
+        rule identifier() -> String
+            = str:$((!['.' | ';' | '[' | '/' | '<' | '>' | ':'] [_])+) { str.to_string() }
+
+        rule base_type() -> FieldType
+            = "B" { FieldType::Byte } /
+              "C" { FieldType::Char } /
+              "D" { FieldType::Double } /
+              "F" { FieldType::Float } /
+              "I" { FieldType::Int } /
+              "J" { FieldType::Long } /
+              "S" { FieldType::Short } /
+              "Z" { FieldType::Boolean }
+
+        pub rule java_type_signature() -> JavaTypeSignature
+            = reference_type_signature() /
+              t:base_type() { JavaTypeSignature::Base(t) }
+
+        pub rule reference_type_signature() -> JavaTypeSignature
+            = c:class_type_signature() { JavaTypeSignature::Class(c) } /
+              type_variable_signature() /
+              "[" t:java_type_signature() { JavaTypeSignature::Array(Box::new(t)) }
+
+        rule class_type_signature() -> ClassTypeSignature
+            = "L" package:$((identifier() "/")*) first:simple_class_type_signature() suffix:("." s:simple_class_type_signature() { s })* ";" {
+                let mut classes = vec![first];
+                classes.extend(suffix);
+                ClassTypeSignature { package: package.to_string(), classes }
+            }
+
+        rule simple_class_type_signature() -> SimpleClassTypeSignature
+            = name:identifier() type_arguments:type_arguments()? {
+                SimpleClassTypeSignature { name, type_arguments: type_arguments.unwrap_or_default() }
+            }
+
+        rule type_arguments() -> Vec<TypeArgument>
+            = "<" arguments:type_argument()+ ">" { arguments }
+
+        rule type_argument() -> TypeArgument
+            = "*" { TypeArgument::Any } /
+              "+" t:reference_type_signature() { TypeArgument::Extends(t) } /
+              "-" t:reference_type_signature() { TypeArgument::Super(t) } /
+              t:reference_type_signature() { TypeArgument::Exact(t) }
+
+        rule type_variable_signature() -> JavaTypeSignature
+            = "T" name:identifier() ";" { JavaTypeSignature::TypeVariable(name) }
+
+        rule type_parameters() -> Vec<TypeParameter>
+            = "<" parameters:type_parameter()+ ">" { parameters }
+
+        rule type_parameter() -> TypeParameter
+            = name:identifier() ":" class_bound:reference_type_signature()? interface_bounds:(":" t:reference_type_signature() { t })* {
+                TypeParameter { name, class_bound, interface_bounds }
+            }
+
+        pub rule class_signature() -> ClassSignature
+            = type_parameters:type_parameters()? super_class:class_type_signature() super_interfaces:class_type_signature()* {
+                ClassSignature { type_parameters: type_parameters.unwrap_or_default(), super_class, super_interfaces }
+            }
+
+        pub rule method_signature() -> MethodSignature
+            = type_parameters:type_parameters()? "(" parameter_types:java_type_signature()* ")" result:result() throws:("^" t:reference_type_signature() { t })* {
+                MethodSignature { type_parameters: type_parameters.unwrap_or_default(), parameter_types, result, throws }
+            }
+
+        rule result() -> Option<JavaTypeSignature>
+            = t:java_type_signature() { Some(t) } /
+              "V" { None }
+    }
+}
+
+pub fn parse_class_signature(signature: &str) -> Result<ClassSignature> {
+    signature_parser::class_signature(signature).or(error(format!("invalid class signature: {}", signature)))
+}
+
+pub fn parse_method_signature(signature: &str) -> Result<MethodSignature> {
+    signature_parser::method_signature(signature).or(error(format!("invalid method signature: {}", signature)))
+}
+
+/// The signature of a field, or of a local variable in LocalVariableTypeTable.
+pub fn parse_field_signature(signature: &str) -> Result<JavaTypeSignature> {
+    signature_parser::reference_type_signature(signature).or(error(format!("invalid field signature: {}", signature)))
+}
+
+#[test]
+fn test_parse_class_signature() {
+    // class Box<T extends Comparable<? super T>> extends java.util.AbstractList<T> implements java.io.Serializable
+    assert_eq!(
+        parse_class_signature("<T::Ljava/lang/Comparable<-TT;>;>Ljava/util/AbstractList<TT;>;Ljava/io/Serializable;"),
+        Ok(ClassSignature {
+            type_parameters: vec![TypeParameter {
+                name: "T".to_string(),
+                class_bound: None,
+                interface_bounds: vec![JavaTypeSignature::Class(ClassTypeSignature {
+                    package: "java/lang/".to_string(),
+                    classes: vec![SimpleClassTypeSignature {
+                        name: "Comparable".to_string(),
+                        type_arguments: vec![TypeArgument::Super(JavaTypeSignature::TypeVariable("T".to_string()))],
+                    }],
+                })],
+            }],
+            super_class: ClassTypeSignature {
+                package: "java/util/".to_string(),
+                classes: vec![SimpleClassTypeSignature {
+                    name: "AbstractList".to_string(),
+                    type_arguments: vec![TypeArgument::Exact(JavaTypeSignature::TypeVariable("T".to_string()))],
+                }],
+            },
+            super_interfaces: vec![ClassTypeSignature {
+                package: "java/io/".to_string(),
+                classes: vec![SimpleClassTypeSignature { name: "Serializable".to_string(), type_arguments: vec![] }],
+            }],
+        })
+    );
+    assert!(parse_class_signature("<T:>Ljava/lang/Object").is_err());
+}
+
+#[test]
+fn test_parse_method_signature() {
+    // <K, V> java.util.Map.Entry<K, V>[] entries(java.util.Map<? extends K, ?> map, int n) throws E
+    let signature = parse_method_signature("<K:Ljava/lang/Object;V:Ljava/lang/Object;>(Ljava/util/Map<+TK;*>;I)[Ljava/util/Map$Entry<TK;TV;>;^TE;").unwrap();
+    assert_eq!(signature.type_parameters.iter().map(|parameter| parameter.name.as_str()).collect::<Vec<_>>(), vec!["K", "V"]);
+    assert_eq!(signature.parameter_types[1], JavaTypeSignature::Base(FieldType::Int));
+    match &signature.parameter_types[0] {
+        JavaTypeSignature::Class(class) => {
+            assert_eq!(class.name(), "java/util/Map");
+            assert_eq!(class.classes[0].type_arguments, vec![
+                TypeArgument::Extends(JavaTypeSignature::TypeVariable("K".to_string())),
+                TypeArgument::Any,
+            ]);
+        }
+        other => panic!("{:?}", other),
+    }
+    match &signature.result {
+        Some(JavaTypeSignature::Array(component)) => match component.as_ref() {
+            JavaTypeSignature::Class(class) => assert_eq!(class.name(), "java/util/Map$Entry"),
+            other => panic!("{:?}", other),
+        },
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(signature.throws, vec![JavaTypeSignature::TypeVariable("E".to_string())]);
+
+    // Map.Entry written as an inner class of a parameterized type
+    let signature = parse_method_signature("()LOuter<Ljava/lang/String;>.Inner;").unwrap();
+    match signature.result {
+        Some(JavaTypeSignature::Class(class)) => {
+            assert_eq!(class.name(), "Outer$Inner");
+            assert_eq!(class.classes.len(), 2);
+        }
+        other => panic!("{:?}", other),
+    }
+}
@@ -1,6 +1,14 @@
+use super::constant_pool::{
+    ConstantClassInfo, ConstantMethodHandleInfo, ConstantModuleInfo, ConstantNameAndTypeInfo, ConstantPackageInfo, ConstantUtf8Info, CpIndex, CpInfo,
+};
+use crate::class_file::access_flags::{
+    ExportsAccessFlags, InnerClassAccessFlags, ModuleAccessFlags, ParameterAccessFlags, RequiresAccessFlags,
+};
+
 /// 4.7. Attributes
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Attribute {
     General(AttributeInfo),
     ConstantValue(ConstantValueAttribute),
@@ -9,6 +17,9 @@ pub enum Attribute {
     Exceptions(ExceptionsAttribute),
     InnerClasses(InnerClassesAttribute),
     EnclosingMethod(EnclosingMethodAttribute),
+    Signature(SignatureAttribute),
+    LocalVariableTable(LocalVariableTableAttribute),
+    LocalVariableTypeTable(LocalVariableTypeTableAttribute),
     BootstrapMethods(BootstrapMethodsAttribute),
     MethodParameters(MethodParametersAttribute),
     Module(ModuleAttribute),
@@ -18,25 +29,28 @@ pub enum Attribute {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeInfo {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub info: Vec<u8>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantValueAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
-    pub constantvalue_index: u16,
+    pub constantvalue_index: CpIndex<CpInfo>,
 }
 
 // 4.7.3. The Code Attribute
 // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.3
 // The Code attribute is a variable-length attribute in the attributes table of a method_info structure
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CodeAttributeInfo {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub max_stack: u16,
     pub max_locals: u16,
@@ -49,11 +63,12 @@ pub struct CodeAttributeInfo {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExceptionTable {
     pub start_pc: u16,
     pub end_pc: u16,
     pub handler_pc: u16,
-    pub catch_type: u16,
+    pub catch_type: CpIndex<ConstantClassInfo>,
 }
 
 // 4.7.4. The StackMapTable Attribute
@@ -61,15 +76,17 @@ pub struct ExceptionTable {
 // The StackMapTable attribute is a variable-length attribute in the attributes table of a Code attribute (§4.7.3).
 // StackMapTable attribute is used during the process of verification by type checking (§4.10.1).
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 pub struct StackMapTableAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub number_of_entries: u16,
     pub entries: Vec<StackMapFrame>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 pub enum StackMapFrame {
     SameFrame {
@@ -115,6 +132,7 @@ pub enum StackMapFrame {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 pub enum VerificationTypeInfo {
     TopVariableInfo {
@@ -141,7 +159,7 @@ pub enum VerificationTypeInfo {
     ObjectVariableInfo {
         tag: u8,
         // = ITEM_Object; /* 7 */
-        cpool_index: u16,
+        cpool_index: CpIndex<ConstantClassInfo>,
     },
     UninitializedVariableInfo {
         tag: u8,
@@ -153,83 +171,144 @@ pub enum VerificationTypeInfo {
 /// 4.7.5. The Exceptions Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.5
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExceptionsAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub number_of_exceptions: u16,
-    pub exception_index_table: Vec<u16>
+    pub exception_index_table: Vec<CpIndex<ConstantClassInfo>>
 }
 
 /// 4.7.6. The InnerClasses Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.6
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InnerClassesAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub number_of_classes: u16,
     pub classes: Vec<InnerClassesAttributeClass>
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InnerClassesAttributeClass {
-    pub inner_class_info_index: u16,
-    pub outer_class_info_index: u16,
-    pub inner_name_index: u16,
-    pub inner_class_access_flags: u16,
+    pub inner_class_info_index: CpIndex<ConstantClassInfo>,
+    pub outer_class_info_index: CpIndex<ConstantClassInfo>,
+    pub inner_name_index: CpIndex<ConstantUtf8Info>,
+    pub inner_class_access_flags: InnerClassAccessFlags,
 }
 
 /// 4.7.7. The EnclosingMethod Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.7
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnclosingMethodAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
+    pub attribute_length: u32,
+    pub class_index: CpIndex<ConstantClassInfo>,
+    pub method_index: CpIndex<ConstantNameAndTypeInfo>,
+}
+
+/// 4.7.9. The Signature Attribute
+/// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.9
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignatureAttribute {
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
-    pub class_index: u16,
-    pub method_index: u16,
+    pub signature_index: CpIndex<ConstantUtf8Info>,
+}
+
+/// 4.7.13. The LocalVariableTable Attribute
+/// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.13
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalVariableTableAttribute {
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
+    pub attribute_length: u32,
+    pub local_variable_table_length: u16,
+    pub local_variable_table: Vec<LocalVariable>,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalVariable {
+    pub start_pc: u16,
+    pub length: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
+    pub descriptor_index: CpIndex<ConstantUtf8Info>,
+    pub index: u16,
+}
+
+/// 4.7.14. The LocalVariableTypeTable Attribute
+/// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.14
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalVariableTypeTableAttribute {
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
+    pub attribute_length: u32,
+    pub local_variable_type_table_length: u16,
+    pub local_variable_type_table: Vec<LocalVariableType>,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalVariableType {
+    pub start_pc: u16,
+    pub length: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
+    pub signature_index: CpIndex<ConstantUtf8Info>,
+    pub index: u16,
 }
 
 /// 4.7.23. The BootstrapMethods Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.23
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BootstrapMethodsAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub num_bootstrap_methods: u16,
     pub bootstrap_methods: Vec<BootstrapMethod>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BootstrapMethod {
-    pub bootstrap_method_ref: u16,
+    pub bootstrap_method_ref: CpIndex<ConstantMethodHandleInfo>,
     pub num_bootstrap_arguments: u16,
-    pub bootstrap_arguments: Vec<u16>,
+    pub bootstrap_arguments: Vec<CpIndex<CpInfo>>,
 }
 
 /// 4.7.24. The MethodParameters Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.24
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodParametersAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub parameters_count: u8,
     pub parameters: Vec<Parameter>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parameter {
-    pub name_index: u16,
-    pub access_flags: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
+    pub access_flags: ParameterAccessFlags,
 }
 
 /// 4.7.25. The Module Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.25
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModuleAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
-    pub module_name_index: u16,
-    pub module_flags: u16,
-    pub module_version_index: u16,
+    pub module_name_index: CpIndex<ConstantModuleInfo>,
+    pub module_flags: ModuleAccessFlags,
+    pub module_version_index: CpIndex<ConstantUtf8Info>,
     pub requires_count: u16,
     pub requires: Vec<Require>,
     pub exports_count: u16,
@@ -237,66 +316,73 @@ pub struct ModuleAttribute {
     pub opens_count: u16,
     pub opens: Vec<Open>,
     pub uses_count: u16,
-    pub uses_index: Vec<u16>,
+    pub uses_index: Vec<CpIndex<ConstantClassInfo>>,
     pub provides_count: u16,
     pub provides: Vec<Provide>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Require {
-    pub requires_index: u16,
-    pub requires_flags: u16,
-    pub requires_version_index: u16,
+    pub requires_index: CpIndex<ConstantModuleInfo>,
+    pub requires_flags: RequiresAccessFlags,
+    pub requires_version_index: CpIndex<ConstantUtf8Info>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Export {
-    pub exports_index: u16,
-    pub exports_flags: u16,
+    pub exports_index: CpIndex<ConstantPackageInfo>,
+    pub exports_flags: ExportsAccessFlags,
     pub exports_to_count: u16,
-    pub exports_to_index: Vec<u16>,
+    pub exports_to_index: Vec<CpIndex<ConstantModuleInfo>>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Open {
-    pub opens_index: u16,
-    pub opens_flags: u16,
+    pub opens_index: CpIndex<ConstantPackageInfo>,
+    pub opens_flags: ExportsAccessFlags,
     pub opens_to_count: u16,
-    pub opens_to_index: Vec<u16>,
+    pub opens_to_index: Vec<CpIndex<ConstantModuleInfo>>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Provide {
-    pub provides_index: u16,
+    pub provides_index: CpIndex<ConstantClassInfo>,
     pub provides_with_count: u16,
-    pub provides_with_index: Vec<u16>,
+    pub provides_with_index: Vec<CpIndex<ConstantClassInfo>>,
 }
 
 /// 4.7.28. The NestHost Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.28
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NestHostAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
-    pub host_class_index: u16,
+    pub host_class_index: CpIndex<ConstantClassInfo>,
 }
 
 /// 4.7.29. The NestMembers Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.29
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NestMembersAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub number_of_classes: u16,
-    pub classes: Vec<u16>
+    pub classes: Vec<CpIndex<ConstantClassInfo>>
 }
 
 /// 4.7.31. The PermittedSubclasses Attribute
 /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.31
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PermittedSubclassesAttribute {
-    pub attribute_name_index: u16,
+    pub attribute_name_index: CpIndex<ConstantUtf8Info>,
     pub attribute_length: u32,
     pub number_of_classes: u16,
-    pub classes: Vec<u16>
+    pub classes: Vec<CpIndex<ConstantClassInfo>>
 }
\ No newline at end of file
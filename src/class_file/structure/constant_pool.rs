@@ -1,11 +1,64 @@
+use std::fmt;
+use std::marker::PhantomData;
+
 // 4.4. The Constant Pool
 // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.4
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantPool {
     pub constant_pool_count: u16,
     pub cp_infos: Vec<CpInfo>,
 }
 
+/// An index into the constant pool that must refer to an entry of the type `T`, e.g. `CpIndex<ConstantUtf8Info>`.
+/// It is a u2 in the class file, and the type of the entry is checked when it is looked up with CpAccessor.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent, bound = ""))]
+pub struct CpIndex<T> {
+    index: u16,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    entry: PhantomData<fn() -> T>,
+}
+
+impl<T> CpIndex<T> {
+    pub const fn new(index: u16) -> CpIndex<T> {
+        CpIndex { index, entry: PhantomData }
+    }
+
+    pub const fn index(self) -> u16 {
+        self.index
+    }
+}
+
+// Derives would require the bounds on T.
+impl<T> Clone for CpIndex<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CpIndex<T> {}
+
+impl<T> PartialEq for CpIndex<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for CpIndex<T> {}
+
+// Shown as the number itself, so that the structures are printed as when the index was a u16, e.g. by `{:#04x?}`.
+impl<T> fmt::Debug for CpIndex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.index, f)
+    }
+}
+
+impl<T> fmt::Display for CpIndex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.index)
+    }
+}
+
 pub type CpInfoTag = u8;
 
 pub const CONSTANT_UTF8: CpInfoTag = 1;
@@ -27,25 +80,57 @@ pub const CONSTANT_MODULE: CpInfoTag = 19;
 pub const CONSTANT_PACKAGE: CpInfoTag = 20;
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantUtf8Info {
     pub tag: CpInfoTag,
     pub length: u16,
+    #[cfg_attr(feature = "serde", serde(with = "utf8_bytes"))]
     pub bytes: Vec<u8>,
 }
 
+// The bytes are a string when they are valid UTF-8, and the numbers otherwise, e.g. for "\0" in modified UTF-8.
+#[cfg(feature = "serde")]
+mod utf8_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(bytes) {
+            Ok(string) => serializer.serialize_str(string),
+            Err(_) => serializer.collect_seq(bytes),
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Utf8Bytes {
+        String(String),
+        Bytes(Vec<u8>),
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        Ok(match Utf8Bytes::deserialize(deserializer)? {
+            Utf8Bytes::String(string) => string.into_bytes(),
+            Utf8Bytes::Bytes(bytes) => bytes,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantIntegerInfo {
     pub tag: CpInfoTag,
     pub bytes: [u8; 4],
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantFloatInfo {
     pub tag: CpInfoTag,
     pub bytes: [u8; 4],
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantLongInfo {
     pub tag: CpInfoTag,
     pub high_bytes: [u8; 4],
@@ -53,6 +138,7 @@ pub struct ConstantLongInfo {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantDoubleInfo {
     pub tag: CpInfoTag,
     pub high_bytes: [u8; 4],
@@ -60,85 +146,98 @@ pub struct ConstantDoubleInfo {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantClassInfo {
     pub tag: CpInfoTag,
-    pub name_index: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantStringInfo {
     pub tag: CpInfoTag,
-    pub string_index: u16,
+    pub string_index: CpIndex<ConstantUtf8Info>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantFieldrefInfo {
     pub tag: CpInfoTag,
-    pub class_index: u16,
-    pub name_and_type_index: u16,
+    pub class_index: CpIndex<ConstantClassInfo>,
+    pub name_and_type_index: CpIndex<ConstantNameAndTypeInfo>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantMethodrefInfo {
     pub tag: CpInfoTag,
-    pub class_index: u16,
-    pub name_and_type_index: u16,
+    pub class_index: CpIndex<ConstantClassInfo>,
+    pub name_and_type_index: CpIndex<ConstantNameAndTypeInfo>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantInterfaceMethodrefInfo {
     pub tag: CpInfoTag,
-    pub class_index: u16,
-    pub name_and_type_index: u16,
+    pub class_index: CpIndex<ConstantClassInfo>,
+    pub name_and_type_index: CpIndex<ConstantNameAndTypeInfo>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantNameAndTypeInfo {
     pub tag: CpInfoTag,
-    pub name_index: u16,
-    pub descriptor_index: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
+    pub descriptor_index: CpIndex<ConstantUtf8Info>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantMethodHandleInfo {
     pub tag: CpInfoTag,
     pub reference_kind: u8,
-    pub reference_index: u16,
+    pub reference_index: CpIndex<CpInfo>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantMethodTypeInfo {
     pub tag: CpInfoTag,
-    pub descriptor_index: u16,
+    pub descriptor_index: CpIndex<ConstantUtf8Info>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantDynamicInfo {
     pub tag: CpInfoTag,
     pub bootstrap_method_attr_index: u16,
-    pub name_and_type_index: u16,
+    pub name_and_type_index: CpIndex<ConstantNameAndTypeInfo>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantInvokeDynamicInfo {
     pub tag: CpInfoTag,
     pub bootstrap_method_attr_index: u16,
-    pub name_and_type_index: u16,
+    pub name_and_type_index: CpIndex<ConstantNameAndTypeInfo>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantModuleInfo {
     pub tag: CpInfoTag,
-    pub name_index: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantPackageInfo {
     pub tag: CpInfoTag,
-    pub name_index: u16,
+    pub name_index: CpIndex<ConstantUtf8Info>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CpInfo {
     Utf8(ConstantUtf8Info),
     Integer(ConstantIntegerInfo),
@@ -157,4 +256,8 @@ pub enum CpInfo {
     InvokeDynamic(ConstantInvokeDynamicInfo),
     Module(ConstantModuleInfo),
     Package(ConstantPackageInfo),
+    /// The slot after a CONSTANT_Long_info or CONSTANT_Double_info, which takes up two entries
+    /// but has nothing in the class file. It keeps the entries at their indexes in the Vec.
+    /// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.4.5
+    Unusable,
 }
\ No newline at end of file
@@ -1,20 +1,23 @@
 use super::constant_pool;
+use super::constant_pool::{ConstantClassInfo, ConstantUtf8Info, CpIndex};
 use super::attribute;
+use crate::class_file::access_flags::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
 
 // 4.1. The ClassFile Structure
 // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.1
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassFile {
     pub magic: [u8; 4],
     pub minor_version: u16,
     pub major_version: u16,
     pub constant_pool_count: u16,
     pub constant_pool: Vec<constant_pool::CpInfo>,
-    pub access_flags: u16,
-    pub this_class: u16,
-    pub super_class: u16,
+    pub access_flags: ClassAccessFlags,
+    pub this_class: CpIndex<ConstantClassInfo>,
+    pub super_class: CpIndex<ConstantClassInfo>,
     pub interfaces_count: u16,
-    pub interfaces: Vec<u16>,
+    pub interfaces: Vec<CpIndex<ConstantClassInfo>>,
     pub fields_count: u16,
     pub fields: Vec<FieldsInfo>,
     pub methods_count: u16,
@@ -24,19 +27,21 @@ pub struct ClassFile {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldsInfo {
-    pub access_flags: u16,
-    pub name_index: u16,
-    pub descriptor_index: u16,
+    pub access_flags: FieldAccessFlags,
+    pub name_index: CpIndex<ConstantUtf8Info>,
+    pub descriptor_index: CpIndex<ConstantUtf8Info>,
     pub attributes_count: u16,
     pub attributes: Vec<attribute::Attribute>,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfo {
-    pub access_flags: u16,
-    pub name_index: u16,
-    pub descriptor_index: u16,
+    pub access_flags: MethodAccessFlags,
+    pub name_index: CpIndex<ConstantUtf8Info>,
+    pub descriptor_index: CpIndex<ConstantUtf8Info>,
     pub attributes_count: u16,
     pub attributes: Vec<attribute::Attribute>,
 }
\ No newline at end of file
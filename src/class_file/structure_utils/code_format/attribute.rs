@@ -1,5 +1,5 @@
 use crate::class_file::structure::attribute::*;
-use super::padding;
+use super::{padding, flags_comment};
 use std::fmt;
 
 
@@ -13,6 +13,9 @@ impl fmt::Display for Attribute {
             Attribute::Exceptions(attribute) => attribute.fmt(f),
             Attribute::InnerClasses(attribute) => attribute.fmt(f),
             Attribute::EnclosingMethod(attribute) => attribute.fmt(f),
+            Attribute::Signature(attribute) => attribute.fmt(f),
+            Attribute::LocalVariableTable(attribute) => attribute.fmt(f),
+            Attribute::LocalVariableTypeTable(attribute) => attribute.fmt(f),
             Attribute::BootstrapMethods(attribute) => attribute.fmt(f),
             Attribute::MethodParameters(attribute) => attribute.fmt(f),
             Attribute::Module(attribute) => attribute.fmt(f),
@@ -190,7 +193,7 @@ impl fmt::Display for InnerClassesAttributeClass {
         write!(f, "    inner_class_info_index: {},\n", self.inner_class_info_index)?;
         write!(f, "    outer_class_info_index: {},\n", self.outer_class_info_index)?;
         write!(f, "    inner_name_index: {},\n", self.inner_name_index)?;
-        write!(f, "    inner_class_access_flags: {},\n", self.inner_class_access_flags)?;
+        writeln!(f, "    inner_class_access_flags: {:#06x?},{}", self.inner_class_access_flags, flags_comment(self.inner_class_access_flags))?;
         write!(f, "}}")?;
         Ok(())
     }
@@ -208,6 +211,65 @@ impl fmt::Display for EnclosingMethodAttribute {
     }
 }
 
+impl fmt::Display for SignatureAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Attribute::Signature(SignatureAttribute {{")?;
+        writeln!(f, "    attribute_name_index: {},", self.attribute_name_index)?;
+        writeln!(f, "    attribute_length: {},", self.attribute_length)?;
+        writeln!(f, "    signature_index: {},", self.signature_index)?;
+        write!(f, "}})")?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for LocalVariableTableAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Attribute::LocalVariableTable(LocalVariableTableAttribute {{")?;
+        writeln!(f, "    attribute_name_index: {},", self.attribute_name_index)?;
+        writeln!(f, "    attribute_length: {},", self.attribute_length)?;
+        writeln!(f, "    local_variable_table_length: {},", self.local_variable_table_length)?;
+        writeln!(f, "    local_variable_table: vec![")?;
+        self.local_variable_table.iter().try_for_each(|local_variable| {
+            writeln!(f, "{},", padding(local_variable.to_string(), 8))
+        })?;
+        writeln!(f, "    ]")?;
+        write!(f, "}})")?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for LocalVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LocalVariable {{ start_pc: {}, length: {}, name_index: {}, descriptor_index: {}, index: {} }},",
+               self.start_pc, self.length, self.name_index, self.descriptor_index, self.index)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for LocalVariableTypeTableAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Attribute::LocalVariableTypeTable(LocalVariableTypeTableAttribute {{")?;
+        writeln!(f, "    attribute_name_index: {},", self.attribute_name_index)?;
+        writeln!(f, "    attribute_length: {},", self.attribute_length)?;
+        writeln!(f, "    local_variable_type_table_length: {},", self.local_variable_type_table_length)?;
+        writeln!(f, "    local_variable_type_table: vec![")?;
+        self.local_variable_type_table.iter().try_for_each(|local_variable_type| {
+            writeln!(f, "{},", padding(local_variable_type.to_string(), 8))
+        })?;
+        writeln!(f, "    ]")?;
+        write!(f, "}})")?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for LocalVariableType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LocalVariableType {{ start_pc: {}, length: {}, name_index: {}, signature_index: {}, index: {} }},",
+               self.start_pc, self.length, self.name_index, self.signature_index, self.index)?;
+        Ok(())
+    }
+}
+
 impl fmt::Display for BootstrapMethodsAttribute {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Attribute::BootstrapMethods(BootstrapMethodsAttribute {{\n")?;
@@ -244,7 +306,7 @@ impl fmt::Display for MethodParametersAttribute {
         write!(f, "    parameters_count: {},\n", self.parameters_count)?;
         write!(f, "    parameters: vec![\n")?;
         self.parameters.iter().try_for_each(|parameter| {
-            write!(f, "{},\n", padding(parameter.to_string(), 8))
+            writeln!(f, "{},{}", padding(parameter.to_string(), 8), flags_comment(parameter.access_flags))
         })?;
         write!(f, "    ]\n")?;
         write!(f, "}})")?;
@@ -266,7 +328,7 @@ impl fmt::Display for ModuleAttribute {
         write!(f, "    attribute_name_index: {},\n", self.attribute_name_index)?;
         write!(f, "    attribute_length: {},\n", self.attribute_length)?;
         write!(f, "    module_name_index: {},\n", self.module_name_index)?;
-        write!(f, "    module_flags: {},\n", self.module_flags)?;
+        writeln!(f, "    module_flags: {:#06x?},{}", self.module_flags, flags_comment(self.module_flags))?;
         write!(f, "    module_version_index: {},\n", self.module_version_index)?;
         write!(f, "    requires_count: {},\n", self.requires_count)?;
         write!(f, "    requires: vec![\n")?;
@@ -304,7 +366,7 @@ impl fmt::Display for Require {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Require {{\n")?;
         write!(f, "    requires_index: {},\n", self.requires_index)?;
-        write!(f, "    requires_flags: {},\n", self.requires_flags)?;
+        writeln!(f, "    requires_flags: {:#06x?},{}", self.requires_flags, flags_comment(self.requires_flags))?;
         write!(f, "    requires_version_index: {},\n", self.requires_version_index)?;
         write!(f, "}}")?;
         Ok(())
@@ -315,7 +377,7 @@ impl fmt::Display for Export {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Export {{\n")?;
         write!(f, "    exports_index: {},\n", self.exports_index)?;
-        write!(f, "    exports_flags: {},\n", self.exports_flags)?;
+        writeln!(f, "    exports_flags: {:#06x?},{}", self.exports_flags, flags_comment(self.exports_flags))?;
         write!(f, "    exports_to_count: {},\n", self.exports_to_count)?;
         let exports_to_index_str: String = self.exports_to_index.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(", ");
         write!(f, "    exports_to_index: vec![{}],\n", exports_to_index_str)?;
@@ -328,7 +390,7 @@ impl fmt::Display for Open {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Open {{\n")?;
         write!(f, "    opens_index: {},\n", self.opens_index)?;
-        write!(f, "    opens_flags: {},\n", self.opens_flags)?;
+        writeln!(f, "    opens_flags: {:#06x?},{}", self.opens_flags, flags_comment(self.opens_flags))?;
         write!(f, "    opens_to_count: {},\n", self.opens_to_count)?;
         let opens_to_index_str: String = self.opens_to_index.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(", ");
         write!(f, "    opens_to_index: vec![{}],\n", opens_to_index_str)?;
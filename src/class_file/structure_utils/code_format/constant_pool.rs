@@ -93,6 +93,7 @@ impl fmt::Display for CpInfo {
                         info.tag, info.name_index
                 )
             }
+            CpInfo::Unusable => "CpInfo::Unusable".to_string(),
         };
         write!(f, "{}", str)
     }
@@ -5,3 +5,8 @@ mod root;
 fn padding(str: String, n: usize) -> String {
     str.lines().map(|x| format!("{}{}", " ".repeat(n), x)).collect::<Vec<String>>().join("\n")
 }
+
+// " // ACC_PUBLIC | ACC_SUPER" after a flags value, or nothing if no flag is set.
+fn flags_comment(flags: impl crate::class_file::access_flags::AccessFlags) -> String {
+    if flags.bits() == 0 { String::new() } else { format!(" // {:#}", flags) }
+}
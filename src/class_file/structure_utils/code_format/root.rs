@@ -1,5 +1,5 @@
 use crate::class_file::structure::root::*;
-use super::padding;
+use super::{padding, flags_comment};
 use std::fmt;
 
 impl fmt::Display for ClassFile {
@@ -30,8 +30,8 @@ impl fmt::Display for ClassFile {
         })?;
         write!(f, "    ],\n")?;
 
-        // access_flags: 0x0001,
-        write!(f, "    access_flags: {:#06x?},\n", self.access_flags)?;
+        // access_flags: 0x0021, // ACC_PUBLIC | ACC_SUPER
+        writeln!(f, "    access_flags: {:#06x?},{}", self.access_flags, flags_comment(self.access_flags))?;
 
         // this_class: 1,
         write!(f, "    this_class: {},\n", self.this_class)?;
@@ -85,7 +85,7 @@ impl fmt::Display for ClassFile {
 impl fmt::Display for FieldsInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "FieldsInfo {{\n")?;
-        write!(f, "    access_flags: {:#06x?},\n", self.access_flags)?;
+        writeln!(f, "    access_flags: {:#06x?},{}", self.access_flags, flags_comment(self.access_flags))?;
         write!(f, "    name_index: {},\n", self.name_index)?;
         write!(f, "    descriptor_index: {},\n", self.descriptor_index)?;
         write!(f, "    attributes_count: {},\n", self.attributes_count)?;
@@ -103,7 +103,7 @@ impl fmt::Display for FieldsInfo {
 impl fmt::Display for MethodInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "MethodInfo {{\n")?;
-        write!(f, "    access_flags: {:#06x?},\n", self.access_flags)?;
+        writeln!(f, "    access_flags: {:#06x?},{}", self.access_flags, flags_comment(self.access_flags))?;
         write!(f, "    name_index: {},\n", self.name_index)?;
         write!(f, "    descriptor_index: {},\n", self.descriptor_index)?;
         write!(f, "    attributes_count: {},\n", self.attributes_count)?;
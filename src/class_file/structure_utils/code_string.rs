@@ -1,7 +1,8 @@
+use crate::class_file::structure::attribute::*;
 use crate::class_file::structure::constant_pool::*;
 use crate::class_file::structure::root::*;
 
-enum Elem {
+pub enum Elem {
     Struct(
         &'static str,
         Vec<Elem>,
@@ -26,119 +27,479 @@ fn _string_lines(elem: &Elem, indent: usize) -> Vec<String> {
     }
 }
 
-trait CodeString {
+/// Rust source of an expression that builds the same structure, e.g. to write the expected value of a test.
+/// The expression uses the types in `structure::{root, constant_pool, attribute}` and `access_flags`.
+pub trait CodeString {
     fn to_elem(&self) -> Elem;
     fn code_string(&self) -> String {
         _string_lines(&self.to_elem(), 0).join("\n")
     }
 }
 
+fn bytes_string(bytes: &[u8]) -> String {
+    format!("vec![{}]", bytes.iter().map(|x| format!("{:#04x?}", x)).collect::<Vec<String>>().join(", "))
+}
+
+fn array_string(bytes: &[u8; 4]) -> String {
+    format!("[{}]", bytes.iter().map(|x| format!("{:#04x?}", x)).collect::<Vec<String>>().join(", "))
+}
+
+fn indexes_string<T>(indexes: &[CpIndex<T>]) -> String {
+    format!("vec![{}]", indexes.iter().map(|x| format!("CpIndex::new({})", x)).collect::<Vec<String>>().join(", "))
+}
+
+fn list_elem<T: CodeString>(header: &'static str, items: &[T]) -> Elem {
+    Elem::Struct(header, items.iter().map(|x| x.to_elem()).collect(), "],")
+}
+
+// A negative literal needs parentheses before a method call, as `-1_i32.to_be_bytes()` negates the array.
+fn literal(value: String) -> String {
+    if value.starts_with('-') { format!("({})", value) } else { value }
+}
+
 impl CodeString for ClassFile {
     fn to_elem(&self) -> Elem {
         Elem::Struct(
             "ClassFile {",
             vec![
-                Elem::Line(format!("magic: [{}],", self.magic.iter().map(|x| format!("{:#04x?}", x)).collect::<Vec<String>>().join(", "))),
+                Elem::Line(format!("magic: {},", array_string(&self.magic))),
                 Elem::Line(format!("minor_version: {},", self.minor_version)),
+                Elem::Line(format!("major_version: {},", self.major_version)),
                 Elem::Line(format!("constant_pool_count: {},", self.constant_pool_count)),
-                Elem::Struct("constant_pool: vec![", self.constant_pool.iter().map(|x| x.to_elem()).collect(), "],"),
-                Elem::Line(format!("access_flags: {:#06x?},", self.access_flags)),
-                Elem::Line(format!("this_class: {},", self.this_class)),
-                Elem::Line(format!("super_class: {},", self.super_class)),
+                list_elem("constant_pool: vec![", &self.constant_pool),
+                Elem::Line(format!("access_flags: ClassAccessFlags::from_bits_retain({:#06x?}),", self.access_flags)),
+                Elem::Line(format!("this_class: CpIndex::new({}),", self.this_class)),
+                Elem::Line(format!("super_class: CpIndex::new({}),", self.super_class)),
                 Elem::Line(format!("interfaces_count: {},", self.interfaces_count)),
-                Elem::Line(format!("interfaces: {},", self.interfaces)),
+                Elem::Line(format!("interfaces: {},", indexes_string(&self.interfaces))),
                 Elem::Line(format!("fields_count: {},", self.fields_count)),
-                Elem::Line(format!("fields: {},", self.fields)),
+                list_elem("fields: vec![", &self.fields),
                 Elem::Line(format!("methods_count: {},", self.methods_count)),
-                Elem::Line(format!("methods: {},", self.methods)),
+                list_elem("methods: vec![", &self.methods),
                 Elem::Line(format!("attributes_count: {},", self.attributes_count)),
-                Elem::Line(format!("attributes: {},", self.attributes)),
+                list_elem("attributes: vec![", &self.attributes),
             ],
             "}",
         )
     }
 }
 
+impl CodeString for FieldsInfo {
+    fn to_elem(&self) -> Elem {
+        Elem::Struct(
+            "FieldsInfo {",
+            vec![
+                Elem::Line(format!("access_flags: FieldAccessFlags::from_bits_retain({:#06x?}),", self.access_flags)),
+                Elem::Line(format!("name_index: CpIndex::new({}),", self.name_index)),
+                Elem::Line(format!("descriptor_index: CpIndex::new({}),", self.descriptor_index)),
+                Elem::Line(format!("attributes_count: {},", self.attributes_count)),
+                list_elem("attributes: vec![", &self.attributes),
+            ],
+            "},",
+        )
+    }
+}
+
+impl CodeString for MethodInfo {
+    fn to_elem(&self) -> Elem {
+        Elem::Struct(
+            "MethodInfo {",
+            vec![
+                Elem::Line(format!("access_flags: MethodAccessFlags::from_bits_retain({:#06x?}),", self.access_flags)),
+                Elem::Line(format!("name_index: CpIndex::new({}),", self.name_index)),
+                Elem::Line(format!("descriptor_index: CpIndex::new({}),", self.descriptor_index)),
+                Elem::Line(format!("attributes_count: {},", self.attributes_count)),
+                list_elem("attributes: vec![", &self.attributes),
+            ],
+            "},",
+        )
+    }
+}
+
 impl CodeString for CpInfo {
     fn to_elem(&self) -> Elem {
         let str = match self {
-            CpInfo::Utf8(info) =>
-                format!("CpInfo::Utf8(ConstantUtf8Info {{ tag: {}, length: {}, bytes: \"{}\".as_bytes().to_vec() }}),",
-                        info.tag, info.length, String::from_utf8(info.bytes.clone()).unwrap()),
+            CpInfo::Utf8(info) => match String::from_utf8(info.bytes.clone()) {
+                Ok(string) =>
+                    format!("CpInfo::Utf8(ConstantUtf8Info {{ tag: {}, length: {}, bytes: {:?}.as_bytes().to_vec() }}),",
+                            info.tag, info.length, string),
+                Err(_) =>
+                    format!("CpInfo::Utf8(ConstantUtf8Info {{ tag: {}, length: {}, bytes: {} }}),",
+                            info.tag, info.length, bytes_string(&info.bytes)),
+            },
             CpInfo::Integer(info) =>
-                format!("CpInfo::Integer(ConstantIntegerInfo {{ tag: {}, bytes: {}_i32.to_be_bytes() }}),",
-                        info.tag, i32::from_be_bytes(info.bytes)),
-            CpInfo::Float(info) =>
-                format!("CpInfo::Float(ConstantFloatInfo {{ tag: {}, bytes: {}_f32.to_be_bytes() }}),",
-                        info.tag, f32::from_be_bytes(info.bytes)),
+                format!("CpInfo::Integer(ConstantIntegerInfo {{ tag: {}, bytes: {}.to_be_bytes() }}),",
+                        info.tag, literal(format!("{}_i32", i32::from_be_bytes(info.bytes)))),
+            CpInfo::Float(info) => {
+                let value = f32::from_be_bytes(info.bytes);
+                // NaN and infinities have no literals, and the bits keep the payload of NaN.
+                let value = if value.is_finite() { literal(format!("{:?}_f32", value)) } else { format!("f32::from_bits({:#010x})", value.to_bits()) };
+                format!("CpInfo::Float(ConstantFloatInfo {{ tag: {}, bytes: {}.to_be_bytes() }}),", info.tag, value)
+            }
             CpInfo::Long(info) => {
                 let value = i64::from_be_bytes([info.high_bytes, info.low_bytes].concat().try_into().unwrap());
-                format!("CpInfo::Long(ConstantLongInfo {{ tag: {}, high_bytes: {}_i64.to_be_bytes()[0..=3], low_bytes: {}_i64.to_be_bytes()[4..=7] }}),",
-                        info.tag, value, value)
+                format!("CpInfo::Long(ConstantLongInfo {{ tag: {}, high_bytes: {}, low_bytes: {} }}), // {}",
+                        info.tag, array_string(&info.high_bytes), array_string(&info.low_bytes), value)
             }
             CpInfo::Double(info) => {
                 let value = f64::from_be_bytes([info.high_bytes, info.low_bytes].concat().try_into().unwrap());
-                format!("CpInfo::Double(ConstantDoubleInfo {{ tag: {}, high_bytes: {}_f64.to_be_bytes()[0..=3], low_bytes: {}_f64.to_be_bytes()[4..=7] }}),",
-                        info.tag, value, value)
+                format!("CpInfo::Double(ConstantDoubleInfo {{ tag: {}, high_bytes: {}, low_bytes: {} }}), // {:?}",
+                        info.tag, array_string(&info.high_bytes), array_string(&info.low_bytes), value)
             }
             CpInfo::Class(info) =>
-                format!("CpInfo::Class(ConstantClassInfo {{ tag: {}, name_index: {} }}),",
+                format!("CpInfo::Class(ConstantClassInfo {{ tag: {}, name_index: CpIndex::new({}) }}),",
                         info.tag, info.name_index),
             CpInfo::String(info) =>
-                format!("CpInfo::String(ConstantStringInfo {{ tag: {}, string_index: {} }}),",
+                format!("CpInfo::String(ConstantStringInfo {{ tag: {}, string_index: CpIndex::new({}) }}),",
                         info.tag, info.string_index),
             CpInfo::Fieldref(info) =>
-                format!("CpInfo::Fieldref(ConstantFieldrefInfo {{ tag: {}, class_index: {}, name_and_type_index: {} }}),",
+                format!("CpInfo::Fieldref(ConstantFieldrefInfo {{ tag: {}, class_index: CpIndex::new({}), name_and_type_index: CpIndex::new({}) }}),",
                         info.tag, info.class_index, info.name_and_type_index),
             CpInfo::Methodref(info) =>
-                format!("CpInfo::Methodref(ConstantMethodrefInfo {{ tag: {}, class_index: {}, name_and_type_index: {} }}),",
+                format!("CpInfo::Methodref(ConstantMethodrefInfo {{ tag: {}, class_index: CpIndex::new({}), name_and_type_index: CpIndex::new({}) }}),",
                         info.tag, info.class_index, info.name_and_type_index),
             CpInfo::InterfaceMethodref(info) =>
-                format!("CpInfo::InterfaceMethodref(ConstantInterfaceMethodrefInfo {{ tag: {}, class_index: {}, name_and_type_index: {} }}),",
+                format!("CpInfo::InterfaceMethodref(ConstantInterfaceMethodrefInfo {{ tag: {}, class_index: CpIndex::new({}), name_and_type_index: CpIndex::new({}) }}),",
                         info.tag, info.class_index, info.name_and_type_index),
             CpInfo::NameAndType(info) =>
-                format!("CpInfo::NameAndType(ConstantNameAndTypeInfo {{ tag: {}, name_index: {}, descriptor_index: {} }}),",
+                format!("CpInfo::NameAndType(ConstantNameAndTypeInfo {{ tag: {}, name_index: CpIndex::new({}), descriptor_index: CpIndex::new({}) }}),",
                         info.tag, info.name_index, info.descriptor_index),
             CpInfo::MethodHandle(info) =>
-                format!("CpInfo::MethodHandle(ConstantMethodHandleInfo {{ tag: {}, reference_kind: {}, reference_index: {} }}),",
+                format!("CpInfo::MethodHandle(ConstantMethodHandleInfo {{ tag: {}, reference_kind: {}, reference_index: CpIndex::new({}) }}),",
                         info.tag, info.reference_kind, info.reference_index),
             CpInfo::MethodType(info) =>
-                format!("CpInfo::MethodType(ConstantMethodTypeInfo {{ tag: {}, descriptor_index: {}, }}),",
+                format!("CpInfo::MethodType(ConstantMethodTypeInfo {{ tag: {}, descriptor_index: CpIndex::new({}) }}),",
                         info.tag, info.descriptor_index),
             CpInfo::Dynamic(info) =>
-                format!("CpInfo::Dynamic(ConstantDynamicInfo {{ tag: {}, bootstrap_method_attr_index: {}, name_and_type_index: {} }}),",
+                format!("CpInfo::Dynamic(ConstantDynamicInfo {{ tag: {}, bootstrap_method_attr_index: {}, name_and_type_index: CpIndex::new({}) }}),",
                         info.tag, info.bootstrap_method_attr_index, info.name_and_type_index),
             CpInfo::InvokeDynamic(info) =>
-                format!("CpInfo::InvokeDynamic(ConstantInvokeDynamicInfo {{ tag: {}, bootstrap_method_attr_index: {}, name_and_type_index: {} }}),",
+                format!("CpInfo::InvokeDynamic(ConstantInvokeDynamicInfo {{ tag: {}, bootstrap_method_attr_index: {}, name_and_type_index: CpIndex::new({}) }}),",
                         info.tag, info.bootstrap_method_attr_index, info.name_and_type_index),
             CpInfo::Module(info) =>
-                format!("CpInfo::Module(ConstantModuleInfo {{ tag: {}, name_index: {} }}),",
+                format!("CpInfo::Module(ConstantModuleInfo {{ tag: {}, name_index: CpIndex::new({}) }}),",
                         info.tag, info.name_index),
             CpInfo::Package(info) =>
-                format!("CpInfo::Package(ConstantPackageInfo {{ tag: {}, name_index: {} }}),",
+                format!("CpInfo::Package(ConstantPackageInfo {{ tag: {}, name_index: CpIndex::new({}) }}),",
                         info.tag, info.name_index),
+            CpInfo::Unusable => "CpInfo::Unusable,".to_string(),
+        };
+        Elem::Line(str)
+    }
+}
+
+impl CodeString for Attribute {
+    fn to_elem(&self) -> Elem {
+        match self {
+            Attribute::General(attribute) => Elem::Struct(
+                "Attribute::General(AttributeInfo {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("info: {},", bytes_string(&attribute.info))),
+                ],
+                "}),",
+            ),
+            Attribute::ConstantValue(attribute) => Elem::Struct(
+                "Attribute::ConstantValue(ConstantValueAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("constantvalue_index: CpIndex::new({}),", attribute.constantvalue_index)),
+                ],
+                "}),",
+            ),
+            Attribute::Code(attribute) => Elem::Struct(
+                "Attribute::Code(CodeAttributeInfo {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("max_stack: {},", attribute.max_stack)),
+                    Elem::Line(format!("max_locals: {},", attribute.max_locals)),
+                    Elem::Line(format!("code_length: {},", attribute.code_length)),
+                    Elem::Line(format!("code: {},", bytes_string(&attribute.code))),
+                    Elem::Line(format!("exception_table_length: {},", attribute.exception_table_length)),
+                    list_elem("exception_table: vec![", &attribute.exception_table),
+                    Elem::Line(format!("attributes_count: {},", attribute.attributes_count)),
+                    list_elem("attributes: vec![", &attribute.attributes),
+                ],
+                "}),",
+            ),
+            Attribute::StackMapTable(attribute) => Elem::Struct(
+                "Attribute::StackMapTable(StackMapTableAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("number_of_entries: {},", attribute.number_of_entries)),
+                    list_elem("entries: vec![", &attribute.entries),
+                ],
+                "}),",
+            ),
+            Attribute::Exceptions(attribute) => Elem::Struct(
+                "Attribute::Exceptions(ExceptionsAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("number_of_exceptions: {},", attribute.number_of_exceptions)),
+                    Elem::Line(format!("exception_index_table: {},", indexes_string(&attribute.exception_index_table))),
+                ],
+                "}),",
+            ),
+            Attribute::InnerClasses(attribute) => Elem::Struct(
+                "Attribute::InnerClasses(InnerClassesAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("number_of_classes: {},", attribute.number_of_classes)),
+                    list_elem("classes: vec![", &attribute.classes),
+                ],
+                "}),",
+            ),
+            Attribute::EnclosingMethod(attribute) => Elem::Struct(
+                "Attribute::EnclosingMethod(EnclosingMethodAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("class_index: CpIndex::new({}),", attribute.class_index)),
+                    Elem::Line(format!("method_index: CpIndex::new({}),", attribute.method_index)),
+                ],
+                "}),",
+            ),
+            Attribute::Signature(attribute) => Elem::Struct(
+                "Attribute::Signature(SignatureAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("signature_index: CpIndex::new({}),", attribute.signature_index)),
+                ],
+                "}),",
+            ),
+            Attribute::LocalVariableTable(attribute) => Elem::Struct(
+                "Attribute::LocalVariableTable(LocalVariableTableAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("local_variable_table_length: {},", attribute.local_variable_table_length)),
+                    list_elem("local_variable_table: vec![", &attribute.local_variable_table),
+                ],
+                "}),",
+            ),
+            Attribute::LocalVariableTypeTable(attribute) => Elem::Struct(
+                "Attribute::LocalVariableTypeTable(LocalVariableTypeTableAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("local_variable_type_table_length: {},", attribute.local_variable_type_table_length)),
+                    list_elem("local_variable_type_table: vec![", &attribute.local_variable_type_table),
+                ],
+                "}),",
+            ),
+            Attribute::BootstrapMethods(attribute) => Elem::Struct(
+                "Attribute::BootstrapMethods(BootstrapMethodsAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("num_bootstrap_methods: {},", attribute.num_bootstrap_methods)),
+                    list_elem("bootstrap_methods: vec![", &attribute.bootstrap_methods),
+                ],
+                "}),",
+            ),
+            Attribute::MethodParameters(attribute) => Elem::Struct(
+                "Attribute::MethodParameters(MethodParametersAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("parameters_count: {},", attribute.parameters_count)),
+                    list_elem("parameters: vec![", &attribute.parameters),
+                ],
+                "}),",
+            ),
+            Attribute::Module(attribute) => Elem::Struct(
+                "Attribute::Module(ModuleAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("module_name_index: CpIndex::new({}),", attribute.module_name_index)),
+                    Elem::Line(format!("module_flags: ModuleAccessFlags::from_bits_retain({:#06x?}),", attribute.module_flags)),
+                    Elem::Line(format!("module_version_index: CpIndex::new({}),", attribute.module_version_index)),
+                    Elem::Line(format!("requires_count: {},", attribute.requires_count)),
+                    list_elem("requires: vec![", &attribute.requires),
+                    Elem::Line(format!("exports_count: {},", attribute.exports_count)),
+                    list_elem("exports: vec![", &attribute.exports),
+                    Elem::Line(format!("opens_count: {},", attribute.opens_count)),
+                    list_elem("opens: vec![", &attribute.opens),
+                    Elem::Line(format!("uses_count: {},", attribute.uses_count)),
+                    Elem::Line(format!("uses_index: {},", indexes_string(&attribute.uses_index))),
+                    Elem::Line(format!("provides_count: {},", attribute.provides_count)),
+                    list_elem("provides: vec![", &attribute.provides),
+                ],
+                "}),",
+            ),
+            Attribute::NestHost(attribute) => Elem::Struct(
+                "Attribute::NestHost(NestHostAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("host_class_index: CpIndex::new({}),", attribute.host_class_index)),
+                ],
+                "}),",
+            ),
+            Attribute::NestMembers(attribute) => Elem::Struct(
+                "Attribute::NestMembers(NestMembersAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("number_of_classes: {},", attribute.number_of_classes)),
+                    Elem::Line(format!("classes: {},", indexes_string(&attribute.classes))),
+                ],
+                "}),",
+            ),
+            Attribute::PermittedSubclasses(attribute) => Elem::Struct(
+                "Attribute::PermittedSubclasses(PermittedSubclassesAttribute {",
+                vec![
+                    Elem::Line(format!("attribute_name_index: CpIndex::new({:#06x?}),", attribute.attribute_name_index.index())),
+                    Elem::Line(format!("attribute_length: {},", attribute.attribute_length)),
+                    Elem::Line(format!("number_of_classes: {},", attribute.number_of_classes)),
+                    Elem::Line(format!("classes: {},", indexes_string(&attribute.classes))),
+                ],
+                "}),",
+            ),
+        }
+    }
+}
+
+impl CodeString for ExceptionTable {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("ExceptionTable {{ start_pc: {}, end_pc: {}, handler_pc: {}, catch_type: CpIndex::new({}) }},",
+                           self.start_pc, self.end_pc, self.handler_pc, self.catch_type))
+    }
+}
+
+impl CodeString for StackMapFrame {
+    fn to_elem(&self) -> Elem {
+        let str = match self {
+            StackMapFrame::SameFrame { frame_type } =>
+                format!("StackMapFrame::SameFrame {{ frame_type: {} }},", frame_type),
+            StackMapFrame::SameLocals1StackItemFrame { frame_type, stack } =>
+                format!("StackMapFrame::SameLocals1StackItemFrame {{ frame_type: {}, stack: {} }},",
+                        frame_type, verification_types_string(stack)),
+            StackMapFrame::SameLocals1StackItemFrameExtended { frame_type, offset_delta, stack } =>
+                format!("StackMapFrame::SameLocals1StackItemFrameExtended {{ frame_type: {}, offset_delta: {}, stack: {} }},",
+                        frame_type, offset_delta, verification_types_string(stack)),
+            StackMapFrame::ChopFrame { frame_type, offset_delta } =>
+                format!("StackMapFrame::ChopFrame {{ frame_type: {}, offset_delta: {} }},", frame_type, offset_delta),
+            StackMapFrame::SameFrameExtended { frame_type, offset_delta } =>
+                format!("StackMapFrame::SameFrameExtended {{ frame_type: {}, offset_delta: {} }},", frame_type, offset_delta),
+            StackMapFrame::AppendFrame { frame_type, offset_delta, locals } =>
+                format!("StackMapFrame::AppendFrame {{ frame_type: {}, offset_delta: {}, locals: {} }},",
+                        frame_type, offset_delta, verification_types_string(locals)),
+            StackMapFrame::FullFrame { frame_type, offset_delta, number_of_locals, locals, number_of_stack_items, stack } =>
+                format!("StackMapFrame::FullFrame {{ frame_type: {}, offset_delta: {}, number_of_locals: {}, locals: {}, number_of_stack_items: {}, stack: {} }},",
+                        frame_type, offset_delta, number_of_locals, verification_types_string(locals), number_of_stack_items, verification_types_string(stack)),
         };
         Elem::Line(str)
     }
 }
 
+fn verification_types_string(types: &[VerificationTypeInfo]) -> String {
+    let types: Vec<String> = types.iter().map(|x| match x {
+        VerificationTypeInfo::TopVariableInfo { tag } => format!("VerificationTypeInfo::TopVariableInfo {{ tag: {} }}", tag),
+        VerificationTypeInfo::IntegerVariableInfo { tag } => format!("VerificationTypeInfo::IntegerVariableInfo {{ tag: {} }}", tag),
+        VerificationTypeInfo::FloatVariableInfo { tag } => format!("VerificationTypeInfo::FloatVariableInfo {{ tag: {} }}", tag),
+        VerificationTypeInfo::DoubleVariableInfo { tag } => format!("VerificationTypeInfo::DoubleVariableInfo {{ tag: {} }}", tag),
+        VerificationTypeInfo::LongVariableInfo { tag } => format!("VerificationTypeInfo::LongVariableInfo {{ tag: {} }}", tag),
+        VerificationTypeInfo::NullVariableInfo { tag } => format!("VerificationTypeInfo::NullVariableInfo {{ tag: {} }}", tag),
+        VerificationTypeInfo::UninitializedThisVariableInfo { tag } =>
+            format!("VerificationTypeInfo::UninitializedThisVariableInfo {{ tag: {} }}", tag),
+        VerificationTypeInfo::ObjectVariableInfo { tag, cpool_index } =>
+            format!("VerificationTypeInfo::ObjectVariableInfo {{ tag: {}, cpool_index: CpIndex::new({}) }}", tag, cpool_index),
+        VerificationTypeInfo::UninitializedVariableInfo { tag, offset } =>
+            format!("VerificationTypeInfo::UninitializedVariableInfo {{ tag: {}, offset: {} }}", tag, offset),
+    }).collect();
+    format!("vec![{}]", types.join(", "))
+}
+
+impl CodeString for InnerClassesAttributeClass {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("InnerClassesAttributeClass {{ inner_class_info_index: CpIndex::new({}), outer_class_info_index: CpIndex::new({}), inner_name_index: CpIndex::new({}), inner_class_access_flags: InnerClassAccessFlags::from_bits_retain({:#06x?}) }},",
+                           self.inner_class_info_index, self.outer_class_info_index, self.inner_name_index, self.inner_class_access_flags))
+    }
+}
+
+impl CodeString for LocalVariable {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("LocalVariable {{ start_pc: {}, length: {}, name_index: CpIndex::new({}), descriptor_index: CpIndex::new({}), index: {} }},",
+                           self.start_pc, self.length, self.name_index, self.descriptor_index, self.index))
+    }
+}
+
+impl CodeString for LocalVariableType {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("LocalVariableType {{ start_pc: {}, length: {}, name_index: CpIndex::new({}), signature_index: CpIndex::new({}), index: {} }},",
+                           self.start_pc, self.length, self.name_index, self.signature_index, self.index))
+    }
+}
+
+impl CodeString for BootstrapMethod {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("BootstrapMethod {{ bootstrap_method_ref: CpIndex::new({}), num_bootstrap_arguments: {}, bootstrap_arguments: {} }},",
+                           self.bootstrap_method_ref, self.num_bootstrap_arguments, indexes_string(&self.bootstrap_arguments)))
+    }
+}
+
+impl CodeString for Parameter {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("Parameter {{ name_index: CpIndex::new({}), access_flags: ParameterAccessFlags::from_bits_retain({:#06x?}) }},", self.name_index, self.access_flags))
+    }
+}
+
+impl CodeString for Require {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("Require {{ requires_index: CpIndex::new({}), requires_flags: RequiresAccessFlags::from_bits_retain({:#06x?}), requires_version_index: CpIndex::new({}) }},",
+                           self.requires_index, self.requires_flags, self.requires_version_index))
+    }
+}
+
+impl CodeString for Export {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("Export {{ exports_index: CpIndex::new({}), exports_flags: ExportsAccessFlags::from_bits_retain({:#06x?}), exports_to_count: {}, exports_to_index: {} }},",
+                           self.exports_index, self.exports_flags, self.exports_to_count, indexes_string(&self.exports_to_index)))
+    }
+}
+
+impl CodeString for Open {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("Open {{ opens_index: CpIndex::new({}), opens_flags: ExportsAccessFlags::from_bits_retain({:#06x?}), opens_to_count: {}, opens_to_index: {} }},",
+                           self.opens_index, self.opens_flags, self.opens_to_count, indexes_string(&self.opens_to_index)))
+    }
+}
+
+impl CodeString for Provide {
+    fn to_elem(&self) -> Elem {
+        Elem::Line(format!("Provide {{ provides_index: CpIndex::new({}), provides_with_count: {}, provides_with_index: {} }},",
+                           self.provides_index, self.provides_with_count, indexes_string(&self.provides_with_index)))
+    }
+}
+
 #[test]
 fn test() {
+    use crate::class_file::access_flags::*;
+
     let class_file = ClassFile {
         magic: [0xca, 0xfe, 0xba, 0xbe],
         minor_version: 0,
         major_version: 61,
         constant_pool_count: 19,
         constant_pool: vec![
-            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: 2, name_and_type_index: 3 }),
-            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 4 }),
-            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 5, descriptor_index: 6 }),
+            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(2), name_and_type_index: CpIndex::new(3) }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(4) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(5), descriptor_index: CpIndex::new(6) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 16, bytes: "java/lang/Object".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "<init>".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()V".as_bytes().to_vec() }),
-            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: 8, name_and_type_index: 9 }),
-            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 10 }),
-            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 11, descriptor_index: 12 }),
+            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(8), name_and_type_index: CpIndex::new(9) }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(10) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(11), descriptor_index: CpIndex::new(12) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 7, bytes: "Sample1".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "add".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 5, bytes: "(II)I".as_bytes().to_vec() }),
@@ -149,9 +510,9 @@ fn test() {
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 10, bytes: "SourceFile".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 12, bytes: "Sample1.java".as_bytes().to_vec() }),
         ],
-        access_flags: 0x0020,
-        this_class: 8,
-        super_class: 2,
+        access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+        this_class: CpIndex::new(8),
+        super_class: CpIndex::new(2),
         interfaces_count: 0,
         interfaces: vec![],
         fields_count: 0,
@@ -167,17 +528,18 @@ fn test() {
     assert_eq!(code_string, r#"ClassFile {
     magic: [0xca, 0xfe, 0xba, 0xbe],
     minor_version: 0,
+    major_version: 61,
     constant_pool_count: 19,
     constant_pool: vec![
-        CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: 2, name_and_type_index: 3 }),
-        CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 4 }),
-        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 5, descriptor_index: 6 }),
+        CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(2), name_and_type_index: CpIndex::new(3) }),
+        CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(4) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(5), descriptor_index: CpIndex::new(6) }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 16, bytes: "java/lang/Object".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "<init>".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()V".as_bytes().to_vec() }),
-        CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: 8, name_and_type_index: 9 }),
-        CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 10 }),
-        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 11, descriptor_index: 12 }),
+        CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(8), name_and_type_index: CpIndex::new(9) }),
+        CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(10) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(11), descriptor_index: CpIndex::new(12) }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 7, bytes: "Sample1".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "add".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 5, bytes: "(II)I".as_bytes().to_vec() }),
@@ -188,9 +550,9 @@ fn test() {
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 10, bytes: "SourceFile".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 12, bytes: "Sample1.java".as_bytes().to_vec() }),
     ],
-    access_flags: 0x0020,
-    this_class: 8,
-    super_class: 2,
+    access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+    this_class: CpIndex::new(8),
+    super_class: CpIndex::new(2),
     interfaces_count: 0,
     interfaces: vec![],
     fields_count: 0,
@@ -200,4 +562,196 @@ fn test() {
     attributes_count: 1,
     attributes: vec![],
 }"#)
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_constants_and_attributes() {
+    use crate::class_file::access_flags::*;
+
+    let class_file = ClassFile {
+        magic: [0xca, 0xfe, 0xba, 0xbe],
+        minor_version: 0,
+        major_version: 61,
+        constant_pool_count: 12,
+        constant_pool: vec![
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 8, bytes: "say \"hi\"".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 2, bytes: vec![0xc0, 0x80] }),
+            CpInfo::Integer(ConstantIntegerInfo { tag: 3, bytes: (-1_i32).to_be_bytes() }),
+            CpInfo::Float(ConstantFloatInfo { tag: 4, bytes: 1.5_f32.to_be_bytes() }),
+            CpInfo::Float(ConstantFloatInfo { tag: 4, bytes: f32::from_bits(0x7fc00000).to_be_bytes() }),
+            CpInfo::Long(ConstantLongInfo { tag: 5, high_bytes: [0x00, 0x00, 0x00, 0x01], low_bytes: [0x00, 0x00, 0x00, 0x00] }), // 4294967296
+            CpInfo::Unusable,
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "main".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "(I)V".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "Code".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 13, bytes: "StackMapTable".as_bytes().to_vec() }),
+        ],
+        access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+        this_class: CpIndex::new(0),
+        super_class: CpIndex::new(0),
+        interfaces_count: 0,
+        interfaces: vec![],
+        fields_count: 0,
+        fields: vec![],
+        methods_count: 1,
+        methods: vec![
+            MethodInfo {
+                access_flags: MethodAccessFlags::from_bits_retain(0x0008),
+                name_index: CpIndex::new(8),
+                descriptor_index: CpIndex::new(9),
+                attributes_count: 1,
+                attributes: vec![
+                    Attribute::Code(CodeAttributeInfo {
+                        attribute_name_index: CpIndex::new(0x000a),
+                        attribute_length: 27,
+                        max_stack: 1,
+                        max_locals: 1,
+                        code_length: 5,
+                        code: vec![0x1a, 0x99, 0x00, 0x03, 0xb1],
+                        exception_table_length: 0,
+                        exception_table: vec![],
+                        attributes_count: 1,
+                        attributes: vec![
+                            Attribute::StackMapTable(StackMapTableAttribute {
+                                attribute_name_index: CpIndex::new(0x000b),
+                                attribute_length: 3,
+                                number_of_entries: 1,
+                                entries: vec![
+                                    StackMapFrame::SameFrame { frame_type: 4 },
+                                ],
+                            }),
+                        ],
+                    }),
+                ],
+            },
+        ],
+        attributes_count: 0,
+        attributes: vec![],
+    };
+
+    let code_string = class_file.code_string();
+
+    assert_eq!(code_string, r#"ClassFile {
+    magic: [0xca, 0xfe, 0xba, 0xbe],
+    minor_version: 0,
+    major_version: 61,
+    constant_pool_count: 12,
+    constant_pool: vec![
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 8, bytes: "say \"hi\"".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 2, bytes: vec![0xc0, 0x80] }),
+        CpInfo::Integer(ConstantIntegerInfo { tag: 3, bytes: (-1_i32).to_be_bytes() }),
+        CpInfo::Float(ConstantFloatInfo { tag: 4, bytes: 1.5_f32.to_be_bytes() }),
+        CpInfo::Float(ConstantFloatInfo { tag: 4, bytes: f32::from_bits(0x7fc00000).to_be_bytes() }),
+        CpInfo::Long(ConstantLongInfo { tag: 5, high_bytes: [0x00, 0x00, 0x00, 0x01], low_bytes: [0x00, 0x00, 0x00, 0x00] }), // 4294967296
+        CpInfo::Unusable,
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "main".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "(I)V".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "Code".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 13, bytes: "StackMapTable".as_bytes().to_vec() }),
+    ],
+    access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+    this_class: CpIndex::new(0),
+    super_class: CpIndex::new(0),
+    interfaces_count: 0,
+    interfaces: vec![],
+    fields_count: 0,
+    fields: vec![],
+    methods_count: 1,
+    methods: vec![
+        MethodInfo {
+            access_flags: MethodAccessFlags::from_bits_retain(0x0008),
+            name_index: CpIndex::new(8),
+            descriptor_index: CpIndex::new(9),
+            attributes_count: 1,
+            attributes: vec![
+                Attribute::Code(CodeAttributeInfo {
+                    attribute_name_index: CpIndex::new(0x000a),
+                    attribute_length: 27,
+                    max_stack: 1,
+                    max_locals: 1,
+                    code_length: 5,
+                    code: vec![0x1a, 0x99, 0x00, 0x03, 0xb1],
+                    exception_table_length: 0,
+                    exception_table: vec![],
+                    attributes_count: 1,
+                    attributes: vec![
+                        Attribute::StackMapTable(StackMapTableAttribute {
+                            attribute_name_index: CpIndex::new(0x000b),
+                            attribute_length: 3,
+                            number_of_entries: 1,
+                            entries: vec![
+                                StackMapFrame::SameFrame { frame_type: 4 },
+                            ],
+                        }),
+                    ],
+                }),
+            ],
+        },
+    ],
+    attributes_count: 0,
+    attributes: vec![],
+}"#)
+}
+
+#[test]
+fn test_wide_constants() {
+    use crate::class_file::access_flags::*;
+
+    let class_file = ClassFile {
+        magic: [0xca, 0xfe, 0xba, 0xbe],
+        minor_version: 0,
+        major_version: 61,
+        constant_pool_count: 9,
+        constant_pool: vec![
+            CpInfo::Long(ConstantLongInfo { tag: 5, high_bytes: [0xff, 0xff, 0xff, 0xff], low_bytes: [0xff, 0xff, 0xff, 0xfe] }),
+            CpInfo::Unusable,
+            CpInfo::Double(ConstantDoubleInfo { tag: 6, high_bytes: [0x40, 0x04, 0x00, 0x00], low_bytes: [0x00, 0x00, 0x00, 0x00] }),
+            CpInfo::Unusable,
+            CpInfo::Double(ConstantDoubleInfo { tag: 6, high_bytes: [0x7f, 0xf8, 0x00, 0x00], low_bytes: [0x00, 0x00, 0x00, 0x00] }),
+            CpInfo::Unusable,
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "J".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "D".as_bytes().to_vec() }),
+        ],
+        access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+        this_class: CpIndex::new(0),
+        super_class: CpIndex::new(0),
+        interfaces_count: 0,
+        interfaces: vec![],
+        fields_count: 0,
+        fields: vec![],
+        methods_count: 0,
+        methods: vec![],
+        attributes_count: 0,
+        attributes: vec![],
+    };
+
+    let code_string = class_file.code_string();
+
+    assert_eq!(code_string, r#"ClassFile {
+    magic: [0xca, 0xfe, 0xba, 0xbe],
+    minor_version: 0,
+    major_version: 61,
+    constant_pool_count: 9,
+    constant_pool: vec![
+        CpInfo::Long(ConstantLongInfo { tag: 5, high_bytes: [0xff, 0xff, 0xff, 0xff], low_bytes: [0xff, 0xff, 0xff, 0xfe] }), // -2
+        CpInfo::Unusable,
+        CpInfo::Double(ConstantDoubleInfo { tag: 6, high_bytes: [0x40, 0x04, 0x00, 0x00], low_bytes: [0x00, 0x00, 0x00, 0x00] }), // 2.5
+        CpInfo::Unusable,
+        CpInfo::Double(ConstantDoubleInfo { tag: 6, high_bytes: [0x7f, 0xf8, 0x00, 0x00], low_bytes: [0x00, 0x00, 0x00, 0x00] }), // NaN
+        CpInfo::Unusable,
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "J".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "D".as_bytes().to_vec() }),
+    ],
+    access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+    this_class: CpIndex::new(0),
+    super_class: CpIndex::new(0),
+    interfaces_count: 0,
+    interfaces: vec![],
+    fields_count: 0,
+    fields: vec![],
+    methods_count: 0,
+    methods: vec![],
+    attributes_count: 0,
+    attributes: vec![],
+}"#)
+}
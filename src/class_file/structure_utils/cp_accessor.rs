@@ -25,694 +25,401 @@ fn cp_info_name(cp_info: &CpInfo) -> &str {
         CpInfo::InvokeDynamic(_) => "CONSTANT_InvokeDynamic",
         CpInfo::Module(_) => "CONSTANT_Module",
         CpInfo::Package(_) => "CONSTANT_Package",
+        CpInfo::Unusable => "the unusable entry",
     }
 }
 
 // original constant_pool table is indexed from 1 to constant_pool_count - 1.
 // Note that the Vec of this cp_infos structure is indexed from 0.
-fn get_constant_pool_info(constant_pool: &Vec<CpInfo>, index: u16) -> Result<&CpInfo> {
-    match constant_pool.get((index as usize) - 1) {
+fn get_constant_pool_info(constant_pool: &[CpInfo], index: u16) -> Result<&CpInfo> {
+    match (index as usize).checked_sub(1).and_then(|i| constant_pool.get(i)) {
         Some(cp_info) => Ok(cp_info),
         None => error(format!("the index of constant_pool not found! index: {}", index)),
     }
 }
 
-pub trait CpAccessor {
-    // indexed from 1 to constant_pool_count - 1.
-    fn access_as_utf8(&self, index: u16) -> Utf8CpAccessor;
-    fn access_as_integer(&self, index: u16) -> IntegerCpAccessor;
-    fn access_as_float(&self, index: u16) -> FloatCpAccessor;
-    fn access_as_long(&self, index: u16) -> LongCpAccessor;
-    fn access_as_double(&self, index: u16) -> DoubleCpAccessor;
-    fn access_as_class(&self, index: u16) -> ClassCpAccessor;
-    fn access_as_string(&self, index: u16) -> StringCpAccessor;
-    fn access_as_fieldref(&self, index: u16) -> FieldrefCpAccessor;
-    fn access_as_methodref(&self, index: u16) -> MethodrefCpAccessor;
-    fn access_as_interface_methodref(&self, index: u16) -> InterfaceMethodrefCpAccessor;
-    fn access_as_name_and_type(&self, index: u16) -> NameAndTypeCpAccessor;
-    fn access_as_method_handle(&self, index: u16) -> MethodHandleCpAccessor;
-    fn access_as_method_type(&self, index: u16) -> MethodTypeCpAccessor;
-    fn access_as_dynamic(&self, index: u16) -> DynamicCpAccessor;
-    fn access_as_invoke_dynamic(&self, index: u16) -> InvokeDynamicCpAccessor;
-    fn access_as_module(&self, index: u16) -> ModuleCpAccessor;
-    fn access_as_package(&self, index: u16) -> PackageCpAccessor;
+/// The structures of the constant pool entries, to be looked up by their types with `get::<T>`.
+/// CpInfo itself is also an entry, which is any of them.
+pub trait CpEntry: Sized {
+    fn from_cp_info(cp_info: &CpInfo) -> Option<&Self>;
+    /// e.g. "CONSTANT_Utf8_info"
+    fn structure_name() -> &'static str;
 }
 
-impl CpAccessor for &Vec<CpInfo> {
-    fn access_as_utf8(&self, index: u16) -> Utf8CpAccessor {
-        Utf8CpAccessor::from(self, index)
-    }
+macro_rules! cp_entries {
+    ($($variant:ident($info:ident) = $name:literal,)*) => {
+        $(impl CpEntry for $info {
+            fn from_cp_info(cp_info: &CpInfo) -> Option<&Self> {
+                match cp_info {
+                    CpInfo::$variant(info) => Some(info),
+                    _ => None,
+                }
+            }
 
-    fn access_as_integer(&self, index: u16) -> IntegerCpAccessor {
-        IntegerCpAccessor::from(self, index)
-    }
+            fn structure_name() -> &'static str {
+                $name
+            }
+        })*
+    };
+}
 
-    fn access_as_float(&self, index: u16) -> FloatCpAccessor {
-        FloatCpAccessor::from(self, index)
-    }
+cp_entries!(
+    Utf8(ConstantUtf8Info) = "CONSTANT_Utf8_info",
+    Integer(ConstantIntegerInfo) = "CONSTANT_Integer_info",
+    Float(ConstantFloatInfo) = "CONSTANT_Float_info",
+    Long(ConstantLongInfo) = "CONSTANT_Long_info",
+    Double(ConstantDoubleInfo) = "CONSTANT_Double_info",
+    Class(ConstantClassInfo) = "CONSTANT_Class_info",
+    String(ConstantStringInfo) = "CONSTANT_String_info",
+    Fieldref(ConstantFieldrefInfo) = "CONSTANT_Fieldref_info",
+    Methodref(ConstantMethodrefInfo) = "CONSTANT_Methodref_info",
+    InterfaceMethodref(ConstantInterfaceMethodrefInfo) = "CONSTANT_InterfaceMethodref_info",
+    NameAndType(ConstantNameAndTypeInfo) = "CONSTANT_NameAndType_info",
+    MethodHandle(ConstantMethodHandleInfo) = "CONSTANT_MethodHandle_info",
+    MethodType(ConstantMethodTypeInfo) = "CONSTANT_MethodType_info",
+    Dynamic(ConstantDynamicInfo) = "CONSTANT_Dynamic_info",
+    InvokeDynamic(ConstantInvokeDynamicInfo) = "CONSTANT_InvokeDynamic_info",
+    Module(ConstantModuleInfo) = "CONSTANT_Module_info",
+    Package(ConstantPackageInfo) = "CONSTANT_Package_info",
+);
 
-    fn access_as_long(&self, index: u16) -> LongCpAccessor {
-        LongCpAccessor::from(self, index)
+impl CpEntry for CpInfo {
+    fn from_cp_info(cp_info: &CpInfo) -> Option<&Self> {
+        Some(cp_info)
     }
 
-    fn access_as_double(&self, index: u16) -> DoubleCpAccessor {
-        DoubleCpAccessor::from(self, index)
+    fn structure_name() -> &'static str {
+        "cp_info"
     }
+}
 
-    fn access_as_class(&self, index: u16) -> ClassCpAccessor {
-        ClassCpAccessor::from(self, index)
+fn get<T: CpEntry>(constant_pool: &[CpInfo], index: u16) -> Result<&T> {
+    let cp_info = get_constant_pool_info(constant_pool, index)?;
+    match T::from_cp_info(cp_info) {
+        Some(info) => Ok(info),
+        None => error(format!("The index must refer to {} structure, but {} found! index: {}", T::structure_name(), cp_info_name(cp_info), index)),
     }
+}
 
-    fn access_as_string(&self, index: u16) -> StringCpAccessor {
-        StringCpAccessor::from(self, index)
+impl<T: CpEntry> CpIndex<T> {
+    pub fn get(self, constant_pool: &[CpInfo]) -> Result<&T> {
+        get(constant_pool, self.index())
     }
+}
 
-    fn access_as_fieldref(&self, index: u16) -> FieldrefCpAccessor {
-        FieldrefCpAccessor::from(self, index)
-    }
+pub trait CpAccessor<'a> {
+    // indexed from 1 to constant_pool_count - 1.
+    fn get<T: CpEntry>(&self, index: u16) -> Result<&'a T>;
 
-    fn access_as_methodref(&self, index: u16) -> MethodrefCpAccessor {
-        MethodrefCpAccessor::from(self, index)
-    }
+    /// The accessor of the entry at a typed index, e.g. `access(class_file.this_class).name()`.
+    fn access<T: CpEntry>(&self, index: CpIndex<T>) -> EntryAccessor<'a, T>;
 
-    fn access_as_interface_methodref(&self, index: u16) -> InterfaceMethodrefCpAccessor {
-        InterfaceMethodrefCpAccessor::from(self, index)
-    }
+    // The accessors follow the indexes in the entries, and return the error at the end of the chain,
+    // e.g. `access_as_methodref(i).name_and_type().name().bytes_as_string()`.
+    fn access_as_utf8(&self, index: u16) -> Utf8CpAccessor<'a>;
+    fn access_as_integer(&self, index: u16) -> IntegerCpAccessor<'a>;
+    fn access_as_float(&self, index: u16) -> FloatCpAccessor<'a>;
+    fn access_as_long(&self, index: u16) -> LongCpAccessor<'a>;
+    fn access_as_double(&self, index: u16) -> DoubleCpAccessor<'a>;
+    fn access_as_class(&self, index: u16) -> ClassCpAccessor<'a>;
+    fn access_as_string(&self, index: u16) -> StringCpAccessor<'a>;
+    fn access_as_fieldref(&self, index: u16) -> FieldrefCpAccessor<'a>;
+    fn access_as_methodref(&self, index: u16) -> MethodrefCpAccessor<'a>;
+    fn access_as_interface_methodref(&self, index: u16) -> InterfaceMethodrefCpAccessor<'a>;
+    fn access_as_name_and_type(&self, index: u16) -> NameAndTypeCpAccessor<'a>;
+    fn access_as_method_handle(&self, index: u16) -> MethodHandleCpAccessor<'a>;
+    fn access_as_method_type(&self, index: u16) -> MethodTypeCpAccessor<'a>;
+    fn access_as_dynamic(&self, index: u16) -> DynamicCpAccessor<'a>;
+    fn access_as_invoke_dynamic(&self, index: u16) -> InvokeDynamicCpAccessor<'a>;
+    fn access_as_module(&self, index: u16) -> ModuleCpAccessor<'a>;
+    fn access_as_package(&self, index: u16) -> PackageCpAccessor<'a>;
+}
 
-    fn access_as_name_and_type(&self, index: u16) -> NameAndTypeCpAccessor {
-        NameAndTypeCpAccessor::from(self, index)
+impl<'a> CpAccessor<'a> for &'a Vec<CpInfo> {
+    fn get<T: CpEntry>(&self, index: u16) -> Result<&'a T> {
+        get(self, index)
     }
 
-    fn access_as_method_handle(&self, index: u16) -> MethodHandleCpAccessor {
-        MethodHandleCpAccessor::from(self, index)
+    fn access<T: CpEntry>(&self, index: CpIndex<T>) -> EntryAccessor<'a, T> {
+        EntryAccessor::from(self, index.index())
     }
 
-    fn access_as_method_type(&self, index: u16) -> MethodTypeCpAccessor {
-        MethodTypeCpAccessor::from(self, index)
+    fn access_as_utf8(&self, index: u16) -> Utf8CpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn access_as_dynamic(&self, index: u16) -> DynamicCpAccessor {
-        DynamicCpAccessor::from(self, index)
+    fn access_as_integer(&self, index: u16) -> IntegerCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn access_as_invoke_dynamic(&self, index: u16) -> InvokeDynamicCpAccessor {
-        InvokeDynamicCpAccessor::from(self, index)
+    fn access_as_float(&self, index: u16) -> FloatCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn access_as_module(&self, index: u16) -> ModuleCpAccessor {
-        ModuleCpAccessor::from(self, index)
+    fn access_as_long(&self, index: u16) -> LongCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn access_as_package(&self, index: u16) -> PackageCpAccessor {
-        PackageCpAccessor::from(self, index)
+    fn access_as_double(&self, index: u16) -> DoubleCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
-}
-
-// Is it possible to commonize code using generics?
-// `struct CpAccessor<'a, T>` comes to mind, but I don't want to use it in the form of CpAccessor::<ConstantUtf8Info>::from(..) .
-// I was hoping that the compiler would look at the type and make a judgment, but it doesn't work
-
-pub struct Utf8CpAccessor<'a> {
-    #[allow(unused)] constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantUtf8Info>,
-}
 
-impl Utf8CpAccessor<'_> {
-    pub fn bytes_as_string(&self) -> Result<String> {
-        match &self.info_or_err {
-            Ok(info) => String::from_utf8(info.bytes.clone()).or_else(|e| error(e.to_string())),
-            Err(e) => Err(e.to_owned())
-        }
+    fn access_as_class(&self, index: u16) -> ClassCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> Utf8CpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Utf8(info)) => Utf8CpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => Utf8CpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Utf8_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => Utf8CpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+    fn access_as_string(&self, index: u16) -> StringCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> Utf8CpAccessor<'a> {
-        Utf8CpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    fn access_as_fieldref(&self, index: u16) -> FieldrefCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
-}
-
-pub struct IntegerCpAccessor<'a> {
-    #[allow(unused)] constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantIntegerInfo>,
-}
 
-impl IntegerCpAccessor<'_> {
-    pub fn bytes_as_integer(&self) -> Result<i32> {
-        match &self.info_or_err {
-            Ok(info) => Ok(i32::from_be_bytes(info.bytes)),
-            Err(e) => Err(e.to_owned())
-        }
+    fn access_as_methodref(&self, index: u16) -> MethodrefCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> IntegerCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Integer(info)) => IntegerCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => IntegerCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Integer_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => IntegerCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+    fn access_as_interface_methodref(&self, index: u16) -> InterfaceMethodrefCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> IntegerCpAccessor<'a> {
-        IntegerCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    fn access_as_name_and_type(&self, index: u16) -> NameAndTypeCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
-}
 
-pub struct FloatCpAccessor<'a> {
-    #[allow(unused)] constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantFloatInfo>,
-}
-
-impl FloatCpAccessor<'_> {
-    pub fn bytes_as_float(&self) -> Result<f32> {
-        match &self.info_or_err {
-            Ok(info) => Ok(f32::from_be_bytes(info.bytes)),
-            Err(e) => Err(e.to_owned())
-        }
+    fn access_as_method_handle(&self, index: u16) -> MethodHandleCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> FloatCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Float(info)) => FloatCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => FloatCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Float_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => FloatCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+    fn access_as_method_type(&self, index: u16) -> MethodTypeCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> FloatCpAccessor<'a> {
-        FloatCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    fn access_as_dynamic(&self, index: u16) -> DynamicCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
-}
 
-pub struct LongCpAccessor<'a> {
-    #[allow(unused)] constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantLongInfo>,
-}
-
-impl LongCpAccessor<'_> {
-    pub fn bytes_as_long(&self) -> Result<i64> {
-        match &self.info_or_err {
-            Ok(info) => Ok(i64::from_be_bytes([info.high_bytes, info.low_bytes].concat().try_into().unwrap())),
-            Err(e) => Err(e.to_owned())
-        }
+    fn access_as_invoke_dynamic(&self, index: u16) -> InvokeDynamicCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> LongCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Long(info)) => LongCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => LongCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Long_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => LongCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+    fn access_as_module(&self, index: u16) -> ModuleCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> LongCpAccessor<'a> {
-        LongCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    fn access_as_package(&self, index: u16) -> PackageCpAccessor<'a> {
+        EntryAccessor::from(self, index)
     }
 }
 
-pub struct DoubleCpAccessor<'a> {
-    #[allow(unused)] constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantDoubleInfo>,
+/// An entry of the constant pool, or the error of looking it up or of an entry before it in the chain.
+/// The accessors of each kind of entry are the aliases of it, e.g. `Utf8CpAccessor`,
+/// so that they are not written as `EntryAccessor::<ConstantUtf8Info>`.
+pub struct EntryAccessor<'a, T> {
+    constant_pool: &'a [CpInfo],
+    pub info_or_err: Result<&'a T>,
 }
 
-impl DoubleCpAccessor<'_> {
-    pub fn bytes_as_double(&self) -> Result<f64> {
-        match &self.info_or_err {
-            Ok(info) => Ok(f64::from_be_bytes([info.high_bytes, info.low_bytes].concat().try_into().unwrap())),
-            Err(e) => Err(e.to_owned())
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> DoubleCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Double(info)) => DoubleCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => DoubleCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Double_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => DoubleCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+impl<'a, T: CpEntry> EntryAccessor<'a, T> {
+    fn from(constant_pool: &'a [CpInfo], index: u16) -> EntryAccessor<'a, T> {
+        EntryAccessor { constant_pool, info_or_err: get(constant_pool, index) }
     }
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> DoubleCpAccessor<'a> {
-        DoubleCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    fn error(constant_pool: &'a [CpInfo], e: &Error) -> EntryAccessor<'a, T> {
+        EntryAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
     }
-}
 
-pub struct ClassCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantClassInfo>,
-}
-
-impl ClassCpAccessor<'_> {
-    pub fn name(&self) -> Utf8CpAccessor {
+    // The entry at an index in this entry.
+    fn follow<U: CpEntry>(&self, index_of: impl FnOnce(&T) -> CpIndex<U>) -> EntryAccessor<'a, U> {
         match &self.info_or_err {
-            Ok(info) => Utf8CpAccessor::from(self.constant_pool, info.name_index),
-            Err(e) => Utf8CpAccessor::error(self.constant_pool, e)
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> ClassCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Class(info)) => ClassCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => ClassCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Class_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => ClassCpAccessor { constant_pool, info_or_err: Err(e) }
+            Ok(info) => EntryAccessor::from(self.constant_pool, index_of(info).index()),
+            Err(e) => EntryAccessor::error(self.constant_pool, e),
         }
     }
 
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> ClassCpAccessor<'a> {
-        ClassCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
-    }
-}
-
-pub struct StringCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantClassInfo>,
-}
-
-impl StringCpAccessor<'_> {
-    pub fn name(&self) -> Utf8CpAccessor {
+    fn map<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U> {
         match &self.info_or_err {
-            Ok(info) => Utf8CpAccessor::from(self.constant_pool, info.name_index),
-            Err(e) => Utf8CpAccessor::error(self.constant_pool, e)
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> StringCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Class(info)) => StringCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => StringCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_String_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => StringCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
-    }
+            Ok(info) => Ok(f(info)),
+            Err(e) => Err(e.to_owned()),
+        }
+    }
+}
+
+pub type Utf8CpAccessor<'a> = EntryAccessor<'a, ConstantUtf8Info>;
+pub type IntegerCpAccessor<'a> = EntryAccessor<'a, ConstantIntegerInfo>;
+pub type FloatCpAccessor<'a> = EntryAccessor<'a, ConstantFloatInfo>;
+pub type LongCpAccessor<'a> = EntryAccessor<'a, ConstantLongInfo>;
+pub type DoubleCpAccessor<'a> = EntryAccessor<'a, ConstantDoubleInfo>;
+pub type ClassCpAccessor<'a> = EntryAccessor<'a, ConstantClassInfo>;
+pub type StringCpAccessor<'a> = EntryAccessor<'a, ConstantStringInfo>;
+pub type FieldrefCpAccessor<'a> = EntryAccessor<'a, ConstantFieldrefInfo>;
+pub type MethodrefCpAccessor<'a> = EntryAccessor<'a, ConstantMethodrefInfo>;
+pub type InterfaceMethodrefCpAccessor<'a> = EntryAccessor<'a, ConstantInterfaceMethodrefInfo>;
+pub type NameAndTypeCpAccessor<'a> = EntryAccessor<'a, ConstantNameAndTypeInfo>;
+pub type MethodHandleCpAccessor<'a> = EntryAccessor<'a, ConstantMethodHandleInfo>;
+pub type MethodTypeCpAccessor<'a> = EntryAccessor<'a, ConstantMethodTypeInfo>;
+pub type DynamicCpAccessor<'a> = EntryAccessor<'a, ConstantDynamicInfo>;
+pub type InvokeDynamicCpAccessor<'a> = EntryAccessor<'a, ConstantInvokeDynamicInfo>;
+pub type ModuleCpAccessor<'a> = EntryAccessor<'a, ConstantModuleInfo>;
+pub type PackageCpAccessor<'a> = EntryAccessor<'a, ConstantPackageInfo>;
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> StringCpAccessor<'a> {
-        StringCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+impl Utf8CpAccessor<'_> {
+    pub fn bytes_as_string(&self) -> Result<String> {
+        self.map(|info| String::from_utf8(info.bytes.clone()).or_else(|e| error(e.to_string())))?
     }
 }
 
-pub struct FieldrefCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantFieldrefInfo>,
-}
-
-impl FieldrefCpAccessor<'_> {
-    pub fn class(&self) -> ClassCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => ClassCpAccessor::from(self.constant_pool, info.class_index),
-            Err(e) => ClassCpAccessor::error(self.constant_pool, e)
-        }
-    }
-
-    pub fn name_and_type(&self) -> NameAndTypeCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => NameAndTypeCpAccessor::from(self.constant_pool, info.name_and_type_index),
-            Err(e) => NameAndTypeCpAccessor::error(self.constant_pool, e)
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> FieldrefCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Fieldref(info)) => FieldrefCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => FieldrefCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Fieldref structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => FieldrefCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
-    }
-
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> FieldrefCpAccessor<'a> {
-        FieldrefCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+impl IntegerCpAccessor<'_> {
+    pub fn bytes_as_integer(&self) -> Result<i32> {
+        self.map(|info| i32::from_be_bytes(info.bytes))
     }
 }
 
-pub struct MethodrefCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantMethodrefInfo>,
-}
-
-impl MethodrefCpAccessor<'_> {
-    pub fn class(&self) -> ClassCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => ClassCpAccessor::from(self.constant_pool, info.class_index),
-            Err(e) => ClassCpAccessor::error(self.constant_pool, e)
-        }
+impl FloatCpAccessor<'_> {
+    pub fn bytes_as_float(&self) -> Result<f32> {
+        self.map(|info| f32::from_be_bytes(info.bytes))
     }
+}
 
-    pub fn name_and_type(&self) -> NameAndTypeCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => NameAndTypeCpAccessor::from(self.constant_pool, info.name_and_type_index),
-            Err(e) => NameAndTypeCpAccessor::error(self.constant_pool, e)
-        }
+impl LongCpAccessor<'_> {
+    pub fn bytes_as_long(&self) -> Result<i64> {
+        self.map(|info| i64::from_be_bytes([info.high_bytes, info.low_bytes].concat().try_into().unwrap()))
     }
+}
 
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> MethodrefCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Methodref(info)) => MethodrefCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => MethodrefCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_Methodref_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => MethodrefCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+impl DoubleCpAccessor<'_> {
+    pub fn bytes_as_double(&self) -> Result<f64> {
+        self.map(|info| f64::from_be_bytes([info.high_bytes, info.low_bytes].concat().try_into().unwrap()))
     }
+}
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> MethodrefCpAccessor<'a> {
-        MethodrefCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+impl<'a> ClassCpAccessor<'a> {
+    pub fn name(&self) -> Utf8CpAccessor<'a> {
+        self.follow(|info| info.name_index)
     }
 }
 
-pub struct InterfaceMethodrefCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantInterfaceMethodrefInfo>,
+impl<'a> StringCpAccessor<'a> {
+    pub fn string(&self) -> Utf8CpAccessor<'a> {
+        self.follow(|info| info.string_index)
+    }
 }
 
-impl InterfaceMethodrefCpAccessor<'_> {
-    pub fn class(&self) -> ClassCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => ClassCpAccessor::from(self.constant_pool, info.class_index),
-            Err(e) => ClassCpAccessor::error(self.constant_pool, e)
-        }
+impl<'a> FieldrefCpAccessor<'a> {
+    pub fn class(&self) -> ClassCpAccessor<'a> {
+        self.follow(|info| info.class_index)
     }
 
-    pub fn name_and_type(&self) -> NameAndTypeCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => NameAndTypeCpAccessor::from(self.constant_pool, info.name_and_type_index),
-            Err(e) => NameAndTypeCpAccessor::error(self.constant_pool, e)
-        }
+    pub fn name_and_type(&self) -> NameAndTypeCpAccessor<'a> {
+        self.follow(|info| info.name_and_type_index)
     }
+}
 
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> InterfaceMethodrefCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::InterfaceMethodref(info)) => InterfaceMethodrefCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => InterfaceMethodrefCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_InterfaceMethodref_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => InterfaceMethodrefCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+impl<'a> MethodrefCpAccessor<'a> {
+    pub fn class(&self) -> ClassCpAccessor<'a> {
+        self.follow(|info| info.class_index)
     }
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> InterfaceMethodrefCpAccessor<'a> {
-        InterfaceMethodrefCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    pub fn name_and_type(&self) -> NameAndTypeCpAccessor<'a> {
+        self.follow(|info| info.name_and_type_index)
     }
 }
 
-
-pub struct NameAndTypeCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantNameAndTypeInfo>,
-}
-
-impl NameAndTypeCpAccessor<'_> {
-
-    pub fn name(&self) -> Utf8CpAccessor {
-        match &self.info_or_err {
-            Ok(info) => Utf8CpAccessor::from(self.constant_pool, info.name_index),
-            Err(e) => Utf8CpAccessor::error(self.constant_pool, e)
-        }
+impl<'a> InterfaceMethodrefCpAccessor<'a> {
+    pub fn class(&self) -> ClassCpAccessor<'a> {
+        self.follow(|info| info.class_index)
     }
 
-    pub fn descriptor(&self) -> Utf8CpAccessor {
-        match &self.info_or_err {
-            Ok(info) => Utf8CpAccessor::from(self.constant_pool, info.descriptor_index),
-            Err(e) => Utf8CpAccessor::error(self.constant_pool, e)
-        }
+    pub fn name_and_type(&self) -> NameAndTypeCpAccessor<'a> {
+        self.follow(|info| info.name_and_type_index)
     }
+}
 
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> NameAndTypeCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::NameAndType(info)) => NameAndTypeCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => NameAndTypeCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_NameAndType_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => NameAndTypeCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+impl<'a> NameAndTypeCpAccessor<'a> {
+    pub fn name(&self) -> Utf8CpAccessor<'a> {
+        self.follow(|info| info.name_index)
     }
 
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> NameAndTypeCpAccessor<'a> {
-        NameAndTypeCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    pub fn descriptor(&self) -> Utf8CpAccessor<'a> {
+        self.follow(|info| info.descriptor_index)
     }
 }
 
-pub struct MethodHandleCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantMethodHandleInfo>,
-}
-
 pub enum MethodHandleReference<'a> {
     Fieldref(FieldrefCpAccessor<'a>),
     Methodref(MethodrefCpAccessor<'a>),
     InterfaceMethodref(InterfaceMethodrefCpAccessor<'a>),
 }
 
-impl MethodHandleCpAccessor<'_> {
-
+impl<'a> MethodHandleCpAccessor<'a> {
     pub fn reference_kind(&self) -> Result<u8> {
-        self.info_or_err.as_ref().map_err(|e|e.to_owned()).map(|info| { info.reference_kind })
+        self.map(|info| info.reference_kind)
     }
 
-    pub fn reference(&self) -> Result<MethodHandleReference> {
-        self.info_or_err.as_ref().map_err(|e| e.to_owned()).and_then(|method_handle_info| {
-            get_constant_pool_info(self.constant_pool, method_handle_info.reference_index).and_then(|cp_info| {
-                match cp_info {
-                    CpInfo::Fieldref(info) => Ok(MethodHandleReference::Fieldref(FieldrefCpAccessor { constant_pool: self.constant_pool, info_or_err: Ok(info) })),
-                    CpInfo::Methodref(info) => Ok(MethodHandleReference::Methodref(MethodrefCpAccessor { constant_pool: self.constant_pool, info_or_err: Ok(info) })),
-                    CpInfo::InterfaceMethodref(info) => Ok(MethodHandleReference::InterfaceMethodref(InterfaceMethodrefCpAccessor { constant_pool: self.constant_pool, info_or_err: Ok(info) })),
-                    other_info => error(format!("The index must refer to CONSTANT_Fieldref_info or CONSTANT_Methodref_info or CONSTANT_InterfaceMethodref_info, but {} found! index: {}", cp_info_name(other_info), method_handle_info.reference_index)),
-                }
-            })
-        })
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> MethodHandleCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::MethodHandle(info)) => MethodHandleCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => MethodHandleCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_MethodHandle_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => MethodHandleCpAccessor { constant_pool, info_or_err: Err(e) }
+    // reference_index is a CpIndex of any entry, since it refers to one of the three kinds of entries.
+    pub fn reference(&self) -> Result<MethodHandleReference<'a>> {
+        let reference_index = self.map(|info| info.reference_index)?;
+        let constant_pool = self.constant_pool;
+        match reference_index.get(constant_pool)? {
+            CpInfo::Fieldref(info) => Ok(MethodHandleReference::Fieldref(EntryAccessor { constant_pool, info_or_err: Ok(info) })),
+            CpInfo::Methodref(info) => Ok(MethodHandleReference::Methodref(EntryAccessor { constant_pool, info_or_err: Ok(info) })),
+            CpInfo::InterfaceMethodref(info) => Ok(MethodHandleReference::InterfaceMethodref(EntryAccessor { constant_pool, info_or_err: Ok(info) })),
+            other_info => error(format!("The index must refer to CONSTANT_Fieldref_info or CONSTANT_Methodref_info or CONSTANT_InterfaceMethodref_info, but {} found! index: {}", cp_info_name(other_info), reference_index)),
         }
     }
-
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> MethodHandleCpAccessor<'a> {
-        MethodHandleCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
-    }
-}
-
-pub struct MethodTypeCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantMethodTypeInfo>,
 }
 
-impl MethodTypeCpAccessor<'_> {
-
-    pub fn descriptor(&self) -> Utf8CpAccessor {
-        match &self.info_or_err {
-            Ok(info) => Utf8CpAccessor::from(self.constant_pool, info.descriptor_index),
-            Err(e) => Utf8CpAccessor::error(self.constant_pool, e)
-        }
+impl<'a> MethodTypeCpAccessor<'a> {
+    pub fn descriptor(&self) -> Utf8CpAccessor<'a> {
+        self.follow(|info| info.descriptor_index)
     }
-    
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> MethodTypeCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::MethodType(info)) => MethodTypeCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => MethodTypeCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_MethodType_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => MethodTypeCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
-    }
-
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> MethodTypeCpAccessor<'a> {
-        MethodTypeCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
-    }
-}
-
-pub struct DynamicCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantDynamicInfo>,
 }
 
-impl DynamicCpAccessor<'_> {
-
+impl<'a> DynamicCpAccessor<'a> {
     pub fn get_bootstrap_method_attr_index(&self) -> Result<u16> {
-        self.info_or_err.as_ref()
-            .map(|info| { info.bootstrap_method_attr_index})
-            .map_err(|e| e.to_owned())
-    }
-
-    pub fn name_and_type(&self) -> NameAndTypeCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => NameAndTypeCpAccessor::from(&self.constant_pool, info.name_and_type_index),
-            Err(e) => NameAndTypeCpAccessor::error(&self.constant_pool, e)
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> DynamicCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Dynamic(info)) => DynamicCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => DynamicCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_MethodType_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => DynamicCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+        self.map(|info| info.bootstrap_method_attr_index)
     }
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> DynamicCpAccessor<'a> {
-        DynamicCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    pub fn name_and_type(&self) -> NameAndTypeCpAccessor<'a> {
+        self.follow(|info| info.name_and_type_index)
     }
 }
 
-pub struct InvokeDynamicCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantInvokeDynamicInfo>,
-}
-
-impl InvokeDynamicCpAccessor<'_> {
-
+impl<'a> InvokeDynamicCpAccessor<'a> {
     pub fn get_bootstrap_method_attr_index(&self) -> Result<u16> {
-        self.info_or_err.as_ref()
-            .map(|info| { info.bootstrap_method_attr_index})
-            .map_err(|e| e.to_owned())
-    }
-
-    pub fn name_and_type(&self) -> NameAndTypeCpAccessor {
-        match &self.info_or_err {
-            Ok(info) => NameAndTypeCpAccessor::from(&self.constant_pool, info.name_and_type_index),
-            Err(e) => NameAndTypeCpAccessor::error(&self.constant_pool, e)
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> InvokeDynamicCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::InvokeDynamic(info)) => InvokeDynamicCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => InvokeDynamicCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_MethodType_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => InvokeDynamicCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+        self.map(|info| info.bootstrap_method_attr_index)
     }
 
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> InvokeDynamicCpAccessor<'a> {
-        InvokeDynamicCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+    pub fn name_and_type(&self) -> NameAndTypeCpAccessor<'a> {
+        self.follow(|info| info.name_and_type_index)
     }
 }
 
-pub struct ModuleCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantModuleInfo>,
-}
-
-impl ModuleCpAccessor<'_> {
-    pub fn name(&self) -> Utf8CpAccessor {
-        match &self.info_or_err {
-            Ok(info) => Utf8CpAccessor::from(self.constant_pool, info.name_index),
-            Err(e) => Utf8CpAccessor::error(self.constant_pool, e)
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> ModuleCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Module(info)) => ModuleCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => ModuleCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_String_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => ModuleCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
+impl<'a> ModuleCpAccessor<'a> {
+    pub fn name(&self) -> Utf8CpAccessor<'a> {
+        self.follow(|info| info.name_index)
     }
-
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> ModuleCpAccessor<'a> {
-        ModuleCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
-    }
-}
-
-pub struct PackageCpAccessor<'a> {
-    constant_pool: &'a Vec<CpInfo>,
-    pub info_or_err: Result<&'a ConstantPackageInfo>,
 }
 
-impl PackageCpAccessor<'_> {
-    pub fn name(&self) -> Utf8CpAccessor {
-        match &self.info_or_err {
-            Ok(info) => Utf8CpAccessor::from(self.constant_pool, info.name_index),
-            Err(e) => Utf8CpAccessor::error(self.constant_pool, e)
-        }
-    }
-
-    fn from(constant_pool: &Vec<CpInfo>, index: u16) -> PackageCpAccessor {
-        match get_constant_pool_info(constant_pool, index) {
-            Ok(CpInfo::Package(info)) => PackageCpAccessor { constant_pool, info_or_err: Ok(&info) },
-            Ok(other_info) => PackageCpAccessor {
-                constant_pool,
-                info_or_err: error(format!("The index must refer to CONSTANT_String_info structure, but {} found! index: {}", cp_info_name(other_info), index)),
-            },
-            Err(e) => PackageCpAccessor { constant_pool, info_or_err: Err(e) }
-        }
-    }
-
-    #[allow(unused)]
-    fn error<'a>(constant_pool: &'a Vec<CpInfo>, e: &'a Error) -> PackageCpAccessor<'a> {
-        PackageCpAccessor { constant_pool, info_or_err: Err(e.to_owned()) }
+impl<'a> PackageCpAccessor<'a> {
+    pub fn name(&self) -> Utf8CpAccessor<'a> {
+        self.follow(|info| info.name_index)
     }
 }
 
 #[test]
 fn test() {
     let constant_pool: &Vec<CpInfo> = &vec![
-        CpInfo::Methodref(ConstantMethodrefInfo { tag: 0x0a, class_index: 0x02, name_and_type_index: 0x03 }),
-        CpInfo::Class(ConstantClassInfo { tag: 0x07, name_index: 0x04 }),
-        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 0x0c, name_index: 0x05, descriptor_index: 0x06 }),
+        CpInfo::Methodref(ConstantMethodrefInfo { tag: 0x0a, class_index: CpIndex::new(0x02), name_and_type_index: CpIndex::new(0x03) }),
+        CpInfo::Class(ConstantClassInfo { tag: 0x07, name_index: CpIndex::new(0x04) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 0x0c, name_index: CpIndex::new(0x05), descriptor_index: CpIndex::new(0x06) }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 0x01, length: 0x10, bytes: "java/lang/Object".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 0x01, length: 0x06, bytes: "<init>".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 0x01, length: 0x03, bytes: "()V".as_bytes().to_vec() }),
-        CpInfo::Methodref(ConstantMethodrefInfo { tag: 0x0a, class_index: 0x08, name_and_type_index: 0x09 }),
-        CpInfo::Class(ConstantClassInfo { tag: 0x07, name_index: 0x0a }),
-        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 0x0c, name_index: 0x0b, descriptor_index: 0x0c }),
+        CpInfo::Methodref(ConstantMethodrefInfo { tag: 0x0a, class_index: CpIndex::new(0x08), name_and_type_index: CpIndex::new(0x09) }),
+        CpInfo::Class(ConstantClassInfo { tag: 0x07, name_index: CpIndex::new(0x0a) }),
+        CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 0x0c, name_index: CpIndex::new(0x0b), descriptor_index: CpIndex::new(0x0c) }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 0x01, length: 0x07, bytes: "Sample1".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 0x01, length: 0x03, bytes: "add".as_bytes().to_vec() }),
         CpInfo::Utf8(ConstantUtf8Info { tag: 0x01, length: 0x05, bytes: "(II)I".as_bytes().to_vec() }),
@@ -727,4 +434,31 @@ fn test() {
     let str = constant_pool.access_as_class(8).name().bytes_as_string();
 
     assert_eq!(str, Ok("Sample1".to_string()))
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_access_as_string() {
+    // String s = "Hello";
+    let constant_pool: &Vec<CpInfo> = &vec![
+        CpInfo::String(ConstantStringInfo { tag: 8, string_index: CpIndex::new(2) }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 5, bytes: "Hello".as_bytes().to_vec() }),
+    ];
+
+    assert_eq!(constant_pool.access_as_string(1).string().bytes_as_string(), Ok("Hello".to_string()));
+    assert!(constant_pool.access_as_string(2).string().bytes_as_string().is_err());
+}
+
+#[test]
+fn test_get() {
+    let constant_pool: &Vec<CpInfo> = &vec![
+        CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(2) }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 7, bytes: "Sample1".as_bytes().to_vec() }),
+    ];
+
+    let class: &ConstantClassInfo = constant_pool.get(1).unwrap();
+    assert_eq!(class.name_index.get(constant_pool).unwrap().bytes, "Sample1".as_bytes());
+    assert!(matches!(constant_pool.get::<CpInfo>(2), Ok(CpInfo::Utf8(_))));
+    assert!(constant_pool.get::<ConstantUtf8Info>(1).is_err());
+    assert!(constant_pool.get::<CpInfo>(0).is_err());
+    assert!(constant_pool.get::<CpInfo>(3).is_err());
+}
@@ -1,3 +1,4 @@
 pub mod cp_accessor;
 pub mod code_format;
 pub mod code_string;
+pub mod resolved;
@@ -0,0 +1,304 @@
+use crate::class_file::access_flags::*;
+use crate::class_file::descriptor::{FieldType, MethodType, parse_field_type, parse_method_descriptor};
+use crate::class_file::error::Result;
+use crate::class_file::instruction::{Instruction, decode};
+use crate::class_file::signature::*;
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure::constant_pool::{ConstantClassInfo, ConstantUtf8Info, CpIndex, CpInfo};
+use crate::class_file::structure::root::{ClassFile, FieldsInfo, MethodInfo};
+use crate::class_file::structure_utils::cp_accessor::*;
+
+// Views of a class file with the constant pool indexes resolved to names, descriptors and references,
+// so that the users do not need to follow the indexes with CpAccessor.
+// Class names are in the internal form (4.2.1), e.g. `java/lang/Object`.
+// The structures are not checked in advance, and the methods return errors for invalid indexes.
+
+/// A view of a class file.
+#[derive(Clone, Copy)]
+pub struct ResolvedClass<'a> {
+    class_file: &'a ClassFile,
+}
+
+impl<'a> ResolvedClass<'a> {
+    pub fn new(class_file: &'a ClassFile) -> ResolvedClass<'a> {
+        ResolvedClass { class_file }
+    }
+
+    pub fn class_file(&self) -> &'a ClassFile {
+        self.class_file
+    }
+
+    pub fn access_flags(&self) -> ClassAccessFlags {
+        self.class_file.access_flags
+    }
+
+    pub fn class_name(&self) -> Result<String> {
+        class_name(self.class_file, self.class_file.this_class)
+    }
+
+    /// None for `java/lang/Object` and module-info, whose super_class is 0.
+    pub fn super_name(&self) -> Result<Option<String>> {
+        match self.class_file.super_class.index() {
+            0 => Ok(None),
+            _ => class_name(self.class_file, self.class_file.super_class).map(Some),
+        }
+    }
+
+    pub fn interfaces(&self) -> Result<Vec<String>> {
+        self.class_file.interfaces.iter().map(|index| class_name(self.class_file, *index)).collect()
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = ResolvedField<'a>> + 'a {
+        let class_file = self.class_file;
+        class_file.fields.iter().map(move |field| ResolvedField { class_file, field })
+    }
+
+    pub fn methods(&self) -> impl Iterator<Item = ResolvedMethod<'a>> + 'a {
+        let class_file = self.class_file;
+        class_file.methods.iter().map(move |method| ResolvedMethod { class_file, method })
+    }
+
+    /// The method declared in this class with the name and the descriptor, e.g. `("main", "([Ljava/lang/String;)V")`.
+    pub fn find_method(&self, name: &str, descriptor: &str) -> Result<Option<ResolvedMethod<'a>>> {
+        for method in self.methods() {
+            if method.name()? == name && method.descriptor()? == descriptor {
+                return Ok(Some(method));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn signature(&self) -> Result<Option<ClassSignature>> {
+        match signature_of(self.class_file, &self.class_file.attributes)? {
+            Some(signature) => parse_class_signature(&signature).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A view of a field_info of a class file.
+#[derive(Clone, Copy)]
+pub struct ResolvedField<'a> {
+    class_file: &'a ClassFile,
+    field: &'a FieldsInfo,
+}
+
+impl<'a> ResolvedField<'a> {
+    pub fn field_info(&self) -> &'a FieldsInfo {
+        self.field
+    }
+
+    pub fn access_flags(&self) -> FieldAccessFlags {
+        self.field.access_flags
+    }
+
+    pub fn name(&self) -> Result<String> {
+        utf8(self.class_file, self.field.name_index)
+    }
+
+    pub fn descriptor(&self) -> Result<String> {
+        utf8(self.class_file, self.field.descriptor_index)
+    }
+
+    pub fn field_type(&self) -> Result<FieldType> {
+        parse_field_type(&self.descriptor()?)
+    }
+
+    pub fn signature(&self) -> Result<Option<JavaTypeSignature>> {
+        match signature_of(self.class_file, &self.field.attributes)? {
+            Some(signature) => parse_field_signature(&signature).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A view of a method_info of a class file.
+#[derive(Clone, Copy)]
+pub struct ResolvedMethod<'a> {
+    class_file: &'a ClassFile,
+    method: &'a MethodInfo,
+}
+
+impl<'a> ResolvedMethod<'a> {
+    pub fn method_info(&self) -> &'a MethodInfo {
+        self.method
+    }
+
+    pub fn access_flags(&self) -> MethodAccessFlags {
+        self.method.access_flags
+    }
+
+    pub fn name(&self) -> Result<String> {
+        utf8(self.class_file, self.method.name_index)
+    }
+
+    pub fn descriptor(&self) -> Result<String> {
+        utf8(self.class_file, self.method.descriptor_index)
+    }
+
+    pub fn method_type(&self) -> Result<MethodType> {
+        parse_method_descriptor(&self.descriptor()?)
+    }
+
+    /// None for abstract and native methods.
+    pub fn code(&self) -> Option<&'a CodeAttributeInfo> {
+        self.method.attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Code(code) => Some(code),
+            _ => None,
+        })
+    }
+
+    /// The classes in the Exceptions attribute, or empty if there is none.
+    pub fn exceptions(&self) -> Result<Vec<String>> {
+        let exceptions = self.method.attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Exceptions(exceptions) => Some(exceptions),
+            _ => None,
+        });
+        match exceptions {
+            Some(exceptions) => exceptions.exception_index_table.iter().map(|index| class_name(self.class_file, *index)).collect(),
+            None => Ok(vec![]),
+        }
+    }
+
+    pub fn signature(&self) -> Result<Option<MethodSignature>> {
+        match signature_of(self.class_file, &self.method.attributes)? {
+            Some(signature) => parse_method_signature(&signature).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The symbolic references that the instructions of the code make, with their pcs, in the order of the code.
+    /// The code is decoded first, and each reference is resolved as the iterator goes.
+    /// Empty for methods without code.
+    pub fn references(&self) -> Result<impl Iterator<Item = Result<(u16, SymbolicReference)>> + 'a> {
+        let instructions = match self.code() {
+            Some(code) => decode(&code.code)?,
+            None => vec![],
+        };
+        let class_file = self.class_file;
+        Ok(instructions.into_iter().filter_map(move |(pc, instruction)| {
+            let index = symbolic_reference_index(&instruction)?;
+            SymbolicReference::from_constant_pool(class_file, index).transpose().map(|reference| reference.map(|reference| (pc, reference)))
+        }))
+    }
+}
+
+/// 5.1. The Run-Time Constant Pool
+/// A symbolic reference in the constant pool, resolved to names and descriptors.
+#[derive(Debug, PartialEq)]
+pub enum SymbolicReference {
+    /// The name of a class or an array class, e.g. `java/lang/String` or `[I`.
+    Class(String),
+    Field { class: String, name: String, descriptor: String },
+    Method { class: String, name: String, descriptor: String },
+    InterfaceMethod { class: String, name: String, descriptor: String },
+    /// The reference_kind of 5.4.3.5 and the field or method that the handle refers to.
+    MethodHandle { reference_kind: u8, reference: Box<SymbolicReference> },
+    MethodType(String),
+    Dynamic { bootstrap_method_attr_index: u16, name: String, descriptor: String },
+    InvokeDynamic { bootstrap_method_attr_index: u16, name: String, descriptor: String },
+}
+
+impl SymbolicReference {
+    /// None for the constants that are not symbolic references, e.g. CONSTANT_Integer and CONSTANT_String.
+    pub fn from_constant_pool(class_file: &ClassFile, index: u16) -> Result<Option<SymbolicReference>> {
+        match (&class_file.constant_pool).get::<CpInfo>(index).ok() {
+            Some(CpInfo::Class(_) | CpInfo::Fieldref(_) | CpInfo::Methodref(_) | CpInfo::InterfaceMethodref(_) |
+                 CpInfo::MethodHandle(_) | CpInfo::MethodType(_) | CpInfo::Dynamic(_) | CpInfo::InvokeDynamic(_)) => {
+                SymbolicReference::resolve(class_file, index).map(Some)
+            }
+            Some(_) => Ok(None),
+            None => SymbolicReference::resolve(class_file, index).map(Some),
+        }
+    }
+
+    fn resolve(class_file: &ClassFile, index: u16) -> Result<SymbolicReference> {
+        let constant_pool = &class_file.constant_pool;
+        let reference = match constant_pool.get::<CpInfo>(index).ok() {
+            Some(CpInfo::Fieldref(_)) => {
+                let fieldref = constant_pool.access_as_fieldref(index);
+                let name_and_type = fieldref.name_and_type();
+                SymbolicReference::Field {
+                    class: fieldref.class().name().bytes_as_string()?,
+                    name: name_and_type.name().bytes_as_string()?,
+                    descriptor: name_and_type.descriptor().bytes_as_string()?,
+                }
+            }
+            Some(CpInfo::Methodref(_)) => {
+                let methodref = constant_pool.access_as_methodref(index);
+                let name_and_type = methodref.name_and_type();
+                SymbolicReference::Method {
+                    class: methodref.class().name().bytes_as_string()?,
+                    name: name_and_type.name().bytes_as_string()?,
+                    descriptor: name_and_type.descriptor().bytes_as_string()?,
+                }
+            }
+            Some(CpInfo::InterfaceMethodref(_)) => {
+                let methodref = constant_pool.access_as_interface_methodref(index);
+                let name_and_type = methodref.name_and_type();
+                SymbolicReference::InterfaceMethod {
+                    class: methodref.class().name().bytes_as_string()?,
+                    name: name_and_type.name().bytes_as_string()?,
+                    descriptor: name_and_type.descriptor().bytes_as_string()?,
+                }
+            }
+            Some(CpInfo::MethodHandle(info)) => SymbolicReference::MethodHandle {
+                reference_kind: info.reference_kind,
+                reference: Box::new(SymbolicReference::resolve(class_file, info.reference_index.index())?),
+            },
+            Some(CpInfo::MethodType(_)) => SymbolicReference::MethodType(constant_pool.access_as_method_type(index).descriptor().bytes_as_string()?),
+            Some(CpInfo::Dynamic(_)) => {
+                let dynamic = constant_pool.access_as_dynamic(index);
+                let name_and_type = dynamic.name_and_type();
+                SymbolicReference::Dynamic {
+                    bootstrap_method_attr_index: dynamic.get_bootstrap_method_attr_index()?,
+                    name: name_and_type.name().bytes_as_string()?,
+                    descriptor: name_and_type.descriptor().bytes_as_string()?,
+                }
+            }
+            Some(CpInfo::InvokeDynamic(_)) => {
+                let invoke_dynamic = constant_pool.access_as_invoke_dynamic(index);
+                let name_and_type = invoke_dynamic.name_and_type();
+                SymbolicReference::InvokeDynamic {
+                    bootstrap_method_attr_index: invoke_dynamic.get_bootstrap_method_attr_index()?,
+                    name: name_and_type.name().bytes_as_string()?,
+                    descriptor: name_and_type.descriptor().bytes_as_string()?,
+                }
+            }
+            // CONSTANT_Class, or the error of the other entries
+            _ => SymbolicReference::Class(class_name(class_file, CpIndex::new(index))?),
+        };
+        Ok(reference)
+    }
+}
+
+// The constant pool index of the instructions whose operand may be a symbolic reference.
+// ldc may also load a CONSTANT_Integer, CONSTANT_Float or CONSTANT_String, which are not.
+fn symbolic_reference_index(instruction: &Instruction) -> Option<u16> {
+    match instruction {
+        Instruction::Getstatic(index) | Instruction::Putstatic(index) | Instruction::Getfield(index) | Instruction::Putfield(index) |
+        Instruction::Invokevirtual(index) | Instruction::Invokespecial(index) | Instruction::Invokestatic(index) |
+        Instruction::Invokedynamic(index) | Instruction::New(index) | Instruction::Anewarray(index) |
+        Instruction::Checkcast(index) | Instruction::Instanceof(index) | Instruction::LdcW(index) | Instruction::Ldc2W(index) => Some(*index),
+        Instruction::Invokeinterface { index, .. } | Instruction::Multianewarray { index, .. } => Some(*index),
+        Instruction::Ldc(index) => Some(*index as u16),
+        _ => None,
+    }
+}
+
+fn utf8(class_file: &ClassFile, index: CpIndex<ConstantUtf8Info>) -> Result<String> {
+    (&class_file.constant_pool).access(index).bytes_as_string()
+}
+
+fn class_name(class_file: &ClassFile, index: CpIndex<ConstantClassInfo>) -> Result<String> {
+    (&class_file.constant_pool).access(index).name().bytes_as_string()
+}
+
+fn signature_of(class_file: &ClassFile, attributes: &[Attribute]) -> Result<Option<String>> {
+    for attribute in attributes {
+        if let Attribute::Signature(signature) = attribute {
+            return utf8(class_file, signature.signature_index).map(Some);
+        }
+    }
+    Ok(None)
+}
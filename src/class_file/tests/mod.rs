@@ -1,3 +1,11 @@
 mod test_1;
 mod test2;
 mod test_module;
+mod test_signature;
+mod test_verifier;
+mod test_dataflow;
+mod test_decompiler;
+mod test_assembly;
+mod test_resolved;
+#[cfg(feature = "serde")]
+mod test_serde;
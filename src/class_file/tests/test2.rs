@@ -1,6 +1,7 @@
 use crate::class_file::structure::root::*;
 use crate::class_file::structure::constant_pool::*;
 use crate::class_file::structure::attribute::*;
+use crate::class_file::access_flags::*;
 use crate::class_file::reader::read_class_file;
 use crate::class_file::checker::check_class_file;
 
@@ -54,20 +55,20 @@ fn test() {
         major_version: 61,
         constant_pool_count: 22,
         constant_pool: vec![
-            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: 2, name_and_type_index: 3 }),
-            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 4 }),
-            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 5, descriptor_index: 6 }),
+            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(2), name_and_type_index: CpIndex::new(3) }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(4) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(5), descriptor_index: CpIndex::new(6) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 16, bytes: "java/lang/Object".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "<init>".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()V".as_bytes().to_vec() }),
-            CpInfo::Fieldref(ConstantFieldrefInfo { tag: 9, class_index: 8, name_and_type_index: 9 }),
-            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 10 }),
-            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 11, descriptor_index: 12 }),
+            CpInfo::Fieldref(ConstantFieldrefInfo { tag: 9, class_index: CpIndex::new(8), name_and_type_index: CpIndex::new(9) }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(10) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(11), descriptor_index: CpIndex::new(12) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "Person".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "name".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 18, bytes: "Ljava/lang/String;".as_bytes().to_vec() }),
-            CpInfo::Fieldref(ConstantFieldrefInfo { tag: 9, class_index: 8, name_and_type_index: 14 }),
-            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 15, descriptor_index: 16 }),
+            CpInfo::Fieldref(ConstantFieldrefInfo { tag: 9, class_index: CpIndex::new(8), name_and_type_index: CpIndex::new(14) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(15), descriptor_index: CpIndex::new(16) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "age".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 1, bytes: "I".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 22, bytes: "(Ljava/lang/String;I)V".as_bytes().to_vec() }),
@@ -76,24 +77,24 @@ fn test() {
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 10, bytes: "SourceFile".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 11, bytes: "Person.java".as_bytes().to_vec() }),
         ],
-        access_flags: 0x0020,
-        this_class: 8,
-        super_class: 2,
+        access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+        this_class: CpIndex::new(8),
+        super_class: CpIndex::new(2),
         interfaces_count: 0,
         interfaces: vec![],
         fields_count: 2,
         fields: vec![
             FieldsInfo {
-                access_flags: 0x0000,
-                name_index: 11,
-                descriptor_index: 12,
+                access_flags: FieldAccessFlags::from_bits_retain(0x0000),
+                name_index: CpIndex::new(11),
+                descriptor_index: CpIndex::new(12),
                 attributes_count: 0,
                 attributes: vec![],
             },
             FieldsInfo {
-                access_flags: 0x0002,
-                name_index: 15,
-                descriptor_index: 16,
+                access_flags: FieldAccessFlags::from_bits_retain(0x0002),
+                name_index: CpIndex::new(15),
+                descriptor_index: CpIndex::new(16),
                 attributes_count: 0,
                 attributes: vec![],
             },
@@ -101,13 +102,13 @@ fn test() {
         methods_count: 1,
         methods: vec![
             MethodInfo {
-                access_flags: 0x0000,
-                name_index: 5,
-                descriptor_index: 17,
+                access_flags: MethodAccessFlags::from_bits_retain(0x0000),
+                name_index: CpIndex::new(5),
+                descriptor_index: CpIndex::new(17),
                 attributes_count: 1,
                 attributes: vec![
                     Attribute::Code(CodeAttributeInfo {
-                        attribute_name_index: 0x0012,
+                        attribute_name_index: CpIndex::new(0x0012),
                         attribute_length: 51,
                         max_stack: 2,
                         max_locals: 3,
@@ -118,7 +119,7 @@ fn test() {
                         attributes_count: 1,
                         attributes: vec![
                             Attribute::General(AttributeInfo {
-                                attribute_name_index: 0x0013,
+                                attribute_name_index: CpIndex::new(0x0013),
                                 attribute_length: 18,
                                 info: vec![0x00, 0x04, 0x00, 0x00, 0x00, 0x06, 0x00, 0x04, 0x00, 0x07, 0x00, 0x09, 0x00, 0x08, 0x00, 0x0e, 0x00, 0x09],
                             }),
@@ -130,7 +131,7 @@ fn test() {
         attributes_count: 1,
         attributes: vec![
             Attribute::General(AttributeInfo {
-                attribute_name_index: 0x0014,
+                attribute_name_index: CpIndex::new(0x0014),
                 attribute_length: 2,
                 info: vec![0x00, 0x15],
             }),
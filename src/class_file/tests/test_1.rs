@@ -1,6 +1,7 @@
 use crate::class_file::structure::root::*;
 use crate::class_file::structure::constant_pool::*;
 use crate::class_file::structure::attribute::*;
+use crate::class_file::access_flags::*;
 use crate::class_file::reader::read_class_file;
 use crate::class_file::checker::check_class_file;
 
@@ -58,15 +59,15 @@ fn test() {
         major_version: 61,
         constant_pool_count: 19,
         constant_pool: vec![
-            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: 2, name_and_type_index: 3 }),
-            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 4 }),
-            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 5, descriptor_index: 6 }),
+            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(2), name_and_type_index: CpIndex::new(3) }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(4) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(5), descriptor_index: CpIndex::new(6) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 16, bytes: "java/lang/Object".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "<init>".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()V".as_bytes().to_vec() }),
-            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: 8, name_and_type_index: 9 }),
-            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 10 }),
-            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: 11, descriptor_index: 12 }),
+            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(8), name_and_type_index: CpIndex::new(9) }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(10) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(11), descriptor_index: CpIndex::new(12) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 7, bytes: "Sample1".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "add".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 5, bytes: "(II)I".as_bytes().to_vec() }),
@@ -77,9 +78,9 @@ fn test() {
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 10, bytes: "SourceFile".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 12, bytes: "Sample1.java".as_bytes().to_vec() }),
         ],
-        access_flags: 0x0020,
-        this_class: 8,
-        super_class: 2,
+        access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+        this_class: CpIndex::new(8),
+        super_class: CpIndex::new(2),
         interfaces_count: 0,
         interfaces: vec![],
         fields_count: 0,
@@ -87,13 +88,13 @@ fn test() {
         methods_count: 3,
         methods: vec![
             MethodInfo {
-                access_flags: 0x0000,
-                name_index: 5,
-                descriptor_index: 6,
+                access_flags: MethodAccessFlags::from_bits_retain(0x0000),
+                name_index: CpIndex::new(5),
+                descriptor_index: CpIndex::new(6),
                 attributes_count: 1,
                 attributes: vec![
                     Attribute::Code(CodeAttributeInfo {
-                        attribute_name_index: 0x000d,
+                        attribute_name_index: CpIndex::new(0x000d),
                         attribute_length: 29,
                         max_stack: 1,
                         max_locals: 1,
@@ -104,7 +105,7 @@ fn test() {
                         attributes_count: 1,
                         attributes: vec![
                             Attribute::General(AttributeInfo {
-                                attribute_name_index: 0x000e,
+                                attribute_name_index: CpIndex::new(0x000e),
                                 attribute_length: 6,
                                 info: vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x01],
                             }),
@@ -113,13 +114,13 @@ fn test() {
                 ],
             },
             MethodInfo {
-                access_flags: 0x0009,
-                name_index: 15,
-                descriptor_index: 16,
+                access_flags: MethodAccessFlags::from_bits_retain(0x0009),
+                name_index: CpIndex::new(15),
+                descriptor_index: CpIndex::new(16),
                 attributes_count: 1,
                 attributes: vec![
                     Attribute::Code(CodeAttributeInfo {
-                        attribute_name_index: 0x000d,
+                        attribute_name_index: CpIndex::new(0x000d),
                         attribute_length: 49,
                         max_stack: 2,
                         max_locals: 3,
@@ -130,7 +131,7 @@ fn test() {
                         attributes_count: 1,
                         attributes: vec![
                             Attribute::General(AttributeInfo {
-                                attribute_name_index: 0x000e,
+                                attribute_name_index: CpIndex::new(0x000e),
                                 attribute_length: 18,
                                 info: vec![0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x00, 0x05, 0x00, 0x05, 0x00, 0x06, 0x00, 0x0b, 0x00, 0x07],
                             }),
@@ -139,13 +140,13 @@ fn test() {
                 ],
             },
             MethodInfo {
-                access_flags: 0x0009,
-                name_index: 11,
-                descriptor_index: 12,
+                access_flags: MethodAccessFlags::from_bits_retain(0x0009),
+                name_index: CpIndex::new(11),
+                descriptor_index: CpIndex::new(12),
                 attributes_count: 1,
                 attributes: vec![
                     Attribute::Code(CodeAttributeInfo {
-                        attribute_name_index: 0x000d,
+                        attribute_name_index: CpIndex::new(0x000d),
                         attribute_length: 28,
                         max_stack: 2,
                         max_locals: 2,
@@ -156,7 +157,7 @@ fn test() {
                         attributes_count: 1,
                         attributes: vec![
                             Attribute::General(AttributeInfo {
-                                attribute_name_index: 0x000e,
+                                attribute_name_index: CpIndex::new(0x000e),
                                 attribute_length: 6,
                                 info: vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x0b],
                             }),
@@ -168,7 +169,7 @@ fn test() {
         attributes_count: 1,
         attributes: vec![
             Attribute::General(AttributeInfo {
-                attribute_name_index: 0x0011,
+                attribute_name_index: CpIndex::new(0x0011),
                 attribute_length: 2,
                 info: vec![0x00, 0x12],
             }),
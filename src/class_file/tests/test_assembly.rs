@@ -0,0 +1,207 @@
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure::constant_pool::CpInfo;
+use crate::class_file::structure::root::ClassFile;
+use crate::class_file::reader::read_class_file;
+use crate::class_file::checker::check_class_file;
+use crate::class_file::writer::write_class_file;
+use crate::class_file::assembly::{assemble, disassemble};
+
+use super::test_verifier::read_verify_class;
+
+#[test]
+fn test_round_trip() {
+    let class_file = read_verify_class();
+    let text = disassemble(&class_file);
+    let assembled = assemble(&text).unwrap();
+    assert_eq!(assembled, class_file);
+    assert_eq!(read_class_file(write_class_file(&assembled)).unwrap(), class_file);
+}
+
+// A class with CONSTANT_Long_info and CONSTANT_Double_info entries, each of which takes up two indexes.
+pub(super) fn wide_constants_bytes() -> Vec<u8> {
+    // % cat Wide.java
+    // class Wide {
+    //
+    //     static final long SEED = 6364136223846793005L;
+    //
+    //     long state = 1442695040888963407L;
+    //
+    //     double scale = 2.5;
+    //
+    //     long next() {
+    //         state = state * SEED + 1442695040888963407L;
+    //         return state;
+    //     }
+    //
+    //     double scaled(double x) {
+    //         return x * scale + 0.125;
+    //     }
+    //
+    //     static long mix(long a, int b) {
+    //         return b > 0 ? a ^ (b * 4294967296L) : a;
+    //     }
+    //
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 Wide.java
+    // % od -An -t x1 Wide.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x28, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x05, 0x14, 0x05, 0x7b, 0x7e, 0xf7, 0x67,
+        0x81, 0x4f, 0x09, 0x00, 0x0a, 0x00, 0x0b, 0x07, 0x00, 0x0c, 0x0c, 0x00, 0x0d, 0x00, 0x0e, 0x01,
+        0x00, 0x04, 0x57, 0x69, 0x64, 0x65, 0x01, 0x00, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x01, 0x00,
+        0x01, 0x4a, 0x06, 0x40, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x0a, 0x00, 0x12,
+        0x0c, 0x00, 0x13, 0x00, 0x14, 0x01, 0x00, 0x05, 0x73, 0x63, 0x61, 0x6c, 0x65, 0x01, 0x00, 0x01,
+        0x44, 0x05, 0x58, 0x51, 0xf4, 0x2d, 0x4c, 0x95, 0x7f, 0x2d, 0x06, 0x3f, 0xc0, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x53,
+        0x45, 0x45, 0x44, 0x01, 0x00, 0x0d, 0x43, 0x6f, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x56, 0x61,
+        0x6c, 0x75, 0x65, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e,
+        0x65, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x04, 0x6e,
+        0x65, 0x78, 0x74, 0x01, 0x00, 0x03, 0x28, 0x29, 0x4a, 0x01, 0x00, 0x06, 0x73, 0x63, 0x61, 0x6c,
+        0x65, 0x64, 0x01, 0x00, 0x04, 0x28, 0x44, 0x29, 0x44, 0x01, 0x00, 0x03, 0x6d, 0x69, 0x78, 0x01,
+        0x00, 0x05, 0x28, 0x4a, 0x49, 0x29, 0x4a, 0x01, 0x00, 0x0d, 0x53, 0x74, 0x61, 0x63, 0x6b, 0x4d,
+        0x61, 0x70, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65,
+        0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x09, 0x57, 0x69, 0x64, 0x65, 0x2e, 0x6a, 0x61, 0x76, 0x61,
+        0x00, 0x20, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x18, 0x00, 0x1b, 0x00, 0x0e,
+        0x00, 0x01, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x02, 0x00, 0x15, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x00, 0x14, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x06, 0x00, 0x01, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x33, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x13, 0x2a, 0xb7, 0x00, 0x01, 0x2a, 0x14, 0x00, 0x07, 0xb5, 0x00, 0x09, 0x2a, 0x14, 0x00,
+        0x0f, 0xb5, 0x00, 0x11, 0xb1, 0x00, 0x00, 0x00, 0x01, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x05, 0x00, 0x0b, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x1f, 0x00, 0x20, 0x00, 0x01, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x31, 0x00, 0x05, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x15, 0x2a, 0x2a, 0xb4, 0x00, 0x09, 0x14, 0x00, 0x15, 0x69, 0x14, 0x00, 0x07, 0x61,
+        0xb5, 0x00, 0x09, 0x2a, 0xb4, 0x00, 0x09, 0xad, 0x00, 0x00, 0x00, 0x01, 0x00, 0x1e, 0x00, 0x00,
+        0x00, 0x0a, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x10, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x22, 0x00, 0x01, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x23, 0x00, 0x04, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x0b, 0x27, 0x2a, 0xb4, 0x00, 0x11, 0x6b, 0x14, 0x00, 0x17, 0x63, 0xaf, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x08, 0x00,
+        0x23, 0x00, 0x24, 0x00, 0x01, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x34, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x11, 0x1c, 0x9e, 0x00, 0x0e, 0x1e, 0x1c, 0x85, 0x14, 0x00, 0x19, 0x69, 0x83, 0xa7,
+        0x00, 0x04, 0x1e, 0xad, 0x00, 0x00, 0x00, 0x02, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x25, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x0f, 0x40, 0x04, 0x00,
+        0x01, 0x00, 0x26, 0x00, 0x00, 0x00, 0x02, 0x00, 0x27,
+    ]
+}
+
+pub(super) fn read_wide_constants_class() -> ClassFile {
+    read_class_file(wide_constants_bytes()).unwrap()
+}
+
+#[test]
+fn test_round_trip_wide_constants() {
+    let bytes = wide_constants_bytes();
+    let class_file = read_wide_constants_class();
+    // #7 = Long 1442695040888963407, and #8 is the unusable entry after it.
+    assert_eq!(class_file.constant_pool_count, 40);
+    assert_eq!(class_file.constant_pool.len(), 39);
+    assert!(matches!(class_file.constant_pool[6], CpInfo::Long(_)));
+    assert_eq!(class_file.constant_pool[7], CpInfo::Unusable);
+    assert!(matches!(class_file.constant_pool[8], CpInfo::Fieldref(_)));
+    assert_eq!(write_class_file(&class_file), bytes);
+
+    let text = disassemble(&class_file);
+    assert!(text.contains("    #7 = Long 1442695040888963407\n    #9 = Fieldref #10 #11"));
+    assert!(text.contains("    #15 = Double 2.5\n    #17 = Fieldref #10 #18"));
+    let assembled = assemble(&text).unwrap();
+    assert_eq!(assembled, class_file);
+    assert_eq!(write_class_file(&assembled), bytes);
+
+    // The unusable entry cannot be numbered in the text.
+    let numbered = text.replace("    #9 = Fieldref", "    #8 = Fieldref");
+    assert!(assemble(&numbered).is_err());
+}
+
+#[test]
+fn test_assemble() {
+    // static int count(int n) {
+    //     int c = 0;
+    //     while (n > 0) { c++; n--; }
+    //     return c;
+    // }
+    let text = r#".version 61 0
+.class super #2                             // Count
+.super #4                                   // java/lang/Object
+.constants {
+    #1 = Utf8 "Count"
+    #2 = Class #1                           // Count
+    #3 = Utf8 "java/lang/Object"
+    #4 = Class #3                           // java/lang/Object
+    #5 = Utf8 "count"
+    #6 = Utf8 "(I)I"
+    #7 = Utf8 "Code"
+    #8 = Utf8 "StackMapTable"
+}
+.method static #5 #6 {                      // count:(I)I
+    .attribute Code stack 1 locals 2 {
+        iconst_0
+        istore_1
+L2:     iload_0
+        ifle L15
+        iinc 1, 1
+        iinc 0, -1
+        goto L2
+L15:    iload_1
+        ireturn
+        .attribute StackMapTable {
+            append 2 [Integer]
+            same 12
+        }
+    }
+}"#;
+    let class_file = assemble(text).unwrap();
+    match &class_file.methods[0].attributes[0] {
+        Attribute::Code(code) => {
+            assert_eq!(code.code, vec![
+                0x03, 0x3c, 0x1a, 0x9e, 0x00, 0x0c, 0x84, 0x01, 0x01, 0x84, 0x00, 0xff, 0xa7, 0xff, 0xf6, 0x1b, 0xac,
+            ]);
+            assert_eq!(code.attribute_length, 12 + 17 + 13);
+        }
+        attribute => panic!("{:?}", attribute),
+    }
+    assert_eq!(disassemble(&class_file), text);
+
+    let undefined_label = text.replace("goto L2", "goto L3");
+    assert!(assemble(&undefined_label).is_err());
+
+    // ACC_PRIVATE is not an access flag of classes.
+    let undefined_flag = text.replace(".class super", ".class private");
+    assert!(assemble(&undefined_flag).is_err());
+}
+
+#[test]
+fn test_check_access_flags() {
+    let text = r#".version 61 0
+.class super #2                             // Init
+.super #4                                   // java/lang/Object
+.constants {
+    #1 = Utf8 "Init"
+    #2 = Class #1                           // Init
+    #3 = Utf8 "java/lang/Object"
+    #4 = Class #3                           // java/lang/Object
+    #5 = Utf8 "<clinit>"
+    #6 = Utf8 "()V"
+    #7 = Utf8 "Code"
+}
+.method static #5 #6 {                      // <clinit>:()V
+    .attribute Code stack 0 locals 0 {
+        return
+    }
+}"#;
+    assert_eq!(check_class_file(&assemble(text).unwrap()), Ok(()));
+
+    // The bits that are not defined are ignored.
+    let undefined_bits = text.replace(".class super", ".class super 0x0100").replace(".method static", ".method static 0x8000");
+    assert_eq!(check_class_file(&assemble(&undefined_bits).unwrap()), Ok(()));
+
+    // <clinit> must have ACC_STATIC from 51.0.
+    let not_static = text.replace(".method static", ".method public");
+    assert_eq!(
+        check_class_file(&assemble(&not_static).unwrap()).unwrap_err().message,
+        "Invalid access flags. <clinit> must have ACC_STATIC in 51.0 and above: ACC_PUBLIC"
+    );
+    assert_eq!(check_class_file(&assemble(&not_static.replace(".version 61", ".version 50")).unwrap()), Ok(()));
+}
@@ -0,0 +1,69 @@
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure_utils::cp_accessor::*;
+use crate::class_file::analysis::dataflow::{MethodFrames, analyze_method};
+use crate::class_file::verifier::types::VerificationType;
+
+use super::test_verifier::{TestHierarchy, code_of, read_verify_class};
+
+fn frames_of(class_file: &crate::class_file::structure::root::ClassFile, method_name: &str) -> MethodFrames {
+    let constant_pool = &class_file.constant_pool;
+    let method = class_file.methods.iter()
+        .find(|method| constant_pool.access(method.name_index).bytes_as_string().unwrap() == method_name)
+        .unwrap();
+    analyze_method(class_file, method, &TestHierarchy::new()).unwrap().unwrap()
+}
+
+fn locals_and_stack(class_file: &crate::class_file::structure::root::ClassFile, method_name: &str, pc: u16) -> (Vec<VerificationType>, Vec<VerificationType>) {
+    let frames = frames_of(class_file, method_name);
+    let frame = frames.frame_at(pc).unwrap();
+    (frame.locals.clone(), frame.stack.clone())
+}
+
+#[test]
+fn test_analyze_method() {
+    use VerificationType::*;
+    let class_file = read_verify_class();
+    let int_array = || Reference("[I".to_string());
+
+    // 10: iload 4, the head of the loop
+    assert_eq!(locals_and_stack(&class_file, "sum", 10), (vec![int_array(), Integer, int_array(), Integer, Integer, Top], vec![]));
+    // 13: if_icmpge 33
+    assert_eq!(locals_and_stack(&class_file, "sum", 13), (vec![int_array(), Integer, int_array(), Integer, Integer, Top], vec![Integer, Integer]));
+    // 33: iload_1, after the loop. The StackMapTable drops the variables of the loop.
+    assert_eq!(locals_and_stack(&class_file, "sum", 33), (vec![int_array(), Integer, Top, Top, Top, Top], vec![]));
+    // 5: astore_1, the exception handler
+    assert_eq!(
+        locals_and_stack(&class_file, "describe", 5),
+        (vec![VerificationType::object(), Top], vec![Reference("java/lang/RuntimeException".to_string())])
+    );
+    // 13: invokespecial #21 // Method "<init>":(I)V
+    assert_eq!(locals_and_stack(&class_file, "create", 13), (vec![Integer], vec![Uninitialized(0), Uninitialized(0), Integer]));
+}
+
+#[test]
+fn test_analyze_method_without_stack_map_table() {
+    use VerificationType::*;
+    let mut class_file = read_verify_class();
+    code_of(&mut class_file, "sum").attributes.retain(|attribute| !matches!(attribute, Attribute::StackMapTable(_)));
+    // The variables of the loop are still there after it.
+    let int_array = || Reference("[I".to_string());
+    assert_eq!(locals_and_stack(&class_file, "sum", 33), (vec![int_array(), Integer, int_array(), Integer, Integer, Top], vec![]));
+}
+
+#[test]
+fn test_analyze_method_with_errors() {
+    use VerificationType::*;
+    let mut class_file = read_verify_class();
+    // 19: iaload -> faload
+    code_of(&mut class_file, "sum").code[19] = 0x30;
+    let frames = frames_of(&class_file, "sum");
+    assert_eq!(
+        frames.errors.iter().map(|(pc, e)| (*pc, e.message.as_str())).collect::<Vec<_>>(),
+        vec![(19, "VerifyError: Bad type on operand stack, expected: [F, actual: [I, method: Verify.sum([I)I, pc: 19")]
+    );
+    // 20: istore 5 is reached only through the faload.
+    assert_eq!(frames.frame_at(20), None);
+    // 33: iload_1, after the loop, is still reached from the head of the loop.
+    let int_array = || Reference("[I".to_string());
+    assert_eq!(frames.frame_at(33).map(|frame| frame.locals.clone()), Some(vec![int_array(), Integer, Top, Top, Top, Top]));
+}
@@ -0,0 +1,1413 @@
+use std::process::Command;
+
+use crate::class_file::reader::read_class_file;
+use crate::class_file::decompiler::decompile_class;
+
+use super::test_assembly::read_wide_constants_class;
+
+// Compiles the decompiled source with javac, if there is one, to check that it is Java.
+fn assert_compiles(class_name: &str, source: &str) {
+    let dir = std::env::temp_dir().join(format!("decompiled-{}-{}", class_name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{}.java", class_name));
+    std::fs::write(&path, source).unwrap();
+    let output = Command::new("javac").arg("-d").arg(&dir).arg(&path).output();
+    std::fs::remove_dir_all(&dir).unwrap();
+    match output {
+        Ok(output) => assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr)),
+        Err(_) => eprintln!("javac is not found, so {} is not compiled.", class_name),
+    }
+}
+
+#[test]
+fn test_decompile_class() {
+    // % cat Decompile.java
+    // import java.util.List;
+    //
+    // class Decompile {
+    //
+    //     private int count;
+    //
+    //     Decompile(int count) {
+    //         this.count = count;
+    //     }
+    //
+    //     int sum(List<Integer> values) {
+    //         int total = 0;
+    //         for (int i = 0; i < values.size(); i++) {
+    //             total += values.get(i);
+    //         }
+    //         return total;
+    //     }
+    //
+    //     static String describe(int kind) {
+    //         switch (kind) {
+    //             case 1:
+    //                 return "one";
+    //             case 2:
+    //                 return "two";
+    //             default:
+    //                 return "many";
+    //         }
+    //     }
+    //
+    //     static int parse(String text) {
+    //         try {
+    //             return Integer.parseInt(text);
+    //         } catch (NumberFormatException e) {
+    //             return -1;
+    //         }
+    //     }
+    //
+    //     String label(boolean flag) {
+    //         return (flag ? "on" : "off") + count;
+    //     }
+    //
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Decompile.java
+    // % od -An -t x1 Decompile.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x60, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x09, 0x00, 0x08, 0x00, 0x09, 0x07, 0x00,
+        0x0a, 0x0c, 0x00, 0x0b, 0x00, 0x0c, 0x01, 0x00, 0x09, 0x44, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69,
+        0x6c, 0x65, 0x01, 0x00, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x01, 0x00, 0x01, 0x49, 0x0b, 0x00,
+        0x0e, 0x00, 0x0f, 0x07, 0x00, 0x10, 0x0c, 0x00, 0x11, 0x00, 0x12, 0x01, 0x00, 0x0e, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x4c, 0x69, 0x73, 0x74, 0x01, 0x00, 0x04, 0x73,
+        0x69, 0x7a, 0x65, 0x01, 0x00, 0x03, 0x28, 0x29, 0x49, 0x0b, 0x00, 0x0e, 0x00, 0x14, 0x0c, 0x00,
+        0x15, 0x00, 0x16, 0x01, 0x00, 0x03, 0x67, 0x65, 0x74, 0x01, 0x00, 0x15, 0x28, 0x49, 0x29, 0x4c,
+        0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+        0x3b, 0x07, 0x00, 0x18, 0x01, 0x00, 0x11, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67,
+        0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x0a, 0x00, 0x17, 0x00, 0x1a, 0x0c, 0x00, 0x1b,
+        0x00, 0x12, 0x01, 0x00, 0x08, 0x69, 0x6e, 0x74, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x08, 0x00, 0x1d,
+        0x01, 0x00, 0x03, 0x6f, 0x6e, 0x65, 0x08, 0x00, 0x1f, 0x01, 0x00, 0x03, 0x74, 0x77, 0x6f, 0x08,
+        0x00, 0x21, 0x01, 0x00, 0x04, 0x6d, 0x61, 0x6e, 0x79, 0x0a, 0x00, 0x17, 0x00, 0x23, 0x0c, 0x00,
+        0x24, 0x00, 0x25, 0x01, 0x00, 0x08, 0x70, 0x61, 0x72, 0x73, 0x65, 0x49, 0x6e, 0x74, 0x01, 0x00,
+        0x15, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x3b, 0x29, 0x49, 0x07, 0x00, 0x27, 0x01, 0x00, 0x1f, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x46, 0x6f, 0x72, 0x6d,
+        0x61, 0x74, 0x45, 0x78, 0x63, 0x65, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x08, 0x00, 0x29, 0x01, 0x00,
+        0x02, 0x6f, 0x6e, 0x08, 0x00, 0x2b, 0x01, 0x00, 0x03, 0x6f, 0x66, 0x66, 0x12, 0x00, 0x00, 0x00,
+        0x2d, 0x0c, 0x00, 0x2e, 0x00, 0x2f, 0x01, 0x00, 0x17, 0x6d, 0x61, 0x6b, 0x65, 0x43, 0x6f, 0x6e,
+        0x63, 0x61, 0x74, 0x57, 0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x73,
+        0x01, 0x00, 0x27, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x49, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x01, 0x00, 0x04, 0x28, 0x49, 0x29,
+        0x56, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65, 0x4e,
+        0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12, 0x4c, 0x6f, 0x63,
+        0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01,
+        0x00, 0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x0b, 0x4c, 0x44, 0x65, 0x63, 0x6f, 0x6d, 0x70,
+        0x69, 0x6c, 0x65, 0x3b, 0x01, 0x00, 0x03, 0x73, 0x75, 0x6d, 0x01, 0x00, 0x13, 0x28, 0x4c, 0x6a,
+        0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x4c, 0x69, 0x73, 0x74, 0x3b, 0x29, 0x49,
+        0x01, 0x00, 0x01, 0x69, 0x01, 0x00, 0x06, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x01, 0x00, 0x10,
+        0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x4c, 0x69, 0x73, 0x74, 0x3b,
+        0x01, 0x00, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x01, 0x00, 0x16, 0x4c, 0x6f, 0x63, 0x61, 0x6c,
+        0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x54, 0x61, 0x62, 0x6c,
+        0x65, 0x01, 0x00, 0x25, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x4c,
+        0x69, 0x73, 0x74, 0x3c, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x49,
+        0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b, 0x3e, 0x3b, 0x01, 0x00, 0x0d, 0x53, 0x74, 0x61, 0x63,
+        0x6b, 0x4d, 0x61, 0x70, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x09, 0x53, 0x69, 0x67, 0x6e,
+        0x61, 0x74, 0x75, 0x72, 0x65, 0x01, 0x00, 0x28, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75,
+        0x74, 0x69, 0x6c, 0x2f, 0x4c, 0x69, 0x73, 0x74, 0x3c, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b, 0x3e, 0x3b, 0x29, 0x49,
+        0x01, 0x00, 0x08, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x01, 0x00, 0x15, 0x28, 0x49,
+        0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69,
+        0x6e, 0x67, 0x3b, 0x01, 0x00, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x01, 0x00, 0x05, 0x70, 0x61, 0x72,
+        0x73, 0x65, 0x01, 0x00, 0x01, 0x65, 0x01, 0x00, 0x21, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x46, 0x6f, 0x72, 0x6d, 0x61, 0x74,
+        0x45, 0x78, 0x63, 0x65, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x3b, 0x01, 0x00, 0x04, 0x74, 0x65, 0x78,
+        0x74, 0x01, 0x00, 0x12, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x01, 0x00, 0x05, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x01, 0x00,
+        0x15, 0x28, 0x5a, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x01, 0x00, 0x04, 0x66, 0x6c, 0x61, 0x67, 0x01, 0x00, 0x01,
+        0x5a, 0x07, 0x00, 0x4e, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67,
+        0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65,
+        0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0e, 0x44, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65,
+        0x2e, 0x6a, 0x61, 0x76, 0x61, 0x01, 0x00, 0x10, 0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61,
+        0x70, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x73, 0x0f, 0x06, 0x00, 0x53, 0x0a, 0x00, 0x54, 0x00,
+        0x55, 0x07, 0x00, 0x56, 0x0c, 0x00, 0x2e, 0x00, 0x57, 0x01, 0x00, 0x24, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x53, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6e, 0x63, 0x61, 0x74, 0x46, 0x61, 0x63, 0x74, 0x6f, 0x72, 0x79,
+        0x01, 0x00, 0x98, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69,
+        0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e, 0x64,
+        0x6c, 0x65, 0x73, 0x24, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d,
+        0x65, 0x74, 0x68, 0x6f, 0x64, 0x54, 0x79, 0x70, 0x65, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x5b, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x29,
+        0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b,
+        0x65, 0x2f, 0x43, 0x61, 0x6c, 0x6c, 0x53, 0x69, 0x74, 0x65, 0x3b, 0x08, 0x00, 0x59, 0x01, 0x00,
+        0x02, 0x01, 0x01, 0x01, 0x00, 0x0c, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x43, 0x6c, 0x61, 0x73, 0x73,
+        0x65, 0x73, 0x07, 0x00, 0x5c, 0x01, 0x00, 0x25, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x48,
+        0x61, 0x6e, 0x64, 0x6c, 0x65, 0x73, 0x24, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x07, 0x00, 0x5e,
+        0x01, 0x00, 0x1e, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76,
+        0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65,
+        0x73, 0x01, 0x00, 0x06, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x00, 0x20, 0x00, 0x08, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x0b, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x30, 0x00, 0x01, 0x00, 0x31, 0x00, 0x00, 0x00, 0x46, 0x00, 0x02, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x0a, 0x2a, 0xb7, 0x00, 0x01, 0x2a, 0x1b, 0xb5, 0x00, 0x07, 0xb1, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x32, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x03, 0x00, 0x00, 0x00, 0x07, 0x00, 0x04,
+        0x00, 0x08, 0x00, 0x09, 0x00, 0x09, 0x00, 0x33, 0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x0a, 0x00, 0x34, 0x00, 0x35, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x0b, 0x00, 0x0c,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x36, 0x00, 0x37, 0x00, 0x02, 0x00, 0x31, 0x00, 0x00, 0x00, 0xa0,
+        0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x00, 0x26, 0x03, 0x3d, 0x03, 0x3e, 0x1d, 0x2b, 0xb9, 0x00,
+        0x0d, 0x01, 0x00, 0xa2, 0x00, 0x19, 0x1c, 0x2b, 0x1d, 0xb9, 0x00, 0x13, 0x02, 0x00, 0xc0, 0x00,
+        0x17, 0xb6, 0x00, 0x19, 0x60, 0x3d, 0x84, 0x03, 0x01, 0xa7, 0xff, 0xe3, 0x1c, 0xac, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x32, 0x00, 0x00, 0x00, 0x16, 0x00, 0x05, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02,
+        0x00, 0x0d, 0x00, 0x0e, 0x00, 0x0e, 0x00, 0x1e, 0x00, 0x0d, 0x00, 0x24, 0x00, 0x10, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x04, 0x00, 0x04, 0x00, 0x20, 0x00, 0x38, 0x00, 0x0c, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x34, 0x00, 0x35, 0x00, 0x00, 0x00, 0x00, 0x00, 0x26, 0x00, 0x39,
+        0x00, 0x3a, 0x00, 0x01, 0x00, 0x02, 0x00, 0x24, 0x00, 0x3b, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x3c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x26, 0x00, 0x39, 0x00, 0x3d, 0x00, 0x01,
+        0x00, 0x3e, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0xfd, 0x00, 0x04, 0x01, 0x01, 0xfa, 0x00, 0x1f,
+        0x00, 0x3f, 0x00, 0x00, 0x00, 0x02, 0x00, 0x40, 0x00, 0x08, 0x00, 0x41, 0x00, 0x42, 0x00, 0x01,
+        0x00, 0x31, 0x00, 0x00, 0x00, 0x66, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x25, 0x1a, 0xab,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x1b, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x1e, 0x12, 0x1c, 0xb0, 0x12, 0x1e, 0xb0,
+        0x12, 0x20, 0xb0, 0x00, 0x00, 0x00, 0x03, 0x00, 0x32, 0x00, 0x00, 0x00, 0x12, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x1c, 0x00, 0x16, 0x00, 0x1f, 0x00, 0x18, 0x00, 0x22, 0x00, 0x1a, 0x00,
+        0x33, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x25, 0x00, 0x43, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x1c, 0x02, 0x02, 0x00, 0x08, 0x00, 0x44,
+        0x00, 0x25, 0x00, 0x01, 0x00, 0x31, 0x00, 0x00, 0x00, 0x58, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x08, 0x2a, 0xb8, 0x00, 0x22, 0xac, 0x4c, 0x02, 0xac, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x05, 0x00, 0x26, 0x00, 0x03, 0x00, 0x32, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x20, 0x00, 0x05, 0x00, 0x21, 0x00, 0x06, 0x00, 0x22, 0x00, 0x33, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x02, 0x00, 0x06, 0x00, 0x02, 0x00, 0x45, 0x00, 0x46, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x47, 0x00, 0x48, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x45, 0x07,
+        0x00, 0x26, 0x00, 0x00, 0x00, 0x49, 0x00, 0x4a, 0x00, 0x01, 0x00, 0x31, 0x00, 0x00, 0x00, 0x56,
+        0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x15, 0x1b, 0x99, 0x00, 0x08, 0x12, 0x28, 0xa7, 0x00,
+        0x05, 0x12, 0x2a, 0x2a, 0xb4, 0x00, 0x07, 0xba, 0x00, 0x2c, 0x00, 0x00, 0xb0, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x32, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x27, 0x00, 0x33, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x15, 0x00, 0x34, 0x00, 0x35, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x4b, 0x00, 0x4c, 0x00, 0x01, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x02, 0x09, 0x41, 0x07, 0x00, 0x4d, 0x00, 0x03, 0x00, 0x4f, 0x00, 0x00, 0x00, 0x02, 0x00, 0x50,
+        0x00, 0x51, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x52, 0x00, 0x01, 0x00, 0x58, 0x00, 0x5a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x5b, 0x00, 0x5d, 0x00, 0x5f, 0x00, 0x19,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"import java.util.List;
+
+class Decompile {
+    private int count;
+
+    Decompile(int count) {
+        this.count = count;
+    }
+
+    int sum(List<Integer> values) {
+        int total = 0;
+        for (int i = 0; i < values.size(); i++) {
+            total += ((Integer) values.get(i)).intValue();
+        }
+        return total;
+    }
+
+    static String describe(int kind) {
+        switch (kind) {
+            case 1:
+                return "one";
+            case 2:
+                return "two";
+        }
+        return "many";
+    }
+
+    static int parse(String text) {
+        try {
+            return Integer.parseInt(text);
+        } catch (NumberFormatException e) {
+            return -1;
+        }
+    }
+
+    String label(boolean flag) {
+        return (flag ? "on" : "off") + this.count;
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|_| None).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_side_effects() {
+    // % cat SideEffects.java
+    // class SideEffects {
+    //
+    //     int count;
+    //     int[] values;
+    //     static int total;
+    //
+    //     int increment() {
+    //         return count++ + ++count;
+    //     }
+    //
+    //     int element(int i) {
+    //         return values[i]++ + values[i];
+    //     }
+    //
+    //     int compound(int x) {
+    //         return (count += x) * 2;
+    //     }
+    //
+    //     static int chain() {
+    //         int x = total = 3;
+    //         return x + total--;
+    //     }
+    //
+    //     int spill(int i) {
+    //         return add(i + 1, i += 2);
+    //     }
+    //
+    //     static int add(int a, int b) {
+    //         return a + b;
+    //     }
+    //
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g SideEffects.java
+    // % od -An -t x1 SideEffects.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x2a, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x09, 0x00, 0x08, 0x00, 0x09, 0x07, 0x00,
+        0x0a, 0x0c, 0x00, 0x0b, 0x00, 0x0c, 0x01, 0x00, 0x0b, 0x53, 0x69, 0x64, 0x65, 0x45, 0x66, 0x66,
+        0x65, 0x63, 0x74, 0x73, 0x01, 0x00, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x01, 0x00, 0x01, 0x49,
+        0x09, 0x00, 0x08, 0x00, 0x0e, 0x0c, 0x00, 0x0f, 0x00, 0x10, 0x01, 0x00, 0x06, 0x76, 0x61, 0x6c,
+        0x75, 0x65, 0x73, 0x01, 0x00, 0x02, 0x5b, 0x49, 0x09, 0x00, 0x08, 0x00, 0x12, 0x0c, 0x00, 0x13,
+        0x00, 0x0c, 0x01, 0x00, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x0a, 0x00, 0x08, 0x00, 0x15, 0x0c,
+        0x00, 0x16, 0x00, 0x17, 0x01, 0x00, 0x03, 0x61, 0x64, 0x64, 0x01, 0x00, 0x05, 0x28, 0x49, 0x49,
+        0x29, 0x49, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65,
+        0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12, 0x4c, 0x6f,
+        0x63, 0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62, 0x6c, 0x65,
+        0x01, 0x00, 0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x0d, 0x4c, 0x53, 0x69, 0x64, 0x65, 0x45,
+        0x66, 0x66, 0x65, 0x63, 0x74, 0x73, 0x3b, 0x01, 0x00, 0x09, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x6d,
+        0x65, 0x6e, 0x74, 0x01, 0x00, 0x03, 0x28, 0x29, 0x49, 0x01, 0x00, 0x07, 0x65, 0x6c, 0x65, 0x6d,
+        0x65, 0x6e, 0x74, 0x01, 0x00, 0x04, 0x28, 0x49, 0x29, 0x49, 0x01, 0x00, 0x01, 0x69, 0x01, 0x00,
+        0x08, 0x63, 0x6f, 0x6d, 0x70, 0x6f, 0x75, 0x6e, 0x64, 0x01, 0x00, 0x01, 0x78, 0x01, 0x00, 0x05,
+        0x63, 0x68, 0x61, 0x69, 0x6e, 0x01, 0x00, 0x05, 0x73, 0x70, 0x69, 0x6c, 0x6c, 0x01, 0x00, 0x01,
+        0x61, 0x01, 0x00, 0x01, 0x62, 0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69,
+        0x6c, 0x65, 0x01, 0x00, 0x10, 0x53, 0x69, 0x64, 0x65, 0x45, 0x66, 0x66, 0x65, 0x63, 0x74, 0x73,
+        0x2e, 0x6a, 0x61, 0x76, 0x61, 0x00, 0x20, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x13, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x2a,
+        0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x1b, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x1e, 0x00, 0x01, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x42, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x2a, 0x59, 0xb4, 0x00,
+        0x07, 0x5a, 0x04, 0x60, 0xb5, 0x00, 0x07, 0x2a, 0x59, 0xb4, 0x00, 0x07, 0x04, 0x60, 0x5a, 0xb5,
+        0x00, 0x07, 0x60, 0xac, 0x00, 0x00, 0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x1b, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x20, 0x00, 0x01, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x47, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x13, 0x2a, 0xb4, 0x00, 0x0d,
+        0x1b, 0x5c, 0x2e, 0x5b, 0x04, 0x60, 0x4f, 0x2a, 0xb4, 0x00, 0x0d, 0x1b, 0x2e, 0x60, 0xac, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x13, 0x00, 0x1b, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x00, 0x21, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x20, 0x00, 0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x42, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x0e, 0x2a, 0x59, 0xb4, 0x00, 0x07, 0x1b, 0x60, 0x5a, 0xb5, 0x00, 0x07, 0x05, 0x68, 0xac, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x1b, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x23, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x08, 0x00, 0x24, 0x00,
+        0x1e, 0x00, 0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x40, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x12, 0x06, 0x59, 0xb3, 0x00, 0x11, 0x3b, 0x1a, 0xb2, 0x00, 0x11, 0x59, 0x04, 0x64, 0xb3, 0x00,
+        0x11, 0x60, 0xac, 0x00, 0x00, 0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x06, 0x00, 0x15, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x0c, 0x00, 0x23, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x25, 0x00, 0x20, 0x00,
+        0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0b, 0x1b,
+        0x04, 0x60, 0x84, 0x01, 0x02, 0x1b, 0xb8, 0x00, 0x14, 0xac, 0x00, 0x00, 0x00, 0x02, 0x00, 0x19,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x19, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x1b, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x21, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x08, 0x00, 0x16, 0x00, 0x17, 0x00, 0x01, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x38, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x1a, 0x1b, 0x60, 0xac,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x1a, 0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x26, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x27, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x01, 0x00, 0x28,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x29,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"class SideEffects {
+    int count;
+    int[] values;
+    static int total;
+
+    SideEffects() {
+    }
+
+    int increment() {
+        return this.count++ + ++this.count;
+    }
+
+    int element(int i) {
+        return this.values[i]++ + this.values[i];
+    }
+
+    int compound(int x) {
+        return (this.count += x) * 2;
+    }
+
+    static int chain() {
+        int x = SideEffects.total = 3;
+        return x + SideEffects.total--;
+    }
+
+    int spill(int i) {
+        int tmp0 = i + 1;
+        i += 2;
+        return SideEffects.add(tmp0, i);
+    }
+
+    static int add(int a, int b) {
+        return a + b;
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|_| None).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_labeled_jumps() {
+    // % cat Jumps.java
+    // class Jumps {
+    //
+    //     static int find(int[][] grid, int value) {
+    //         int found = -1;
+    //         outer:
+    //         for (int i = 0; i < grid.length; i++) {
+    //             for (int j = 0; j < grid[i].length; j++) {
+    //                 if (grid[i][j] == value) {
+    //                     found = i;
+    //                     break outer;
+    //                 }
+    //             }
+    //         }
+    //         return found;
+    //     }
+    //
+    //     static int count(int[] values, int limit) {
+    //         int n = 0;
+    //         int i = 0;
+    //         outer:
+    //         while (i < values.length) {
+    //             int v = values[i++];
+    //             int j = 0;
+    //             while (j < v) {
+    //                 if (j == limit) {
+    //                     continue outer;
+    //                 }
+    //                 j++;
+    //             }
+    //             n++;
+    //         }
+    //         return n;
+    //     }
+    //
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Jumps.java
+    // % od -An -t x1 Jumps.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x21, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x07, 0x00, 0x08, 0x01, 0x00, 0x05, 0x4a,
+        0x75, 0x6d, 0x70, 0x73, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69,
+        0x6e, 0x65, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12,
+        0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62,
+        0x6c, 0x65, 0x01, 0x00, 0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x07, 0x4c, 0x4a, 0x75, 0x6d,
+        0x70, 0x73, 0x3b, 0x01, 0x00, 0x04, 0x66, 0x69, 0x6e, 0x64, 0x01, 0x00, 0x07, 0x28, 0x5b, 0x5b,
+        0x49, 0x49, 0x29, 0x49, 0x01, 0x00, 0x01, 0x6a, 0x01, 0x00, 0x01, 0x49, 0x01, 0x00, 0x01, 0x69,
+        0x01, 0x00, 0x04, 0x67, 0x72, 0x69, 0x64, 0x01, 0x00, 0x03, 0x5b, 0x5b, 0x49, 0x01, 0x00, 0x05,
+        0x76, 0x61, 0x6c, 0x75, 0x65, 0x01, 0x00, 0x05, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x01, 0x00, 0x0d,
+        0x53, 0x74, 0x61, 0x63, 0x6b, 0x4d, 0x61, 0x70, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x05,
+        0x63, 0x6f, 0x75, 0x6e, 0x74, 0x01, 0x00, 0x06, 0x28, 0x5b, 0x49, 0x49, 0x29, 0x49, 0x01, 0x00,
+        0x01, 0x76, 0x01, 0x00, 0x06, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x01, 0x00, 0x02, 0x5b, 0x49,
+        0x01, 0x00, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x01, 0x00, 0x01, 0x6e, 0x01, 0x00, 0x0a, 0x53,
+        0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0a, 0x4a, 0x75, 0x6d, 0x70,
+        0x73, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x00, 0x20, 0x00, 0x07, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x2a, 0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x0c, 0x00, 0x0d, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00, 0xbd, 0x00, 0x03, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x33, 0x02, 0x3d, 0x03, 0x3e, 0x1d, 0x2a, 0xbe, 0xa2, 0x00, 0x2a, 0x03,
+        0x36, 0x04, 0x15, 0x04, 0x2a, 0x1d, 0x32, 0xbe, 0xa2, 0x00, 0x18, 0x2a, 0x1d, 0x32, 0x15, 0x04,
+        0x2e, 0x1b, 0xa0, 0x00, 0x08, 0x1d, 0x3d, 0xa7, 0x00, 0x0f, 0x84, 0x04, 0x01, 0xa7, 0xff, 0xe5,
+        0x84, 0x03, 0x01, 0xa7, 0xff, 0xd6, 0x1c, 0xac, 0x00, 0x00, 0x00, 0x03, 0x00, 0x0a, 0x00, 0x00,
+        0x00, 0x26, 0x00, 0x09, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x00, 0x06, 0x00, 0x0a, 0x00, 0x07,
+        0x00, 0x16, 0x00, 0x08, 0x00, 0x20, 0x00, 0x09, 0x00, 0x22, 0x00, 0x0a, 0x00, 0x25, 0x00, 0x07,
+        0x00, 0x2b, 0x00, 0x06, 0x00, 0x31, 0x00, 0x0e, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x34, 0x00, 0x05,
+        0x00, 0x0d, 0x00, 0x1e, 0x00, 0x10, 0x00, 0x11, 0x00, 0x04, 0x00, 0x04, 0x00, 0x2d, 0x00, 0x12,
+        0x00, 0x11, 0x00, 0x03, 0x00, 0x00, 0x00, 0x33, 0x00, 0x13, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x33, 0x00, 0x15, 0x00, 0x11, 0x00, 0x01, 0x00, 0x02, 0x00, 0x31, 0x00, 0x16, 0x00, 0x11,
+        0x00, 0x02, 0x00, 0x17, 0x00, 0x00, 0x00, 0x12, 0x00, 0x05, 0xfd, 0x00, 0x04, 0x01, 0x01, 0xfc,
+        0x00, 0x08, 0x01, 0x17, 0xfa, 0x00, 0x05, 0xfa, 0x00, 0x05, 0x00, 0x08, 0x00, 0x18, 0x00, 0x19,
+        0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00, 0xd2, 0x00, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00, 0x33,
+        0x03, 0x3d, 0x03, 0x3e, 0x1d, 0x2a, 0xbe, 0xa2, 0x00, 0x2a, 0x2a, 0x1d, 0x84, 0x03, 0x01, 0x2e,
+        0x36, 0x04, 0x03, 0x36, 0x05, 0x15, 0x05, 0x15, 0x04, 0xa2, 0x00, 0x12, 0x15, 0x05, 0x1b, 0xa0,
+        0x00, 0x06, 0xa7, 0xff, 0xe2, 0x84, 0x05, 0x01, 0xa7, 0xff, 0xed, 0x84, 0x02, 0x01, 0xa7, 0xff,
+        0xd6, 0x1c, 0xac, 0x00, 0x00, 0x00, 0x03, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x32, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x02, 0x00, 0x13, 0x00, 0x04, 0x00, 0x15, 0x00, 0x0a, 0x00, 0x16, 0x00,
+        0x12, 0x00, 0x17, 0x00, 0x15, 0x00, 0x18, 0x00, 0x1c, 0x00, 0x19, 0x00, 0x22, 0x00, 0x1a, 0x00,
+        0x25, 0x00, 0x1c, 0x00, 0x2b, 0x00, 0x1e, 0x00, 0x2e, 0x00, 0x1f, 0x00, 0x31, 0x00, 0x20, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x06, 0x00, 0x12, 0x00, 0x1c, 0x00, 0x1a, 0x00, 0x11, 0x00,
+        0x04, 0x00, 0x15, 0x00, 0x19, 0x00, 0x10, 0x00, 0x11, 0x00, 0x05, 0x00, 0x00, 0x00, 0x33, 0x00,
+        0x1b, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x33, 0x00, 0x1d, 0x00, 0x11, 0x00, 0x01, 0x00,
+        0x02, 0x00, 0x31, 0x00, 0x1e, 0x00, 0x11, 0x00, 0x02, 0x00, 0x04, 0x00, 0x2f, 0x00, 0x12, 0x00,
+        0x11, 0x00, 0x03, 0x00, 0x17, 0x00, 0x00, 0x00, 0x11, 0x00, 0x05, 0xfd, 0x00, 0x04, 0x01, 0x01,
+        0xfd, 0x00, 0x10, 0x01, 0x01, 0x0f, 0x05, 0xf9, 0x00, 0x05, 0x00, 0x01, 0x00, 0x1f, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x20,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"class Jumps {
+    Jumps() {
+    }
+
+    static int find(int[][] grid, int value) {
+        int found = -1;
+        label4: for (int i = 0; i < grid.length; i++) {
+            for (int j = 0; j < grid[i].length; j++) {
+                if (grid[i][j] == value) {
+                    found = i;
+                    break label4;
+                }
+            }
+        }
+        return found;
+    }
+
+    static int count(int[] values, int limit) {
+        int n = 0;
+        int i = 0;
+        label4: while (i < values.length) {
+            int v = values[i++];
+            for (int j = 0; j < v; j++) {
+                if (j == limit) {
+                    continue label4;
+                }
+            }
+            n++;
+        }
+        return n;
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|_| None).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_inner_class() {
+    // % cat Outer.java
+    // class Outer {
+    //
+    //     int base;
+    //
+    //     class Inner {
+    //
+    //         int offset;
+    //
+    //         Inner(int offset) {
+    //             this.offset = offset;
+    //         }
+    //
+    //         int get() {
+    //             return base + offset;
+    //         }
+    //     }
+    //
+    //     Inner make() {
+    //         return new Inner(1);
+    //     }
+    //
+    //     static Inner make(Outer outer) {
+    //         return outer.new Inner(2);
+    //     }
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Outer.java
+    // % od -An -t x1 Outer.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x24, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x07, 0x00, 0x08, 0x01, 0x00, 0x0b, 0x4f,
+        0x75, 0x74, 0x65, 0x72, 0x24, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x0a, 0x00, 0x07, 0x00, 0x0a, 0x0c,
+        0x00, 0x05, 0x00, 0x0b, 0x01, 0x00, 0x0b, 0x28, 0x4c, 0x4f, 0x75, 0x74, 0x65, 0x72, 0x3b, 0x49,
+        0x29, 0x56, 0x0a, 0x00, 0x0d, 0x00, 0x0e, 0x07, 0x00, 0x0f, 0x0c, 0x00, 0x10, 0x00, 0x11, 0x01,
+        0x00, 0x11, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x4f, 0x62, 0x6a, 0x65,
+        0x63, 0x74, 0x73, 0x01, 0x00, 0x0e, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x4e, 0x6f, 0x6e,
+        0x4e, 0x75, 0x6c, 0x6c, 0x01, 0x00, 0x26, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x07, 0x00, 0x13,
+        0x01, 0x00, 0x05, 0x4f, 0x75, 0x74, 0x65, 0x72, 0x01, 0x00, 0x04, 0x62, 0x61, 0x73, 0x65, 0x01,
+        0x00, 0x01, 0x49, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e,
+        0x65, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12, 0x4c,
+        0x6f, 0x63, 0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62, 0x6c,
+        0x65, 0x01, 0x00, 0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x07, 0x4c, 0x4f, 0x75, 0x74, 0x65,
+        0x72, 0x3b, 0x01, 0x00, 0x04, 0x6d, 0x61, 0x6b, 0x65, 0x01, 0x00, 0x0f, 0x28, 0x29, 0x4c, 0x4f,
+        0x75, 0x74, 0x65, 0x72, 0x24, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x3b, 0x01, 0x00, 0x16, 0x28, 0x4c,
+        0x4f, 0x75, 0x74, 0x65, 0x72, 0x3b, 0x29, 0x4c, 0x4f, 0x75, 0x74, 0x65, 0x72, 0x24, 0x49, 0x6e,
+        0x6e, 0x65, 0x72, 0x3b, 0x01, 0x00, 0x05, 0x6f, 0x75, 0x74, 0x65, 0x72, 0x01, 0x00, 0x0a, 0x53,
+        0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0a, 0x4f, 0x75, 0x74, 0x65,
+        0x72, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x01, 0x00, 0x0b, 0x4e, 0x65, 0x73, 0x74, 0x4d, 0x65, 0x6d,
+        0x62, 0x65, 0x72, 0x73, 0x01, 0x00, 0x0c, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x43, 0x6c, 0x61, 0x73,
+        0x73, 0x65, 0x73, 0x01, 0x00, 0x05, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x00, 0x20, 0x00, 0x12, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x15, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x16, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x2a, 0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00, 0x02, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x19, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x1c, 0x00, 0x01, 0x00, 0x16, 0x00, 0x00, 0x00, 0x34, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x0a, 0xbb, 0x00, 0x07, 0x59, 0x2a, 0x04, 0xb7, 0x00, 0x09, 0xb0, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x17, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x13, 0x00, 0x18, 0x00, 0x00,
+        0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x19, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x1b, 0x00, 0x1d, 0x00, 0x01, 0x00, 0x16, 0x00, 0x00, 0x00, 0x39, 0x00, 0x04, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x0f, 0xbb, 0x00, 0x07, 0x59, 0x2a, 0x59, 0xb8, 0x00, 0x0c, 0x57, 0x05, 0xb7,
+        0x00, 0x09, 0xb0, 0x00, 0x00, 0x00, 0x02, 0x00, 0x17, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x18, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x1e, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x03, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x02, 0x00, 0x20, 0x00,
+        0x21, 0x00, 0x00, 0x00, 0x04, 0x00, 0x01, 0x00, 0x07, 0x00, 0x22, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x01, 0x00, 0x07, 0x00, 0x12, 0x00, 0x23, 0x00, 0x00,
+    ];
+    // % od -An -t x1 'Outer$Inner.class' | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let inner_bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x23, 0x09, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x0b, 0x4f, 0x75, 0x74, 0x65, 0x72, 0x24,
+        0x49, 0x6e, 0x6e, 0x65, 0x72, 0x01, 0x00, 0x06, 0x74, 0x68, 0x69, 0x73, 0x24, 0x30, 0x01, 0x00,
+        0x07, 0x4c, 0x4f, 0x75, 0x74, 0x65, 0x72, 0x3b, 0x0a, 0x00, 0x08, 0x00, 0x09, 0x07, 0x00, 0x0a,
+        0x0c, 0x00, 0x0b, 0x00, 0x0c, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e, 0x69, 0x74,
+        0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x09, 0x00, 0x02, 0x00, 0x0e, 0x0c, 0x00, 0x0f, 0x00,
+        0x10, 0x01, 0x00, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x01, 0x00, 0x01, 0x49, 0x09, 0x00,
+        0x12, 0x00, 0x13, 0x07, 0x00, 0x14, 0x0c, 0x00, 0x15, 0x00, 0x10, 0x01, 0x00, 0x05, 0x4f, 0x75,
+        0x74, 0x65, 0x72, 0x01, 0x00, 0x04, 0x62, 0x61, 0x73, 0x65, 0x01, 0x00, 0x0b, 0x28, 0x4c, 0x4f,
+        0x75, 0x74, 0x65, 0x72, 0x3b, 0x49, 0x29, 0x56, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01,
+        0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c,
+        0x65, 0x01, 0x00, 0x12, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c,
+        0x65, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x0d,
+        0x4c, 0x4f, 0x75, 0x74, 0x65, 0x72, 0x24, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x3b, 0x01, 0x00, 0x03,
+        0x67, 0x65, 0x74, 0x01, 0x00, 0x03, 0x28, 0x29, 0x49, 0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72,
+        0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0a, 0x4f, 0x75, 0x74, 0x65, 0x72, 0x2e, 0x6a,
+        0x61, 0x76, 0x61, 0x01, 0x00, 0x08, 0x4e, 0x65, 0x73, 0x74, 0x48, 0x6f, 0x73, 0x74, 0x01, 0x00,
+        0x0c, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x43, 0x6c, 0x61, 0x73, 0x73, 0x65, 0x73, 0x01, 0x00, 0x05,
+        0x49, 0x6e, 0x6e, 0x65, 0x72, 0x00, 0x20, 0x00, 0x02, 0x00, 0x08, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x10, 0x00, 0x00, 0x10, 0x10, 0x00, 0x05, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x16, 0x00, 0x01, 0x00, 0x17, 0x00, 0x00, 0x00, 0x55, 0x00,
+        0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x0f, 0x2a, 0x2b, 0xb5, 0x00, 0x01, 0x2a, 0xb7, 0x00, 0x07,
+        0x2a, 0x1c, 0xb5, 0x00, 0x0d, 0xb1, 0x00, 0x00, 0x00, 0x02, 0x00, 0x18, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x09, 0x00, 0x09, 0x00, 0x0a, 0x00, 0x0e, 0x00, 0x0b, 0x00, 0x19,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x03, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x1a, 0x00, 0x1b, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x0f,
+        0x00, 0x10, 0x00, 0x02, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x1d, 0x00, 0x01, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x37, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0d, 0x2a, 0xb4, 0x00, 0x01, 0xb4, 0x00,
+        0x11, 0x2a, 0xb4, 0x00, 0x0d, 0x60, 0xac, 0x00, 0x00, 0x00, 0x02, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x19, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x1a, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x03, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x1f, 0x00, 0x20, 0x00, 0x00, 0x00, 0x02, 0x00, 0x12, 0x00, 0x21, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x01, 0x00, 0x02, 0x00, 0x12, 0x00, 0x22, 0x00, 0x00,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"import java.util.Objects;
+
+class Outer {
+    int base;
+
+    Outer() {
+    }
+
+    Outer.Inner make() {
+        return new Outer.Inner(1);
+    }
+
+    static Outer.Inner make(Outer outer) {
+        Objects.requireNonNull(outer);
+        return outer.new Inner(2);
+    }
+
+    class Inner {
+        int offset;
+
+        Inner(int offset) {
+            this.offset = offset;
+        }
+
+        int get() {
+            return Outer.this.base + this.offset;
+        }
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|name| (name == "Outer$Inner").then(|| read_class_file(inner_bytes.clone()).unwrap())).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_lambdas() {
+    // % cat Lambdas.java
+    // import java.util.List;
+    // import java.util.function.Function;
+    // import java.util.function.IntSupplier;
+    //
+    // class Lambdas {
+    //
+    //     int base;
+    //
+    //     IntSupplier capture(int n) {
+    //         return () -> base + n;
+    //     }
+    //
+    //     static Function<String, Integer> length() {
+    //         return s -> s.length();
+    //     }
+    //
+    //     static void print(List<String> names, String prefix) {
+    //         names.forEach(name -> {
+    //             if (!name.isEmpty()) {
+    //                 System.out.println(prefix + name);
+    //             }
+    //         });
+    //     }
+    //
+    //     static Function<Integer, IntSupplier> nested() {
+    //         return n -> () -> n * 2;
+    //     }
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Lambdas.java
+    // % od -An -t x1 Lambdas.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x97, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x12, 0x00, 0x00, 0x00, 0x08, 0x0c, 0x00,
+        0x09, 0x00, 0x0a, 0x01, 0x00, 0x08, 0x67, 0x65, 0x74, 0x41, 0x73, 0x49, 0x6e, 0x74, 0x01, 0x00,
+        0x2c, 0x28, 0x4c, 0x4c, 0x61, 0x6d, 0x62, 0x64, 0x61, 0x73, 0x3b, 0x49, 0x29, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+        0x2f, 0x49, 0x6e, 0x74, 0x53, 0x75, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x72, 0x3b, 0x12, 0x00, 0x01,
+        0x00, 0x0c, 0x0c, 0x00, 0x0d, 0x00, 0x0e, 0x01, 0x00, 0x05, 0x61, 0x70, 0x70, 0x6c, 0x79, 0x01,
+        0x00, 0x1f, 0x28, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66,
+        0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+        0x3b, 0x12, 0x00, 0x02, 0x00, 0x10, 0x0c, 0x00, 0x11, 0x00, 0x12, 0x01, 0x00, 0x06, 0x61, 0x63,
+        0x63, 0x65, 0x70, 0x74, 0x01, 0x00, 0x31, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x43,
+        0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x3b, 0x0b, 0x00, 0x14, 0x00, 0x15, 0x07, 0x00, 0x16,
+        0x0c, 0x00, 0x17, 0x00, 0x18, 0x01, 0x00, 0x0e, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69,
+        0x6c, 0x2f, 0x4c, 0x69, 0x73, 0x74, 0x01, 0x00, 0x07, 0x66, 0x6f, 0x72, 0x45, 0x61, 0x63, 0x68,
+        0x01, 0x00, 0x20, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66,
+        0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72,
+        0x3b, 0x29, 0x56, 0x12, 0x00, 0x03, 0x00, 0x0c, 0x12, 0x00, 0x04, 0x00, 0x1b, 0x0c, 0x00, 0x09,
+        0x00, 0x1c, 0x01, 0x00, 0x35, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67,
+        0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x49, 0x6e,
+        0x74, 0x53, 0x75, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x72, 0x3b, 0x0a, 0x00, 0x1e, 0x00, 0x1f, 0x07,
+        0x00, 0x20, 0x0c, 0x00, 0x21, 0x00, 0x22, 0x01, 0x00, 0x11, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x01, 0x00, 0x08, 0x69, 0x6e,
+        0x74, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x01, 0x00, 0x03, 0x28, 0x29, 0x49, 0x0a, 0x00, 0x24, 0x00,
+        0x25, 0x07, 0x00, 0x26, 0x0c, 0x00, 0x27, 0x00, 0x28, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x01, 0x00, 0x07, 0x69,
+        0x73, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x01, 0x00, 0x03, 0x28, 0x29, 0x5a, 0x09, 0x00, 0x2a, 0x00,
+        0x2b, 0x07, 0x00, 0x2c, 0x0c, 0x00, 0x2d, 0x00, 0x2e, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x01, 0x00, 0x03, 0x6f,
+        0x75, 0x74, 0x01, 0x00, 0x15, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x69, 0x6f, 0x2f, 0x50, 0x72,
+        0x69, 0x6e, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x3b, 0x12, 0x00, 0x05, 0x00, 0x30, 0x0c,
+        0x00, 0x31, 0x00, 0x32, 0x01, 0x00, 0x17, 0x6d, 0x61, 0x6b, 0x65, 0x43, 0x6f, 0x6e, 0x63, 0x61,
+        0x74, 0x57, 0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x73, 0x01, 0x00,
+        0x38, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x0a, 0x00, 0x34, 0x00, 0x35, 0x07, 0x00,
+        0x36, 0x0c, 0x00, 0x37, 0x00, 0x38, 0x01, 0x00, 0x13, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x69, 0x6f,
+        0x2f, 0x50, 0x72, 0x69, 0x6e, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x01, 0x00, 0x07, 0x70,
+        0x72, 0x69, 0x6e, 0x74, 0x6c, 0x6e, 0x01, 0x00, 0x15, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x56, 0x0a, 0x00,
+        0x24, 0x00, 0x3a, 0x0c, 0x00, 0x3b, 0x00, 0x22, 0x01, 0x00, 0x06, 0x6c, 0x65, 0x6e, 0x67, 0x74,
+        0x68, 0x0a, 0x00, 0x1e, 0x00, 0x3d, 0x0c, 0x00, 0x3e, 0x00, 0x3f, 0x01, 0x00, 0x07, 0x76, 0x61,
+        0x6c, 0x75, 0x65, 0x4f, 0x66, 0x01, 0x00, 0x16, 0x28, 0x49, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b, 0x09, 0x00,
+        0x41, 0x00, 0x42, 0x07, 0x00, 0x43, 0x0c, 0x00, 0x44, 0x00, 0x45, 0x01, 0x00, 0x07, 0x4c, 0x61,
+        0x6d, 0x62, 0x64, 0x61, 0x73, 0x01, 0x00, 0x04, 0x62, 0x61, 0x73, 0x65, 0x01, 0x00, 0x01, 0x49,
+        0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65, 0x4e, 0x75,
+        0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12, 0x4c, 0x6f, 0x63, 0x61,
+        0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00,
+        0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x09, 0x4c, 0x4c, 0x61, 0x6d, 0x62, 0x64, 0x61, 0x73,
+        0x3b, 0x01, 0x00, 0x07, 0x63, 0x61, 0x70, 0x74, 0x75, 0x72, 0x65, 0x01, 0x00, 0x23, 0x28, 0x49,
+        0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66, 0x75, 0x6e, 0x63,
+        0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x49, 0x6e, 0x74, 0x53, 0x75, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x72,
+        0x3b, 0x01, 0x00, 0x01, 0x6e, 0x01, 0x00, 0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
+        0x65, 0x01, 0x00, 0x46, 0x28, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c,
+        0x2f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69,
+        0x6f, 0x6e, 0x3c, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74,
+        0x72, 0x69, 0x6e, 0x67, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f,
+        0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b, 0x3e, 0x3b, 0x01, 0x00, 0x05, 0x70, 0x72, 0x69,
+        0x6e, 0x74, 0x01, 0x00, 0x25, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c,
+        0x2f, 0x4c, 0x69, 0x73, 0x74, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67,
+        0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x56, 0x01, 0x00, 0x05, 0x6e, 0x61, 0x6d,
+        0x65, 0x73, 0x01, 0x00, 0x10, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f,
+        0x4c, 0x69, 0x73, 0x74, 0x3b, 0x01, 0x00, 0x06, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x01, 0x00,
+        0x12, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69,
+        0x6e, 0x67, 0x3b, 0x01, 0x00, 0x16, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61,
+        0x62, 0x6c, 0x65, 0x54, 0x79, 0x70, 0x65, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x24, 0x4c,
+        0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x4c, 0x69, 0x73, 0x74, 0x3c, 0x4c,
+        0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67,
+        0x3b, 0x3e, 0x3b, 0x01, 0x00, 0x39, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x75, 0x74, 0x69,
+        0x6c, 0x2f, 0x4c, 0x69, 0x73, 0x74, 0x3c, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x3e, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x56, 0x01,
+        0x00, 0x06, 0x6e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x01, 0x00, 0x54, 0x28, 0x29, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+        0x2f, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3c, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x75, 0x74, 0x69, 0x6c, 0x2f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+        0x2f, 0x49, 0x6e, 0x74, 0x53, 0x75, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x72, 0x3b, 0x3e, 0x3b, 0x01,
+        0x00, 0x0f, 0x6c, 0x61, 0x6d, 0x62, 0x64, 0x61, 0x24, 0x6e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x24,
+        0x34, 0x01, 0x00, 0x13, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x49,
+        0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b, 0x01, 0x00, 0x0f, 0x6c, 0x61, 0x6d, 0x62, 0x64, 0x61,
+        0x24, 0x6e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x24, 0x33, 0x01, 0x00, 0x16, 0x28, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b,
+        0x29, 0x49, 0x01, 0x00, 0x0e, 0x6c, 0x61, 0x6d, 0x62, 0x64, 0x61, 0x24, 0x70, 0x72, 0x69, 0x6e,
+        0x74, 0x24, 0x32, 0x01, 0x00, 0x27, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x56, 0x01, 0x00, 0x04,
+        0x6e, 0x61, 0x6d, 0x65, 0x01, 0x00, 0x0d, 0x53, 0x74, 0x61, 0x63, 0x6b, 0x4d, 0x61, 0x70, 0x54,
+        0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x0f, 0x6c, 0x61, 0x6d, 0x62, 0x64, 0x61, 0x24, 0x6c, 0x65,
+        0x6e, 0x67, 0x74, 0x68, 0x24, 0x31, 0x01, 0x00, 0x27, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x3b,
+        0x01, 0x00, 0x01, 0x73, 0x01, 0x00, 0x10, 0x6c, 0x61, 0x6d, 0x62, 0x64, 0x61, 0x24, 0x63, 0x61,
+        0x70, 0x74, 0x75, 0x72, 0x65, 0x24, 0x30, 0x01, 0x00, 0x04, 0x28, 0x49, 0x29, 0x49, 0x01, 0x00,
+        0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0c, 0x4c, 0x61,
+        0x6d, 0x62, 0x64, 0x61, 0x73, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x01, 0x00, 0x10, 0x42, 0x6f, 0x6f,
+        0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x73, 0x0f, 0x06, 0x00,
+        0x6c, 0x0a, 0x00, 0x6d, 0x00, 0x6e, 0x07, 0x00, 0x6f, 0x0c, 0x00, 0x70, 0x00, 0x71, 0x01, 0x00,
+        0x22, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b,
+        0x65, 0x2f, 0x4c, 0x61, 0x6d, 0x62, 0x64, 0x61, 0x4d, 0x65, 0x74, 0x61, 0x66, 0x61, 0x63, 0x74,
+        0x6f, 0x72, 0x79, 0x01, 0x00, 0x0b, 0x6d, 0x65, 0x74, 0x61, 0x66, 0x61, 0x63, 0x74, 0x6f, 0x72,
+        0x79, 0x01, 0x00, 0xcc, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f,
+        0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e,
+        0x64, 0x6c, 0x65, 0x73, 0x24, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x3b, 0x4c, 0x6a, 0x61, 0x76,
+        0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x4c, 0x6a,
+        0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f,
+        0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x54, 0x79, 0x70, 0x65, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74,
+        0x68, 0x6f, 0x64, 0x54, 0x79, 0x70, 0x65, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64,
+        0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x54,
+        0x79, 0x70, 0x65, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f,
+        0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x43, 0x61, 0x6c, 0x6c, 0x53, 0x69, 0x74, 0x65, 0x3b,
+        0x10, 0x00, 0x22, 0x0f, 0x05, 0x00, 0x74, 0x0a, 0x00, 0x41, 0x00, 0x75, 0x0c, 0x00, 0x66, 0x00,
+        0x67, 0x10, 0x00, 0x77, 0x01, 0x00, 0x26, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x0f, 0x06, 0x00,
+        0x79, 0x0a, 0x00, 0x41, 0x00, 0x7a, 0x0c, 0x00, 0x63, 0x00, 0x64, 0x10, 0x00, 0x64, 0x10, 0x00,
+        0x7d, 0x01, 0x00, 0x15, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f,
+        0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x29, 0x56, 0x0f, 0x06, 0x00, 0x7f, 0x0a, 0x00, 0x41,
+        0x00, 0x80, 0x0c, 0x00, 0x5f, 0x00, 0x60, 0x10, 0x00, 0x38, 0x0f, 0x06, 0x00, 0x83, 0x0a, 0x00,
+        0x41, 0x00, 0x84, 0x0c, 0x00, 0x5b, 0x00, 0x1c, 0x10, 0x00, 0x1c, 0x0f, 0x06, 0x00, 0x87, 0x0a,
+        0x00, 0x41, 0x00, 0x88, 0x0c, 0x00, 0x5d, 0x00, 0x5e, 0x0f, 0x06, 0x00, 0x8a, 0x0a, 0x00, 0x8b,
+        0x00, 0x8c, 0x07, 0x00, 0x8d, 0x0c, 0x00, 0x31, 0x00, 0x8e, 0x01, 0x00, 0x24, 0x6a, 0x61, 0x76,
+        0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x53, 0x74,
+        0x72, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6e, 0x63, 0x61, 0x74, 0x46, 0x61, 0x63, 0x74, 0x6f, 0x72,
+        0x79, 0x01, 0x00, 0x98, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f,
+        0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e,
+        0x64, 0x6c, 0x65, 0x73, 0x24, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x3b, 0x4c, 0x6a, 0x61, 0x76,
+        0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x4c, 0x6a,
+        0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f,
+        0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x54, 0x79, 0x70, 0x65, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x5b, 0x4c, 0x6a,
+        0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b,
+        0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f,
+        0x6b, 0x65, 0x2f, 0x43, 0x61, 0x6c, 0x6c, 0x53, 0x69, 0x74, 0x65, 0x3b, 0x08, 0x00, 0x90, 0x01,
+        0x00, 0x02, 0x01, 0x01, 0x01, 0x00, 0x0c, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x43, 0x6c, 0x61, 0x73,
+        0x73, 0x65, 0x73, 0x07, 0x00, 0x93, 0x01, 0x00, 0x25, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64,
+        0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x73, 0x24, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x07, 0x00,
+        0x95, 0x01, 0x00, 0x1e, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e,
+        0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e, 0x64, 0x6c,
+        0x65, 0x73, 0x01, 0x00, 0x06, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x00, 0x20, 0x00, 0x41, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x44, 0x00, 0x45, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x46, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x2a, 0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00, 0x02, 0x00, 0x47,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x48, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x49, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4b,
+        0x00, 0x4c, 0x00, 0x01, 0x00, 0x46, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x08, 0x2a, 0x1b, 0xba, 0x00, 0x07, 0x00, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x02, 0x00, 0x47,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x48, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x49, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x4d, 0x00, 0x45, 0x00, 0x01, 0x00, 0x08, 0x00, 0x3b, 0x00, 0x0e, 0x00, 0x02, 0x00, 0x46,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xba, 0x00, 0x0b, 0x00,
+        0x00, 0xb0, 0x00, 0x00, 0x00, 0x01, 0x00, 0x47, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x0e, 0x00, 0x4e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x4f, 0x00, 0x08, 0x00, 0x50, 0x00, 0x51,
+        0x00, 0x02, 0x00, 0x46, 0x00, 0x00, 0x00, 0x57, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0d,
+        0x2a, 0x2b, 0xba, 0x00, 0x0f, 0x00, 0x00, 0xb9, 0x00, 0x13, 0x02, 0x00, 0xb1, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x47, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x00, 0x00, 0x12, 0x00, 0x0c, 0x00,
+        0x17, 0x00, 0x48, 0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x52, 0x00,
+        0x53, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x54, 0x00, 0x55, 0x00, 0x01, 0x00, 0x56, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x52, 0x00, 0x57, 0x00, 0x00, 0x00,
+        0x4e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x58, 0x00, 0x08, 0x00, 0x59, 0x00, 0x0e, 0x00, 0x02, 0x00,
+        0x46, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xba, 0x00, 0x19,
+        0x00, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x01, 0x00, 0x47, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x4e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x5a, 0x10, 0x0a, 0x00, 0x5b, 0x00,
+        0x1c, 0x00, 0x01, 0x00, 0x46, 0x00, 0x00, 0x00, 0x31, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x07, 0x2a, 0xba, 0x00, 0x1a, 0x00, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x02, 0x00, 0x47, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x48, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x4d, 0x00, 0x5c, 0x00, 0x00, 0x10, 0x0a, 0x00, 0x5d, 0x00, 0x5e,
+        0x00, 0x01, 0x00, 0x46, 0x00, 0x00, 0x00, 0x31, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x07,
+        0x2a, 0xb6, 0x00, 0x1d, 0x05, 0x68, 0xac, 0x00, 0x00, 0x00, 0x02, 0x00, 0x47, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x48, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x4d, 0x00, 0x5c, 0x00, 0x00, 0x10, 0x0a, 0x00, 0x5f, 0x00, 0x60, 0x00,
+        0x01, 0x00, 0x46, 0x00, 0x00, 0x00, 0x5a, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x15, 0x2b,
+        0xb6, 0x00, 0x23, 0x9a, 0x00, 0x10, 0xb2, 0x00, 0x29, 0x2a, 0x2b, 0xba, 0x00, 0x2f, 0x00, 0x00,
+        0xb6, 0x00, 0x33, 0xb1, 0x00, 0x00, 0x00, 0x03, 0x00, 0x47, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x07, 0x00, 0x14, 0x00, 0x14, 0x00, 0x16, 0x00, 0x48, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x15, 0x00, 0x54, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x61, 0x00, 0x55, 0x00, 0x01, 0x00, 0x62, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01,
+        0x14, 0x10, 0x0a, 0x00, 0x63, 0x00, 0x64, 0x00, 0x01, 0x00, 0x46, 0x00, 0x00, 0x00, 0x32, 0x00,
+        0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x2a, 0xb6, 0x00, 0x39, 0xb8, 0x00, 0x3c, 0xb0, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x47, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x48, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x65, 0x00, 0x55, 0x00,
+        0x00, 0x10, 0x02, 0x00, 0x66, 0x00, 0x67, 0x00, 0x01, 0x00, 0x46, 0x00, 0x00, 0x00, 0x3b, 0x00,
+        0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x07, 0x2a, 0xb4, 0x00, 0x40, 0x1b, 0x60, 0xac, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x47, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x48,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x49, 0x00, 0x4a, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x4d, 0x00, 0x45, 0x00, 0x01, 0x00, 0x03, 0x00, 0x68, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x69, 0x00, 0x6a, 0x00, 0x00, 0x00, 0x3a, 0x00, 0x06, 0x00, 0x6b, 0x00, 0x03,
+        0x00, 0x72, 0x00, 0x73, 0x00, 0x72, 0x00, 0x6b, 0x00, 0x03, 0x00, 0x76, 0x00, 0x78, 0x00, 0x7b,
+        0x00, 0x6b, 0x00, 0x03, 0x00, 0x7c, 0x00, 0x7e, 0x00, 0x81, 0x00, 0x6b, 0x00, 0x03, 0x00, 0x76,
+        0x00, 0x82, 0x00, 0x85, 0x00, 0x6b, 0x00, 0x03, 0x00, 0x72, 0x00, 0x86, 0x00, 0x72, 0x00, 0x89,
+        0x00, 0x01, 0x00, 0x8f, 0x00, 0x91, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x92, 0x00, 0x94,
+        0x00, 0x96, 0x00, 0x19,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"import java.util.List;
+import java.util.function.Function;
+import java.util.function.IntSupplier;
+
+class Lambdas {
+    int base;
+
+    Lambdas() {
+    }
+
+    IntSupplier capture(int n) {
+        return () -> this.base + n;
+    }
+
+    static Function<String, Integer> length() {
+        return s -> Integer.valueOf(s.length());
+    }
+
+    static void print(List<String> names, String prefix) {
+        names.forEach(name -> {
+            if (!name.isEmpty()) {
+                System.out.println(prefix + name);
+            }
+        });
+    }
+
+    static Function<Integer, IntSupplier> nested() {
+        return n -> () -> n.intValue() * 2;
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|_| None).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_try_finally() {
+    // % cat Finally.java
+    // class Finally {
+    //
+    //     static int count(int[] counts) {
+    //         try {
+    //             return counts[0];
+    //         } finally {
+    //             counts[1]++;
+    //         }
+    //     }
+    //
+    //     static void close(AutoCloseable resource, StringBuilder log) throws Exception {
+    //         try {
+    //             log.append("open");
+    //         } finally {
+    //             resource.close();
+    //         }
+    //     }
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Finally.java
+    // % od -An -t x1 Finally.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x2c, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x08, 0x00, 0x08, 0x01, 0x00, 0x04, 0x6f,
+        0x70, 0x65, 0x6e, 0x0a, 0x00, 0x0a, 0x00, 0x0b, 0x07, 0x00, 0x0c, 0x0c, 0x00, 0x0d, 0x00, 0x0e,
+        0x01, 0x00, 0x17, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x42, 0x75, 0x69, 0x6c, 0x64, 0x65, 0x72, 0x01, 0x00, 0x06, 0x61, 0x70, 0x70,
+        0x65, 0x6e, 0x64, 0x01, 0x00, 0x2d, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x42, 0x75, 0x69, 0x6c, 0x64,
+        0x65, 0x72, 0x3b, 0x0b, 0x00, 0x10, 0x00, 0x11, 0x07, 0x00, 0x12, 0x0c, 0x00, 0x13, 0x00, 0x06,
+        0x01, 0x00, 0x17, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x41, 0x75, 0x74,
+        0x6f, 0x43, 0x6c, 0x6f, 0x73, 0x65, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x05, 0x63, 0x6c, 0x6f,
+        0x73, 0x65, 0x07, 0x00, 0x15, 0x01, 0x00, 0x07, 0x46, 0x69, 0x6e, 0x61, 0x6c, 0x6c, 0x79, 0x01,
+        0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65, 0x4e, 0x75, 0x6d,
+        0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12, 0x4c, 0x6f, 0x63, 0x61, 0x6c,
+        0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x04,
+        0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x09, 0x4c, 0x46, 0x69, 0x6e, 0x61, 0x6c, 0x6c, 0x79, 0x3b,
+        0x01, 0x00, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x01, 0x00, 0x05, 0x28, 0x5b, 0x49, 0x29, 0x49,
+        0x01, 0x00, 0x06, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x01, 0x00, 0x02, 0x5b, 0x49, 0x01, 0x00,
+        0x0d, 0x53, 0x74, 0x61, 0x63, 0x6b, 0x4d, 0x61, 0x70, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x07, 0x00,
+        0x21, 0x01, 0x00, 0x13, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x54, 0x68,
+        0x72, 0x6f, 0x77, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x35, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x41, 0x75, 0x74, 0x6f, 0x43, 0x6c, 0x6f, 0x73, 0x65, 0x61,
+        0x62, 0x6c, 0x65, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x42, 0x75, 0x69, 0x6c, 0x64, 0x65, 0x72, 0x3b, 0x29, 0x56, 0x01,
+        0x00, 0x08, 0x72, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x01, 0x00, 0x19, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x41, 0x75, 0x74, 0x6f, 0x43, 0x6c, 0x6f, 0x73,
+        0x65, 0x61, 0x62, 0x6c, 0x65, 0x3b, 0x01, 0x00, 0x03, 0x6c, 0x6f, 0x67, 0x01, 0x00, 0x19, 0x4c,
+        0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67,
+        0x42, 0x75, 0x69, 0x6c, 0x64, 0x65, 0x72, 0x3b, 0x01, 0x00, 0x0a, 0x45, 0x78, 0x63, 0x65, 0x70,
+        0x74, 0x69, 0x6f, 0x6e, 0x73, 0x07, 0x00, 0x29, 0x01, 0x00, 0x13, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x45, 0x78, 0x63, 0x65, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x01, 0x00,
+        0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0c, 0x46, 0x69,
+        0x6e, 0x61, 0x6c, 0x6c, 0x79, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x00, 0x20, 0x00, 0x14, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x2a, 0xb7, 0x00, 0x01,
+        0xb1, 0x00, 0x00, 0x00, 0x02, 0x00, 0x17, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x19, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x1b, 0x00, 0x1c, 0x00, 0x01, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x65, 0x00, 0x04, 0x00, 0x03, 0x00, 0x00, 0x00, 0x17, 0x2a, 0x03, 0x2e, 0x3c, 0x2a, 0x04, 0x5c,
+        0x2e, 0x04, 0x60, 0x4f, 0x1b, 0xac, 0x4d, 0x2a, 0x04, 0x5c, 0x2e, 0x04, 0x60, 0x4f, 0x2c, 0xbf,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x03, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x07, 0x00, 0x0b, 0x00, 0x05,
+        0x00, 0x0d, 0x00, 0x07, 0x00, 0x15, 0x00, 0x08, 0x00, 0x18, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x1d, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x01, 0x4d, 0x07, 0x00, 0x20, 0x00, 0x08, 0x00, 0x13, 0x00, 0x22, 0x00, 0x02, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x77, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x1a, 0x2b, 0x12, 0x07, 0xb6,
+        0x00, 0x09, 0x57, 0x2a, 0xb9, 0x00, 0x0f, 0x01, 0x00, 0xa7, 0x00, 0x0c, 0x4d, 0x2a, 0xb9, 0x00,
+        0x0f, 0x01, 0x00, 0x2c, 0xbf, 0xb1, 0x00, 0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x10, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x17, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x06, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x07,
+        0x00, 0x0f, 0x00, 0x0d, 0x00, 0x10, 0x00, 0x10, 0x00, 0x0f, 0x00, 0x17, 0x00, 0x10, 0x00, 0x19,
+        0x00, 0x11, 0x00, 0x18, 0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x23,
+        0x00, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x25, 0x00, 0x26, 0x00, 0x01, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x02, 0x50, 0x07, 0x00, 0x20, 0x08, 0x00, 0x27, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x01, 0x00, 0x28, 0x00, 0x01, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x2b,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"class Finally {
+    Finally() {
+    }
+
+    static int count(int[] counts) {
+        int i1;
+        try {
+            i1 = counts[0];
+        } catch (Throwable a2) {
+            counts[1]++;
+            throw a2;
+        }
+        counts[1]++;
+        return i1;
+    }
+
+    static void close(AutoCloseable resource, StringBuilder log) throws Exception {
+        try {
+            log.append("open");
+        } catch (Throwable a2) {
+            resource.close();
+            throw a2;
+        }
+        resource.close();
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|_| None).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_string_switch() {
+    // % cat Switches.java
+    // class Switches {
+    //
+    //     static int code(String name) {
+    //         switch (name) {
+    //             case "one":
+    //                 return 1;
+    //             case "two":
+    //                 return 2;
+    //             default:
+    //                 return 0;
+    //         }
+    //     }
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Switches.java
+    // % od -An -t x1 Switches.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x23, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x0a, 0x00, 0x08, 0x00, 0x09, 0x07, 0x00,
+        0x0a, 0x0c, 0x00, 0x0b, 0x00, 0x0c, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x01, 0x00, 0x08, 0x68, 0x61, 0x73, 0x68,
+        0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x03, 0x28, 0x29, 0x49, 0x08, 0x00, 0x0e, 0x01, 0x00, 0x03,
+        0x6f, 0x6e, 0x65, 0x0a, 0x00, 0x08, 0x00, 0x10, 0x0c, 0x00, 0x11, 0x00, 0x12, 0x01, 0x00, 0x06,
+        0x65, 0x71, 0x75, 0x61, 0x6c, 0x73, 0x01, 0x00, 0x15, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f,
+        0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x29, 0x5a, 0x08, 0x00,
+        0x14, 0x01, 0x00, 0x03, 0x74, 0x77, 0x6f, 0x07, 0x00, 0x16, 0x01, 0x00, 0x08, 0x53, 0x77, 0x69,
+        0x74, 0x63, 0x68, 0x65, 0x73, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c,
+        0x69, 0x6e, 0x65, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00,
+        0x12, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61,
+        0x62, 0x6c, 0x65, 0x01, 0x00, 0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x0a, 0x4c, 0x53, 0x77,
+        0x69, 0x74, 0x63, 0x68, 0x65, 0x73, 0x3b, 0x01, 0x00, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x01, 0x00,
+        0x15, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x3b, 0x29, 0x49, 0x01, 0x00, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x01, 0x00, 0x12,
+        0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e,
+        0x67, 0x3b, 0x01, 0x00, 0x0d, 0x53, 0x74, 0x61, 0x63, 0x6b, 0x4d, 0x61, 0x70, 0x54, 0x61, 0x62,
+        0x6c, 0x65, 0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01,
+        0x00, 0x0d, 0x53, 0x77, 0x69, 0x74, 0x63, 0x68, 0x65, 0x73, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x00,
+        0x20, 0x00, 0x15, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x06, 0x00, 0x01, 0x00, 0x17, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x2a, 0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00, 0x02, 0x00, 0x18, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x19, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x1a, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x08, 0x00, 0x1c, 0x00, 0x1d, 0x00, 0x01,
+        0x00, 0x17, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x5e, 0x2a, 0x4c,
+        0x02, 0x3d, 0x2b, 0xb6, 0x00, 0x07, 0xab, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x01, 0xae, 0x66, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x01, 0xc2, 0x4c, 0x00, 0x00,
+        0x00, 0x2a, 0x2b, 0x12, 0x0d, 0xb6, 0x00, 0x0f, 0x99, 0x00, 0x13, 0x03, 0x3d, 0xa7, 0x00, 0x0e,
+        0x2b, 0x12, 0x13, 0xb6, 0x00, 0x0f, 0x99, 0x00, 0x05, 0x04, 0x3d, 0x1c, 0xab, 0x00, 0x00, 0x00,
+        0x00, 0x1e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x1c, 0x04, 0xac, 0x05, 0xac, 0x03, 0xac, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x18, 0x00, 0x00, 0x00, 0x12, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x58, 0x00, 0x06,
+        0x00, 0x5a, 0x00, 0x08, 0x00, 0x5c, 0x00, 0x0a, 0x00, 0x19, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x5e, 0x00, 0x1e, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x06, 0xfd, 0x00, 0x24, 0x07, 0x00, 0x08, 0x01, 0x0d, 0x0a, 0x1a, 0x01, 0x01, 0x00, 0x01,
+        0x00, 0x21, 0x00, 0x00, 0x00, 0x02, 0x00, 0x22,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"class Switches {
+    Switches() {
+    }
+
+    static int code(String name) {
+        String a1 = name;
+        int i2 = -1;
+        switch (a1.hashCode()) {
+            case 110182:
+                if (!a1.equals("one")) {
+                    break;
+                }
+                i2 = 0;
+                break;
+            case 115276:
+                if (a1.equals("two")) {
+                    i2 = 1;
+                }
+        }
+        switch (i2) {
+            case 0:
+                return 1;
+            case 1:
+                return 2;
+        }
+        return 0;
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|_| None).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_fallback() {
+    // % cat Fallback.java
+    // class Fallback {
+    //
+    //     int value;
+    //
+    //     int get() {
+    //         synchronized (this) {
+    //             return value;
+    //         }
+    //     }
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Fallback.java
+    // % od -An -t x1 Fallback.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x19, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x09, 0x00, 0x08, 0x00, 0x09, 0x07, 0x00,
+        0x0a, 0x0c, 0x00, 0x0b, 0x00, 0x0c, 0x01, 0x00, 0x08, 0x46, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63,
+        0x6b, 0x01, 0x00, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x01, 0x00, 0x01, 0x49, 0x01, 0x00, 0x04,
+        0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65, 0x4e, 0x75, 0x6d, 0x62, 0x65,
+        0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x56, 0x61,
+        0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x04, 0x74, 0x68,
+        0x69, 0x73, 0x01, 0x00, 0x0a, 0x4c, 0x46, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x3b, 0x01,
+        0x00, 0x03, 0x67, 0x65, 0x74, 0x01, 0x00, 0x03, 0x28, 0x29, 0x49, 0x01, 0x00, 0x0d, 0x53, 0x74,
+        0x61, 0x63, 0x6b, 0x4d, 0x61, 0x70, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x07, 0x00, 0x16, 0x01, 0x00,
+        0x13, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x54, 0x68, 0x72, 0x6f, 0x77,
+        0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c,
+        0x65, 0x01, 0x00, 0x0d, 0x46, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x2e, 0x6a, 0x61, 0x76,
+        0x61, 0x00, 0x20, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x2f, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x2a, 0xb7, 0x00, 0x01, 0xb1,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x10, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x13, 0x00, 0x01, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x6a,
+        0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x10, 0x2a, 0x59, 0x4c, 0xc2, 0x2a, 0xb4, 0x00, 0x07,
+        0x2b, 0xc3, 0xac, 0x4d, 0x2b, 0xc3, 0x2c, 0xbf, 0x00, 0x02, 0x00, 0x04, 0x00, 0x0a, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x0e, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x03, 0x00, 0x0e, 0x00, 0x00,
+        0x00, 0x0e, 0x00, 0x03, 0x00, 0x00, 0x00, 0x06, 0x00, 0x04, 0x00, 0x07, 0x00, 0x0b, 0x00, 0x08,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x10, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x12, 0x00, 0x01, 0xff, 0x00, 0x0b, 0x00, 0x02, 0x07,
+        0x00, 0x08, 0x07, 0x00, 0x02, 0x00, 0x01, 0x07, 0x00, 0x15, 0x00, 0x01, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x18,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    let expected = r#"class Fallback {
+    int value;
+
+    Fallback() {
+    }
+
+    int get() {
+        // Decompilation failed. synchronized statements are not supported, pc: 3
+        // 0: aload_0
+        // 1: dup
+        // 2: astore_1
+        // 3: monitorenter
+        // 4: aload_0
+        // 5: getfield #7
+        // 8: aload_1
+        // 9: monitorexit
+        // 10: ireturn
+        // 11: astore_2
+        // 12: aload_1
+        // 13: monitorexit
+        // 14: aload_2
+        // 15: athrow
+        throw new UnsupportedOperationException();
+    }
+}
+"#;
+    assert_eq!(decompile_class(&class_file, &|_| None).unwrap(), expected);
+}
+
+#[test]
+fn test_decompile_asserts() {
+    // % cat Checked.java
+    // class Checked {
+    //
+    //     static final int[] TABLE = { 1, 2, 3 };
+    //
+    //     int size;
+    //
+    //     static int half(int n) {
+    //         assert n % 2 == 0 : "odd: " + n;
+    //         return n / 2;
+    //     }
+    //
+    //     void grow(int by) {
+    //         for (int i = 0; i < by; i++) {
+    //             assert size >= 0 && i < TABLE.length;
+    //             size++;
+    //         }
+    //     }
+    //
+    //     static boolean check(Object o) {
+    //         if (o != null) {
+    //             assert o instanceof String || o instanceof Integer : o;
+    //         }
+    //         return o == null;
+    //     }
+    //
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 -g Checked.java
+    // % od -An -t x1 Checked.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x4c, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x09, 0x00, 0x08, 0x00, 0x09, 0x07, 0x00,
+        0x0a, 0x0c, 0x00, 0x0b, 0x00, 0x0c, 0x01, 0x00, 0x07, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x65, 0x64,
+        0x01, 0x00, 0x13, 0x24, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x44, 0x69,
+        0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x01, 0x00, 0x01, 0x5a, 0x07, 0x00, 0x0e, 0x01, 0x00, 0x18,
+        0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x41, 0x73, 0x73, 0x65, 0x72, 0x74,
+        0x69, 0x6f, 0x6e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x00, 0x00, 0x00, 0x10, 0x0c, 0x00, 0x11,
+        0x00, 0x12, 0x01, 0x00, 0x17, 0x6d, 0x61, 0x6b, 0x65, 0x43, 0x6f, 0x6e, 0x63, 0x61, 0x74, 0x57,
+        0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x73, 0x01, 0x00, 0x15, 0x28,
+        0x49, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x3b, 0x0a, 0x00, 0x0d, 0x00, 0x14, 0x0c, 0x00, 0x05, 0x00, 0x15, 0x01, 0x00,
+        0x15, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a,
+        0x65, 0x63, 0x74, 0x3b, 0x29, 0x56, 0x09, 0x00, 0x08, 0x00, 0x17, 0x0c, 0x00, 0x18, 0x00, 0x19,
+        0x01, 0x00, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x01, 0x00, 0x01, 0x49, 0x09, 0x00, 0x08, 0x00, 0x1b,
+        0x0c, 0x00, 0x1c, 0x00, 0x1d, 0x01, 0x00, 0x05, 0x54, 0x41, 0x42, 0x4c, 0x45, 0x01, 0x00, 0x02,
+        0x5b, 0x49, 0x0a, 0x00, 0x0d, 0x00, 0x03, 0x07, 0x00, 0x20, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76,
+        0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x07, 0x00, 0x22,
+        0x01, 0x00, 0x11, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x49, 0x6e, 0x74,
+        0x65, 0x67, 0x65, 0x72, 0x0a, 0x00, 0x24, 0x00, 0x25, 0x07, 0x00, 0x26, 0x0c, 0x00, 0x27, 0x00,
+        0x28, 0x01, 0x00, 0x0f, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x43, 0x6c,
+        0x61, 0x73, 0x73, 0x01, 0x00, 0x16, 0x64, 0x65, 0x73, 0x69, 0x72, 0x65, 0x64, 0x41, 0x73, 0x73,
+        0x65, 0x72, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x01, 0x00, 0x03, 0x28,
+        0x29, 0x5a, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65,
+        0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x12, 0x4c, 0x6f,
+        0x63, 0x61, 0x6c, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x54, 0x61, 0x62, 0x6c, 0x65,
+        0x01, 0x00, 0x04, 0x74, 0x68, 0x69, 0x73, 0x01, 0x00, 0x09, 0x4c, 0x43, 0x68, 0x65, 0x63, 0x6b,
+        0x65, 0x64, 0x3b, 0x01, 0x00, 0x04, 0x68, 0x61, 0x6c, 0x66, 0x01, 0x00, 0x04, 0x28, 0x49, 0x29,
+        0x49, 0x01, 0x00, 0x01, 0x6e, 0x01, 0x00, 0x0d, 0x53, 0x74, 0x61, 0x63, 0x6b, 0x4d, 0x61, 0x70,
+        0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x04, 0x67, 0x72, 0x6f, 0x77, 0x01, 0x00, 0x04, 0x28,
+        0x49, 0x29, 0x56, 0x01, 0x00, 0x01, 0x69, 0x01, 0x00, 0x02, 0x62, 0x79, 0x01, 0x00, 0x05, 0x63,
+        0x68, 0x65, 0x63, 0x6b, 0x01, 0x00, 0x15, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61,
+        0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x29, 0x5a, 0x01, 0x00, 0x01, 0x6f,
+        0x01, 0x00, 0x12, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62,
+        0x6a, 0x65, 0x63, 0x74, 0x3b, 0x01, 0x00, 0x08, 0x3c, 0x63, 0x6c, 0x69, 0x6e, 0x69, 0x74, 0x3e,
+        0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0c,
+        0x43, 0x68, 0x65, 0x63, 0x6b, 0x65, 0x64, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x01, 0x00, 0x10, 0x42,
+        0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x73, 0x0f,
+        0x06, 0x00, 0x3f, 0x0a, 0x00, 0x40, 0x00, 0x41, 0x07, 0x00, 0x42, 0x0c, 0x00, 0x11, 0x00, 0x43,
+        0x01, 0x00, 0x24, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76,
+        0x6f, 0x6b, 0x65, 0x2f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x43, 0x6f, 0x6e, 0x63, 0x61, 0x74,
+        0x46, 0x61, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x01, 0x00, 0x98, 0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61,
+        0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74,
+        0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x73, 0x24, 0x4c, 0x6f, 0x6f, 0x6b, 0x75,
+        0x70, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69,
+        0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x54, 0x79, 0x70, 0x65,
+        0x3b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53, 0x74, 0x72, 0x69,
+        0x6e, 0x67, 0x3b, 0x5b, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f,
+        0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e,
+        0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x43, 0x61, 0x6c, 0x6c, 0x53, 0x69, 0x74,
+        0x65, 0x3b, 0x08, 0x00, 0x45, 0x01, 0x00, 0x06, 0x6f, 0x64, 0x64, 0x3a, 0x20, 0x01, 0x01, 0x00,
+        0x0c, 0x49, 0x6e, 0x6e, 0x65, 0x72, 0x43, 0x6c, 0x61, 0x73, 0x73, 0x65, 0x73, 0x07, 0x00, 0x48,
+        0x01, 0x00, 0x25, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76,
+        0x6f, 0x6b, 0x65, 0x2f, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65,
+        0x73, 0x24, 0x4c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x07, 0x00, 0x4a, 0x01, 0x00, 0x1e, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x2f, 0x4d,
+        0x65, 0x74, 0x68, 0x6f, 0x64, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x73, 0x01, 0x00, 0x06, 0x4c,
+        0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x00, 0x20, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x18, 0x00, 0x1c, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x19, 0x00, 0x00, 0x10,
+        0x18, 0x00, 0x0b, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x29, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x2a,
+        0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00, 0x02, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x2c, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x08, 0x00, 0x2e, 0x00, 0x2f, 0x00, 0x01, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x55, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1e, 0xb2, 0x00, 0x07, 0x9a,
+        0x00, 0x17, 0x1a, 0x05, 0x70, 0x99, 0x00, 0x11, 0xbb, 0x00, 0x0d, 0x59, 0x1a, 0xba, 0x00, 0x0f,
+        0x00, 0x00, 0xb7, 0x00, 0x13, 0xbf, 0x1a, 0x05, 0x6c, 0xac, 0x00, 0x00, 0x00, 0x03, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x1a, 0x00, 0x09, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x30, 0x00, 0x19, 0x00, 0x00,
+        0x00, 0x31, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x1a, 0x00, 0x00, 0x00, 0x32, 0x00, 0x33, 0x00,
+        0x01, 0x00, 0x29, 0x00, 0x00, 0x00, 0x94, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x35, 0x03,
+        0x3d, 0x1c, 0x1b, 0xa2, 0x00, 0x30, 0xb2, 0x00, 0x07, 0x9a, 0x00, 0x1a, 0x2a, 0xb4, 0x00, 0x16,
+        0x9b, 0x00, 0x0b, 0x1c, 0xb2, 0x00, 0x1a, 0xbe, 0xa1, 0x00, 0x0b, 0xbb, 0x00, 0x0d, 0x59, 0xb7,
+        0x00, 0x1e, 0xbf, 0x2a, 0x59, 0xb4, 0x00, 0x16, 0x04, 0x60, 0xb5, 0x00, 0x16, 0x84, 0x02, 0x01,
+        0xa7, 0xff, 0xd1, 0xb1, 0x00, 0x00, 0x00, 0x03, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x16, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x07, 0x00, 0x0e, 0x00, 0x24, 0x00, 0x0f, 0x00, 0x2e, 0x00, 0x0d,
+        0x00, 0x34, 0x00, 0x11, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x20, 0x00, 0x03, 0x00, 0x02, 0x00, 0x32,
+        0x00, 0x34, 0x00, 0x19, 0x00, 0x02, 0x00, 0x00, 0x00, 0x35, 0x00, 0x2c, 0x00, 0x2d, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x35, 0x00, 0x19, 0x00, 0x01, 0x00, 0x31, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x04, 0xfc, 0x00, 0x02, 0x01, 0x19, 0x07, 0xfa, 0x00, 0x0f, 0x00, 0x08, 0x00, 0x36, 0x00,
+        0x37, 0x00, 0x01, 0x00, 0x29, 0x00, 0x00, 0x00, 0x69, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x2b, 0x2a, 0xc6, 0x00, 0x20, 0xb2, 0x00, 0x07, 0x9a, 0x00, 0x1a, 0x2a, 0xc1, 0x00, 0x1f, 0x9a,
+        0x00, 0x13, 0x2a, 0xc1, 0x00, 0x21, 0x9a, 0x00, 0x0c, 0xbb, 0x00, 0x0d, 0x59, 0x2a, 0xb7, 0x00,
+        0x13, 0xbf, 0x2a, 0xc7, 0x00, 0x07, 0x04, 0xa7, 0x00, 0x04, 0x03, 0xac, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x2a, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x03, 0x00, 0x00, 0x00, 0x14, 0x00, 0x04, 0x00, 0x15,
+        0x00, 0x21, 0x00, 0x17, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x38, 0x00, 0x39, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x21, 0x07,
+        0x40, 0x01, 0x00, 0x08, 0x00, 0x3a, 0x00, 0x06, 0x00, 0x01, 0x00, 0x29, 0x00, 0x00, 0x00, 0x4a,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x12, 0x08, 0xb6, 0x00, 0x23, 0x9a, 0x00, 0x07,
+        0x04, 0xa7, 0x00, 0x04, 0x03, 0xb3, 0x00, 0x07, 0x06, 0xbc, 0x0a, 0x59, 0x03, 0x04, 0x4f, 0x59,
+        0x04, 0x05, 0x4f, 0x59, 0x05, 0x06, 0x4f, 0xb3, 0x00, 0x1a, 0xb1, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x2a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x10, 0x00, 0x03, 0x00,
+        0x31, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x0c, 0x40, 0x01, 0x00, 0x03, 0x00, 0x3b, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x3c, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x3e, 0x00, 0x01,
+        0x00, 0x44, 0x00, 0x46, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x47, 0x00, 0x49, 0x00, 0x4b,
+        0x00, 0x19,
+    ];
+    let class_file = read_class_file(bytes).unwrap();
+    // The synthetic field $assertionsDisabled and its initialization are left out.
+    let expected = r#"class Checked {
+    static final int[] TABLE;
+    int size;
+
+    Checked() {
+    }
+
+    static int half(int n) {
+        assert n % 2 == 0 : "odd: " + n;
+        return n / 2;
+    }
+
+    void grow(int by) {
+        for (int i = 0; i < by; i++) {
+            assert this.size >= 0 && i < Checked.TABLE.length;
+            this.size++;
+        }
+    }
+
+    static boolean check(Object o) {
+        if (o != null) {
+            assert o instanceof String || o instanceof Integer : o;
+        }
+        return o == null;
+    }
+
+    static {
+        TABLE = new int[]{1, 2, 3};
+    }
+}
+"#;
+    let source = decompile_class(&class_file, &|_| None).unwrap();
+    assert_eq!(source, expected);
+    assert_compiles("Checked", &source);
+}
+
+#[test]
+fn test_decompile_wide_constants() {
+    let class_file = read_wide_constants_class();
+    let expected = r#"class Wide {
+    static final long SEED = 6364136223846793005L;
+    long state;
+    double scale;
+
+    Wide() {
+        this.state = 1442695040888963407L;
+        this.scale = 2.5;
+    }
+
+    long next() {
+        this.state = this.state * 6364136223846793005L + 1442695040888963407L;
+        return this.state;
+    }
+
+    double scaled(double arg0) {
+        return arg0 * this.scale + 0.125;
+    }
+
+    static long mix(long arg0, int arg1) {
+        return arg1 > 0 ? arg0 ^ (long) arg1 * 4294967296L : arg0;
+    }
+}
+"#;
+    let source = decompile_class(&class_file, &|_| None).unwrap();
+    assert_eq!(source, expected);
+    assert_compiles("Wide", &source);
+}
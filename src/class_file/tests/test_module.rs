@@ -1,6 +1,7 @@
 use crate::class_file::structure::root::*;
 use crate::class_file::structure::constant_pool::*;
 use crate::class_file::structure::attribute::*;
+use crate::class_file::access_flags::*;
 use crate::class_file::reader::read_class_file;
 use crate::class_file::checker::check_class_file;
 
@@ -38,22 +39,22 @@ fn test() {
         major_version: 61,
         constant_pool_count: 13,
         constant_pool: vec![
-            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: 2 }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(2) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 11, bytes: "module-info".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 10, bytes: "SourceFile".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 16, bytes: "module-info.java".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "Module".as_bytes().to_vec() }),
-            CpInfo::Module(ConstantModuleInfo { tag: 19, name_index: 7 }),
+            CpInfo::Module(ConstantModuleInfo { tag: 19, name_index: CpIndex::new(7) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "foo".as_bytes().to_vec() }),
-            CpInfo::Module(ConstantModuleInfo { tag: 19, name_index: 9 }),
+            CpInfo::Module(ConstantModuleInfo { tag: 19, name_index: CpIndex::new(9) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 9, bytes: "java.base".as_bytes().to_vec() }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "17.0.5".as_bytes().to_vec() }),
-            CpInfo::Module(ConstantModuleInfo { tag: 19, name_index: 12 }),
+            CpInfo::Module(ConstantModuleInfo { tag: 19, name_index: CpIndex::new(12) }),
             CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 13, bytes: "java.net.http".as_bytes().to_vec() }),
         ],
-        access_flags: 0x8000,
-        this_class: 1,
-        super_class: 0,
+        access_flags: ClassAccessFlags::from_bits_retain(0x8000),
+        this_class: CpIndex::new(1),
+        super_class: CpIndex::new(0),
         interfaces_count: 0,
         interfaces: vec![],
         fields_count: 0,
@@ -63,27 +64,27 @@ fn test() {
         attributes_count: 2,
         attributes: vec![
             Attribute::General(AttributeInfo {
-                attribute_name_index: 3,
+                attribute_name_index: CpIndex::new(3),
                 attribute_length: 2,
                 info: vec![0x00, 0x04],
             }),
             Attribute::Module(ModuleAttribute {
-                attribute_name_index: 5,
+                attribute_name_index: CpIndex::new(5),
                 attribute_length: 28,
-                module_name_index: 6,
-                module_flags: 0,
-                module_version_index: 0,
+                module_name_index: CpIndex::new(6),
+                module_flags: ModuleAccessFlags::from_bits_retain(0),
+                module_version_index: CpIndex::new(0),
                 requires_count: 2,
                 requires: vec![
                     Require {
-                        requires_index: 8,
-                        requires_flags: 32768,
-                        requires_version_index: 10,
+                        requires_index: CpIndex::new(8),
+                        requires_flags: RequiresAccessFlags::from_bits_retain(32768),
+                        requires_version_index: CpIndex::new(10),
                     },
                     Require {
-                        requires_index: 11,
-                        requires_flags: 0,
-                        requires_version_index: 10,
+                        requires_index: CpIndex::new(11),
+                        requires_flags: RequiresAccessFlags::from_bits_retain(0),
+                        requires_version_index: CpIndex::new(10),
                     },
                 ],
                 exports_count: 0,
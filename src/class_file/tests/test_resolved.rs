@@ -0,0 +1,79 @@
+use crate::class_file::access_flags::*;
+use crate::class_file::descriptor::{FieldType, ReturnType};
+use crate::class_file::structure_utils::resolved::*;
+
+use super::test_assembly::read_wide_constants_class;
+use super::test_verifier::read_verify_class;
+
+#[test]
+fn test_resolved_class() {
+    let class_file = read_verify_class();
+    let class = ResolvedClass::new(&class_file);
+    assert_eq!(class.class_name().unwrap(), "Verify");
+    assert_eq!(class.super_name().unwrap(), Some("java/lang/Object".to_string()));
+    assert_eq!(class.interfaces().unwrap(), Vec::<String>::new());
+    assert_eq!(format!("{}", class.access_flags()), "super");
+    assert!(class.signature().unwrap().is_none());
+
+    let fields: Vec<ResolvedField> = class.fields().collect();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].name().unwrap(), "value");
+    assert_eq!(fields[0].field_type().unwrap(), FieldType::Int);
+
+    let names: Vec<String> = class.methods().map(|method| method.name().unwrap()).collect();
+    assert_eq!(names, vec!["<init>", "sum", "describe", "create", "twice", "choose"]);
+
+    let twice = class.find_method("twice", "(J)J").unwrap().unwrap();
+    assert!(twice.access_flags().contains(ACC_STATIC));
+    assert_eq!(twice.method_type().unwrap().return_type, ReturnType::Field { value: FieldType::Long });
+    assert!(twice.code().is_some());
+    assert_eq!(twice.exceptions().unwrap(), Vec::<String>::new());
+    assert!(class.find_method("twice", "(I)I").unwrap().is_none());
+}
+
+#[test]
+fn test_references() {
+    let class_file = read_verify_class();
+    let class = ResolvedClass::new(&class_file);
+
+    let create = class.find_method("create", "(Z)LVerify;").unwrap().unwrap();
+    let references: Vec<(u16, SymbolicReference)> = create.references().unwrap().map(|reference| reference.unwrap()).collect();
+    assert_eq!(references, vec![
+        (0, SymbolicReference::Class("Verify".to_string())),
+        (13, SymbolicReference::Method { class: "Verify".to_string(), name: "<init>".to_string(), descriptor: "(I)V".to_string() }),
+    ]);
+
+    // ldc of a CONSTANT_String is not a symbolic reference.
+    let describe = class.find_method("describe", "(Ljava/lang/Object;)Ljava/lang/String;").unwrap().unwrap();
+    let references: Vec<SymbolicReference> = describe.references().unwrap().map(|reference| reference.unwrap().1).collect();
+    assert_eq!(references, vec![
+        SymbolicReference::Method { class: "java/lang/Object".to_string(), name: "toString".to_string(), descriptor: "()Ljava/lang/String;".to_string() },
+    ]);
+}
+
+#[test]
+fn test_wide_constants() {
+    // The indexes after a Long or a Double skip the unusable entry that follows it.
+    let class_file = read_wide_constants_class();
+    let class = ResolvedClass::new(&class_file);
+    assert_eq!(class.class_name().unwrap(), "Wide");
+    assert_eq!(class.super_name().unwrap(), Some("java/lang/Object".to_string()));
+
+    let fields: Vec<(String, FieldType)> = class.fields().map(|field| (field.name().unwrap(), field.field_type().unwrap())).collect();
+    assert_eq!(fields, vec![
+        ("SEED".to_string(), FieldType::Long),
+        ("state".to_string(), FieldType::Long),
+        ("scale".to_string(), FieldType::Double),
+    ]);
+
+    // ldc2_w of a CONSTANT_Long is not a symbolic reference.
+    let next = class.find_method("next", "()J").unwrap().unwrap();
+    let references: Vec<(u16, SymbolicReference)> = next.references().unwrap().map(|reference| reference.unwrap()).collect();
+    let state = || SymbolicReference::Field { class: "Wide".to_string(), name: "state".to_string(), descriptor: "J".to_string() };
+    assert_eq!(references, vec![(2, state()), (13, state()), (17, state())]);
+
+    let mix = class.find_method("mix", "(JI)J").unwrap().unwrap();
+    assert!(mix.access_flags().contains(ACC_STATIC));
+    assert_eq!(mix.method_type().unwrap().parameter_types, vec![FieldType::Long, FieldType::Int]);
+    assert_eq!(mix.references().unwrap().count(), 0);
+}
@@ -0,0 +1,27 @@
+use crate::class_file::structure::constant_pool::{ConstantUtf8Info, CpInfo};
+use crate::class_file::structure::root::ClassFile;
+
+use super::test_verifier::read_verify_class;
+
+#[test]
+fn test_json_round_trip() {
+    let class_file = read_verify_class();
+    let json = serde_json::to_string(&class_file).unwrap();
+    assert!(json.starts_with(r#"{"magic":[202,254,186,190],"minor_version":0,"major_version":61,"#));
+    assert!(json.contains(r#"{"Utf8":{"tag":1,"length":16,"bytes":"java/lang/Object"}}"#));
+    let deserialized: ClassFile = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, class_file);
+}
+
+#[test]
+fn test_json_utf8() {
+    // "\0" in modified UTF-8 is not valid UTF-8, so its bytes are written as numbers.
+    let constants = vec![
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 5, bytes: "caf\u{e9}".as_bytes().to_vec() }),
+        CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 2, bytes: vec![0xc0, 0x80] }),
+    ];
+    let json = serde_json::to_string(&constants).unwrap();
+    assert_eq!(json, r#"[{"Utf8":{"tag":1,"length":5,"bytes":"café"}},{"Utf8":{"tag":1,"length":2,"bytes":[192,128]}}]"#);
+    let deserialized: Vec<CpInfo> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, constants);
+}
@@ -0,0 +1,135 @@
+use crate::class_file::structure::root::*;
+use crate::class_file::structure::constant_pool::*;
+use crate::class_file::structure::attribute::*;
+use crate::class_file::access_flags::*;
+use crate::class_file::reader::read_class_file;
+use crate::class_file::checker::check_class_file;
+
+#[test]
+fn test() {
+    // % cat Box.java
+    // class Box<T> {
+    //
+    //     T value;
+    //
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac Box.java
+    // % od -An -t x1 Box.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x12, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x07, 0x00, 0x08, 0x01, 0x00, 0x03, 0x42,
+        0x6f, 0x78, 0x01, 0x00, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x01, 0x00, 0x12, 0x4c, 0x6a, 0x61,
+        0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x3b, 0x01,
+        0x00, 0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x01, 0x00, 0x03, 0x54, 0x54,
+        0x3b, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e, 0x65, 0x4e,
+        0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x28, 0x3c, 0x54, 0x3a,
+        0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63,
+        0x74, 0x3b, 0x3e, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62,
+        0x6a, 0x65, 0x63, 0x74, 0x3b, 0x01, 0x00, 0x0a, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69,
+        0x6c, 0x65, 0x01, 0x00, 0x08, 0x42, 0x6f, 0x78, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x00, 0x20, 0x00,
+        0x07, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x09, 0x00, 0x0a, 0x00, 0x01, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x02, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x2a,
+        0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00, 0x01, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x02, 0x00, 0x0f, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x11, ];
+
+    let class_file = read_class_file(bytes).unwrap();
+
+    println!("{}", class_file);
+
+    assert_eq!(class_file, ClassFile {
+        magic: [0xca, 0xfe, 0xba, 0xbe],
+        minor_version: 0,
+        major_version: 61,
+        constant_pool_count: 18,
+        constant_pool: vec![
+            CpInfo::Methodref(ConstantMethodrefInfo { tag: 10, class_index: CpIndex::new(2), name_and_type_index: CpIndex::new(3) }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(4) }),
+            CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(5), descriptor_index: CpIndex::new(6) }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 16, bytes: "java/lang/Object".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 6, bytes: "<init>".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "()V".as_bytes().to_vec() }),
+            CpInfo::Class(ConstantClassInfo { tag: 7, name_index: CpIndex::new(8) }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "Box".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 5, bytes: "value".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 18, bytes: "Ljava/lang/Object;".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 9, bytes: "Signature".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 3, bytes: "TT;".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 4, bytes: "Code".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 15, bytes: "LineNumberTable".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 40, bytes: "<T:Ljava/lang/Object;>Ljava/lang/Object;".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 10, bytes: "SourceFile".as_bytes().to_vec() }),
+            CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 8, bytes: "Box.java".as_bytes().to_vec() }),
+        ],
+        access_flags: ClassAccessFlags::from_bits_retain(0x0020),
+        this_class: CpIndex::new(7),
+        super_class: CpIndex::new(2),
+        interfaces_count: 0,
+        interfaces: vec![],
+        fields_count: 1,
+        fields: vec![
+            FieldsInfo {
+                access_flags: FieldAccessFlags::from_bits_retain(0x0000),
+                name_index: CpIndex::new(9),
+                descriptor_index: CpIndex::new(10),
+                attributes_count: 1,
+                attributes: vec![
+                    Attribute::Signature(SignatureAttribute {
+                        attribute_name_index: CpIndex::new(11),
+                        attribute_length: 2,
+                        signature_index: CpIndex::new(12),
+                    }),
+                ],
+            },
+        ],
+        methods_count: 1,
+        methods: vec![
+            MethodInfo {
+                access_flags: MethodAccessFlags::from_bits_retain(0x0000),
+                name_index: CpIndex::new(5),
+                descriptor_index: CpIndex::new(6),
+                attributes_count: 1,
+                attributes: vec![
+                    Attribute::Code(CodeAttributeInfo {
+                        attribute_name_index: CpIndex::new(13),
+                        attribute_length: 29,
+                        max_stack: 1,
+                        max_locals: 1,
+                        code_length: 5,
+                        code: vec![0x2a, 0xb7, 0x00, 0x01, 0xb1],
+                        exception_table_length: 0,
+                        exception_table: vec![],
+                        attributes_count: 1,
+                        attributes: vec![
+                            Attribute::General(AttributeInfo {
+                                attribute_name_index: CpIndex::new(14),
+                                attribute_length: 6,
+                                info: vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x01],
+                            }),
+                        ],
+                    }),
+                ],
+            },
+        ],
+        attributes_count: 2,
+        attributes: vec![
+            Attribute::Signature(SignatureAttribute {
+                attribute_name_index: CpIndex::new(11),
+                attribute_length: 2,
+                signature_index: CpIndex::new(15),
+            }),
+            Attribute::General(AttributeInfo {
+                attribute_name_index: CpIndex::new(16),
+                attribute_length: 2,
+                info: vec![0x00, 0x11],
+            }),
+        ],
+    });
+
+    check_class_file(&class_file).unwrap();
+}
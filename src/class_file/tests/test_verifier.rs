@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+
+use crate::class_file::structure::root::*;
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure::constant_pool::*;
+use crate::class_file::structure_utils::cp_accessor::*;
+use crate::class_file::reader::read_class_file;
+use crate::class_file::class_hierarchy::{ClassHierarchy, is_protected_in_object};
+use crate::class_file::error::{Result, error};
+use crate::class_file::verifier::verify_class_file;
+use crate::class_file::assembly::assemble;
+
+use super::test_assembly::read_wide_constants_class;
+
+// (super class, is interface) of the classes that the test classes refer to.
+pub(super) struct TestHierarchy(HashMap<&'static str, (Option<&'static str>, bool)>);
+
+impl TestHierarchy {
+    pub(super) fn new() -> TestHierarchy {
+        TestHierarchy(HashMap::from([
+            ("java/lang/Object", (None, false)),
+            ("java/lang/Throwable", (Some("java/lang/Object"), false)),
+            ("java/lang/Exception", (Some("java/lang/Throwable"), false)),
+            ("java/lang/RuntimeException", (Some("java/lang/Exception"), false)),
+            ("Verify", (Some("java/lang/Object"), false)),
+            ("Wide", (Some("java/lang/Object"), false)),
+            ("Middle", (Some("java/lang/Object"), false)),
+            ("Caller", (Some("Middle"), false)),
+        ]))
+    }
+}
+
+impl ClassHierarchy for TestHierarchy {
+    fn super_class(&self, class_name: &str) -> Result<Option<String>> {
+        match self.0.get(class_name) {
+            Some((super_class, _)) => Ok(super_class.map(|name| name.to_string())),
+            None => error(format!("class not found: {}", class_name)),
+        }
+    }
+
+    fn is_interface(&self, class_name: &str) -> Result<bool> {
+        match self.0.get(class_name) {
+            Some((_, is_interface)) => Ok(*is_interface),
+            None => error(format!("class not found: {}", class_name)),
+        }
+    }
+
+    // Only java/lang/Object declares members that the test classes access.
+    fn declared_protected(&self, class_name: &str, member_name: &str, member_descriptor: &str) -> Result<Option<bool>> {
+        self.super_class(class_name)?;
+        Ok((class_name == "java/lang/Object" && is_protected_in_object(member_name, member_descriptor)).then_some(true))
+    }
+}
+
+pub(super) fn read_verify_class() -> ClassFile {
+    // % cat Verify.java
+    // class Verify {
+    //
+    //     int value;
+    //
+    //     Verify(int value) {
+    //         this.value = value;
+    //     }
+    //
+    //     static int sum(int[] values) {
+    //         int total = 0;
+    //         for (int v : values) {
+    //             total += v;
+    //         }
+    //         return total;
+    //     }
+    //
+    //     static String describe(Object o) {
+    //         try {
+    //             return o.toString();
+    //         } catch (RuntimeException e) {
+    //             return "error";
+    //         }
+    //     }
+    //
+    //     static Verify create(boolean flag) {
+    //         return new Verify(flag ? 1 : 2);
+    //     }
+    //
+    //     static long twice(long x) {
+    //         return x + x;
+    //     }
+    //
+    //     static int choose(int i) {
+    //         switch (i) {
+    //             case 1: return 10;
+    //             case 2: return 20;
+    //             case 3: return 30;
+    //             default: return 0;
+    //         }
+    //     }
+    //
+    // }
+    // % javac --version
+    // javac 17.0.15
+    // % javac --release 17 Verify.java
+    // % od -An -t x1 Verify.class | sed -e 's/^[ \s]*//' -e 's/[ \s]*$//' -e 's/\([0-9|a-z][0-9|a-z]\)/0x\1,/g'
+    let bytes: Vec<u8> = vec![
+        0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x29, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+        0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+        0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+        0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x09, 0x00, 0x08, 0x00, 0x09, 0x07, 0x00,
+        0x0a, 0x0c, 0x00, 0x0b, 0x00, 0x0c, 0x01, 0x00, 0x06, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x01,
+        0x00, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x01, 0x00, 0x01, 0x49, 0x0a, 0x00, 0x02, 0x00, 0x0e,
+        0x0c, 0x00, 0x0f, 0x00, 0x10, 0x01, 0x00, 0x08, 0x74, 0x6f, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67,
+        0x01, 0x00, 0x14, 0x28, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f,
+        0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x07, 0x00, 0x12, 0x01, 0x00, 0x1a, 0x6a, 0x61, 0x76,
+        0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x52, 0x75, 0x6e, 0x74, 0x69, 0x6d, 0x65, 0x45, 0x78,
+        0x63, 0x65, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x08, 0x00, 0x14, 0x01, 0x00, 0x05, 0x65, 0x72, 0x72,
+        0x6f, 0x72, 0x0a, 0x00, 0x08, 0x00, 0x16, 0x0c, 0x00, 0x05, 0x00, 0x17, 0x01, 0x00, 0x04, 0x28,
+        0x49, 0x29, 0x56, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x01, 0x00, 0x0f, 0x4c, 0x69, 0x6e,
+        0x65, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, 0x03, 0x73,
+        0x75, 0x6d, 0x01, 0x00, 0x05, 0x28, 0x5b, 0x49, 0x29, 0x49, 0x01, 0x00, 0x0d, 0x53, 0x74, 0x61,
+        0x63, 0x6b, 0x4d, 0x61, 0x70, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x07, 0x00, 0x1e, 0x01, 0x00, 0x02,
+        0x5b, 0x49, 0x01, 0x00, 0x08, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x01, 0x00, 0x26,
+        0x28, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65,
+        0x63, 0x74, 0x3b, 0x29, 0x4c, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c, 0x61, 0x6e, 0x67, 0x2f, 0x53,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x3b, 0x01, 0x00, 0x06, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x01,
+        0x00, 0x0b, 0x28, 0x5a, 0x29, 0x4c, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x3b, 0x01, 0x00, 0x05,
+        0x74, 0x77, 0x69, 0x63, 0x65, 0x01, 0x00, 0x04, 0x28, 0x4a, 0x29, 0x4a, 0x01, 0x00, 0x06, 0x63,
+        0x68, 0x6f, 0x6f, 0x73, 0x65, 0x01, 0x00, 0x04, 0x28, 0x49, 0x29, 0x49, 0x01, 0x00, 0x0a, 0x53,
+        0x6f, 0x75, 0x72, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x01, 0x00, 0x0b, 0x56, 0x65, 0x72, 0x69,
+        0x66, 0x79, 0x2e, 0x6a, 0x61, 0x76, 0x61, 0x00, 0x20, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x17, 0x00, 0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x0a, 0x2a, 0xb7, 0x00, 0x01, 0x2a, 0x1b, 0xb5, 0x00, 0x07, 0xb1, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x03, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x06, 0x00,
+        0x09, 0x00, 0x07, 0x00, 0x08, 0x00, 0x1a, 0x00, 0x1b, 0x00, 0x01, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x66, 0x00, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00, 0x23, 0x03, 0x3c, 0x2a, 0x4d, 0x2c, 0xbe, 0x3e,
+        0x03, 0x36, 0x04, 0x15, 0x04, 0x1d, 0xa2, 0x00, 0x14, 0x2c, 0x15, 0x04, 0x2e, 0x36, 0x05, 0x1b,
+        0x15, 0x05, 0x60, 0x3c, 0x84, 0x04, 0x01, 0xa7, 0xff, 0xec, 0x1b, 0xac, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x19, 0x00, 0x00, 0x00, 0x16, 0x00, 0x05, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x0b,
+        0x00, 0x16, 0x00, 0x0c, 0x00, 0x1b, 0x00, 0x0b, 0x00, 0x21, 0x00, 0x0e, 0x00, 0x1c, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x02, 0xff, 0x00, 0x0a, 0x00, 0x05, 0x07, 0x00, 0x1d, 0x01, 0x07, 0x00, 0x1d,
+        0x01, 0x01, 0x00, 0x00, 0xf8, 0x00, 0x16, 0x00, 0x08, 0x00, 0x1f, 0x00, 0x20, 0x00, 0x01, 0x00,
+        0x18, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x09, 0x2a, 0xb6, 0x00,
+        0x0d, 0xb0, 0x4c, 0x12, 0x13, 0xb0, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x05, 0x00, 0x11,
+        0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x03, 0x00, 0x00, 0x00, 0x13, 0x00, 0x05,
+        0x00, 0x14, 0x00, 0x06, 0x00, 0x15, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x45, 0x07,
+        0x00, 0x11, 0x00, 0x08, 0x00, 0x21, 0x00, 0x22, 0x00, 0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x4e,
+        0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x11, 0xbb, 0x00, 0x08, 0x59, 0x1a, 0x99, 0x00, 0x07,
+        0x04, 0xa7, 0x00, 0x04, 0x05, 0xb7, 0x00, 0x15, 0xb0, 0x00, 0x00, 0x00, 0x02, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1f, 0x00,
+        0x02, 0xff, 0x00, 0x0c, 0x00, 0x01, 0x01, 0x00, 0x02, 0x08, 0x00, 0x00, 0x08, 0x00, 0x00, 0xff,
+        0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x03, 0x08, 0x00, 0x00, 0x08, 0x00, 0x00, 0x01, 0x00, 0x08,
+        0x00, 0x23, 0x00, 0x24, 0x00, 0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x04, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x04, 0x1e, 0x1e, 0x61, 0xad, 0x00, 0x00, 0x00, 0x01, 0x00, 0x19, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x08, 0x00, 0x25, 0x00, 0x26, 0x00, 0x01,
+        0x00, 0x18, 0x00, 0x00, 0x00, 0x5b, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x27, 0x1a, 0xaa,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x1b, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x21, 0x10, 0x0a, 0xac, 0x10, 0x14, 0xac,
+        0x10, 0x1e, 0xac, 0x03, 0xac, 0x00, 0x00, 0x00, 0x02, 0x00, 0x19, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x22, 0x00, 0x1c, 0x00, 0x23, 0x00, 0x1f, 0x00, 0x24, 0x00, 0x22, 0x00,
+        0x25, 0x00, 0x25, 0x00, 0x26, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x06, 0x00, 0x04, 0x1c, 0x02, 0x02,
+        0x02, 0x00, 0x01, 0x00, 0x27, 0x00, 0x00, 0x00, 0x02, 0x00, 0x28, ];
+
+    read_class_file(bytes).unwrap()
+}
+
+pub(super) fn code_of<'a>(class_file: &'a mut ClassFile, method_name: &str) -> &'a mut CodeAttributeInfo {
+    let constant_pool = &class_file.constant_pool;
+    let method = class_file.methods.iter_mut()
+        .find(|method| constant_pool.access(method.name_index).bytes_as_string().unwrap() == method_name)
+        .unwrap();
+    method.attributes.iter_mut().find_map(|attribute| match attribute {
+        Attribute::Code(code) => Some(code),
+        _ => None,
+    }).unwrap()
+}
+
+fn verify_error(class_file: &ClassFile) -> String {
+    verify_class_file(class_file, &TestHierarchy::new()).unwrap_err().message
+}
+
+#[test]
+fn test_verify() {
+    let class_file = read_verify_class();
+    assert_eq!(verify_class_file(&class_file, &TestHierarchy::new()), Ok(()));
+}
+
+#[test]
+fn test_verify_bad_operand_type() {
+    let mut class_file = read_verify_class();
+    // 19: iaload -> faload
+    code_of(&mut class_file, "sum").code[19] = 0x30;
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Bad type on operand stack, expected: [F, actual: [I, method: Verify.sum([I)I, pc: 19"
+    );
+}
+
+#[test]
+fn test_verify_uninitialized() {
+    let mut class_file = read_verify_class();
+    // 13: invokespecial #21 // Method "<init>":(I)V -> pop, nop, nop
+    code_of(&mut class_file, "create").code[13..16].copy_from_slice(&[0x57, 0x00, 0x00]);
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Bad type on operand stack, expected: Verify, actual: uninitialized(0), method: Verify.create(Z)LVerify;, pc: 16"
+    );
+}
+
+#[test]
+fn test_verify_max_stack_and_max_locals() {
+    let mut class_file = read_verify_class();
+    code_of(&mut class_file, "twice").max_stack = 3;
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Exceeded max stack size 3, method: Verify.twice(J)J, pc: 1"
+    );
+
+    let mut class_file = read_verify_class();
+    // 5: astore_1 in the exception handler
+    code_of(&mut class_file, "describe").max_locals = 1;
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Local variable index 1 is out of max_locals 1, method: Verify.describe(Ljava/lang/Object;)Ljava/lang/String;, pc: 5"
+    );
+}
+
+#[test]
+fn test_verify_stack_map_table() {
+    let mut class_file = read_verify_class();
+    code_of(&mut class_file, "choose").attributes.retain(|attribute| !matches!(attribute, Attribute::StackMapTable(_)));
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Expecting a stackmap frame at branch target 37, method: Verify.choose(I)I, pc: 1"
+    );
+
+    let mut class_file = read_verify_class();
+    code_of(&mut class_file, "describe").attributes.retain(|attribute| !matches!(attribute, Attribute::StackMapTable(_)));
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Expecting a stackmap frame at exception handler 5, method: Verify.describe(Ljava/lang/Object;)Ljava/lang/String;"
+    );
+}
+
+#[test]
+fn test_verify_type_inference() {
+    // Without a StackMapTable, as compiled for Java 5.
+    let mut class_file = read_verify_class();
+    class_file.major_version = 49;
+    for method_name in ["<init>", "sum", "describe", "create", "twice", "choose"] {
+        code_of(&mut class_file, method_name).attributes.retain(|attribute| !matches!(attribute, Attribute::StackMapTable(_)));
+    }
+    assert_eq!(verify_class_file(&class_file, &TestHierarchy::new()), Ok(()));
+
+    // 8: iconst_1 -> aconst_null, merged with iconst_2 at 13
+    code_of(&mut class_file, "create").code[8] = 0x01;
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Mismatched stack types null and int at 13, method: Verify.create(Z)LVerify;, pc: 12"
+    );
+}
+
+#[test]
+fn test_verify_subroutine() {
+    // static String describe(Object o) {
+    //     String s;
+    //     try { s = o.toString(); } finally { /* empty */ }
+    //     return s;
+    // }
+    // as compiled by an old javac, with finally as a subroutine.
+    let code: Vec<u8> = vec![
+        0x2a, //  0: aload_0
+        0xb6, 0x00, 0x0d, //  1: invokevirtual #13 // Method java/lang/Object.toString:()Ljava/lang/String;
+        0x4c, //  4: astore_1
+        0xa8, 0x00, 0x0b, //  5: jsr 16
+        0x2b, //  8: aload_1
+        0xb0, //  9: areturn
+        0x4d, // 10: astore_2
+        0xa8, 0x00, 0x05, // 11: jsr 16
+        0x2c, // 14: aload_2
+        0xbf, // 15: athrow
+        0x4e, // 16: astore_3
+        0xa9, 0x03, // 17: ret 3
+    ];
+    let mut class_file = read_verify_class();
+    class_file.major_version = 49;
+    let describe = code_of(&mut class_file, "describe");
+    describe.max_stack = 1;
+    describe.max_locals = 4;
+    describe.code_length = code.len() as u32;
+    describe.code = code;
+    describe.exception_table = vec![ExceptionTable { start_pc: 0, end_pc: 5, handler_pc: 10, catch_type: CpIndex::new(0) }];
+    describe.attributes.clear();
+    assert_eq!(verify_class_file(&class_file, &TestHierarchy::new()), Ok(()));
+
+    // 8: aload_1 -> aload_3, which holds the return address after ret.
+    code_of(&mut class_file, "describe").code[8] = 0x2d;
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Bad local variable type at index 3, expected: reference, actual: returnAddress(16), method: Verify.describe(Ljava/lang/Object;)Ljava/lang/String;, pc: 8"
+    );
+}
+
+#[test]
+fn test_verify_subroutine_wide_local() {
+    // The same method with a subroutine that stores a long to the last local variable index, beyond max_locals.
+    // The local variables used by the subroutine are collected at the first ret, before the store is checked.
+    let code: Vec<u8> = vec![
+        0x2a, //  0: aload_0
+        0xb6, 0x00, 0x0d, //  1: invokevirtual #13 // Method java/lang/Object.toString:()Ljava/lang/String;
+        0x4c, //  4: astore_1
+        0xa8, 0x00, 0x0b, //  5: jsr 16
+        0x2b, //  8: aload_1
+        0xb0, //  9: areturn
+        0x4d, // 10: astore_2
+        0xa8, 0x00, 0x05, // 11: jsr 16
+        0x2c, // 14: aload_2
+        0xbf, // 15: athrow
+        0x4e, // 16: astore_3
+        0x03, // 17: iconst_0
+        0x99, 0x00, 0x05, // 18: ifeq 23
+        0xa9, 0x03, // 21: ret 3
+        0x09, // 23: lconst_0
+        0xc4, 0x37, 0xff, 0xff, // 24: wide lstore 65535
+        0xa9, 0x03, // 28: ret 3
+    ];
+    let mut class_file = read_verify_class();
+    class_file.major_version = 49;
+    let describe = code_of(&mut class_file, "describe");
+    describe.max_stack = 2;
+    describe.max_locals = 4;
+    describe.code_length = code.len() as u32;
+    describe.code = code;
+    describe.exception_table = vec![ExceptionTable { start_pc: 0, end_pc: 5, handler_pc: 10, catch_type: CpIndex::new(0) }];
+    describe.attributes.clear();
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Local variable index 65536 is out of max_locals 4, method: Verify.describe(Ljava/lang/Object;)Ljava/lang/String;, pc: 24"
+    );
+}
+
+#[test]
+fn test_verify_protected() {
+    // 1: invokevirtual #13 // Method java/lang/Object.toString:()Ljava/lang/String;
+    // -> Method java/lang/Object.clone:()Ljava/lang/Object;, which is protected and called on an Object, not on a Verify.
+    let mut class_file = read_verify_class();
+    let constant_pool = &mut class_file.constant_pool;
+    constant_pool.push(CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 5, bytes: "clone".as_bytes().to_vec() }));
+    constant_pool.push(CpInfo::Utf8(ConstantUtf8Info { tag: 1, length: 20, bytes: "()Ljava/lang/Object;".as_bytes().to_vec() }));
+    let name_index = constant_pool.len() as u16 - 1;
+    constant_pool.push(CpInfo::NameAndType(ConstantNameAndTypeInfo { tag: 12, name_index: CpIndex::new(name_index), descriptor_index: CpIndex::new(name_index + 1) }));
+    let name_and_type_index = constant_pool.len() as u16;
+    match &mut constant_pool[12] {
+        CpInfo::Methodref(methodref) => methodref.name_and_type_index = CpIndex::new(name_and_type_index),
+        other => panic!("{:?}", other),
+    }
+    class_file.constant_pool_count += 3;
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Bad access to protected data in invokevirtual, expected: Verify, actual: java/lang/Object, method: Verify.describe(Ljava/lang/Object;)Ljava/lang/String;, pc: 1"
+    );
+}
+
+#[test]
+fn test_verify_inherited_protected() {
+    // Middle inherits the protected clone from java/lang/Object, which is in another package,
+    // so Caller calls it only on a Caller, even though Middle is in the same package as Caller.
+    let text = r#".version 61 0
+.class super #2                             // Caller
+.super #4                                   // Middle
+.constants {
+    #1 = Utf8 "Caller"
+    #2 = Class #1                           // Caller
+    #3 = Utf8 "Middle"
+    #4 = Class #3                           // Middle
+    #5 = Utf8 "clone"
+    #6 = Utf8 "()Ljava/lang/Object;"
+    #7 = NameAndType #5 #6                  // clone:()Ljava/lang/Object;
+    #8 = Methodref #4 #7                    // Middle.clone:()Ljava/lang/Object;
+    #9 = Utf8 "copy"
+    #10 = Utf8 "(LMiddle;)Ljava/lang/Object;"
+    #11 = Utf8 "Code"
+}
+.method static #9 #10 {                     // copy:(LMiddle;)Ljava/lang/Object;
+    .attribute Code stack 1 locals 1 {
+        aload_0
+        invokevirtual #8                    // Middle.clone:()Ljava/lang/Object;
+        areturn
+    }
+}"#;
+    assert_eq!(
+        verify_error(&assemble(text).unwrap()),
+        "VerifyError: Bad access to protected data in invokevirtual, expected: Caller, actual: Middle, method: Caller.copy(LMiddle;)Ljava/lang/Object;, pc: 1"
+    );
+
+    let on_caller = text.replace("(LMiddle;)Ljava/lang/Object;", "(LCaller;)Ljava/lang/Object;");
+    assert_eq!(verify_class_file(&assemble(&on_caller).unwrap(), &TestHierarchy::new()), Ok(()));
+}
+
+#[test]
+fn test_verify_wide_constants() {
+    // ldc2_w of the constants that take up two indexes, and long locals in a StackMapTable.
+    let class_file = read_wide_constants_class();
+    assert_eq!(verify_class_file(&class_file, &TestHierarchy::new()), Ok(()));
+
+    // 7: ldc2_w #25 // long 4294967296l -> ldc2_w #26, the unusable entry after it.
+    let mut class_file = read_wide_constants_class();
+    code_of(&mut class_file, "mix").code[9] = 26;
+    assert_eq!(
+        verify_error(&class_file),
+        "VerifyError: Illegal constant pool index 26 for ldc, method: Wide.mix(JI)J, pc: 7"
+    );
+}
@@ -0,0 +1,172 @@
+// 4.10. Verification of class Files
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.10
+pub mod types;
+mod transfer;
+pub(crate) mod type_checker;
+pub(crate) mod type_inferencer;
+
+use crate::class_file::access_flags::{AccessFlags, ACC_STATIC};
+use crate::class_file::class_hierarchy::ClassHierarchy;
+use crate::class_file::descriptor::{MethodType, parse_method_descriptor};
+use crate::class_file::error::{Error, Result};
+use crate::class_file::instruction::{Instruction, decode};
+use crate::class_file::structure::attribute::{Attribute, CodeAttributeInfo};
+use crate::class_file::structure::root::{ClassFile, MethodInfo};
+use crate::class_file::structure_utils::cp_accessor::*;
+
+use types::{Frame, VerificationType, expand_locals, is_assignable};
+
+fn error<T>(message: String) -> Result<T> {
+    Err(Error { message })
+}
+
+/// Verifies the code of every method in the class file.
+/// Classes that the code refers to are looked up in `hierarchy` when subtyping has to be checked.
+pub fn verify_class_file(class_file: &ClassFile, hierarchy: &dyn ClassHierarchy) -> Result<()> {
+    for method in &class_file.methods {
+        let code = method.attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Code(code) => Some(code),
+            _ => None,
+        });
+        // abstract and native methods have no code.
+        if let Some(code) = code {
+            let context = MethodContext::new(class_file, method, code, hierarchy)?;
+            if class_file.major_version >= 50 {
+                let result = type_checker::check_method(&context, code);
+                // A class file of version 50.0 may fail over to type inference (4.10).
+                if result.is_err() && class_file.major_version == 50 {
+                    type_inferencer::check_method(&context, code)?;
+                } else {
+                    result?;
+                }
+            } else {
+                type_inferencer::check_method(&context, code)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) struct Handler {
+    pub start_pc: u16,
+    pub end_pc: u16,
+    pub handler_pc: u16,
+    pub catch_type: VerificationType,
+}
+
+impl Handler {
+    pub fn covers(&self, pc: u16) -> bool {
+        self.start_pc <= pc && pc < self.end_pc
+    }
+}
+
+/// What the verification of one method needs to know besides the instructions.
+pub(crate) struct MethodContext<'a> {
+    pub class_file: &'a ClassFile,
+    pub hierarchy: &'a dyn ClassHierarchy,
+    pub class_name: String,
+    pub method_name: String,
+    pub method_descriptor: String,
+    pub method_type: MethodType,
+    pub is_static: bool,
+    pub max_stack: u16,
+    pub max_locals: u16,
+    pub instructions: Vec<(u16, Instruction)>,
+}
+
+impl<'a> MethodContext<'a> {
+    pub fn new(class_file: &'a ClassFile, method: &MethodInfo, code: &CodeAttributeInfo, hierarchy: &'a dyn ClassHierarchy) -> Result<MethodContext<'a>> {
+        let constant_pool = &class_file.constant_pool;
+        let class_name = constant_pool.access(class_file.this_class).name().bytes_as_string()?;
+        let method_name = constant_pool.access(method.name_index).bytes_as_string()?;
+        let method_descriptor = constant_pool.access(method.descriptor_index).bytes_as_string()?;
+        let method_type = parse_method_descriptor(&method_descriptor)?;
+        let mut context = MethodContext {
+            class_file,
+            hierarchy,
+            class_name,
+            method_name,
+            method_descriptor,
+            method_type,
+            is_static: method.access_flags.contains(ACC_STATIC),
+            max_stack: code.max_stack,
+            max_locals: code.max_locals,
+            instructions: Vec::new(),
+        };
+        context.instructions = decode(&code.code).or_else(|e| context.error(e.message))?;
+        Ok(context)
+    }
+
+    pub fn this_type(&self) -> VerificationType {
+        VerificationType::class(&self.class_name)
+    }
+
+    pub fn is_init(&self) -> bool {
+        self.method_name == "<init>"
+    }
+
+    // methodInitialStackFrame of 4.10.1.6, before long and double take two local variables.
+    pub fn initial_locals(&self) -> Vec<VerificationType> {
+        let mut locals = Vec::new();
+        if !self.is_static {
+            if self.is_init() && self.class_name != "java/lang/Object" {
+                locals.push(VerificationType::UninitializedThis);
+            } else {
+                locals.push(self.this_type());
+            }
+        }
+        for parameter in &self.method_type.parameter_types {
+            locals.push(VerificationType::from_field_type(parameter));
+        }
+        locals
+    }
+
+    pub fn initial_frame(&self) -> Result<Frame> {
+        let initial_locals = self.initial_locals();
+        Ok(Frame {
+            locals: expand_locals(&initial_locals, self.max_locals)
+                .or_else(|e| self.error(format!("{} for the arguments", e.message)))?,
+            stack: Vec::new(),
+            flag_this_uninit: initial_locals.contains(&VerificationType::UninitializedThis),
+        })
+    }
+
+    pub fn instruction_at(&self, pc: u16) -> Option<&Instruction> {
+        self.instructions.binary_search_by_key(&pc, |(pc, _)| *pc).ok().map(|i| &self.instructions[i].1)
+    }
+
+    // The exception table with its ranges checked, and the catch types resolved.
+    pub fn exception_handlers(&self, code: &CodeAttributeInfo) -> Result<Vec<Handler>> {
+        let throwable = VerificationType::class("java/lang/Throwable");
+        let mut handlers = Vec::new();
+        for entry in &code.exception_table {
+            let is_end_valid = entry.end_pc as u32 == code.code_length || self.instruction_at(entry.end_pc).is_some();
+            if entry.start_pc >= entry.end_pc || self.instruction_at(entry.start_pc).is_none() || !is_end_valid {
+                return self.error(format!("Illegal exception table range [{}, {})", entry.start_pc, entry.end_pc));
+            }
+            if self.instruction_at(entry.handler_pc).is_none() {
+                return self.error(format!("Illegal exception handler {}", entry.handler_pc));
+            }
+            let catch_type = if entry.catch_type.index() == 0 {
+                throwable.clone()
+            } else {
+                let name = (&self.class_file.constant_pool).access(entry.catch_type).name().bytes_as_string()?;
+                VerificationType::Reference(name)
+            };
+            if !is_assignable(&catch_type, &throwable, self.hierarchy)? {
+                return self.error(format!("Catch type is not a subclass of Throwable: {}", catch_type));
+            }
+            handlers.push(Handler { start_pc: entry.start_pc, end_pc: entry.end_pc, handler_pc: entry.handler_pc, catch_type });
+        }
+        Ok(handlers)
+    }
+
+    /// Errors that are not about one instruction, e.g. a malformed StackMapTable.
+    pub fn error<T>(&self, message: String) -> Result<T> {
+        error(format!("VerifyError: {}, method: {}.{}{}", message, self.class_name, self.method_name, self.method_descriptor))
+    }
+
+    pub fn error_at<T>(&self, pc: u16, message: String) -> Result<T> {
+        error(format!("VerifyError: {}, method: {}.{}{}, pc: {}", message, self.class_name, self.method_name, self.method_descriptor, pc))
+    }
+}
@@ -0,0 +1,629 @@
+use crate::class_file::descriptor::{MethodType, ReturnType, parse_field_type, parse_method_descriptor};
+use crate::class_file::error::Result;
+use crate::class_file::instruction::Instruction;
+use crate::class_file::structure::constant_pool::CpInfo;
+use crate::class_file::structure_utils::cp_accessor::*;
+
+use super::MethodContext;
+use super::error;
+use super::types::{Frame, VerificationType, is_assignable};
+
+// 4.10.1.9. Type Checking Instructions
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.10.1.9
+// Changes `frame` from the type state before the instruction into the one after it.
+// The type state at branch targets is the one after the instruction as well.
+// jsr and ret are left to the caller, since only the type inference (4.10.2) allows them.
+pub(crate) fn execute(context: &MethodContext, frame: &mut Frame, pc: u16, instruction: &Instruction) -> Result<()> {
+    use Instruction::*;
+    use VerificationType::*;
+    let hierarchy = context.hierarchy;
+    let max_stack = context.max_stack;
+    match instruction {
+        Nop => {}
+        AconstNull => frame.push(Null, max_stack)?,
+        IconstM1 | Iconst0 | Iconst1 | Iconst2 | Iconst3 | Iconst4 | Iconst5 | Bipush(_) | Sipush(_) => frame.push(Integer, max_stack)?,
+        Lconst0 | Lconst1 => frame.push(Long, max_stack)?,
+        Fconst0 | Fconst1 | Fconst2 => frame.push(Float, max_stack)?,
+        Dconst0 | Dconst1 => frame.push(Double, max_stack)?,
+        Ldc(index) => frame.push(loadable_constant(context, *index as u16, false)?, max_stack)?,
+        LdcW(index) => frame.push(loadable_constant(context, *index, false)?, max_stack)?,
+        Ldc2W(index) => frame.push(loadable_constant(context, *index, true)?, max_stack)?,
+        Iload(index) => load(frame, *index, Integer, context)?,
+        Iload0 => load(frame, 0, Integer, context)?,
+        Iload1 => load(frame, 1, Integer, context)?,
+        Iload2 => load(frame, 2, Integer, context)?,
+        Iload3 => load(frame, 3, Integer, context)?,
+        Lload(index) => load(frame, *index, Long, context)?,
+        Lload0 => load(frame, 0, Long, context)?,
+        Lload1 => load(frame, 1, Long, context)?,
+        Lload2 => load(frame, 2, Long, context)?,
+        Lload3 => load(frame, 3, Long, context)?,
+        Fload(index) => load(frame, *index, Float, context)?,
+        Fload0 => load(frame, 0, Float, context)?,
+        Fload1 => load(frame, 1, Float, context)?,
+        Fload2 => load(frame, 2, Float, context)?,
+        Fload3 => load(frame, 3, Float, context)?,
+        Dload(index) => load(frame, *index, Double, context)?,
+        Dload0 => load(frame, 0, Double, context)?,
+        Dload1 => load(frame, 1, Double, context)?,
+        Dload2 => load(frame, 2, Double, context)?,
+        Dload3 => load(frame, 3, Double, context)?,
+        Aload(index) => load_reference(frame, *index, context)?,
+        Aload0 => load_reference(frame, 0, context)?,
+        Aload1 => load_reference(frame, 1, context)?,
+        Aload2 => load_reference(frame, 2, context)?,
+        Aload3 => load_reference(frame, 3, context)?,
+        Istore(index) => store(frame, *index, Integer, context)?,
+        Istore0 => store(frame, 0, Integer, context)?,
+        Istore1 => store(frame, 1, Integer, context)?,
+        Istore2 => store(frame, 2, Integer, context)?,
+        Istore3 => store(frame, 3, Integer, context)?,
+        Lstore(index) => store(frame, *index, Long, context)?,
+        Lstore0 => store(frame, 0, Long, context)?,
+        Lstore1 => store(frame, 1, Long, context)?,
+        Lstore2 => store(frame, 2, Long, context)?,
+        Lstore3 => store(frame, 3, Long, context)?,
+        Fstore(index) => store(frame, *index, Float, context)?,
+        Fstore0 => store(frame, 0, Float, context)?,
+        Fstore1 => store(frame, 1, Float, context)?,
+        Fstore2 => store(frame, 2, Float, context)?,
+        Fstore3 => store(frame, 3, Float, context)?,
+        Dstore(index) => store(frame, *index, Double, context)?,
+        Dstore0 => store(frame, 0, Double, context)?,
+        Dstore1 => store(frame, 1, Double, context)?,
+        Dstore2 => store(frame, 2, Double, context)?,
+        Dstore3 => store(frame, 3, Double, context)?,
+        Astore(index) => store_reference(frame, *index)?,
+        Astore0 => store_reference(frame, 0)?,
+        Astore1 => store_reference(frame, 1)?,
+        Astore2 => store_reference(frame, 2)?,
+        Astore3 => store_reference(frame, 3)?,
+        Iaload => array_load(frame, "[I", Integer, context)?,
+        Laload => array_load(frame, "[J", Long, context)?,
+        Faload => array_load(frame, "[F", Float, context)?,
+        Daload => array_load(frame, "[D", Double, context)?,
+        Caload => array_load(frame, "[C", Integer, context)?,
+        Saload => array_load(frame, "[S", Integer, context)?,
+        Baload => {
+            frame.pop(&Integer, hierarchy)?;
+            pop_byte_or_boolean_array(frame)?;
+            frame.push(Integer, max_stack)?;
+        }
+        Aaload => {
+            frame.pop(&Integer, hierarchy)?;
+            let component = match pop_reference_array(frame)? {
+                Null => Null,
+                array => array.component_type()?,
+            };
+            frame.push(component, max_stack)?;
+        }
+        Iastore => array_store(frame, "[I", Integer, context)?,
+        Lastore => array_store(frame, "[J", Long, context)?,
+        Fastore => array_store(frame, "[F", Float, context)?,
+        Dastore => array_store(frame, "[D", Double, context)?,
+        Castore => array_store(frame, "[C", Integer, context)?,
+        Sastore => array_store(frame, "[S", Integer, context)?,
+        Bastore => {
+            frame.pop(&Integer, hierarchy)?;
+            frame.pop(&Integer, hierarchy)?;
+            pop_byte_or_boolean_array(frame)?;
+        }
+        Aastore => {
+            // Whether the value can be stored in the array is checked at run time.
+            frame.pop(&VerificationType::object(), hierarchy)?;
+            frame.pop(&Integer, hierarchy)?;
+            pop_reference_array(frame)?;
+        }
+        Pop => {
+            frame.pop_category1()?;
+        }
+        Pop2 => {
+            if !frame.pop_any()?.is_category2() {
+                frame.pop_category1()?;
+            }
+        }
+        Dup => {
+            let value = frame.pop_category1()?;
+            push_all(frame, &[&value, &value], max_stack)?;
+        }
+        DupX1 => {
+            let value1 = frame.pop_category1()?;
+            let value2 = frame.pop_category1()?;
+            push_all(frame, &[&value1, &value2, &value1], max_stack)?;
+        }
+        DupX2 => {
+            let value1 = frame.pop_category1()?;
+            let value2 = frame.pop_any()?;
+            if value2.is_category2() {
+                push_all(frame, &[&value1, &value2, &value1], max_stack)?;
+            } else {
+                let value3 = frame.pop_category1()?;
+                push_all(frame, &[&value1, &value3, &value2, &value1], max_stack)?;
+            }
+        }
+        Dup2 => {
+            let value1 = frame.pop_any()?;
+            if value1.is_category2() {
+                push_all(frame, &[&value1, &value1], max_stack)?;
+            } else {
+                let value2 = frame.pop_category1()?;
+                push_all(frame, &[&value2, &value1, &value2, &value1], max_stack)?;
+            }
+        }
+        Dup2X1 => {
+            let value1 = frame.pop_any()?;
+            if value1.is_category2() {
+                let value2 = frame.pop_category1()?;
+                push_all(frame, &[&value1, &value2, &value1], max_stack)?;
+            } else {
+                let value2 = frame.pop_category1()?;
+                let value3 = frame.pop_category1()?;
+                push_all(frame, &[&value2, &value1, &value3, &value2, &value1], max_stack)?;
+            }
+        }
+        Dup2X2 => {
+            let value1 = frame.pop_any()?;
+            if value1.is_category2() {
+                let value2 = frame.pop_any()?;
+                if value2.is_category2() {
+                    push_all(frame, &[&value1, &value2, &value1], max_stack)?;
+                } else {
+                    let value3 = frame.pop_category1()?;
+                    push_all(frame, &[&value1, &value3, &value2, &value1], max_stack)?;
+                }
+            } else {
+                let value2 = frame.pop_category1()?;
+                let value3 = frame.pop_any()?;
+                if value3.is_category2() {
+                    push_all(frame, &[&value2, &value1, &value3, &value2, &value1], max_stack)?;
+                } else {
+                    let value4 = frame.pop_category1()?;
+                    push_all(frame, &[&value2, &value1, &value4, &value3, &value2, &value1], max_stack)?;
+                }
+            }
+        }
+        Swap => {
+            let value1 = frame.pop_category1()?;
+            let value2 = frame.pop_category1()?;
+            push_all(frame, &[&value1, &value2], max_stack)?;
+        }
+        Iadd | Isub | Imul | Idiv | Irem | Ishl | Ishr | Iushr | Iand | Ior | Ixor => operation(frame, &[Integer, Integer], Integer, context)?,
+        Ladd | Lsub | Lmul | Ldiv | Lrem | Land | Lor | Lxor => operation(frame, &[Long, Long], Long, context)?,
+        Fadd | Fsub | Fmul | Fdiv | Frem => operation(frame, &[Float, Float], Float, context)?,
+        Dadd | Dsub | Dmul | Ddiv | Drem => operation(frame, &[Double, Double], Double, context)?,
+        Lshl | Lshr | Lushr => operation(frame, &[Long, Integer], Long, context)?,
+        Ineg | I2b | I2c | I2s => operation(frame, &[Integer], Integer, context)?,
+        Lneg => operation(frame, &[Long], Long, context)?,
+        Fneg => operation(frame, &[Float], Float, context)?,
+        Dneg => operation(frame, &[Double], Double, context)?,
+        Iinc(index, _) => {
+            frame.load(*index, &Integer, hierarchy)?;
+        }
+        I2l => operation(frame, &[Integer], Long, context)?,
+        I2f => operation(frame, &[Integer], Float, context)?,
+        I2d => operation(frame, &[Integer], Double, context)?,
+        L2i => operation(frame, &[Long], Integer, context)?,
+        L2f => operation(frame, &[Long], Float, context)?,
+        L2d => operation(frame, &[Long], Double, context)?,
+        F2i => operation(frame, &[Float], Integer, context)?,
+        F2l => operation(frame, &[Float], Long, context)?,
+        F2d => operation(frame, &[Float], Double, context)?,
+        D2i => operation(frame, &[Double], Integer, context)?,
+        D2l => operation(frame, &[Double], Long, context)?,
+        D2f => operation(frame, &[Double], Float, context)?,
+        Lcmp => operation(frame, &[Long, Long], Integer, context)?,
+        Fcmpl | Fcmpg => operation(frame, &[Float, Float], Integer, context)?,
+        Dcmpl | Dcmpg => operation(frame, &[Double, Double], Integer, context)?,
+        Ifeq(_) | Ifne(_) | Iflt(_) | Ifge(_) | Ifgt(_) | Ifle(_) | Tableswitch { .. } | Lookupswitch { .. } => {
+            frame.pop(&Integer, hierarchy)?;
+        }
+        IfIcmpeq(_) | IfIcmpne(_) | IfIcmplt(_) | IfIcmpge(_) | IfIcmpgt(_) | IfIcmple(_) => {
+            frame.pop(&Integer, hierarchy)?;
+            frame.pop(&Integer, hierarchy)?;
+        }
+        IfAcmpeq(_) | IfAcmpne(_) => {
+            frame.pop_reference()?;
+            frame.pop_reference()?;
+        }
+        Ifnull(_) | Ifnonnull(_) => {
+            frame.pop_reference()?;
+        }
+        Goto(_) | GotoW(_) => {}
+        Jsr(_) | JsrW(_) | Ret(_) => {
+            return error(format!("{} is not allowed in a method verified by type checking", instruction.mnemonic()));
+        }
+        Ireturn => return_value(frame, Integer, context)?,
+        Lreturn => return_value(frame, Long, context)?,
+        Freturn => return_value(frame, Float, context)?,
+        Dreturn => return_value(frame, Double, context)?,
+        Areturn => return_value(frame, VerificationType::object(), context)?,
+        Return => {
+            if context.method_type.return_type != ReturnType::Void {
+                return error("Method expects a return value".to_string());
+            }
+            if context.is_init() && frame.flag_this_uninit {
+                return error("Constructor must call super() or this() before return".to_string());
+            }
+        }
+        Getstatic(index) => {
+            let (_, _, _, field_type) = field_ref(context, *index)?;
+            frame.push(field_type, max_stack)?;
+        }
+        Putstatic(index) => {
+            let (_, _, _, field_type) = field_ref(context, *index)?;
+            frame.pop(&field_type, hierarchy)?;
+        }
+        Getfield(index) => {
+            let (class_name, field_name, descriptor, field_type) = field_ref(context, *index)?;
+            let target = frame.pop(&VerificationType::class(&class_name), hierarchy)?;
+            passes_protected_check(context, instruction, &target, &class_name, &field_name, &descriptor)?;
+            frame.push(field_type, max_stack)?;
+        }
+        Putfield(index) => {
+            let (class_name, field_name, descriptor, field_type) = field_ref(context, *index)?;
+            frame.pop(&field_type, hierarchy)?;
+            // A constructor may assign the fields declared in its class before calling super() (4.10.1.9 putfield).
+            if frame.stack.last() == Some(&UninitializedThis) && class_name == context.class_name && declares_field(context, &field_name, &field_type)? {
+                frame.pop_any()?;
+            } else {
+                let target = frame.pop(&VerificationType::class(&class_name), hierarchy)?;
+                passes_protected_check(context, instruction, &target, &class_name, &field_name, &descriptor)?;
+            }
+        }
+        Invokevirtual(index) | Invokespecial(index) | Invokestatic(index) | Invokeinterface { index, .. } => {
+            invoke(frame, instruction, *index, context)?;
+        }
+        Invokedynamic(index) => {
+            let constant_pool = &context.class_file.constant_pool;
+            let invoke_dynamic = constant_pool.access_as_invoke_dynamic(*index);
+            let name_and_type = invoke_dynamic.name_and_type();
+            let name = name_and_type.name().bytes_as_string()?;
+            if name.starts_with('<') {
+                return error(format!("Illegal call to {} by invokedynamic", name));
+            }
+            let method_type = parse_method_descriptor(&name_and_type.descriptor().bytes_as_string()?)?;
+            pop_arguments(frame, &method_type, context)?;
+            push_return_value(frame, &method_type, context)?;
+        }
+        New(index) => {
+            let class_name = class_name(context, *index)?;
+            if class_name.starts_with('[') {
+                return error(format!("Illegal new of an array type: {}", class_name));
+            }
+            let uninitialized = Uninitialized(pc);
+            if frame.stack.contains(&uninitialized) {
+                return error(format!("{} is already on the operand stack", uninitialized));
+            }
+            frame.initialize(&uninitialized, &Top);
+            frame.push(uninitialized, max_stack)?;
+        }
+        Newarray(atype) => {
+            let array = match atype {
+                4 => "[Z",
+                5 => "[C",
+                6 => "[F",
+                7 => "[D",
+                8 => "[B",
+                9 => "[S",
+                10 => "[I",
+                11 => "[J",
+                _ => return error(format!("Illegal newarray type: {}", atype)),
+            };
+            frame.pop(&Integer, hierarchy)?;
+            frame.push(VerificationType::class(array), max_stack)?;
+        }
+        Anewarray(index) => {
+            let component = VerificationType::class(&class_name(context, *index)?);
+            frame.pop(&Integer, hierarchy)?;
+            frame.push(component.array_of()?, max_stack)?;
+        }
+        Arraylength => {
+            let array = frame.pop_reference()?;
+            if array != Null && !array.is_array() {
+                return error(format!("Bad type on operand stack, expected: array, actual: {}", array));
+            }
+            frame.push(Integer, max_stack)?;
+        }
+        Athrow => {
+            frame.pop(&VerificationType::class("java/lang/Throwable"), hierarchy)?;
+        }
+        Checkcast(index) => {
+            let class_name = class_name(context, *index)?;
+            frame.pop(&VerificationType::object(), hierarchy)?;
+            frame.push(VerificationType::class(&class_name), max_stack)?;
+        }
+        Instanceof(index) => {
+            class_name(context, *index)?;
+            frame.pop(&VerificationType::object(), hierarchy)?;
+            frame.push(Integer, max_stack)?;
+        }
+        Monitorenter | Monitorexit => {
+            frame.pop(&VerificationType::object(), hierarchy)?;
+        }
+        Wide(instruction) => execute(context, frame, pc, instruction)?,
+        Multianewarray { index, dimensions } => {
+            let class_name = class_name(context, *index)?;
+            if *dimensions == 0 || class_name.chars().take_while(|c| *c == '[').count() < *dimensions as usize {
+                return error(format!("Illegal dimensions {} for multianewarray of {}", dimensions, class_name));
+            }
+            for _ in 0..*dimensions {
+                frame.pop(&Integer, hierarchy)?;
+            }
+            frame.push(VerificationType::class(&class_name), max_stack)?;
+        }
+    }
+    Ok(())
+}
+
+fn load(frame: &mut Frame, index: u16, expected: VerificationType, context: &MethodContext) -> Result<()> {
+    frame.load(index, &expected, context.hierarchy)?;
+    frame.push(expected, context.max_stack)
+}
+
+fn load_reference(frame: &mut Frame, index: u16, context: &MethodContext) -> Result<()> {
+    let actual = frame.local(index)?.clone();
+    if !actual.is_reference() {
+        return error(format!("Bad local variable type at index {}, expected: reference, actual: {}", index, actual));
+    }
+    frame.push(actual, context.max_stack)
+}
+
+fn store(frame: &mut Frame, index: u16, expected: VerificationType, context: &MethodContext) -> Result<()> {
+    frame.pop(&expected, context.hierarchy)?;
+    frame.store(index, expected)
+}
+
+// astore also stores the return address of jsr.
+fn store_reference(frame: &mut Frame, index: u16) -> Result<()> {
+    let actual = frame.pop_any()?;
+    if !actual.is_reference() && !matches!(actual, VerificationType::ReturnAddress(_)) {
+        return error(format!("Bad type on operand stack, expected: reference or returnAddress, actual: {}", actual));
+    }
+    frame.store(index, actual)
+}
+
+fn push_all(frame: &mut Frame, values: &[&VerificationType], max_stack: u16) -> Result<()> {
+    for value in values {
+        frame.push((*value).clone(), max_stack)?;
+    }
+    Ok(())
+}
+
+// Pops the operands, the last one first, and pushes the result.
+fn operation(frame: &mut Frame, operands: &[VerificationType], result: VerificationType, context: &MethodContext) -> Result<()> {
+    for operand in operands.iter().rev() {
+        frame.pop(operand, context.hierarchy)?;
+    }
+    frame.push(result, context.max_stack)
+}
+
+fn array_load(frame: &mut Frame, array: &str, component: VerificationType, context: &MethodContext) -> Result<()> {
+    operation(frame, &[VerificationType::class(array), VerificationType::Integer], component, context)
+}
+
+fn array_store(frame: &mut Frame, array: &str, component: VerificationType, context: &MethodContext) -> Result<()> {
+    frame.pop(&component, context.hierarchy)?;
+    frame.pop(&VerificationType::Integer, context.hierarchy)?;
+    frame.pop(&VerificationType::class(array), context.hierarchy)?;
+    Ok(())
+}
+
+fn pop_byte_or_boolean_array(frame: &mut Frame) -> Result<()> {
+    let array = frame.pop_any()?;
+    match &array {
+        VerificationType::Null => Ok(()),
+        VerificationType::Reference(name) if name == "[B" || name == "[Z" => Ok(()),
+        _ => error(format!("Bad type on operand stack, expected: [B or [Z, actual: {}", array)),
+    }
+}
+
+fn pop_reference_array(frame: &mut Frame) -> Result<VerificationType> {
+    let array = frame.pop_any()?;
+    match &array {
+        VerificationType::Null => Ok(array),
+        VerificationType::Reference(name) if name.starts_with("[L") || name.starts_with("[[") => Ok(array),
+        _ => error(format!("Bad type on operand stack, expected: array of references, actual: {}", array)),
+    }
+}
+
+// `kind` is the type that the return instruction is for, and java/lang/Object for areturn.
+fn return_value(frame: &mut Frame, kind: VerificationType, context: &MethodContext) -> Result<()> {
+    let expected = match &context.method_type.return_type {
+        ReturnType::Field { value } => VerificationType::from_field_type(value),
+        ReturnType::Void => return error("Method does not expect a return value".to_string()),
+    };
+    let is_same_kind = if kind.is_reference() { expected.is_reference() } else { expected == kind };
+    if !is_same_kind {
+        return error(format!("Bad return type, expected: {}, actual: {}", expected, kind));
+    }
+    frame.pop(&expected, context.hierarchy)?;
+    Ok(())
+}
+
+fn class_name(context: &MethodContext, index: u16) -> Result<String> {
+    (&context.class_file.constant_pool).access_as_class(index).name().bytes_as_string()
+}
+
+// 4.10.1.3. The constant pool entries that ldc, ldc_w and ldc2_w can load
+fn loadable_constant(context: &MethodContext, index: u16, category2: bool) -> Result<VerificationType> {
+    let constant_pool = &context.class_file.constant_pool;
+    let verification_type = match constant_pool.get::<CpInfo>(index).ok() {
+        Some(CpInfo::Integer(_)) => VerificationType::Integer,
+        Some(CpInfo::Float(_)) => VerificationType::Float,
+        Some(CpInfo::Long(_)) => VerificationType::Long,
+        Some(CpInfo::Double(_)) => VerificationType::Double,
+        Some(CpInfo::String(_)) => VerificationType::class("java/lang/String"),
+        Some(CpInfo::Class(_)) => VerificationType::class("java/lang/Class"),
+        Some(CpInfo::MethodType(_)) => VerificationType::class("java/lang/invoke/MethodType"),
+        Some(CpInfo::MethodHandle(_)) => VerificationType::class("java/lang/invoke/MethodHandle"),
+        Some(CpInfo::Dynamic(_)) => {
+            let descriptor = constant_pool.access_as_dynamic(index).name_and_type().descriptor().bytes_as_string()?;
+            VerificationType::from_field_type(&parse_field_type(&descriptor)?)
+        }
+        _ => return error(format!("Illegal constant pool index {} for ldc", index)),
+    };
+    if verification_type.is_category2() != category2 {
+        let mnemonic = if category2 { "ldc2_w" } else { "ldc or ldc_w" };
+        return error(format!("Illegal type {} for {}", verification_type, mnemonic));
+    }
+    Ok(verification_type)
+}
+
+// (class name, field name, descriptor, field type)
+fn field_ref(context: &MethodContext, index: u16) -> Result<(String, String, String, VerificationType)> {
+    let constant_pool = &context.class_file.constant_pool;
+    let fieldref = constant_pool.access_as_fieldref(index);
+    let class_name = fieldref.class().name().bytes_as_string()?;
+    let name_and_type = fieldref.name_and_type();
+    let field_name = name_and_type.name().bytes_as_string()?;
+    let descriptor = name_and_type.descriptor().bytes_as_string()?;
+    let field_type = parse_field_type(&descriptor)?;
+    Ok((class_name, field_name, descriptor, VerificationType::from_field_type(&field_type)))
+}
+
+fn declares_field(context: &MethodContext, name: &str, field_type: &VerificationType) -> Result<bool> {
+    let constant_pool = &context.class_file.constant_pool;
+    for field in &context.class_file.fields {
+        let field_name = constant_pool.access(field.name_index).bytes_as_string()?;
+        let descriptor = constant_pool.access(field.descriptor_index).bytes_as_string()?;
+        if field_name == name && VerificationType::from_field_type(&parse_field_type(&descriptor)?) == *field_type {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn invoke(frame: &mut Frame, instruction: &Instruction, index: u16, context: &MethodContext) -> Result<()> {
+    let constant_pool = &context.class_file.constant_pool;
+    // invokevirtual takes a CONSTANT_Methodref and invokeinterface a CONSTANT_InterfaceMethodref.
+    // invokespecial and invokestatic take either (4.4.2).
+    let is_interface_methodref = matches!(constant_pool.get::<CpInfo>(index).ok(), Some(CpInfo::InterfaceMethodref(_)));
+    let (class_name, method_name, descriptor) = match (instruction, is_interface_methodref) {
+        (Instruction::Invokeinterface { .. } | Instruction::Invokespecial(_) | Instruction::Invokestatic(_), true) => {
+            let methodref = constant_pool.access_as_interface_methodref(index);
+            let name_and_type = methodref.name_and_type();
+            (methodref.class().name().bytes_as_string()?, name_and_type.name().bytes_as_string()?, name_and_type.descriptor().bytes_as_string()?)
+        }
+        (Instruction::Invokeinterface { .. }, false) => {
+            return error(format!("invokeinterface requires a CONSTANT_InterfaceMethodref, index: {}", index));
+        }
+        _ => {
+            let methodref = constant_pool.access_as_methodref(index);
+            let name_and_type = methodref.name_and_type();
+            (methodref.class().name().bytes_as_string()?, name_and_type.name().bytes_as_string()?, name_and_type.descriptor().bytes_as_string()?)
+        }
+    };
+    let method_type = parse_method_descriptor(&descriptor)?;
+    let is_init = method_name == "<init>";
+    if method_name.starts_with('<') && !(is_init && matches!(instruction, Instruction::Invokespecial(_))) {
+        return error(format!("Illegal call to {} by {}", method_name, instruction.mnemonic()));
+    }
+    if let Instruction::Invokeinterface { count, .. } = instruction {
+        let argument_size: usize = method_type.parameter_types.iter()
+            .map(|parameter| VerificationType::from_field_type(parameter).size())
+            .sum();
+        if *count as usize != argument_size + 1 {
+            return error(format!("Inconsistent args count operand {} in invokeinterface", count));
+        }
+    }
+    pop_arguments(frame, &method_type, context)?;
+    match instruction {
+        Instruction::Invokestatic(_) => {}
+        Instruction::Invokespecial(_) if is_init => {
+            let receiver = frame.pop_any()?;
+            let initialized = match &receiver {
+                VerificationType::UninitializedThis => {
+                    // this() or super()
+                    let super_name = context.hierarchy.super_class(&context.class_name)?;
+                    if class_name != context.class_name && Some(&class_name) != super_name.as_ref() {
+                        return error(format!("Bad <init> method call on uninitializedThis to {}", class_name));
+                    }
+                    frame.flag_this_uninit = false;
+                    context.this_type()
+                }
+                VerificationType::Uninitialized(new_pc) => {
+                    let new_class_name = new_class_name(context, *new_pc)?;
+                    if class_name != new_class_name {
+                        return error(format!("Bad <init> method call on {} created by new {}", class_name, new_class_name));
+                    }
+                    VerificationType::class(&class_name)
+                }
+                _ => return error(format!("Bad operand type for invokespecial <init>, actual: {}", receiver)),
+            };
+            frame.initialize(&receiver, &initialized);
+        }
+        Instruction::Invokespecial(_) => {
+            frame.pop(&context.this_type(), context.hierarchy)?;
+        }
+        Instruction::Invokevirtual(_) => {
+            let target = frame.pop(&VerificationType::class(&class_name), context.hierarchy)?;
+            passes_protected_check(context, instruction, &target, &class_name, &method_name, &descriptor)?;
+        }
+        _ => {
+            frame.pop(&VerificationType::class(&class_name), context.hierarchy)?;
+        }
+    }
+    push_return_value(frame, &method_type, context)
+}
+
+// 4.10.1.8. Type Checking for protected Members
+// A protected member that a superclass in another run-time package declares is accessed only through
+// the current class or its subclasses. `target` is the type of the object whose member is accessed.
+// The class in the symbolic reference may inherit the member, so the class that declares it is the one checked.
+fn passes_protected_check(context: &MethodContext, instruction: &Instruction, target: &VerificationType, member_class_name: &str, member_name: &str, member_descriptor: &str) -> Result<()> {
+    let hierarchy = context.hierarchy;
+    if !is_super_class(context, member_class_name)? {
+        return Ok(());
+    }
+    let mut class_name = Some(member_class_name.to_string());
+    while let Some(declaring_class_name) = class_name {
+        match hierarchy.declared_protected(&declaring_class_name, member_name, member_descriptor)? {
+            Some(true) => {
+                // All classes are loaded by the same loader, so the run-time package is the package of the name.
+                let package = |class_name: &str| class_name.rfind('/').map_or(String::new(), |i| class_name[..i].to_string());
+                if package(&declaring_class_name) != package(&context.class_name) && !is_assignable(target, &context.this_type(), hierarchy)? {
+                    return error(format!("Bad access to protected data in {}, expected: {}, actual: {}", instruction.mnemonic(), context.this_type(), target));
+                }
+                return Ok(());
+            }
+            Some(false) => return Ok(()),
+            None => class_name = hierarchy.super_class(&declaring_class_name)?,
+        }
+    }
+    // The member is not found, which is left to the resolution at run time.
+    Ok(())
+}
+
+// Whether the class is a superclass of the current class.
+fn is_super_class(context: &MethodContext, class_name: &str) -> Result<bool> {
+    let mut super_class = context.hierarchy.super_class(&context.class_name)?;
+    while let Some(super_class_name) = super_class {
+        if super_class_name == class_name {
+            return Ok(true);
+        }
+        super_class = context.hierarchy.super_class(&super_class_name)?;
+    }
+    Ok(false)
+}
+
+fn pop_arguments(frame: &mut Frame, method_type: &MethodType, context: &MethodContext) -> Result<()> {
+    for parameter in method_type.parameter_types.iter().rev() {
+        frame.pop(&VerificationType::from_field_type(parameter), context.hierarchy)?;
+    }
+    Ok(())
+}
+
+fn push_return_value(frame: &mut Frame, method_type: &MethodType, context: &MethodContext) -> Result<()> {
+    match &method_type.return_type {
+        ReturnType::Field { value } => frame.push(VerificationType::from_field_type(value), context.max_stack),
+        ReturnType::Void => Ok(()),
+    }
+}
+
+// The class of the `new` instruction at new_pc, which created an uninitialized object.
+fn new_class_name(context: &MethodContext, new_pc: u16) -> Result<String> {
+    match context.instruction_at(new_pc) {
+        Some(Instruction::New(index)) => class_name(context, *index),
+        _ => error(format!("Expecting new instruction at pc {}", new_pc)),
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+
+use crate::class_file::error::Result;
+use crate::class_file::instruction::Instruction;
+use crate::class_file::structure::attribute::*;
+use crate::class_file::structure_utils::cp_accessor::*;
+
+use super::{Handler, MethodContext};
+use super::transfer::execute;
+use super::types::{Frame, VerificationType, check_frame_assignable, expand_locals};
+
+// 4.10.1. Verification by Type Checking
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.10.1
+// The instructions are checked in order, once each. The type state at a branch target,
+// at an exception handler, and after an unconditional branch is given by the StackMapTable.
+pub(crate) fn check_method(context: &MethodContext, code: &CodeAttributeInfo) -> Result<()> {
+    let initial_frame = context.initial_frame()?;
+    let stack_map = stack_map_frames(context, code, context.initial_locals())?;
+    let handlers = context.exception_handlers(code)?;
+    check_handler_frames(context, &handlers, &stack_map)?;
+
+    let mut current: Option<Frame> = Some(initial_frame);
+    for (pc, instruction) in &context.instructions {
+        let pc = *pc;
+        let frame = match (current.take(), stack_map.get(&pc)) {
+            (Some(frame), Some(map_frame)) => {
+                check_frame_assignable(&frame, map_frame, context.hierarchy).or_else(|e| context.error_at(pc, e.message))?;
+                map_frame.clone()
+            }
+            (None, Some(map_frame)) => map_frame.clone(),
+            (Some(frame), None) => frame,
+            (None, None) => return context.error_at(pc, "Expecting a stackmap frame at this location".to_string()),
+        };
+
+        check_handlers(context, &handlers, &stack_map, pc, &frame)?;
+        let mut next = frame.clone();
+        execute(context, &mut next, pc, instruction).or_else(|e| context.error_at(pc, e.message))?;
+        // The handlers also have to accept the local variables just stored.
+        if is_store(instruction) {
+            check_handlers(context, &handlers, &stack_map, pc, &next)?;
+        }
+
+        for target in instruction.branch_targets(pc) {
+            match stack_map.get(&target) {
+                Some(target_frame) => check_frame_assignable(&next, target_frame, context.hierarchy)
+                    .or_else(|e| context.error_at(pc, format!("{} at branch target {}", e.message, target)))?,
+                None => return context.error_at(pc, format!("Expecting a stackmap frame at branch target {}", target)),
+            }
+        }
+        current = if instruction.falls_through() { Some(next) } else { None };
+    }
+    if current.is_some() {
+        let last_pc = context.instructions.last().map_or(0, |(pc, _)| *pc);
+        return context.error_at(last_pc, "Falling off the end of the code".to_string());
+    }
+    Ok(())
+}
+
+// 4.7.4. The StackMapTable Attribute
+// Each frame is given as a difference from the previous one, starting from the locals of the initial frame.
+pub(crate) fn stack_map_frames(context: &MethodContext, code: &CodeAttributeInfo, initial_locals: Vec<VerificationType>) -> Result<BTreeMap<u16, Frame>> {
+    let mut stack_map_tables = code.attributes.iter().filter_map(|attribute| match attribute {
+        Attribute::StackMapTable(stack_map_table) => Some(stack_map_table),
+        _ => None,
+    });
+    let stack_map_table = stack_map_tables.next();
+    if stack_map_tables.next().is_some() {
+        return context.error("Multiple StackMapTable attributes".to_string());
+    }
+    let entries: &[StackMapFrame] = match stack_map_table {
+        Some(stack_map_table) => &stack_map_table.entries,
+        None => &[],
+    };
+
+    let mut frames = BTreeMap::new();
+    let mut locals = initial_locals;
+    let mut previous_pc: Option<u16> = None;
+    for entry in entries {
+        let (offset_delta, stack) = match entry {
+            StackMapFrame::SameFrame { frame_type } => (*frame_type as u16, Vec::new()),
+            StackMapFrame::SameLocals1StackItemFrame { frame_type, stack } => (*frame_type as u16 - 64, convert_types(context, stack)?),
+            StackMapFrame::SameLocals1StackItemFrameExtended { offset_delta, stack, .. } => (*offset_delta, convert_types(context, stack)?),
+            StackMapFrame::ChopFrame { frame_type, offset_delta } => {
+                let k = (251 - *frame_type) as usize;
+                if k > locals.len() {
+                    return context.error(format!("Chop frame removes {} locals from {}", k, locals.len()));
+                }
+                locals.truncate(locals.len() - k);
+                (*offset_delta, Vec::new())
+            }
+            StackMapFrame::SameFrameExtended { offset_delta, .. } => (*offset_delta, Vec::new()),
+            StackMapFrame::AppendFrame { offset_delta, locals: appended, .. } => {
+                locals.extend(convert_types(context, appended)?);
+                (*offset_delta, Vec::new())
+            }
+            StackMapFrame::FullFrame { offset_delta, locals: full_locals, stack, .. } => {
+                locals = convert_types(context, full_locals)?;
+                (*offset_delta, convert_types(context, stack)?)
+            }
+        };
+        // The offset of the first frame is offset_delta, and the rest are offset_delta + 1 from the previous one.
+        let pc = match previous_pc {
+            None => offset_delta as u32,
+            Some(previous_pc) => previous_pc as u32 + offset_delta as u32 + 1,
+        };
+        if pc > u16::MAX as u32 || context.instruction_at(pc as u16).is_none() {
+            return context.error(format!("StackMapTable error: bad offset {}", pc));
+        }
+        let pc = pc as u16;
+        let frame = Frame {
+            locals: expand_locals(&locals, context.max_locals)
+                .or_else(|e| context.error_at(pc, format!("StackMapTable error: {}", e.message)))?,
+            flag_this_uninit: locals.contains(&VerificationType::UninitializedThis),
+            stack,
+        };
+        if frame.stack_size() > context.max_stack as usize {
+            return context.error_at(pc, format!("StackMapTable error: stack size {} exceeds max_stack {}", frame.stack_size(), context.max_stack));
+        }
+        frames.insert(pc, frame);
+        previous_pc = Some(pc);
+    }
+    Ok(frames)
+}
+
+fn convert_types(context: &MethodContext, infos: &[VerificationTypeInfo]) -> Result<Vec<VerificationType>> {
+    infos.iter().map(|info| convert_type(context, info)).collect()
+}
+
+fn convert_type(context: &MethodContext, info: &VerificationTypeInfo) -> Result<VerificationType> {
+    let verification_type = match info {
+        VerificationTypeInfo::TopVariableInfo { .. } => VerificationType::Top,
+        VerificationTypeInfo::IntegerVariableInfo { .. } => VerificationType::Integer,
+        VerificationTypeInfo::FloatVariableInfo { .. } => VerificationType::Float,
+        VerificationTypeInfo::DoubleVariableInfo { .. } => VerificationType::Double,
+        VerificationTypeInfo::LongVariableInfo { .. } => VerificationType::Long,
+        VerificationTypeInfo::NullVariableInfo { .. } => VerificationType::Null,
+        VerificationTypeInfo::UninitializedThisVariableInfo { .. } => VerificationType::UninitializedThis,
+        VerificationTypeInfo::ObjectVariableInfo { cpool_index, .. } => {
+            let name = (&context.class_file.constant_pool).access(*cpool_index).name().bytes_as_string()
+                .or_else(|e| context.error(format!("StackMapTable error: {}", e.message)))?;
+            VerificationType::Reference(name)
+        }
+        VerificationTypeInfo::UninitializedVariableInfo { offset, .. } => {
+            if !matches!(context.instruction_at(*offset), Some(Instruction::New(_))) {
+                return context.error(format!("StackMapTable error: uninitialized offset {} is not a new instruction", offset));
+            }
+            VerificationType::Uninitialized(*offset)
+        }
+    };
+    Ok(verification_type)
+}
+
+fn check_handler_frames(context: &MethodContext, handlers: &[Handler], stack_map: &BTreeMap<u16, Frame>) -> Result<()> {
+    for handler in handlers {
+        if !stack_map.contains_key(&handler.handler_pc) {
+            return context.error(format!("Expecting a stackmap frame at exception handler {}", handler.handler_pc));
+        }
+    }
+    Ok(())
+}
+
+// instructionSatisfiesHandlers of 4.10.1.6
+// A handler starts with the local variables at the instruction and only the exception on the stack.
+fn check_handlers(context: &MethodContext, handlers: &[Handler], stack_map: &BTreeMap<u16, Frame>, pc: u16, frame: &Frame) -> Result<()> {
+    for handler in handlers.iter().filter(|handler| handler.covers(pc)) {
+        let exception_frame = Frame {
+            locals: frame.locals.clone(),
+            stack: vec![handler.catch_type.clone()],
+            flag_this_uninit: frame.flag_this_uninit,
+        };
+        check_frame_assignable(&exception_frame, &stack_map[&handler.handler_pc], context.hierarchy)
+            .or_else(|e| context.error_at(pc, format!("{} at exception handler {}", e.message, handler.handler_pc)))?;
+    }
+    Ok(())
+}
+
+fn is_store(instruction: &Instruction) -> bool {
+    matches!(
+        instruction.unwide(),
+        Instruction::Istore(_) | Instruction::Lstore(_) | Instruction::Fstore(_) | Instruction::Dstore(_) | Instruction::Astore(_) |
+        Instruction::Istore0 | Instruction::Istore1 | Instruction::Istore2 | Instruction::Istore3 |
+        Instruction::Lstore0 | Instruction::Lstore1 | Instruction::Lstore2 | Instruction::Lstore3 |
+        Instruction::Fstore0 | Instruction::Fstore1 | Instruction::Fstore2 | Instruction::Fstore3 |
+        Instruction::Dstore0 | Instruction::Dstore1 | Instruction::Dstore2 | Instruction::Dstore3 |
+        Instruction::Astore0 | Instruction::Astore1 | Instruction::Astore2 | Instruction::Astore3
+    )
+}
@@ -0,0 +1,255 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::class_file::error::{Error, Result};
+use crate::class_file::instruction::Instruction;
+use crate::class_file::structure::attribute::CodeAttributeInfo;
+
+use super::{Handler, MethodContext};
+use super::transfer::execute;
+use super::types::{Frame, VerificationType, check_frame_assignable, merge};
+
+// 4.10.2. Verification by Type Inference
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.10.2
+// A dataflow analysis: the type state of each instruction is the merge of the states flowing into it,
+// and instructions are visited again until no state changes.
+pub(crate) fn check_method(context: &MethodContext, code: &CodeAttributeInfo) -> Result<()> {
+    infer_frames(context, code, &BTreeMap::new()).map(|_| ())
+}
+
+// The type state before each reachable instruction.
+// Where `hints` has a frame, e.g. from the StackMapTable, the incoming states are checked against it and it is used as is.
+pub(crate) fn infer_frames(context: &MethodContext, code: &CodeAttributeInfo, hints: &BTreeMap<u16, Frame>) -> Result<BTreeMap<u16, Frame>> {
+    let mut inferencer = TypeInferencer::new(context, hints);
+    let handlers = context.exception_handlers(code)?;
+    inferencer.merge_into(0, 0, context.initial_frame()?)?;
+    while let Some(pc) = inferencer.changed.pop_first() {
+        inferencer.visit(pc, &handlers)?;
+    }
+    Ok(inferencer.states)
+}
+
+// The same as infer_frames, but an instruction that fails to check does not stop the analysis.
+// Its error is kept by pc and its successors are not reached through it.
+pub(crate) fn infer_frames_leniently(context: &MethodContext, code: &CodeAttributeInfo, hints: &BTreeMap<u16, Frame>) -> Result<(BTreeMap<u16, Frame>, BTreeMap<u16, Error>)> {
+    let mut inferencer = TypeInferencer::new(context, hints);
+    let handlers = context.exception_handlers(code)?;
+    let mut errors = BTreeMap::new();
+    if let Err(e) = inferencer.merge_into(0, 0, context.initial_frame()?) {
+        errors.insert(0, e);
+    }
+    while let Some(pc) = inferencer.changed.pop_first() {
+        if let Err(e) = inferencer.visit(pc, &handlers) {
+            errors.insert(pc, e);
+        }
+    }
+    Ok((inferencer.states, errors))
+}
+
+struct TypeInferencer<'a> {
+    context: &'a MethodContext<'a>,
+    hints: &'a BTreeMap<u16, Frame>,
+    // the type state before each instruction reached so far
+    states: BTreeMap<u16, Frame>,
+    changed: BTreeSet<u16>,
+    // the jsr instructions calling each subroutine, and the ret instructions returning from it
+    callers: BTreeMap<u16, BTreeSet<u16>>,
+    rets: BTreeMap<u16, BTreeSet<u16>>,
+}
+
+impl<'a> TypeInferencer<'a> {
+    fn new(context: &'a MethodContext<'a>, hints: &'a BTreeMap<u16, Frame>) -> Self {
+        TypeInferencer {
+            context,
+            hints,
+            states: BTreeMap::new(),
+            changed: BTreeSet::new(),
+            callers: BTreeMap::new(),
+            rets: BTreeMap::new(),
+        }
+    }
+
+    fn visit(&mut self, pc: u16, handlers: &[Handler]) -> Result<()> {
+        let context = self.context;
+        let frame = self.states[&pc].clone();
+        let instruction = context.instruction_at(pc).unwrap();
+
+        self.merge_into_handlers(pc, handlers, &frame)?;
+
+        match instruction.unwide() {
+            Instruction::Jsr(_) | Instruction::JsrW(_) => self.jsr(pc, instruction, frame)?,
+            Instruction::Ret(index) => self.ret(pc, *index, frame)?,
+            _ => {
+                let mut next = frame.clone();
+                execute(context, &mut next, pc, instruction).or_else(|e| context.error_at(pc, e.message))?;
+                // The handlers also have to accept the local variables just stored.
+                if next.locals != frame.locals {
+                    self.merge_into_handlers(pc, handlers, &next)?;
+                }
+                for target in instruction.branch_targets(pc) {
+                    self.merge_into(pc, target, next.clone())?;
+                }
+                if instruction.falls_through() {
+                    let next_pc = self.next_pc(pc)?;
+                    self.merge_into(pc, next_pc, next)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn next_pc(&self, pc: u16) -> Result<u16> {
+        let instructions = &self.context.instructions;
+        let index = instructions.binary_search_by_key(&pc, |(pc, _)| *pc).unwrap();
+        match instructions.get(index + 1) {
+            Some((next_pc, _)) => Ok(*next_pc),
+            None => self.context.error_at(pc, "Falling off the end of the code".to_string()),
+        }
+    }
+
+    fn merge_into(&mut self, from_pc: u16, target: u16, frame: Frame) -> Result<()> {
+        let hierarchy = self.context.hierarchy;
+        if let Some(hint) = self.hints.get(&target) {
+            check_frame_assignable(&frame, hint, hierarchy).or_else(|e| self.context.error_at(from_pc, format!("{} at {}", e.message, target)))?;
+            if self.states.insert(target, hint.clone()).is_none() {
+                self.changed.insert(target);
+            }
+            return Ok(());
+        }
+        let merged = match self.states.get(&target) {
+            None => frame,
+            Some(current) => {
+                if current.stack.len() != frame.stack.len() {
+                    return self.context.error_at(from_pc, format!("Inconsistent stack height {} != {} at {}", current.stack.len(), frame.stack.len(), target));
+                }
+                let mut stack = Vec::new();
+                for (a, b) in current.stack.iter().zip(frame.stack.iter()) {
+                    match merge(a, b, hierarchy)? {
+                        Some(merged) => stack.push(merged),
+                        None => return self.context.error_at(from_pc, format!("Mismatched stack types {} and {} at {}", a, b, target)),
+                    }
+                }
+                let mut locals = Vec::new();
+                for (a, b) in current.locals.iter().zip(frame.locals.iter()) {
+                    locals.push(merge(a, b, hierarchy)?.unwrap_or(VerificationType::Top));
+                }
+                // A long or double whose other half has become unusable is unusable as well.
+                for index in 0..locals.len() {
+                    if locals[index].is_category2() && locals.get(index + 1) != Some(&VerificationType::Top) {
+                        locals[index] = VerificationType::Top;
+                    }
+                }
+                let merged = Frame { locals, stack, flag_this_uninit: current.flag_this_uninit || frame.flag_this_uninit };
+                if &merged == current {
+                    return Ok(());
+                }
+                merged
+            }
+        };
+        self.states.insert(target, merged);
+        self.changed.insert(target);
+        Ok(())
+    }
+
+    // A handler starts with the local variables at the instruction and only the exception on the stack.
+    fn merge_into_handlers(&mut self, pc: u16, handlers: &[Handler], frame: &Frame) -> Result<()> {
+        for handler in handlers.iter().filter(|handler| handler.covers(pc)) {
+            let exception_frame = Frame {
+                locals: frame.locals.clone(),
+                stack: vec![handler.catch_type.clone()],
+                flag_this_uninit: frame.flag_this_uninit,
+            };
+            self.merge_into(pc, handler.handler_pc, exception_frame)?;
+        }
+        Ok(())
+    }
+
+    fn jsr(&mut self, pc: u16, instruction: &Instruction, frame: Frame) -> Result<()> {
+        let subroutine = instruction.branch_targets(pc)[0];
+        let return_address = VerificationType::ReturnAddress(subroutine);
+        if frame.locals.contains(&return_address) || frame.stack.contains(&return_address) {
+            return self.context.error_at(pc, format!("Recursive call to the subroutine at {}", subroutine));
+        }
+        let mut next = frame;
+        next.push(return_address, self.context.max_stack).or_else(|e| self.context.error_at(pc, e.message))?;
+        self.callers.entry(subroutine).or_default().insert(pc);
+        // The ret instructions seen so far now also return here.
+        if let Some(rets) = self.rets.get(&subroutine) {
+            self.changed.extend(rets.iter().filter(|ret| self.states.contains_key(ret)));
+        }
+        self.merge_into(pc, subroutine, next)
+    }
+
+    // 4.10.2.5. Exceptions and finally
+    // After ret, the local variables that the subroutine uses come from the ret,
+    // and the others from the jsr, since a subroutine can be called from places with different types.
+    fn ret(&mut self, pc: u16, index: u16, frame: Frame) -> Result<()> {
+        let subroutine = match frame.local(index).or_else(|e| self.context.error_at(pc, e.message))? {
+            VerificationType::ReturnAddress(subroutine) => *subroutine,
+            other => return self.context.error_at(pc, format!("Bad local variable type at index {}, expected: returnAddress, actual: {}", index, other)),
+        };
+        self.rets.entry(subroutine).or_default().insert(pc);
+        let used_locals = self.used_locals(subroutine);
+        let callers: Vec<u16> = self.callers.get(&subroutine).map_or(Vec::new(), |callers| callers.iter().copied().collect());
+        for caller in callers {
+            let caller_frame = &self.states[&caller];
+            let locals = (0..frame.locals.len())
+                .map(|i| if used_locals.contains(&(i as u16)) { frame.locals[i].clone() } else { caller_frame.locals[i].clone() })
+                .collect();
+            let returned = Frame { locals, stack: frame.stack.clone(), flag_this_uninit: frame.flag_this_uninit };
+            let next_pc = self.next_pc(caller)?;
+            self.merge_into(pc, next_pc, returned)?;
+        }
+        Ok(())
+    }
+
+    // The local variables read or written by the instructions reachable from the subroutine without going through ret.
+    fn used_locals(&self, subroutine: u16) -> HashSet<u16> {
+        let mut used = HashSet::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![subroutine];
+        while let Some(pc) = pending.pop() {
+            if !visited.insert(pc) {
+                continue;
+            }
+            let instruction = match self.context.instruction_at(pc) {
+                Some(instruction) => instruction,
+                None => continue,
+            };
+            if let Some((index, size)) = local_variable(instruction) {
+                used.insert(index);
+                // the second half of `wide lstore 65535` is beyond any max_locals, and the store itself is rejected
+                if let Some(next) = index.checked_add(1).filter(|_| size == 2) {
+                    used.insert(next);
+                }
+            }
+            if let Instruction::Ret(_) = instruction.unwide() {
+                continue;
+            }
+            pending.extend(instruction.branch_targets(pc));
+            if instruction.falls_through() || matches!(instruction, Instruction::Jsr(_) | Instruction::JsrW(_)) {
+                if let Ok(next_pc) = self.next_pc(pc) {
+                    pending.push(next_pc);
+                }
+            }
+        }
+        used
+    }
+}
+
+// (index, size) of the local variable an instruction loads or stores.
+fn local_variable(instruction: &Instruction) -> Option<(u16, u16)> {
+    use Instruction::*;
+    match instruction.unwide() {
+        Iload(index) | Fload(index) | Aload(index) | Istore(index) | Fstore(index) | Astore(index) | Iinc(index, _) | Ret(index) => Some((*index, 1)),
+        Lload(index) | Dload(index) | Lstore(index) | Dstore(index) => Some((*index, 2)),
+        Iload0 | Fload0 | Aload0 | Istore0 | Fstore0 | Astore0 => Some((0, 1)),
+        Iload1 | Fload1 | Aload1 | Istore1 | Fstore1 | Astore1 => Some((1, 1)),
+        Iload2 | Fload2 | Aload2 | Istore2 | Fstore2 | Astore2 => Some((2, 1)),
+        Iload3 | Fload3 | Aload3 | Istore3 | Fstore3 | Astore3 => Some((3, 1)),
+        Lload0 | Dload0 | Lstore0 | Dstore0 => Some((0, 2)),
+        Lload1 | Dload1 | Lstore1 | Dstore1 => Some((1, 2)),
+        Lload2 | Dload2 | Lstore2 | Dstore2 => Some((2, 2)),
+        Lload3 | Dload3 | Lstore3 | Dstore3 => Some((3, 2)),
+        _ => None,
+    }
+}
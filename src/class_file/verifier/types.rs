@@ -0,0 +1,340 @@
+use std::fmt;
+
+use crate::class_file::class_hierarchy::ClassHierarchy;
+use crate::class_file::descriptor::{FieldType, parse_field_type};
+use crate::class_file::error::Result;
+
+use super::error;
+
+// 4.10.1.2. Verification Type System
+// https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.10.1.2
+// Class types hold the internal form of the class name (`java/lang/String`),
+// and array types hold the descriptor (`[I`, `[Ljava/lang/String;`), as in CONSTANT_Class_info.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VerificationType {
+    Top,
+    Integer,
+    Float,
+    Long,
+    Double,
+    Null,
+    UninitializedThis,
+    Uninitialized(u16 /* pc of the `new` instruction */),
+    Reference(String),
+    // Only in the type inference (4.10.2.5), where jsr pushes the address to return to.
+    ReturnAddress(u16 /* pc of the subroutine */),
+}
+
+impl VerificationType {
+    pub fn object() -> VerificationType {
+        VerificationType::Reference("java/lang/Object".to_string())
+    }
+
+    pub fn class(name: &str) -> VerificationType {
+        VerificationType::Reference(name.to_string())
+    }
+
+    pub fn from_field_type(field_type: &FieldType) -> VerificationType {
+        match field_type {
+            FieldType::Byte | FieldType::Char | FieldType::Short | FieldType::Boolean | FieldType::Int => VerificationType::Integer,
+            FieldType::Float => VerificationType::Float,
+            FieldType::Long => VerificationType::Long,
+            FieldType::Double => VerificationType::Double,
+            FieldType::Class { name } => VerificationType::Reference(name.clone()),
+            FieldType::Array { .. } => VerificationType::Reference(field_type.descriptor()),
+        }
+    }
+
+    /// Long and double take two local variables, and count twice towards max_stack.
+    pub fn is_category2(&self) -> bool {
+        matches!(self, VerificationType::Long | VerificationType::Double)
+    }
+
+    pub fn size(&self) -> usize {
+        if self.is_category2() { 2 } else { 1 }
+    }
+
+    pub fn is_reference(&self) -> bool {
+        matches!(
+            self,
+            VerificationType::Null | VerificationType::UninitializedThis | VerificationType::Uninitialized(_) | VerificationType::Reference(_)
+        )
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self, VerificationType::Reference(name) if name.starts_with('['))
+    }
+
+    /// The type of the elements of an array type, e.g. `Integer` for `[I` and `java/lang/String` for `[Ljava/lang/String;`.
+    pub fn component_type(&self) -> Result<VerificationType> {
+        match self {
+            VerificationType::Reference(name) if name.starts_with('[') => {
+                Ok(VerificationType::from_field_type(&parse_field_type(&name[1..])?))
+            }
+            _ => error(format!("not an array type: {}", self)),
+        }
+    }
+
+    /// The array type whose elements are of this type.
+    pub fn array_of(&self) -> Result<VerificationType> {
+        match self {
+            VerificationType::Reference(name) if name.starts_with('[') => Ok(VerificationType::Reference(format!("[{}", name))),
+            VerificationType::Reference(name) => Ok(VerificationType::Reference(format!("[L{};", name))),
+            _ => error(format!("cannot make an array of {}", self)),
+        }
+    }
+}
+
+impl fmt::Display for VerificationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationType::Top => write!(f, "top"),
+            VerificationType::Integer => write!(f, "int"),
+            VerificationType::Float => write!(f, "float"),
+            VerificationType::Long => write!(f, "long"),
+            VerificationType::Double => write!(f, "double"),
+            VerificationType::Null => write!(f, "null"),
+            VerificationType::UninitializedThis => write!(f, "uninitializedThis"),
+            VerificationType::Uninitialized(pc) => write!(f, "uninitialized({})", pc),
+            VerificationType::Reference(name) => write!(f, "{}", name),
+            VerificationType::ReturnAddress(pc) => write!(f, "returnAddress({})", pc),
+        }
+    }
+}
+
+// isAssignable of 4.10.1.2
+pub fn is_assignable(from: &VerificationType, to: &VerificationType, hierarchy: &dyn ClassHierarchy) -> Result<bool> {
+    if from == to || *to == VerificationType::Top {
+        return Ok(true);
+    }
+    match (from, to) {
+        (VerificationType::Null, VerificationType::Reference(_)) => Ok(true),
+        (VerificationType::Reference(from), VerificationType::Reference(to)) => is_java_assignable(from, to, hierarchy),
+        _ => Ok(false),
+    }
+}
+
+// isJavaAssignable of 4.10.1.2
+// Interfaces are treated like java/lang/Object; the check is deferred to run time (invokeinterface).
+fn is_java_assignable(from: &str, to: &str, hierarchy: &dyn ClassHierarchy) -> Result<bool> {
+    if from == to || to == "java/lang/Object" {
+        return Ok(true);
+    }
+    match (from.strip_prefix('['), to.strip_prefix('[')) {
+        (Some(from_component), Some(to_component)) => {
+            match (reference_component(from_component), reference_component(to_component)) {
+                (Some(from), Some(to)) => is_java_assignable(from, to, hierarchy),
+                _ => Ok(from_component == to_component),
+            }
+        }
+        (Some(_), None) => Ok(to == "java/lang/Cloneable" || to == "java/io/Serializable"),
+        (None, Some(_)) => Ok(false),
+        (None, None) => {
+            if hierarchy.is_interface(to)? {
+                return Ok(true);
+            }
+            is_java_subclass_of(from, to, hierarchy)
+        }
+    }
+}
+
+fn is_java_subclass_of(from: &str, to: &str, hierarchy: &dyn ClassHierarchy) -> Result<bool> {
+    let mut current = hierarchy.super_class(from)?;
+    while let Some(name) = current {
+        if name == to {
+            return Ok(true);
+        }
+        current = hierarchy.super_class(&name)?;
+    }
+    Ok(false)
+}
+
+// `Ljava/lang/String;` -> `java/lang/String`, `[I` -> `[I`, `I` -> None
+fn reference_component(descriptor: &str) -> Option<&str> {
+    if descriptor.starts_with('[') {
+        Some(descriptor)
+    } else {
+        descriptor.strip_prefix('L').and_then(|name| name.strip_suffix(';'))
+    }
+}
+
+// 4.10.1.4. Stack Map Frame Representation
+// Unlike the stack map frames in the class file, `locals` always has max_locals entries, and
+// a long or double local is followed by a `Top`. `stack` has one entry per value regardless of its size.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Frame {
+    pub locals: Vec<VerificationType>,
+    pub stack: Vec<VerificationType>,
+    pub flag_this_uninit: bool,
+}
+
+impl Frame {
+    pub fn stack_size(&self) -> usize {
+        self.stack.iter().map(|t| t.size()).sum()
+    }
+
+    pub fn push(&mut self, verification_type: VerificationType, max_stack: u16) -> Result<()> {
+        if self.stack_size() + verification_type.size() > max_stack as usize {
+            return error(format!("Exceeded max stack size {}", max_stack));
+        }
+        self.stack.push(verification_type);
+        Ok(())
+    }
+
+    /// Pops a value assignable to `expected`, and returns the actual type.
+    pub fn pop(&mut self, expected: &VerificationType, hierarchy: &dyn ClassHierarchy) -> Result<VerificationType> {
+        let actual = self.pop_any()?;
+        if !is_assignable(&actual, expected, hierarchy)? {
+            return error(format!("Bad type on operand stack, expected: {}, actual: {}", expected, actual));
+        }
+        Ok(actual)
+    }
+
+    pub fn pop_any(&mut self) -> Result<VerificationType> {
+        match self.stack.pop() {
+            Some(verification_type) => Ok(verification_type),
+            None => error("Attempt to pop empty stack".to_string()),
+        }
+    }
+
+    pub fn pop_reference(&mut self) -> Result<VerificationType> {
+        let actual = self.pop_any()?;
+        if !actual.is_reference() {
+            return error(format!("Bad type on operand stack, expected: reference, actual: {}", actual));
+        }
+        Ok(actual)
+    }
+
+    pub fn pop_category1(&mut self) -> Result<VerificationType> {
+        let actual = self.pop_any()?;
+        if actual.is_category2() {
+            return error(format!("Bad type on operand stack, expected: category 1 value, actual: {}", actual));
+        }
+        Ok(actual)
+    }
+
+    pub fn load(&self, index: u16, expected: &VerificationType, hierarchy: &dyn ClassHierarchy) -> Result<VerificationType> {
+        let actual = self.local(index)?.clone();
+        if expected.is_category2() && self.local(index + 1)? != &VerificationType::Top {
+            return error(format!("Bad local variable type at index {}", index + 1));
+        }
+        if !is_assignable(&actual, expected, hierarchy)? {
+            return error(format!("Bad local variable type at index {}, expected: {}, actual: {}", index, expected, actual));
+        }
+        Ok(actual)
+    }
+
+    pub fn local(&self, index: u16) -> Result<&VerificationType> {
+        match self.locals.get(index as usize) {
+            Some(verification_type) => Ok(verification_type),
+            None => error(format!("Local variable index {} is out of max_locals {}", index, self.locals.len())),
+        }
+    }
+
+    pub fn store(&mut self, index: u16, verification_type: VerificationType) -> Result<()> {
+        let index = index as usize;
+        let size = verification_type.size();
+        if index + size > self.locals.len() {
+            return error(format!("Local variable index {} is out of max_locals {}", index + size - 1, self.locals.len()));
+        }
+        // Overwriting the second half of a long or double invalidates the first half.
+        if index > 0 && self.locals[index - 1].is_category2() {
+            self.locals[index - 1] = VerificationType::Top;
+        }
+        self.locals[index] = verification_type;
+        if size == 2 {
+            self.locals[index + 1] = VerificationType::Top;
+        }
+        Ok(())
+    }
+
+    /// Replaces all occurrences of an uninitialized type after its `<init>` is called.
+    pub fn initialize(&mut self, uninitialized: &VerificationType, initialized: &VerificationType) {
+        for verification_type in self.locals.iter_mut().chain(self.stack.iter_mut()) {
+            if verification_type == uninitialized {
+                *verification_type = initialized.clone();
+            }
+        }
+    }
+}
+
+// Long and double take two local variables, and the rest up to max_locals are Top.
+pub fn expand_locals(locals: &[VerificationType], max_locals: u16) -> Result<Vec<VerificationType>> {
+    let mut expanded = Vec::new();
+    for local in locals {
+        expanded.push(local.clone());
+        if local.is_category2() {
+            expanded.push(VerificationType::Top);
+        }
+    }
+    if expanded.len() > max_locals as usize {
+        return error(format!("Local variable count {} exceeds max_locals {}", expanded.len(), max_locals));
+    }
+    expanded.resize(max_locals as usize, VerificationType::Top);
+    Ok(expanded)
+}
+
+// 4.10.2.2. The Bytecode Verifier, merging the types at a join point.
+// Returns None if the types have nothing in common, which makes a local variable unusable.
+pub fn merge(a: &VerificationType, b: &VerificationType, hierarchy: &dyn ClassHierarchy) -> Result<Option<VerificationType>> {
+    if a == b {
+        return Ok(Some(a.clone()));
+    }
+    match (a, b) {
+        (VerificationType::Null, VerificationType::Reference(_)) => Ok(Some(b.clone())),
+        (VerificationType::Reference(_), VerificationType::Null) => Ok(Some(a.clone())),
+        (VerificationType::Reference(a), VerificationType::Reference(b)) => Ok(Some(VerificationType::Reference(merge_references(a, b, hierarchy)?))),
+        _ => Ok(None),
+    }
+}
+
+// The first common superclass. Interfaces are merged into java/lang/Object.
+fn merge_references(a: &str, b: &str, hierarchy: &dyn ClassHierarchy) -> Result<String> {
+    match (a.strip_prefix('['), b.strip_prefix('[')) {
+        (Some(a_component), Some(b_component)) => {
+            match (reference_component(a_component), reference_component(b_component)) {
+                (Some(a_component), Some(b_component)) => {
+                    let merged = VerificationType::Reference(merge_references(a_component, b_component, hierarchy)?);
+                    Ok(merged.array_of()?.to_string())
+                }
+                _ => Ok("java/lang/Object".to_string()),
+            }
+        }
+        (None, None) => {
+            let mut a_superclasses = vec![a.to_string()];
+            while let Some(name) = hierarchy.super_class(a_superclasses.last().unwrap())? {
+                a_superclasses.push(name);
+            }
+            let mut current = Some(b.to_string());
+            while let Some(name) = current {
+                if a_superclasses.contains(&name) {
+                    return Ok(name);
+                }
+                current = hierarchy.super_class(&name)?;
+            }
+            Ok("java/lang/Object".to_string())
+        }
+        _ => Ok("java/lang/Object".to_string()),
+    }
+}
+
+// frameIsAssignable of 4.10.1.4
+pub fn check_frame_assignable(from: &Frame, to: &Frame, hierarchy: &dyn ClassHierarchy) -> Result<()> {
+    if from.stack.len() != to.stack.len() {
+        return error(format!("Inconsistent stack height {} != {}", from.stack.len(), to.stack.len()));
+    }
+    for (index, (from_type, to_type)) in from.locals.iter().zip(to.locals.iter()).enumerate() {
+        if !is_assignable(from_type, to_type, hierarchy)? {
+            return error(format!("Type {} (current frame, locals[{}]) is not assignable to {} (stack map, locals[{}])", from_type, index, to_type, index));
+        }
+    }
+    for (index, (from_type, to_type)) in from.stack.iter().zip(to.stack.iter()).enumerate() {
+        if !is_assignable(from_type, to_type, hierarchy)? {
+            return error(format!("Type {} (current frame, stack[{}]) is not assignable to {} (stack map, stack[{}])", from_type, index, to_type, index));
+        }
+    }
+    if from.flag_this_uninit && !to.flag_this_uninit {
+        return error("Current frame's flags are not assignable to stack map frame's".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,455 @@
+use crate::class_file::structure::root::*;
+use crate::class_file::structure::constant_pool::*;
+use crate::class_file::structure::attribute::*;
+use crate::class_file::access_flags::AccessFlags;
+
+/// The bytes of a class file, the reverse of `read_class_file`.
+/// Counts and lengths are written as they are in the structure, so they have to be consistent with the items.
+pub fn write_class_file(class_file: &ClassFile) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::new();
+    class_file.write(&mut bytes);
+    bytes
+}
+
+/// The bytes of an attribute including its name index and length.
+pub fn write_attribute(attribute: &Attribute) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::new();
+    attribute.write(&mut bytes);
+    bytes
+}
+
+trait Writer {
+    fn write(&self, bytes: &mut Vec<u8>);
+}
+
+impl<const N: usize> Writer for [u8; N] {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(self);
+    }
+}
+
+impl Writer for u8 {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(*self);
+    }
+}
+
+impl Writer for u16 {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.to_be_bytes().write(bytes);
+    }
+}
+
+impl Writer for u32 {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.to_be_bytes().write(bytes);
+    }
+}
+
+impl<T> Writer for CpIndex<T> {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.index().write(bytes);
+    }
+}
+
+impl<T: AccessFlags> Writer for T {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.bits().write(bytes);
+    }
+}
+
+// Only the items. The count is a field of its own in the structures.
+impl<T> Writer for Vec<T> where T: Writer {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        for item in self {
+            item.write(bytes);
+        }
+    }
+}
+
+impl Writer for ClassFile {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.magic.write(bytes);
+        self.minor_version.write(bytes);
+        self.major_version.write(bytes);
+        self.constant_pool_count.write(bytes);
+        self.constant_pool.write(bytes);
+        self.access_flags.write(bytes);
+        self.this_class.write(bytes);
+        self.super_class.write(bytes);
+        self.interfaces_count.write(bytes);
+        self.interfaces.write(bytes);
+        self.fields_count.write(bytes);
+        self.fields.write(bytes);
+        self.methods_count.write(bytes);
+        self.methods.write(bytes);
+        self.attributes_count.write(bytes);
+        self.attributes.write(bytes);
+    }
+}
+
+impl Writer for CpInfo {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        match self {
+            CpInfo::Utf8(info) => {
+                info.tag.write(bytes);
+                info.length.write(bytes);
+                info.bytes.write(bytes);
+            }
+            CpInfo::Integer(info) => {
+                info.tag.write(bytes);
+                info.bytes.write(bytes);
+            }
+            CpInfo::Float(info) => {
+                info.tag.write(bytes);
+                info.bytes.write(bytes);
+            }
+            CpInfo::Long(info) => {
+                info.tag.write(bytes);
+                info.high_bytes.write(bytes);
+                info.low_bytes.write(bytes);
+            }
+            CpInfo::Double(info) => {
+                info.tag.write(bytes);
+                info.high_bytes.write(bytes);
+                info.low_bytes.write(bytes);
+            }
+            CpInfo::Class(info) => {
+                info.tag.write(bytes);
+                info.name_index.write(bytes);
+            }
+            CpInfo::String(info) => {
+                info.tag.write(bytes);
+                info.string_index.write(bytes);
+            }
+            CpInfo::Fieldref(info) => {
+                info.tag.write(bytes);
+                info.class_index.write(bytes);
+                info.name_and_type_index.write(bytes);
+            }
+            CpInfo::Methodref(info) => {
+                info.tag.write(bytes);
+                info.class_index.write(bytes);
+                info.name_and_type_index.write(bytes);
+            }
+            CpInfo::InterfaceMethodref(info) => {
+                info.tag.write(bytes);
+                info.class_index.write(bytes);
+                info.name_and_type_index.write(bytes);
+            }
+            CpInfo::NameAndType(info) => {
+                info.tag.write(bytes);
+                info.name_index.write(bytes);
+                info.descriptor_index.write(bytes);
+            }
+            CpInfo::MethodHandle(info) => {
+                info.tag.write(bytes);
+                info.reference_kind.write(bytes);
+                info.reference_index.write(bytes);
+            }
+            CpInfo::MethodType(info) => {
+                info.tag.write(bytes);
+                info.descriptor_index.write(bytes);
+            }
+            CpInfo::Dynamic(info) => {
+                info.tag.write(bytes);
+                info.bootstrap_method_attr_index.write(bytes);
+                info.name_and_type_index.write(bytes);
+            }
+            CpInfo::InvokeDynamic(info) => {
+                info.tag.write(bytes);
+                info.bootstrap_method_attr_index.write(bytes);
+                info.name_and_type_index.write(bytes);
+            }
+            CpInfo::Module(info) => {
+                info.tag.write(bytes);
+                info.name_index.write(bytes);
+            }
+            CpInfo::Package(info) => {
+                info.tag.write(bytes);
+                info.name_index.write(bytes);
+            }
+            // It has no bytes of its own, the entry before it takes up the two indexes.
+            CpInfo::Unusable => {}
+        }
+    }
+}
+
+impl Writer for FieldsInfo {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.access_flags.write(bytes);
+        self.name_index.write(bytes);
+        self.descriptor_index.write(bytes);
+        self.attributes_count.write(bytes);
+        self.attributes.write(bytes);
+    }
+}
+
+impl Writer for MethodInfo {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.access_flags.write(bytes);
+        self.name_index.write(bytes);
+        self.descriptor_index.write(bytes);
+        self.attributes_count.write(bytes);
+        self.attributes.write(bytes);
+    }
+}
+
+impl Writer for Attribute {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Attribute::General(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.info.write(bytes);
+            }
+            Attribute::ConstantValue(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.constantvalue_index.write(bytes);
+            }
+            Attribute::Code(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.max_stack.write(bytes);
+                attribute.max_locals.write(bytes);
+                attribute.code_length.write(bytes);
+                attribute.code.write(bytes);
+                attribute.exception_table_length.write(bytes);
+                attribute.exception_table.write(bytes);
+                attribute.attributes_count.write(bytes);
+                attribute.attributes.write(bytes);
+            }
+            Attribute::StackMapTable(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.number_of_entries.write(bytes);
+                attribute.entries.write(bytes);
+            }
+            Attribute::Exceptions(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.number_of_exceptions.write(bytes);
+                attribute.exception_index_table.write(bytes);
+            }
+            Attribute::InnerClasses(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.number_of_classes.write(bytes);
+                attribute.classes.write(bytes);
+            }
+            Attribute::EnclosingMethod(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.class_index.write(bytes);
+                attribute.method_index.write(bytes);
+            }
+            Attribute::Signature(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.signature_index.write(bytes);
+            }
+            Attribute::LocalVariableTable(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.local_variable_table_length.write(bytes);
+                attribute.local_variable_table.write(bytes);
+            }
+            Attribute::LocalVariableTypeTable(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.local_variable_type_table_length.write(bytes);
+                attribute.local_variable_type_table.write(bytes);
+            }
+            Attribute::BootstrapMethods(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.num_bootstrap_methods.write(bytes);
+                attribute.bootstrap_methods.write(bytes);
+            }
+            Attribute::MethodParameters(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.parameters_count.write(bytes);
+                attribute.parameters.write(bytes);
+            }
+            Attribute::Module(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.module_name_index.write(bytes);
+                attribute.module_flags.write(bytes);
+                attribute.module_version_index.write(bytes);
+                attribute.requires_count.write(bytes);
+                attribute.requires.write(bytes);
+                attribute.exports_count.write(bytes);
+                attribute.exports.write(bytes);
+                attribute.opens_count.write(bytes);
+                attribute.opens.write(bytes);
+                attribute.uses_count.write(bytes);
+                attribute.uses_index.write(bytes);
+                attribute.provides_count.write(bytes);
+                attribute.provides.write(bytes);
+            }
+            Attribute::NestHost(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.host_class_index.write(bytes);
+            }
+            Attribute::NestMembers(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.number_of_classes.write(bytes);
+                attribute.classes.write(bytes);
+            }
+            Attribute::PermittedSubclasses(attribute) => {
+                attribute.attribute_name_index.write(bytes);
+                attribute.attribute_length.write(bytes);
+                attribute.number_of_classes.write(bytes);
+                attribute.classes.write(bytes);
+            }
+        }
+    }
+}
+
+impl Writer for StackMapFrame {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        match self {
+            StackMapFrame::SameFrame { frame_type } => frame_type.write(bytes),
+            StackMapFrame::SameLocals1StackItemFrame { frame_type, stack } => {
+                frame_type.write(bytes);
+                stack.write(bytes);
+            }
+            StackMapFrame::SameLocals1StackItemFrameExtended { frame_type, offset_delta, stack } => {
+                frame_type.write(bytes);
+                offset_delta.write(bytes);
+                stack.write(bytes);
+            }
+            StackMapFrame::ChopFrame { frame_type, offset_delta } | StackMapFrame::SameFrameExtended { frame_type, offset_delta } => {
+                frame_type.write(bytes);
+                offset_delta.write(bytes);
+            }
+            StackMapFrame::AppendFrame { frame_type, offset_delta, locals } => {
+                frame_type.write(bytes);
+                offset_delta.write(bytes);
+                locals.write(bytes);
+            }
+            StackMapFrame::FullFrame { frame_type, offset_delta, number_of_locals, locals, number_of_stack_items, stack } => {
+                frame_type.write(bytes);
+                offset_delta.write(bytes);
+                number_of_locals.write(bytes);
+                locals.write(bytes);
+                number_of_stack_items.write(bytes);
+                stack.write(bytes);
+            }
+        }
+    }
+}
+
+impl Writer for VerificationTypeInfo {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        match self {
+            VerificationTypeInfo::TopVariableInfo { tag } |
+            VerificationTypeInfo::IntegerVariableInfo { tag } |
+            VerificationTypeInfo::FloatVariableInfo { tag } |
+            VerificationTypeInfo::DoubleVariableInfo { tag } |
+            VerificationTypeInfo::LongVariableInfo { tag } |
+            VerificationTypeInfo::NullVariableInfo { tag } |
+            VerificationTypeInfo::UninitializedThisVariableInfo { tag } => tag.write(bytes),
+            VerificationTypeInfo::ObjectVariableInfo { tag, cpool_index } => {
+                tag.write(bytes);
+                cpool_index.write(bytes);
+            }
+            VerificationTypeInfo::UninitializedVariableInfo { tag, offset } => {
+                tag.write(bytes);
+                offset.write(bytes);
+            }
+        }
+    }
+}
+
+impl Writer for ExceptionTable {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.start_pc.write(bytes);
+        self.end_pc.write(bytes);
+        self.handler_pc.write(bytes);
+        self.catch_type.write(bytes);
+    }
+}
+
+impl Writer for InnerClassesAttributeClass {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.inner_class_info_index.write(bytes);
+        self.outer_class_info_index.write(bytes);
+        self.inner_name_index.write(bytes);
+        self.inner_class_access_flags.write(bytes);
+    }
+}
+
+impl Writer for BootstrapMethod {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.bootstrap_method_ref.write(bytes);
+        self.num_bootstrap_arguments.write(bytes);
+        self.bootstrap_arguments.write(bytes);
+    }
+}
+
+impl Writer for Parameter {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.name_index.write(bytes);
+        self.access_flags.write(bytes);
+    }
+}
+
+impl Writer for LocalVariable {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.start_pc.write(bytes);
+        self.length.write(bytes);
+        self.name_index.write(bytes);
+        self.descriptor_index.write(bytes);
+        self.index.write(bytes);
+    }
+}
+
+impl Writer for LocalVariableType {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.start_pc.write(bytes);
+        self.length.write(bytes);
+        self.name_index.write(bytes);
+        self.signature_index.write(bytes);
+        self.index.write(bytes);
+    }
+}
+
+impl Writer for Require {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.requires_index.write(bytes);
+        self.requires_flags.write(bytes);
+        self.requires_version_index.write(bytes);
+    }
+}
+
+impl Writer for Export {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.exports_index.write(bytes);
+        self.exports_flags.write(bytes);
+        self.exports_to_count.write(bytes);
+        self.exports_to_index.write(bytes);
+    }
+}
+
+impl Writer for Open {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.opens_index.write(bytes);
+        self.opens_flags.write(bytes);
+        self.opens_to_count.write(bytes);
+        self.opens_to_index.write(bytes);
+    }
+}
+
+impl Writer for Provide {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        self.provides_index.write(bytes);
+        self.provides_with_count.write(bytes);
+        self.provides_with_index.write(bytes);
+    }
+}
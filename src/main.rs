@@ -1,30 +1,112 @@
 pub mod class_file;
 
+use class_file::assembly;
+use class_file::decompiler;
 use class_file::error::{Result, error};
 use class_file::reader;
+use class_file::writer;
+use class_file::structure::root::ClassFile;
+use class_file::structure_utils::code_string::CodeString;
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Read;
 use std::path::Path;
+use std::process;
+
+const USAGE: &str = "usage: rust-jvm inspect [--json] YourClass.class\n       rust-jvm decompile YourClass.class\n       rust-jvm code-string YourClass.class\n       rust-jvm disassemble YourClass.class\n       rust-jvm assemble YourClass.txt YourClass.class";
 
 fn main() {
-    let str = match run() {
-        Ok(str) => str,
-        Err(e) => e.message
-    };
-    println!("{}", str)
+    match run() {
+        Ok(str) => println!("{}", str),
+        Err(e) => {
+            eprintln!("{}", e.message);
+            process::exit(1)
+        }
+    }
 }
 
 fn run() -> Result<String> {
     let args: Vec<String> = env::args().collect();
-    let file_name = match args.len() {
-        2 => Ok(args[1].to_owned()),
-        _ => error("usage: cargo run YourClass.class".to_string()),
-    }?;
-    let path = Path::new(&file_name);
-    let mut file = File::open(&path).or_else(|e| error(e.to_string()))?;
+    match args.get(1).map(|x| x.as_str()) {
+        Some("inspect") => match args.len() {
+            3 => inspect(&args[2]),
+            4 if args[2] == "--json" => inspect_json(&args[3]),
+            _ => error(USAGE.to_string()),
+        },
+        Some("decompile") => match args.len() {
+            3 => decompile(&args[2]),
+            _ => error(USAGE.to_string()),
+        },
+        Some("code-string") => match args.len() {
+            3 => code_string(&args[2]),
+            _ => error(USAGE.to_string()),
+        },
+        Some("disassemble") => match args.len() {
+            3 => disassemble(&args[2]),
+            _ => error(USAGE.to_string()),
+        },
+        Some("assemble") => match args.len() {
+            4 => assemble(&args[2], &args[3]),
+            _ => error(USAGE.to_string()),
+        },
+        // `rust-jvm [-cp path] Main args...` needs an interpreter, which this JVM does not have yet.
+        Some(_) => error(format!("Running a main class is not supported yet.\n{}", USAGE)),
+        None => error(USAGE.to_string()),
+    }
+}
+
+fn inspect(file_name: &str) -> Result<String> {
+    let class_file = read_class_file(Path::new(file_name))?;
+    Ok(format!("{}", class_file))
+}
+
+#[cfg(feature = "serde")]
+fn inspect_json(file_name: &str) -> Result<String> {
+    let class_file = read_class_file(Path::new(file_name))?;
+    serde_json::to_string_pretty(&class_file).or_else(|e| error(e.to_string()))
+}
+
+#[cfg(not(feature = "serde"))]
+fn inspect_json(_file_name: &str) -> Result<String> {
+    error("--json needs the serde feature: cargo run --features serde".to_string())
+}
+
+// Member classes are looked up next to the class file, e.g. `Outer$Inner.class` for `a/b/Outer$Inner`.
+fn decompile(file_name: &str) -> Result<String> {
+    let path = Path::new(file_name);
+    let class_file = read_class_file(path)?;
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let find_class = |class_name: &str| {
+        let simple_name = class_name.rsplit('/').next().unwrap_or(class_name);
+        read_class_file(&directory.join(format!("{}.class", simple_name))).ok()
+    };
+    decompiler::decompile_class(&class_file, &find_class)
+}
+
+// A Rust expression of the class file, e.g. for the expected value of a test.
+fn code_string(file_name: &str) -> Result<String> {
+    let class_file = read_class_file(Path::new(file_name))?;
+    Ok(class_file.code_string())
+}
+
+fn disassemble(file_name: &str) -> Result<String> {
+    let class_file = read_class_file(Path::new(file_name))?;
+    Ok(assembly::disassemble(&class_file))
+}
+
+// The assembled class file is read again, so that what is written is a class file that the reader accepts.
+fn assemble(file_name: &str, output_file_name: &str) -> Result<String> {
+    let text = fs::read_to_string(file_name).or_else(|e| error(e.to_string()))?;
+    let class_file = assembly::assemble(&text)?;
+    let bytes = writer::write_class_file(&class_file);
+    reader::read_class_file(bytes.clone())?;
+    fs::write(output_file_name, bytes).or_else(|e| error(e.to_string()))?;
+    Ok(format!("Wrote {}", output_file_name))
+}
+
+fn read_class_file(path: &Path) -> Result<ClassFile> {
+    let mut file = File::open(path).or_else(|e| error(e.to_string()))?;
     let mut buffer = Vec::new();
     file.read_to_end(&mut buffer).or_else(|e| error(e.to_string()))?;
-    let class_file = reader::read_class_file(buffer)?;
-    Ok(format!("{}", class_file))
-}
\ No newline at end of file
+    reader::read_class_file(buffer)
+}